@@ -0,0 +1,282 @@
+//! Command-line arguments.
+
+use {
+    clap::{Parser, Subcommand, ValueEnum},
+    paladin_governance_program::state::ProposalVoteElection,
+    paladin_governance_report::Format,
+    solana_sdk::{instruction::AccountMeta, pubkey::Pubkey},
+    std::{path::PathBuf, str::FromStr},
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "paladin-governance", version, about)]
+pub struct Cli {
+    /// JSON RPC URL of the cluster.
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://localhost:8899"
+    )]
+    pub url: String,
+    /// Keypair file of the fee payer, which also acts as the stake authority.
+    ///
+    /// Defaults to `~/.config/solana/id.json`.
+    #[arg(long, short = 'k', global = true)]
+    pub keypair: Option<PathBuf>,
+    /// Output format.
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Display)]
+    pub output: OutputFormat,
+    /// Print the signed transactions, serialized as base64, instead of
+    /// sending them.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Display,
+    Json,
+    JsonCompact,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Initialize the governance config for a stake config.
+    ///
+    /// Must be run by the stake config authority or the program's upgrade
+    /// authority.
+    InitGovernance {
+        /// The Paladin stake config account.
+        #[arg(long)]
+        stake_config: Pubkey,
+        /// The governance config ID, distinguishing configs of the same stake
+        /// config.
+        #[arg(long, default_value_t = 0)]
+        governance_id: u64,
+        #[arg(long)]
+        cooldown_period_seconds: u64,
+        /// Minimum quorum, in 1e9 scaled format.
+        #[arg(long)]
+        proposal_minimum_quorum: u32,
+        /// Pass threshold, in 1e9 scaled format.
+        #[arg(long)]
+        proposal_pass_threshold: u32,
+        #[arg(long)]
+        voting_period_seconds: u64,
+        #[arg(long)]
+        stake_per_proposal: u64,
+        /// Seconds after initialization before proposals can be created.
+        #[arg(long, default_value_t = 0)]
+        cooldown_seconds: u64,
+    },
+    /// Initialize the author account of the stake authority.
+    InitAuthor,
+    /// Create a proposal.
+    CreateProposal {
+        /// The governance config account.
+        #[arg(long)]
+        governance: Pubkey,
+        /// The stake authority's Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        /// Keypair file for the new proposal account. A new keypair is
+        /// generated if omitted.
+        #[arg(long)]
+        proposal_keypair: Option<PathBuf>,
+    },
+    /// Push an instruction to a draft proposal.
+    Push {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The program to invoke.
+        #[arg(long)]
+        program_id: Pubkey,
+        /// An account to pass to the program, as `PUBKEY[:s][:w]` for signer
+        /// and writable accounts. May be repeated.
+        #[arg(long = "account")]
+        accounts: Vec<AccountMetaArg>,
+        /// The instruction data, in hex.
+        #[arg(long, default_value = "", value_parser = parse_hex)]
+        data: HexData,
+    },
+    /// Begin voting on a draft proposal.
+    BeginVoting {
+        #[arg(long)]
+        proposal: Pubkey,
+    },
+    /// Vote on a proposal.
+    Vote {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The stake authority's Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        #[arg(long, value_enum)]
+        election: Election,
+    },
+    /// Switch an existing vote on a proposal.
+    SwitchVote {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The stake authority's Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+        #[arg(long, value_enum)]
+        election: Election,
+    },
+    /// Finish voting on a proposal whose voting or cooldown period has ended.
+    Finish {
+        #[arg(long)]
+        proposal: Pubkey,
+    },
+    /// Execute the instructions of an accepted proposal.
+    Execute {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// Execute only the instruction at this index. Every instruction not
+        /// yet executed is executed, in order, if omitted.
+        #[arg(long)]
+        index: Option<u32>,
+    },
+    /// Save the accounts a proposal references as JSON account dumps, to
+    /// simulate it offline.
+    Snapshot {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The directory to write the account dumps to.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Simulate executing a proposal against a snapshot of its accounts, as
+    /// if it had been accepted.
+    Simulate {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// A directory of JSON account dumps, as written by `snapshot` or
+        /// `solana account --output json`.
+        #[arg(long)]
+        snapshot: PathBuf,
+        /// The option to execute, for multiple-choice proposals. Defaults to
+        /// the stored winning option.
+        #[arg(long)]
+        option: Option<u8>,
+    },
+    /// Delete a vote on an inactive proposal, refunding its rent.
+    DeleteVote {
+        #[arg(long)]
+        proposal: Pubkey,
+        /// The stake authority's Paladin stake account.
+        #[arg(long)]
+        stake: Pubkey,
+    },
+    /// Show a governance account.
+    Show {
+        address: Pubkey,
+        /// The governance config whose treasury is flagged in decoded
+        /// proposal instructions.
+        #[arg(long)]
+        governance: Option<Pubkey>,
+    },
+    /// List proposals.
+    List {
+        /// Only list proposals of this governance config.
+        #[arg(long)]
+        governance: Option<Pubkey>,
+    },
+    /// Render a status report of proposals, with their turnout, top voters
+    /// and decoded instructions.
+    Report {
+        /// A proposal to report. May be repeated. Every proposal is reported
+        /// if omitted.
+        #[arg(long = "proposal")]
+        proposals: Vec<Pubkey>,
+        /// Only report proposals of this governance config.
+        #[arg(long)]
+        governance: Option<Pubkey>,
+        /// Only report proposals being voted on or awaiting execution.
+        #[arg(long)]
+        active: bool,
+        #[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
+        format: ReportFormat,
+        /// The number of largest voters to list for each proposal.
+        #[arg(long, default_value_t = 10)]
+        top_voters: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Election {
+    For,
+    Against,
+}
+
+impl From<Election> for ProposalVoteElection {
+    fn from(value: Election) -> Self {
+        match value {
+            Election::For => ProposalVoteElection::For,
+            Election::Against => ProposalVoteElection::Against,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl From<ReportFormat> for Format {
+    fn from(value: ReportFormat) -> Self {
+        match value {
+            ReportFormat::Markdown => Format::Markdown,
+            ReportFormat::Html => Format::Html,
+        }
+    }
+}
+
+/// An account meta, parsed from `PUBKEY[:s][:w]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountMetaArg(pub AccountMeta);
+
+impl FromStr for AccountMetaArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        let pubkey = parts
+            .next()
+            .unwrap_or_default()
+            .parse::<Pubkey>()
+            .map_err(|err| format!("invalid pubkey: {err}"))?;
+        let mut meta = AccountMeta::new_readonly(pubkey, false);
+        for flags in parts {
+            for flag in flags.chars() {
+                match flag {
+                    's' => meta.is_signer = true,
+                    'w' => meta.is_writable = true,
+                    _ => return Err(format!("invalid account flag `{flag}`")),
+                }
+            }
+        }
+        Ok(Self(meta))
+    }
+}
+
+/// Instruction data, parsed from hex.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HexData(pub Vec<u8>);
+
+fn parse_hex(s: &str) -> Result<HexData, String> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.len() % 2 != 0 {
+        return Err("hex data must have an even number of digits".to_string());
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|err| err.to_string()))
+        .collect::<Result<_, _>>()
+        .map(HexData)
+}
@@ -0,0 +1,51 @@
+//! The cluster access the CLI needs, abstracted so commands can run against
+//! either an RPC node or a test bank.
+
+use {
+    crate::Error,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{
+        account::Account, hash::Hash, pubkey::Pubkey, signature::Signature,
+        transaction::Transaction,
+    },
+};
+
+#[allow(async_fn_in_trait)]
+pub trait GovernanceClient {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, Error>;
+
+    /// Every account owned by the Paladin Governance program.
+    async fn get_program_accounts(&self) -> Result<Vec<(Pubkey, Account)>, Error>;
+
+    async fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64, Error>;
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, Error>;
+
+    /// Send a transaction and wait for it to be confirmed.
+    async fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, Error>;
+}
+
+impl GovernanceClient for RpcClient {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, Error> {
+        Ok(self
+            .get_account_with_commitment(address, self.commitment())
+            .await?
+            .value)
+    }
+
+    async fn get_program_accounts(&self) -> Result<Vec<(Pubkey, Account)>, Error> {
+        Ok(RpcClient::get_program_accounts(self, &paladin_governance_program::id()).await?)
+    }
+
+    async fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64, Error> {
+        Ok(RpcClient::get_minimum_balance_for_rent_exemption(self, data_len).await?)
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, Error> {
+        Ok(RpcClient::get_latest_blockhash(self).await?)
+    }
+
+    async fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, Error> {
+        Ok(self.send_and_confirm_transaction(transaction).await?)
+    }
+}
@@ -0,0 +1,628 @@
+use {
+    crate::{
+        args::Command,
+        client::GovernanceClient,
+        output::{
+            AccountOutput, AuthorOutput, GovernanceConfigOutput, Output, ProposalOutput,
+            ProposalTransactionOutput, ProposalVoteOutput, SimulationOutput, SnapshotOutput,
+            TransactionsOutput,
+        },
+        Error,
+    },
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        instruction::{
+            begin_voting, create_proposal, delete_vote, finish_voting, initialize_author,
+            initialize_governance, push_instruction, switch_vote, vote,
+        },
+        state::{
+            get_governance_address, get_proposal_author_address, get_proposal_transaction_address,
+            get_proposal_vote_address, get_treasury_address, Author, GovernanceConfig, Proposal,
+            ProposalAccountMeta, ProposalInstruction, ProposalKind, ProposalTransaction,
+            ProposalVote,
+        },
+    },
+    paladin_governance_program_client::{
+        accounts::Proposal as ClientProposal, sdk,
+        types::ProposalTransaction as ClientProposalTransaction,
+    },
+    paladin_governance_report::{Digest, ProposalAccounts, ProposalReport},
+    paladin_governance_simulator::{referenced_addresses, simulate, Snapshot},
+    paladin_stake_program::state::Config as StakeConfig,
+    solana_sdk::{
+        account::Account,
+        borsh1::get_instance_packed_len,
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        clock::Clock,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{read_keypair_file, Keypair, Signer},
+        system_instruction, sysvar,
+        transaction::Transaction,
+    },
+    spl_discriminator::SplDiscriminate,
+    std::collections::HashMap,
+};
+
+/// Settings shared by every command.
+pub struct Config<'a> {
+    /// Pays for transactions and new accounts, and acts as the stake
+    /// authority.
+    pub payer: &'a dyn Signer,
+    /// Serialize transactions instead of sending them.
+    pub dry_run: bool,
+}
+
+/// Runs a command against the cluster.
+pub async fn process<C: GovernanceClient>(
+    client: &C,
+    config: &Config<'_>,
+    command: Command,
+) -> Result<Output, Error> {
+    let program_id = paladin_governance_program::id();
+    let authority = config.payer.pubkey();
+
+    match command {
+        Command::InitGovernance {
+            stake_config,
+            governance_id,
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            voting_period_seconds,
+            stake_per_proposal,
+            cooldown_seconds,
+        } => {
+            let governance = get_governance_address(&stake_config, &governance_id, &program_id);
+            let mut instructions = fund_rent(
+                client,
+                &authority,
+                &governance,
+                std::mem::size_of::<GovernanceConfig>(),
+            )
+            .await?;
+            instructions.push(initialize_governance(
+                &governance,
+                &stake_config,
+                &authority,
+                governance_id,
+                cooldown_period_seconds,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                voting_period_seconds,
+                stake_per_proposal,
+                cooldown_seconds,
+            ));
+            send(
+                client,
+                config,
+                Some(governance),
+                vec![(instructions, vec![])],
+            )
+            .await
+        }
+        Command::InitAuthor => {
+            let author = get_proposal_author_address(&authority, &program_id);
+            let mut instructions = fund_rent(client, &authority, &author, Author::LEN).await?;
+            instructions.push(initialize_author(authority));
+            send(client, config, Some(author), vec![(instructions, vec![])]).await
+        }
+        Command::CreateProposal {
+            governance,
+            stake,
+            proposal_keypair,
+        } => {
+            let proposal = match proposal_keypair {
+                Some(path) => read_keypair_file(&path)
+                    .map_err(|err| format!("failed to read {}: {err}", path.display()))?,
+                None => Keypair::new(),
+            };
+            let proposal_transaction =
+                get_proposal_transaction_address(&proposal.pubkey(), &program_id);
+            let proposal_transaction_len =
+                get_instance_packed_len(&ProposalTransaction::default())?;
+
+            let mut instructions = vec![system_instruction::create_account(
+                &authority,
+                &proposal.pubkey(),
+                client
+                    .get_minimum_balance_for_rent_exemption(Proposal::LEN)
+                    .await?,
+                Proposal::LEN as u64,
+                &program_id,
+            )];
+            instructions.extend(
+                fund_rent(
+                    client,
+                    &authority,
+                    &proposal_transaction,
+                    proposal_transaction_len,
+                )
+                .await?,
+            );
+            instructions.push(create_proposal(
+                &authority,
+                &stake,
+                &proposal.pubkey(),
+                &proposal_transaction,
+                &governance,
+            ));
+            send(
+                client,
+                config,
+                Some(proposal.pubkey()),
+                vec![(instructions, vec![&proposal])],
+            )
+            .await
+        }
+        Command::Push {
+            proposal,
+            program_id: instruction_program_id,
+            accounts,
+            data,
+        } => {
+            let proposal_transaction = get_proposal_transaction_address(&proposal, &program_id);
+            let account_metas: Vec<ProposalAccountMeta> =
+                accounts.iter().map(|meta| (&meta.0).into()).collect();
+
+            // The proposal transaction account grows by the new instruction,
+            // and must remain rent exempt.
+            let current_len = get_account(client, &proposal_transaction).await?.data.len();
+            let instruction_len = get_instance_packed_len(&ProposalInstruction::new(
+                &instruction_program_id,
+                account_metas.clone(),
+                data.0.clone(),
+            ))?;
+            let mut instructions = fund_rent(
+                client,
+                &authority,
+                &proposal_transaction,
+                current_len + instruction_len,
+            )
+            .await?;
+            instructions.push(push_instruction(
+                &authority,
+                &proposal,
+                &proposal_transaction,
+                &instruction_program_id,
+                account_metas,
+                data.0,
+            ));
+            send(client, config, None, vec![(instructions, vec![])]).await
+        }
+        Command::BeginVoting { proposal } => {
+            let proposal_state = get_proposal(client, &proposal).await?;
+            let instruction = begin_voting(
+                &authority,
+                &proposal,
+                &proposal_state.governance_config.governance_config,
+            );
+            send(client, config, None, vec![(vec![instruction], vec![])]).await
+        }
+        Command::Vote {
+            proposal,
+            stake,
+            election,
+        } => {
+            let (governance, governance_state) = get_proposal_governance(client, &proposal).await?;
+            let proposal_vote = get_proposal_vote_address(&stake, &proposal, &program_id);
+            let mut instructions = fund_rent(
+                client,
+                &authority,
+                &proposal_vote,
+                std::mem::size_of::<ProposalVote>(),
+            )
+            .await?;
+            instructions.push(vote(
+                &authority,
+                &stake,
+                &governance_state.stake_config_address,
+                &proposal_vote,
+                &proposal,
+                &governance,
+                election.into(),
+            ));
+            send(
+                client,
+                config,
+                Some(proposal_vote),
+                vec![(instructions, vec![])],
+            )
+            .await
+        }
+        Command::SwitchVote {
+            proposal,
+            stake,
+            election,
+        } => {
+            let (governance, governance_state) = get_proposal_governance(client, &proposal).await?;
+            let instruction = switch_vote(
+                &authority,
+                &stake,
+                &governance_state.stake_config_address,
+                &get_proposal_vote_address(&stake, &proposal, &program_id),
+                &proposal,
+                &governance,
+                election.into(),
+            );
+            send(client, config, None, vec![(vec![instruction], vec![])]).await
+        }
+        Command::Finish { proposal } => {
+            let (governance, governance_state) = get_proposal_governance(client, &proposal).await?;
+            let instruction = finish_voting(
+                governance_state.stake_config_address,
+                &proposal,
+                &governance,
+            );
+            send(client, config, None, vec![(vec![instruction], vec![])]).await
+        }
+        Command::Execute { proposal, index } => {
+            let proposal_state = get_proposal(client, &proposal).await?;
+            let governance = proposal_state.governance_config.governance_config;
+
+            let mut proposal_transaction_state = ProposalTransaction::try_from_slice(
+                &get_account(
+                    client,
+                    &get_proposal_transaction_address(&proposal, &program_id),
+                )
+                .await?
+                .data,
+            )?;
+            let client_proposal = ClientProposal::try_from(&proposal_state)?;
+            let client_proposal_transaction =
+                ClientProposalTransaction::from(&proposal_transaction_state);
+            let outcome_instructions =
+                proposal_transaction_state.outcome_instructions_mut(&proposal_state)?;
+
+            let indices: Vec<u32> = match index {
+                Some(index) => vec![index],
+                None => (0..outcome_instructions.len() as u32)
+                    .filter(|index| !outcome_instructions[*index as usize].executed)
+                    .collect(),
+            };
+            if indices.is_empty() {
+                return Err("every instruction has already been executed".into());
+            }
+
+            // Each instruction is executed in its own transaction, in order.
+            let mut transactions = Vec::with_capacity(indices.len());
+            for index in indices {
+                let instruction = sdk::resolve_process_instruction(
+                    proposal,
+                    governance,
+                    &client_proposal,
+                    &client_proposal_transaction,
+                    index,
+                )
+                .ok_or_else(|| format!("proposal has no instruction at index {index}"))?;
+                transactions.push((vec![instruction], vec![]));
+            }
+            send(client, config, None, transactions).await
+        }
+        Command::Snapshot { proposal, out } => {
+            let proposal_state = get_proposal(client, &proposal).await?;
+            let proposal_transaction_state = ProposalTransaction::try_from_slice(
+                &get_account(
+                    client,
+                    &get_proposal_transaction_address(&proposal, &program_id),
+                )
+                .await?
+                .data,
+            )?;
+
+            let mut snapshot = Snapshot::default();
+            let mut missing = vec![];
+            for address in
+                referenced_addresses(&proposal, &proposal_state, &proposal_transaction_state)
+            {
+                let Some(account) = client.get_account(&address).await? else {
+                    missing.push(address.to_string());
+                    continue;
+                };
+                // Upgradeable programs are executed from their program data
+                // account.
+                if account.owner == bpf_loader_upgradeable::id() {
+                    if let Ok(UpgradeableLoaderState::Program {
+                        programdata_address,
+                    }) = bincode::deserialize(&account.data)
+                    {
+                        if let Some(program_data) = client.get_account(&programdata_address).await?
+                        {
+                            snapshot.accounts.insert(programdata_address, program_data);
+                        }
+                    }
+                }
+                snapshot.accounts.insert(address, account);
+            }
+            snapshot.write_dir(&out)?;
+
+            let mut accounts: Vec<String> =
+                snapshot.accounts.keys().map(Pubkey::to_string).collect();
+            accounts.sort();
+            Ok(Output::Snapshot(SnapshotOutput {
+                directory: out.display().to_string(),
+                accounts,
+                missing,
+            }))
+        }
+        Command::Simulate {
+            proposal,
+            snapshot,
+            option,
+        } => {
+            let snapshot = Snapshot::from_dir(&snapshot)?;
+            let report = simulate(&snapshot, &proposal, option).await?;
+            Ok(Output::Simulation(SimulationOutput::new(&report)))
+        }
+        Command::DeleteVote { proposal, stake } => {
+            let instruction = delete_vote(
+                proposal,
+                get_proposal_vote_address(&stake, &proposal, &program_id),
+                authority,
+            );
+            send(client, config, None, vec![(vec![instruction], vec![])]).await
+        }
+        Command::Show {
+            address,
+            governance,
+        } => {
+            let account = get_account(client, &address).await?;
+            if account.owner != program_id {
+                return Err(format!("{address} is not owned by the governance program").into());
+            }
+            let treasury =
+                governance.map(|governance| get_treasury_address(&governance, &program_id));
+            decode_account(&address, &account, treasury.as_ref())
+                .map(Output::Account)
+                .ok_or_else(|| format!("{address} is not a recognized governance account").into())
+        }
+        Command::List { governance } => {
+            let mut proposals: Vec<ProposalOutput> = client
+                .get_program_accounts()
+                .await?
+                .iter()
+                .filter_map(|(address, account)| {
+                    let proposal = decode_proposal(&account.data)?;
+                    governance
+                        .map_or(true, |governance| {
+                            proposal.governance_config.governance_config == governance
+                        })
+                        .then(|| ProposalOutput::new(address, proposal))
+                })
+                .collect();
+            proposals.sort_by_key(|proposal| proposal.creation_timestamp);
+            Ok(Output::Proposals(proposals))
+        }
+        Command::Report {
+            proposals,
+            governance,
+            active,
+            format,
+            top_voters,
+        } => {
+            let clock: Clock =
+                bincode::deserialize(&get_account(client, &sysvar::clock::id()).await?.data)?;
+            let now = clock.unix_timestamp as u64;
+
+            let program_accounts = client.get_program_accounts().await?;
+            let mut selected: Vec<(Pubkey, Proposal)> = if proposals.is_empty() {
+                program_accounts
+                    .iter()
+                    .filter_map(|(address, account)| {
+                        decode_proposal(&account.data).map(|proposal| (*address, *proposal))
+                    })
+                    .collect()
+            } else {
+                let mut selected = Vec::with_capacity(proposals.len());
+                for address in proposals {
+                    selected.push((address, get_proposal(client, &address).await?));
+                }
+                selected
+            };
+            selected.retain(|(_, proposal)| {
+                governance.map_or(true, |governance| {
+                    proposal.governance_config.governance_config == governance
+                }) && (!active || proposal.status.is_active())
+            });
+            selected.sort_by_key(|(_, proposal)| proposal.creation_timestamp);
+
+            let votes: Vec<ProposalVote> = program_accounts
+                .iter()
+                .filter(|(_, account)| account.data.len() == std::mem::size_of::<ProposalVote>())
+                .map(|(_, account)| *bytemuck::from_bytes(&account.data))
+                .collect();
+
+            // Proposals of the same governance config share its pause state
+            // and total stake.
+            let mut governance_configs: HashMap<Pubkey, (GovernanceConfig, u64)> = HashMap::new();
+            let mut reports = Vec::with_capacity(selected.len());
+            for (address, proposal) in selected {
+                let governance = proposal.governance_config.governance_config;
+                let (governance_config, total_stake) = match governance_configs.get(&governance) {
+                    Some(entry) => *entry,
+                    None => {
+                        let governance_config = decode_governance_config(
+                            &get_account(client, &governance).await?.data,
+                        )
+                        .copied()
+                        .ok_or_else(|| format!("{governance} is not a governance config"))?;
+                        let stake_config = governance_config.stake_config_address;
+                        let total_stake = bytemuck::try_from_bytes::<StakeConfig>(
+                            &get_account(client, &stake_config).await?.data,
+                        )
+                        .map_err(|_| format!("{stake_config} is not a stake config"))?
+                        .token_amount_effective;
+                        governance_configs.insert(governance, (governance_config, total_stake));
+                        (governance_config, total_stake)
+                    }
+                };
+
+                let transaction = match proposal.kind {
+                    ProposalKind::Executable => client
+                        .get_account(&get_proposal_transaction_address(&address, &program_id))
+                        .await?
+                        .map(|account| ProposalTransaction::try_from_slice(&account.data))
+                        .transpose()?,
+                    ProposalKind::Signal => None,
+                };
+
+                reports.push(ProposalReport::new(
+                    &ProposalAccounts {
+                        address,
+                        proposal: &proposal,
+                        governance_config: &governance_config,
+                        transaction: transaction.as_ref(),
+                        votes: &votes,
+                        total_stake,
+                    },
+                    now,
+                    top_voters,
+                ));
+            }
+
+            let digest = Digest {
+                generated_at: now,
+                proposals: reports,
+            };
+            Ok(Output::Report(ReportOutput {
+                rendered: digest.render(format.into()),
+                digest,
+            }))
+        }
+    }
+}
+
+/// A set of instructions sent as one transaction, with any signers besides
+/// the payer.
+type TransactionInstructions<'a> = (Vec<Instruction>, Vec<&'a dyn Signer>);
+
+async fn send<C: GovernanceClient>(
+    client: &C,
+    config: &Config<'_>,
+    address: Option<Pubkey>,
+    transactions: Vec<TransactionInstructions<'_>>,
+) -> Result<Output, Error> {
+    let mut output = TransactionsOutput {
+        address: address.map(|address| address.to_string()),
+        ..TransactionsOutput::default()
+    };
+
+    for (instructions, extra_signers) in transactions {
+        let mut signers = vec![config.payer];
+        signers.extend(extra_signers);
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&config.payer.pubkey()),
+            &signers,
+            client.get_latest_blockhash().await?,
+        );
+
+        if config.dry_run {
+            output
+                .transactions
+                .push(STANDARD.encode(bincode::serialize(&transaction)?));
+        } else {
+            let signature = client.send_transaction(&transaction).await?;
+            output.signatures.push(signature.to_string());
+        }
+    }
+
+    Ok(Output::Transactions(output))
+}
+
+/// Transfers the lamports `address` needs to be rent exempt at `data_len`
+/// bytes, if any.
+async fn fund_rent<C: GovernanceClient>(
+    client: &C,
+    payer: &Pubkey,
+    address: &Pubkey,
+    data_len: usize,
+) -> Result<Vec<Instruction>, Error> {
+    let rent = client
+        .get_minimum_balance_for_rent_exemption(data_len)
+        .await?;
+    let lamports = client
+        .get_account(address)
+        .await?
+        .map_or(0, |account| account.lamports);
+
+    Ok(sdk::fund_rent(*payer, *address, lamports, rent)
+        .into_iter()
+        .collect())
+}
+
+async fn get_account<C: GovernanceClient>(client: &C, address: &Pubkey) -> Result<Account, Error> {
+    client
+        .get_account(address)
+        .await?
+        .ok_or_else(|| format!("account {address} not found").into())
+}
+
+async fn get_proposal<C: GovernanceClient>(
+    client: &C,
+    address: &Pubkey,
+) -> Result<Proposal, Error> {
+    decode_proposal(&get_account(client, address).await?.data)
+        .copied()
+        .ok_or_else(|| format!("{address} is not a proposal").into())
+}
+
+/// The governance config of a proposal, as currently stored.
+async fn get_proposal_governance<C: GovernanceClient>(
+    client: &C,
+    proposal: &Pubkey,
+) -> Result<(Pubkey, GovernanceConfig), Error> {
+    let governance = get_proposal(client, proposal)
+        .await?
+        .governance_config
+        .governance_config;
+    let governance_state = decode_governance_config(&get_account(client, &governance).await?.data)
+        .copied()
+        .ok_or_else(|| format!("{governance} is not a governance config"))?;
+    Ok((governance, governance_state))
+}
+
+fn decode_proposal(data: &[u8]) -> Option<&Proposal> {
+    (data.len() == Proposal::LEN && data[..8] == *Proposal::SPL_DISCRIMINATOR_SLICE)
+        .then(|| bytemuck::from_bytes(data))
+}
+
+fn decode_governance_config(data: &[u8]) -> Option<&GovernanceConfig> {
+    (data.len() == std::mem::size_of::<GovernanceConfig>()
+        && data[..8] == *GovernanceConfig::SPL_DISCRIMINATOR_SLICE)
+        .then(|| bytemuck::from_bytes(data))
+}
+
+fn decode_account(
+    address: &Pubkey,
+    account: &Account,
+    treasury: Option<&Pubkey>,
+) -> Option<AccountOutput> {
+    let data = &account.data;
+    if let Some(state) = decode_governance_config(data) {
+        return Some(AccountOutput::GovernanceConfig(
+            GovernanceConfigOutput::new(address, state),
+        ));
+    }
+    if let Some(state) = decode_proposal(data) {
+        return Some(AccountOutput::Proposal(ProposalOutput::new(address, state)));
+    }
+    if data.len() == std::mem::size_of::<ProposalVote>() {
+        return Some(AccountOutput::ProposalVote(ProposalVoteOutput::new(
+            address,
+            bytemuck::from_bytes(data),
+        )));
+    }
+    if data.len() == Author::LEN {
+        return Some(AccountOutput::Author(AuthorOutput::new(
+            address,
+            bytemuck::from_bytes(data),
+        )));
+    }
+    ProposalTransaction::try_from_slice(data).ok().map(|state| {
+        AccountOutput::ProposalTransaction(ProposalTransactionOutput::new(
+            address, &state, treasury,
+        ))
+    })
+}
@@ -0,0 +1,15 @@
+//! Command-line interface for the Paladin Governance program.
+//!
+//! Every subcommand is built on the instruction builders in
+//! `paladin_governance_program::instruction`, deriving program addresses
+//! internally and funding new accounts for rent where the program requires it.
+
+pub mod args;
+pub mod client;
+mod commands;
+pub mod output;
+
+pub use commands::{process, Config};
+
+/// Errors surfaced to the user.
+pub type Error = Box<dyn std::error::Error>;
@@ -0,0 +1,37 @@
+use {
+    clap::Parser,
+    paladin_governance_cli::{args::Cli, process, Config},
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{commitment_config::CommitmentConfig, signature::read_keypair_file},
+    std::{path::PathBuf, process::exit},
+};
+
+fn default_keypair_path() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_default();
+    PathBuf::from(home).join(".config/solana/id.json")
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+
+    let keypair_path = cli.keypair.unwrap_or_else(default_keypair_path);
+    let payer = read_keypair_file(&keypair_path).unwrap_or_else(|err| {
+        eprintln!("error: failed to read {}: {err}", keypair_path.display());
+        exit(1);
+    });
+
+    let client = RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed());
+    let config = Config {
+        payer: &payer,
+        dry_run: cli.dry_run,
+    };
+
+    match process(&client, &config, cli.command).await {
+        Ok(output) => println!("{}", output.format(cli.output)),
+        Err(err) => {
+            eprintln!("error: {err}");
+            exit(1);
+        }
+    }
+}
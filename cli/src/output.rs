@@ -0,0 +1,412 @@
+//! Command output, printed either for humans or as JSON.
+
+use {
+    crate::args::OutputFormat,
+    paladin_governance_decoder::{decode, DecodedInstruction},
+    paladin_governance_program::state::{
+        Author, GovernanceConfig, Proposal, ProposalInstruction, ProposalTransaction, ProposalVote,
+    },
+    paladin_governance_report::Digest,
+    paladin_governance_simulator::{AccountDiff, InstructionReport, SimulationReport},
+    serde::Serialize,
+    serde_json::Value,
+    solana_sdk::{account::Account, pubkey::Pubkey},
+    std::fmt::{self, Write},
+};
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Output {
+    Transactions(TransactionsOutput),
+    Account(AccountOutput),
+    Proposals(Vec<ProposalOutput>),
+    Snapshot(SnapshotOutput),
+    Simulation(SimulationOutput),
+    Report(ReportOutput),
+}
+
+impl Output {
+    pub fn format(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Display => self.to_string(),
+            OutputFormat::Json => serde_json::to_string_pretty(self).unwrap(),
+            OutputFormat::JsonCompact => serde_json::to_string(self).unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Proposals(proposals) if proposals.is_empty() => writeln!(f, "No proposals"),
+            Self::Proposals(proposals) => {
+                for proposal in proposals {
+                    write_value(f, &serde_json::to_value(proposal).unwrap(), 0)?;
+                    writeln!(f)?;
+                }
+                Ok(())
+            }
+            Self::Report(report) => f.write_str(report.rendered.trim_end()),
+            _ => write_value(f, &serde_json::to_value(self).unwrap(), 0),
+        }
+    }
+}
+
+/// Writes a JSON value as indented `key: value` lines.
+fn write_value(f: &mut fmt::Formatter<'_>, value: &Value, indent: usize) -> fmt::Result {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                match value {
+                    Value::Object(_) | Value::Array(_) => {
+                        writeln!(f, "{pad}{key}:")?;
+                        write_value(f, value, indent + 1)?;
+                    }
+                    _ => writeln!(f, "{pad}{key}: {}", scalar(value))?,
+                }
+            }
+            Ok(())
+        }
+        Value::Array(items) if items.is_empty() => writeln!(f, "{pad}(none)"),
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                match item {
+                    Value::Object(_) | Value::Array(_) => {
+                        writeln!(f, "{pad}[{index}]")?;
+                        write_value(f, item, indent + 1)?;
+                    }
+                    _ => writeln!(f, "{pad}- {}", scalar(item))?,
+                }
+            }
+            Ok(())
+        }
+        _ => writeln!(f, "{pad}{}", scalar(value)),
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "none".to_string(),
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+/// Transactions sent, or serialized when running with `--dry-run`.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsOutput {
+    /// The account created by the command, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub signatures: Vec<String>,
+    /// Base64-encoded signed transactions.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "accountType", rename_all = "camelCase")]
+pub enum AccountOutput {
+    GovernanceConfig(GovernanceConfigOutput),
+    Proposal(ProposalOutput),
+    ProposalTransaction(ProposalTransactionOutput),
+    ProposalVote(ProposalVoteOutput),
+    Author(AuthorOutput),
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceConfigOutput {
+    pub address: String,
+    pub stake_config: String,
+    pub cooldown_period_seconds: u64,
+    pub proposal_minimum_quorum: u32,
+    pub proposal_pass_threshold: u32,
+    pub voting_period_seconds: u64,
+    pub stake_per_proposal: u64,
+    pub cooldown_expires: u64,
+    pub pause_guardian: Option<String>,
+    pub paused_timestamp: Option<u64>,
+    pub paused_seconds: u64,
+    pub successor: Option<String>,
+    pub proposal_winning_margin: u32,
+}
+
+impl GovernanceConfigOutput {
+    pub fn new(address: &Pubkey, state: &GovernanceConfig) -> Self {
+        Self {
+            address: address.to_string(),
+            stake_config: state.stake_config_address.to_string(),
+            cooldown_period_seconds: state.cooldown_period_seconds,
+            proposal_minimum_quorum: state.proposal_minimum_quorum,
+            proposal_pass_threshold: state.proposal_pass_threshold,
+            voting_period_seconds: state.voting_period_seconds,
+            stake_per_proposal: state.stake_per_proposal,
+            cooldown_expires: state.cooldown_expires,
+            pause_guardian: optional_pubkey(&state.pause_guardian),
+            paused_timestamp: state.paused_timestamp.map(u64::from),
+            paused_seconds: state.paused_seconds,
+            successor: optional_pubkey(&state.successor),
+            proposal_winning_margin: state.proposal_winning_margin,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalOutput {
+    pub address: String,
+    pub author: String,
+    pub governance_config: String,
+    pub status: String,
+    pub kind: String,
+    pub creation_timestamp: i64,
+    pub voting_start_timestamp: Option<u64>,
+    pub cooldown_timestamp: Option<u64>,
+    pub stake_for: u64,
+    pub stake_against: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub option_stake: Vec<u64>,
+}
+
+impl ProposalOutput {
+    pub fn new(address: &Pubkey, state: &Proposal) -> Self {
+        Self {
+            address: address.to_string(),
+            author: state.author.to_string(),
+            governance_config: state.governance_config.governance_config.to_string(),
+            status: format!("{:?}", state.status),
+            kind: format!("{:?}", state.kind),
+            creation_timestamp: state.creation_timestamp,
+            voting_start_timestamp: state.voting_start_timestamp.map(u64::from),
+            cooldown_timestamp: state.cooldown_timestamp.map(u64::from),
+            stake_for: state.stake_for,
+            stake_against: state.stake_against,
+            option_stake: state.option_stake[..state.option_count as usize].to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTransactionOutput {
+    pub address: String,
+    pub prerequisites: Vec<String>,
+    pub instructions: Vec<InstructionOutput>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<Vec<InstructionOutput>>,
+}
+
+impl ProposalTransactionOutput {
+    /// Only the treasury can sign for a proposal's instructions, so without
+    /// a known treasury, the first signer is flagged as the treasury.
+    pub fn new(address: &Pubkey, state: &ProposalTransaction, treasury: Option<&Pubkey>) -> Self {
+        let treasury = treasury.copied().or_else(|| {
+            state
+                .instructions
+                .iter()
+                .chain(state.options.iter().flat_map(|option| &option.instructions))
+                .flat_map(|instruction| &instruction.accounts)
+                .find(|meta| meta.is_signer)
+                .map(|meta| meta.pubkey)
+        });
+        let instructions = |instructions: &[ProposalInstruction]| {
+            instructions
+                .iter()
+                .map(|instruction| InstructionOutput::new(instruction, treasury.as_ref()))
+                .collect()
+        };
+        Self {
+            address: address.to_string(),
+            prerequisites: state.prerequisites.iter().map(Pubkey::to_string).collect(),
+            instructions: instructions(&state.instructions),
+            options: state
+                .options
+                .iter()
+                .map(|option| instructions(&option.instructions))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionOutput {
+    pub program_id: String,
+    /// Accounts as `PUBKEY[:s][:w]`, matching the `push` command's input.
+    pub accounts: Vec<String>,
+    /// The instruction data, in hex.
+    pub data: String,
+    pub executed: bool,
+    pub decoded: DecodedInstruction,
+}
+
+impl InstructionOutput {
+    fn new(instruction: &ProposalInstruction, treasury: Option<&Pubkey>) -> Self {
+        Self {
+            program_id: instruction.program_id.to_string(),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| {
+                    let flags = match (meta.is_signer, meta.is_writable) {
+                        (true, true) => ":sw",
+                        (true, false) => ":s",
+                        (false, true) => ":w",
+                        (false, false) => "",
+                    };
+                    format!("{}{flags}", meta.pubkey)
+                })
+                .collect(),
+            data: instruction
+                .data
+                .iter()
+                .fold(String::new(), |mut hex, byte| {
+                    let _ = write!(hex, "{byte:02x}");
+                    hex
+                }),
+            executed: instruction.executed,
+            decoded: decode(&instruction.into(), treasury),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalVoteOutput {
+    pub address: String,
+    pub proposal: String,
+    pub authority: String,
+    pub stake: u64,
+    pub election: String,
+}
+
+impl ProposalVoteOutput {
+    pub fn new(address: &Pubkey, state: &ProposalVote) -> Self {
+        Self {
+            address: address.to_string(),
+            proposal: state.proposal.to_string(),
+            authority: state.authority.to_string(),
+            stake: state.stake,
+            election: format!("{:?}", state.election),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorOutput {
+    pub address: String,
+    pub active_proposals: u64,
+}
+
+impl AuthorOutput {
+    pub fn new(address: &Pubkey, state: &Author) -> Self {
+        Self {
+            address: address.to_string(),
+            active_proposals: state.active_proposals,
+        }
+    }
+}
+
+/// Account dumps written for `simulate`.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotOutput {
+    pub directory: String,
+    pub accounts: Vec<String>,
+    /// Referenced accounts that don't exist yet.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationOutput {
+    pub success: bool,
+    pub instructions: Vec<SimulatedInstructionOutput>,
+}
+
+impl SimulationOutput {
+    pub fn new(report: &SimulationReport) -> Self {
+        Self {
+            success: report.is_success(),
+            instructions: report
+                .instructions
+                .iter()
+                .map(SimulatedInstructionOutput::new)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedInstructionOutput {
+    pub index: u32,
+    pub compute_units: u64,
+    pub error: Option<String>,
+    pub accounts: Vec<AccountDiffOutput>,
+    pub logs: Vec<String>,
+}
+
+impl SimulatedInstructionOutput {
+    fn new(report: &InstructionReport) -> Self {
+        Self {
+            index: report.index,
+            compute_units: report.compute_units,
+            error: report.error.as_ref().map(ToString::to_string),
+            accounts: report
+                .account_diffs
+                .iter()
+                .map(AccountDiffOutput::new)
+                .collect(),
+            logs: report.logs.clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDiffOutput {
+    pub address: String,
+    pub lamports_before: u64,
+    pub lamports_after: u64,
+    pub owner_before: Option<String>,
+    pub owner_after: Option<String>,
+    pub data_changed: bool,
+}
+
+impl AccountDiffOutput {
+    fn new(diff: &AccountDiff) -> Self {
+        let lamports =
+            |account: &Option<Account>| account.as_ref().map_or(0, |account| account.lamports);
+        let owner =
+            |account: &Option<Account>| account.as_ref().map(|account| account.owner.to_string());
+        Self {
+            address: diff.address.to_string(),
+            lamports_before: lamports(&diff.before),
+            lamports_after: lamports(&diff.after),
+            owner_before: owner(&diff.before),
+            owner_after: owner(&diff.after),
+            data_changed: diff.before.as_ref().map(|account| &account.data)
+                != diff.after.as_ref().map(|account| &account.data),
+        }
+    }
+}
+
+/// Proposal reports, rendered as Markdown or HTML for display.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ReportOutput {
+    #[serde(flatten)]
+    pub digest: Digest,
+    #[serde(skip)]
+    pub rendered: String,
+}
+
+fn optional_pubkey(pubkey: &Pubkey) -> Option<String> {
+    (*pubkey != Pubkey::default()).then(|| pubkey.to_string())
+}
@@ -0,0 +1,530 @@
+#![cfg(feature = "test-sbf")]
+
+use {
+    clap::Parser,
+    paladin_governance_cli::{
+        args::{Cli, Command},
+        client::GovernanceClient,
+        output::{AccountOutput, Output, TransactionsOutput},
+        process, Config, Error,
+    },
+    paladin_governance_program::state::{
+        get_governance_address, get_proposal_author_address, get_proposal_transaction_address,
+        get_proposal_vote_address, get_treasury_address,
+    },
+    paladin_governance_report::Stage,
+    paladin_stake_program::state::{
+        find_validator_stake_pda, Config as StakeConfig, Delegation, ValidatorStake,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        account_utils::StateMut,
+        bpf_loader_upgradeable::{self, get_program_data_address, UpgradeableLoaderState},
+        hash::Hash,
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+        signer::Signer,
+        system_instruction, system_program,
+        transaction::Transaction,
+    },
+    spl_discriminator::SplDiscriminate,
+    std::cell::RefCell,
+};
+
+/// Runs commands against a test bank.
+///
+/// Banks have no account index, so accounts touched by sent transactions are
+/// tracked to answer `get_program_accounts`.
+struct TestClient {
+    banks_client: BanksClient,
+    addresses: RefCell<Vec<Pubkey>>,
+}
+
+impl GovernanceClient for TestClient {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, Error> {
+        Ok(self.banks_client.clone().get_account(*address).await?)
+    }
+
+    async fn get_program_accounts(&self) -> Result<Vec<(Pubkey, Account)>, Error> {
+        let addresses = self.addresses.borrow().clone();
+        let mut accounts = vec![];
+        for address in addresses {
+            if let Some(account) = self.get_account(&address).await? {
+                if account.owner == paladin_governance_program::id() {
+                    accounts.push((address, account));
+                }
+            }
+        }
+        Ok(accounts)
+    }
+
+    async fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64, Error> {
+        let rent = self.banks_client.clone().get_rent().await?;
+        Ok(rent.minimum_balance(data_len))
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash, Error> {
+        Ok(self.banks_client.clone().get_latest_blockhash().await?)
+    }
+
+    async fn send_transaction(&self, transaction: &Transaction) -> Result<Signature, Error> {
+        {
+            let mut addresses = self.addresses.borrow_mut();
+            for key in &transaction.message.account_keys {
+                if !addresses.contains(key) {
+                    addresses.push(*key);
+                }
+            }
+        }
+        self.banks_client
+            .clone()
+            .process_transaction(transaction.clone())
+            .await?;
+        Ok(transaction.signatures[0])
+    }
+}
+
+struct TestContext {
+    client: TestClient,
+    /// Kept alive for the bank to keep running.
+    context: ProgramTestContext,
+    stake_config: Pubkey,
+    stake: Pubkey,
+}
+
+impl TestContext {
+    fn payer(&self) -> &Keypair {
+        &self.context.payer
+    }
+
+    async fn run(&self, args: &[&str]) -> Output {
+        self.try_run(args, false).await.unwrap()
+    }
+
+    async fn try_run(&self, args: &[&str], dry_run: bool) -> Result<Output, Error> {
+        let command: Command =
+            Cli::try_parse_from(std::iter::once("paladin-governance").chain(args.iter().copied()))
+                .unwrap()
+                .command;
+        let config = Config {
+            payer: self.payer(),
+            dry_run,
+        };
+        process(&self.client, &config, command).await
+    }
+
+    async fn show(&self, address: &Pubkey) -> AccountOutput {
+        match self.run(&["show", &address.to_string()]).await {
+            Output::Account(account) => account,
+            output => panic!("unexpected output: {output:?}"),
+        }
+    }
+}
+
+async fn setup() -> TestContext {
+    let mut context = ProgramTest::new(
+        "paladin_governance_program",
+        paladin_governance_program::id(),
+        processor!(paladin_governance_program::processor::process),
+    )
+    .start_with_context()
+    .await;
+
+    let payer = context.payer.pubkey();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let stake_config = Pubkey::new_unique();
+    let stake = find_validator_stake_pda(
+        &Pubkey::new_unique(),
+        &stake_config,
+        &paladin_stake_program::id(),
+    )
+    .0;
+
+    // The payer is the stake config authority, and holds all of its stake.
+    let stake_config_state = StakeConfig {
+        discriminator: StakeConfig::SPL_DISCRIMINATOR.into(),
+        authority: Some(payer).try_into().unwrap(),
+        slash_authority: Some(Pubkey::new_unique()).try_into().unwrap(),
+        vault: Pubkey::new_unique(),
+        cooldown_time_seconds: 0,
+        max_deactivation_basis_points: 0,
+        sync_rewards_lamports: 0,
+        vault_authority_bump: 0,
+        lamports_last: 0,
+        token_amount_effective: 100,
+        accumulated_stake_rewards_per_token: 0.into(),
+        duna_document_hash: [0; 32],
+        _padding: [0; 5],
+    };
+    let data = bytemuck::bytes_of(&stake_config_state).to_vec();
+    context.set_account(
+        &stake_config,
+        &AccountSharedData::from(Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: paladin_stake_program::id(),
+            ..Account::default()
+        }),
+    );
+
+    let stake_state = ValidatorStake {
+        _discriminator: ValidatorStake::SPL_DISCRIMINATOR.into(),
+        delegation: Delegation {
+            staked_amount: 100,
+            effective_amount: 100,
+            authority: payer,
+            ..Default::default()
+        },
+        total_staked_lamports_amount: 0,
+        total_staked_lamports_amount_min: 0,
+    };
+    let data = bytemuck::bytes_of(&stake_state).to_vec();
+    context.set_account(
+        &stake,
+        &AccountSharedData::from(Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: paladin_stake_program::id(),
+            ..Account::default()
+        }),
+    );
+
+    let space = UpgradeableLoaderState::size_of_programdata_metadata();
+    let mut program_data = AccountSharedData::new(
+        rent.minimum_balance(space),
+        space,
+        &bpf_loader_upgradeable::id(),
+    );
+    program_data
+        .set_state(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(payer),
+        })
+        .unwrap();
+    context.set_account(
+        &get_program_data_address(&paladin_governance_program::id()),
+        &program_data,
+    );
+
+    TestContext {
+        client: TestClient {
+            banks_client: context.banks_client.clone(),
+            addresses: RefCell::new(vec![]),
+        },
+        context,
+        stake_config,
+        stake,
+    }
+}
+
+fn created_address(output: Output) -> Pubkey {
+    match output {
+        Output::Transactions(TransactionsOutput {
+            address: Some(address),
+            ..
+        }) => address.parse().unwrap(),
+        output => panic!("unexpected output: {output:?}"),
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[tokio::test]
+async fn success_proposal_lifecycle() {
+    let context = setup().await;
+    let stake_config = context.stake_config.to_string();
+    let stake = context.stake.to_string();
+
+    let governance = created_address(
+        context
+            .run(&[
+                "init-governance",
+                "--stake-config",
+                &stake_config,
+                "--cooldown-period-seconds",
+                "0",
+                "--proposal-minimum-quorum",
+                "500000000",
+                "--proposal-pass-threshold",
+                "500000000",
+                "--voting-period-seconds",
+                "100",
+                "--stake-per-proposal",
+                "0",
+            ])
+            .await,
+    );
+    assert_eq!(
+        governance,
+        get_governance_address(&context.stake_config, &0, &paladin_governance_program::id())
+    );
+    let AccountOutput::GovernanceConfig(governance_config) = context.show(&governance).await else {
+        panic!("expected a governance config");
+    };
+    assert_eq!(governance_config.stake_config, stake_config);
+    assert_eq!(governance_config.voting_period_seconds, 100);
+
+    let author = created_address(context.run(&["init-author"]).await);
+    assert_eq!(
+        author,
+        get_proposal_author_address(&context.payer().pubkey(), &paladin_governance_program::id())
+    );
+
+    let proposal = created_address(
+        context
+            .run(&[
+                "create-proposal",
+                "--governance",
+                &governance.to_string(),
+                "--stake",
+                &stake,
+            ])
+            .await,
+    );
+    let proposal_arg = proposal.to_string();
+
+    // The proposal pays a recipient from the treasury.
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let recipient = Pubkey::new_unique();
+    let transfer = system_instruction::transfer(&treasury, &recipient, 1_000_000_000);
+    {
+        let fund =
+            system_instruction::transfer(&context.payer().pubkey(), &treasury, 2_000_000_000);
+        let transaction = Transaction::new_signed_with_payer(
+            &[fund],
+            Some(&context.payer().pubkey()),
+            &[context.payer()],
+            context.client.get_latest_blockhash().await.unwrap(),
+        );
+        context.client.send_transaction(&transaction).await.unwrap();
+    }
+    context
+        .run(&[
+            "push",
+            "--proposal",
+            &proposal_arg,
+            "--program-id",
+            &system_program::id().to_string(),
+            "--account",
+            &format!("{treasury}:sw"),
+            "--account",
+            &format!("{recipient}:w"),
+            "--data",
+            &hex(&transfer.data),
+        ])
+        .await;
+
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let AccountOutput::ProposalTransaction(transaction) = context.show(&proposal_transaction).await
+    else {
+        panic!("expected a proposal transaction");
+    };
+    assert_eq!(transaction.instructions.len(), 1);
+    assert_eq!(transaction.instructions[0].data, hex(&transfer.data));
+    assert!(!transaction.instructions[0].executed);
+    let decoded = &transaction.instructions[0].decoded;
+    assert_eq!(decoded.name.as_deref(), Some("Transfer"));
+    assert_eq!(decoded.accounts[0].name, "from");
+    assert!(decoded.accounts[0].is_treasury);
+    assert!(decoded.touches_treasury);
+
+    // The proposal executes when simulated against a snapshot of its accounts.
+    let snapshot_dir = std::env::temp_dir().join(format!("snapshot-{proposal}"));
+    let snapshot_arg = snapshot_dir.display().to_string();
+    let Output::Snapshot(snapshot) = context
+        .run(&[
+            "snapshot",
+            "--proposal",
+            &proposal_arg,
+            "--out",
+            &snapshot_arg,
+        ])
+        .await
+    else {
+        panic!("expected a snapshot");
+    };
+    assert!(snapshot.accounts.contains(&treasury.to_string()));
+    assert_eq!(snapshot.missing, vec![recipient.to_string()]);
+    let Output::Simulation(simulation) = context
+        .run(&[
+            "simulate",
+            "--proposal",
+            &proposal_arg,
+            "--snapshot",
+            &snapshot_arg,
+        ])
+        .await
+    else {
+        panic!("expected a simulation");
+    };
+    std::fs::remove_dir_all(&snapshot_dir).unwrap();
+    assert!(simulation.success);
+    assert_eq!(simulation.instructions.len(), 1);
+    assert!(simulation.instructions[0]
+        .accounts
+        .iter()
+        .any(|diff| diff.address == recipient.to_string() && diff.lamports_after == 1_000_000_000));
+
+    context
+        .run(&["begin-voting", "--proposal", &proposal_arg])
+        .await;
+
+    let proposal_vote = created_address(
+        context
+            .run(&[
+                "vote",
+                "--proposal",
+                &proposal_arg,
+                "--stake",
+                &stake,
+                "--election",
+                "for",
+            ])
+            .await,
+    );
+    assert_eq!(
+        proposal_vote,
+        get_proposal_vote_address(&context.stake, &proposal, &paladin_governance_program::id())
+    );
+    let AccountOutput::ProposalVote(vote) = context.show(&proposal_vote).await else {
+        panic!("expected a proposal vote");
+    };
+    assert_eq!(vote.stake, 100);
+    assert_eq!(vote.election, "For");
+
+    // With no cooldown period, the proposal can be finished right away.
+    let Output::Report(report) = context
+        .run(&["report", "--proposal", &proposal_arg, "--top-voters", "1"])
+        .await
+    else {
+        panic!("expected a report");
+    };
+    let proposal_report = &report.digest.proposals[0];
+    assert_eq!(proposal_report.stage, Stage::Finishable { passes: true });
+    assert_eq!(proposal_report.votes.turnout, 1_000_000_000);
+    assert_eq!(proposal_report.top_voters[0].stake, 100);
+    assert!(proposal_report.instructions[0].decoded.touches_treasury);
+    assert!(report
+        .rendered
+        .contains(&format!("## Proposal `{proposal_arg}`")));
+    assert!(report
+        .rendered
+        .contains("| Status | Voting ended, accepted once finished |"));
+    let Output::Report(report) = context
+        .run(&["report", "--active", "--format", "html"])
+        .await
+    else {
+        panic!("expected a report");
+    };
+    assert!(report
+        .rendered
+        .contains(&format!("<section id=\"{proposal_arg}\">")));
+
+    context.run(&["finish", "--proposal", &proposal_arg]).await;
+    let AccountOutput::Proposal(proposal_output) = context.show(&proposal).await else {
+        panic!("expected a proposal");
+    };
+    assert_eq!(proposal_output.status, "Accepted");
+    assert_eq!(proposal_output.stake_for, 100);
+
+    context.run(&["execute", "--proposal", &proposal_arg]).await;
+    assert_eq!(
+        context
+            .client
+            .get_account(&recipient)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        1_000_000_000
+    );
+    let AccountOutput::Proposal(proposal_output) = context.show(&proposal).await else {
+        panic!("expected a proposal");
+    };
+    assert_eq!(proposal_output.status, "Processed");
+
+    // Every instruction has been executed.
+    assert!(context
+        .try_run(&["execute", "--proposal", &proposal_arg], false)
+        .await
+        .is_err());
+
+    let Output::Proposals(proposals) = context
+        .run(&["list", "--governance", &governance.to_string()])
+        .await
+    else {
+        panic!("expected proposals");
+    };
+    assert_eq!(proposals.len(), 1);
+    assert_eq!(proposals[0].address, proposal_arg);
+    let Output::Proposals(proposals) = context
+        .run(&["list", "--governance", &Pubkey::new_unique().to_string()])
+        .await
+    else {
+        panic!("expected proposals");
+    };
+    assert!(proposals.is_empty());
+
+    context
+        .run(&[
+            "delete-vote",
+            "--proposal",
+            &proposal_arg,
+            "--stake",
+            &stake,
+        ])
+        .await;
+    assert!(context
+        .client
+        .get_account(&proposal_vote)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn success_dry_run() {
+    let context = setup().await;
+
+    let output = context.try_run(&["init-author"], true).await.unwrap();
+    let Output::Transactions(transactions) = output else {
+        panic!("unexpected output: {output:?}");
+    };
+    assert!(transactions.signatures.is_empty());
+    assert_eq!(transactions.transactions.len(), 1);
+
+    // Nothing was sent.
+    let author =
+        get_proposal_author_address(&context.payer().pubkey(), &paladin_governance_program::id());
+    assert!(context.client.get_account(&author).await.unwrap().is_none());
+
+    // The serialized transaction is the one that would have been sent.
+    let transaction: Transaction = bincode::deserialize(
+        &base64::Engine::decode(
+            &base64::engine::general_purpose::STANDARD,
+            &transactions.transactions[0],
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    transaction.verify().unwrap();
+    context.client.send_transaction(&transaction).await.unwrap();
+    assert!(context.client.get_account(&author).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn fail_show_unknown_account() {
+    let context = setup().await;
+
+    let err = context
+        .try_run(&["show", &context.stake_config.to_string()], false)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("not owned by the governance program"));
+}
@@ -15,12 +15,16 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Account,
   type Address,
   type Codec,
@@ -33,57 +37,85 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
 import {
-  getConfigDecoder,
-  getConfigEncoder,
-  type Config,
-  type ConfigArgs,
-} from '../types';
+  getNullableU64Decoder,
+  getNullableU64Encoder,
+  type NullableU64,
+  type NullableU64Args,
+} from '../../hooked';
 
 export type GovernanceConfig = {
+  discriminator: Array<number>;
   cooldownPeriodSeconds: bigint;
   proposalMinimumQuorum: number;
   proposalPassThreshold: number;
   stakeConfigAddress: Address;
   votingPeriodSeconds: bigint;
   stakePerProposal: bigint;
-  governanceConfig: Config;
+  governanceConfig: Address;
   cooldownExpires: bigint;
+  pauseGuardian: Address;
+  pausedTimestamp: NullableU64;
+  pausedSeconds: bigint;
+  successor: Address;
+  proposalWinningMargin: number;
+  padding: Array<number>;
 };
 
 export type GovernanceConfigArgs = {
+  discriminator: Array<number>;
   cooldownPeriodSeconds: number | bigint;
   proposalMinimumQuorum: number;
   proposalPassThreshold: number;
   stakeConfigAddress: Address;
   votingPeriodSeconds: number | bigint;
   stakePerProposal: number | bigint;
-  governanceConfig: ConfigArgs;
+  governanceConfig: Address;
   cooldownExpires: number | bigint;
+  pauseGuardian: Address;
+  pausedTimestamp: NullableU64Args;
+  pausedSeconds: number | bigint;
+  successor: Address;
+  proposalWinningMargin: number;
+  padding: Array<number>;
 };
 
 export function getGovernanceConfigEncoder(): Encoder<GovernanceConfigArgs> {
   return getStructEncoder([
+    ['discriminator', getArrayEncoder(getU8Encoder(), { size: 8 })],
     ['cooldownPeriodSeconds', getU64Encoder()],
     ['proposalMinimumQuorum', getU32Encoder()],
     ['proposalPassThreshold', getU32Encoder()],
     ['stakeConfigAddress', getAddressEncoder()],
     ['votingPeriodSeconds', getU64Encoder()],
     ['stakePerProposal', getU64Encoder()],
-    ['governanceConfig', getConfigEncoder()],
+    ['governanceConfig', getAddressEncoder()],
     ['cooldownExpires', getU64Encoder()],
+    ['pauseGuardian', getAddressEncoder()],
+    ['pausedTimestamp', getNullableU64Encoder()],
+    ['pausedSeconds', getU64Encoder()],
+    ['successor', getAddressEncoder()],
+    ['proposalWinningMargin', getU32Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 4 })],
   ]);
 }
 
 export function getGovernanceConfigDecoder(): Decoder<GovernanceConfig> {
   return getStructDecoder([
+    ['discriminator', getArrayDecoder(getU8Decoder(), { size: 8 })],
     ['cooldownPeriodSeconds', getU64Decoder()],
     ['proposalMinimumQuorum', getU32Decoder()],
     ['proposalPassThreshold', getU32Decoder()],
     ['stakeConfigAddress', getAddressDecoder()],
     ['votingPeriodSeconds', getU64Decoder()],
     ['stakePerProposal', getU64Decoder()],
-    ['governanceConfig', getConfigDecoder()],
+    ['governanceConfig', getAddressDecoder()],
     ['cooldownExpires', getU64Decoder()],
+    ['pauseGuardian', getAddressDecoder()],
+    ['pausedTimestamp', getNullableU64Decoder()],
+    ['pausedSeconds', getU64Decoder()],
+    ['successor', getAddressDecoder()],
+    ['proposalWinningMargin', getU32Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 4 })],
   ]);
 }
 
@@ -161,5 +193,5 @@ export async function fetchAllMaybeGovernanceConfig(
 }
 
 export function getGovernanceConfigSize(): number {
-  return 104;
+  return 200;
 }
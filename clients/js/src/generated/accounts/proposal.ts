@@ -45,10 +45,14 @@ import {
 import {
   getConfigDecoder,
   getConfigEncoder,
+  getProposalKindDecoder,
+  getProposalKindEncoder,
   getProposalStatusDecoder,
   getProposalStatusEncoder,
   type Config,
   type ConfigArgs,
+  type ProposalKind,
+  type ProposalKindArgs,
   type ProposalStatus,
   type ProposalStatusArgs,
 } from '../types';
@@ -62,8 +66,13 @@ export type Proposal = {
   stakeAgainst: bigint;
   stakeFor: bigint;
   status: ProposalStatus;
+  optionCount: number;
+  winningOption: number;
+  kind: ProposalKind;
   padding: Array<number>;
   votingStartTimestamp: NullableU64;
+  optionStake: Array<bigint>;
+  pausedSecondsCheckpoint: bigint;
 };
 
 export type ProposalArgs = {
@@ -75,8 +84,13 @@ export type ProposalArgs = {
   stakeAgainst: number | bigint;
   stakeFor: number | bigint;
   status: ProposalStatusArgs;
+  optionCount: number;
+  winningOption: number;
+  kind: ProposalKindArgs;
   padding: Array<number>;
   votingStartTimestamp: NullableU64Args;
+  optionStake: Array<number | bigint>;
+  pausedSecondsCheckpoint: number | bigint;
 };
 
 export function getProposalEncoder(): Encoder<ProposalArgs> {
@@ -89,8 +103,13 @@ export function getProposalEncoder(): Encoder<ProposalArgs> {
     ['stakeAgainst', getU64Encoder()],
     ['stakeFor', getU64Encoder()],
     ['status', getProposalStatusEncoder()],
-    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+    ['optionCount', getU8Encoder()],
+    ['winningOption', getU8Encoder()],
+    ['kind', getProposalKindEncoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 4 })],
     ['votingStartTimestamp', getNullableU64Encoder()],
+    ['optionStake', getArrayEncoder(getU64Encoder(), { size: 8 })],
+    ['pausedSecondsCheckpoint', getU64Encoder()],
   ]);
 }
 
@@ -104,8 +123,13 @@ export function getProposalDecoder(): Decoder<Proposal> {
     ['stakeAgainst', getU64Decoder()],
     ['stakeFor', getU64Decoder()],
     ['status', getProposalStatusDecoder()],
-    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+    ['optionCount', getU8Decoder()],
+    ['winningOption', getU8Decoder()],
+    ['kind', getProposalKindDecoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 4 })],
     ['votingStartTimestamp', getNullableU64Decoder()],
+    ['optionStake', getArrayDecoder(getU64Decoder(), { size: 8 })],
+    ['pausedSecondsCheckpoint', getU64Decoder()],
   ]);
 }
 
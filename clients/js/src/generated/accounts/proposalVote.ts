@@ -46,6 +46,7 @@ export type ProposalVote = {
   stake: bigint;
   authority: Address;
   election: ProposalVoteElection;
+  optionIndex: number;
   padding: Array<number>;
 };
 
@@ -54,6 +55,7 @@ export type ProposalVoteArgs = {
   stake: number | bigint;
   authority: Address;
   election: ProposalVoteElectionArgs;
+  optionIndex: number;
   padding: Array<number>;
 };
 
@@ -63,7 +65,8 @@ export function getProposalVoteEncoder(): Encoder<ProposalVoteArgs> {
     ['stake', getU64Encoder()],
     ['authority', getAddressEncoder()],
     ['election', getProposalVoteElectionEncoder()],
-    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+    ['optionIndex', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 6 })],
   ]);
 }
 
@@ -73,7 +76,8 @@ export function getProposalVoteDecoder(): Decoder<ProposalVote> {
     ['stake', getU64Decoder()],
     ['authority', getAddressDecoder()],
     ['election', getProposalVoteElectionDecoder()],
-    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+    ['optionIndex', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 6 })],
   ]);
 }
 
@@ -48,48 +48,128 @@ export const PALADIN_GOVERNANCE_ERROR__PREVIOUS_INSTRUCTION_HAS_NOT_BEEN_EXECUTE
 export const PALADIN_GOVERNANCE_ERROR__TOO_MANY_ACTIVE_PROPOSALS = 0xf; // 15
 /** CooldownPeriodNotOver: Cooldown period is not over yet, cannot create new proposal. */
 export const PALADIN_GOVERNANCE_ERROR__COOLDOWN_PERIOD_NOT_OVER = 0x10; // 16
+/** ProposalCooldownStarted: Proposal cooldown period has already begun. */
+export const PALADIN_GOVERNANCE_ERROR__PROPOSAL_COOLDOWN_STARTED = 0x11; // 17
+/** InvalidPrerequisite: Invalid prerequisite proposal. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_PREREQUISITE = 0x12; // 18
+/** IncorrectPrerequisiteAddress: Incorrect prerequisite proposal address. */
+export const PALADIN_GOVERNANCE_ERROR__INCORRECT_PREREQUISITE_ADDRESS = 0x13; // 19
+/** PrerequisiteNotProcessed: Prerequisite proposal has not been processed. */
+export const PALADIN_GOVERNANCE_ERROR__PREREQUISITE_NOT_PROCESSED = 0x14; // 20
+/** TooManyProposalOptions: Proposal has too many options. */
+export const PALADIN_GOVERNANCE_ERROR__TOO_MANY_PROPOSAL_OPTIONS = 0x15; // 21
+/** InvalidProposalOption: Invalid proposal option. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_OPTION = 0x16; // 22
+/** ProposalNotExecutable: Proposal is not executable. */
+export const PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_EXECUTABLE = 0x17; // 23
+/** AuthorHasActiveProposals: Author has active proposals. */
+export const PALADIN_GOVERNANCE_ERROR__AUTHOR_HAS_ACTIVE_PROPOSALS = 0x18; // 24
+/** IncorrectProgramDataAddress: Incorrect program data address. */
+export const PALADIN_GOVERNANCE_ERROR__INCORRECT_PROGRAM_DATA_ADDRESS = 0x19; // 25
+/** InvalidProposalMinimumQuorum: Proposal minimum quorum out of bounds. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_MINIMUM_QUORUM = 0x1a; // 26
+/** InvalidProposalPassThreshold: Proposal pass threshold out of bounds. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_PASS_THRESHOLD = 0x1b; // 27
+/** InvalidVotingPeriod: Voting period out of bounds. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_VOTING_PERIOD = 0x1c; // 28
+/** InvalidCooldownPeriod: Cooldown period out of bounds. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_COOLDOWN_PERIOD = 0x1d; // 29
+/** AccountNotMigrated: Account must be migrated to the current layout. */
+export const PALADIN_GOVERNANCE_ERROR__ACCOUNT_NOT_MIGRATED = 0x1e; // 30
+/** GovernancePaused: Governance is paused. */
+export const PALADIN_GOVERNANCE_ERROR__GOVERNANCE_PAUSED = 0x1f; // 31
+/** GovernanceRetired: Governance config has been retired. */
+export const PALADIN_GOVERNANCE_ERROR__GOVERNANCE_RETIRED = 0x20; // 32
+/** IncorrectTreasuryTokenAccount: Incorrect treasury token account. */
+export const PALADIN_GOVERNANCE_ERROR__INCORRECT_TREASURY_TOKEN_ACCOUNT = 0x21; // 33
+/** PauseGuardianNotSet: Pause guardian not set. */
+export const PALADIN_GOVERNANCE_ERROR__PAUSE_GUARDIAN_NOT_SET = 0x22; // 34
+/** PrerequisiteClosed: Prerequisite proposal was deleted, rejected or cancelled. */
+export const PALADIN_GOVERNANCE_ERROR__PREREQUISITE_CLOSED = 0x23; // 35
+/** InvalidProposalWinningMargin: Invalid proposal winning margin. */
+export const PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_WINNING_MARGIN = 0x24; // 36
 
 export type PaladinGovernanceError =
+  | typeof PALADIN_GOVERNANCE_ERROR__ACCOUNT_NOT_MIGRATED
+  | typeof PALADIN_GOVERNANCE_ERROR__AUTHOR_HAS_ACTIVE_PROPOSALS
   | typeof PALADIN_GOVERNANCE_ERROR__COOLDOWN_PERIOD_NOT_OVER
+  | typeof PALADIN_GOVERNANCE_ERROR__GOVERNANCE_PAUSED
+  | typeof PALADIN_GOVERNANCE_ERROR__GOVERNANCE_RETIRED
   | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_GOVERNANCE_CONFIG_ADDRESS
+  | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_PREREQUISITE_ADDRESS
+  | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_PROGRAM_DATA_ADDRESS
   | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_PROPOSAL_ADDRESS
   | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_PROPOSAL_TRANSACTION_ADDRESS
   | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_PROPOSAL_VOTE_ADDRESS
   | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_STAKE_CONFIG
   | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_TREASURY_ADDRESS
+  | typeof PALADIN_GOVERNANCE_ERROR__INCORRECT_TREASURY_TOKEN_ACCOUNT
   | typeof PALADIN_GOVERNANCE_ERROR__INSTRUCTION_ALREADY_EXECUTED
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_COOLDOWN_PERIOD
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_PREREQUISITE
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_MINIMUM_QUORUM
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_OPTION
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_PASS_THRESHOLD
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_WINNING_MARGIN
   | typeof PALADIN_GOVERNANCE_ERROR__INVALID_TRANSACTION_INDEX
+  | typeof PALADIN_GOVERNANCE_ERROR__INVALID_VOTING_PERIOD
+  | typeof PALADIN_GOVERNANCE_ERROR__PAUSE_GUARDIAN_NOT_SET
+  | typeof PALADIN_GOVERNANCE_ERROR__PREREQUISITE_CLOSED
+  | typeof PALADIN_GOVERNANCE_ERROR__PREREQUISITE_NOT_PROCESSED
   | typeof PALADIN_GOVERNANCE_ERROR__PREVIOUS_INSTRUCTION_HAS_NOT_BEEN_EXECUTED
+  | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_COOLDOWN_STARTED
   | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_IS_ACTIVE
   | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_IS_IMMUTABLE
   | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_ACCEPTED
+  | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_EXECUTABLE
   | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_IN_VOTING_STAGE
   | typeof PALADIN_GOVERNANCE_ERROR__PROPOSAL_VOTING_PERIOD_STILL_ACTIVE
   | typeof PALADIN_GOVERNANCE_ERROR__STAKE_CONFIG_MISMATCH
-  | typeof PALADIN_GOVERNANCE_ERROR__TOO_MANY_ACTIVE_PROPOSALS;
+  | typeof PALADIN_GOVERNANCE_ERROR__TOO_MANY_ACTIVE_PROPOSALS
+  | typeof PALADIN_GOVERNANCE_ERROR__TOO_MANY_PROPOSAL_OPTIONS;
 
 let paladinGovernanceErrorMessages:
   | Record<PaladinGovernanceError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   paladinGovernanceErrorMessages = {
+    [PALADIN_GOVERNANCE_ERROR__ACCOUNT_NOT_MIGRATED]: `Account must be migrated to the current layout.`,
+    [PALADIN_GOVERNANCE_ERROR__AUTHOR_HAS_ACTIVE_PROPOSALS]: `Author has active proposals.`,
     [PALADIN_GOVERNANCE_ERROR__COOLDOWN_PERIOD_NOT_OVER]: `Cooldown period is not over yet, cannot create new proposal.`,
+    [PALADIN_GOVERNANCE_ERROR__GOVERNANCE_PAUSED]: `Governance is paused.`,
+    [PALADIN_GOVERNANCE_ERROR__GOVERNANCE_RETIRED]: `Governance config has been retired.`,
     [PALADIN_GOVERNANCE_ERROR__INCORRECT_GOVERNANCE_CONFIG_ADDRESS]: `Incorrect governance config address.`,
+    [PALADIN_GOVERNANCE_ERROR__INCORRECT_PREREQUISITE_ADDRESS]: `Incorrect prerequisite proposal address.`,
+    [PALADIN_GOVERNANCE_ERROR__INCORRECT_PROGRAM_DATA_ADDRESS]: `Incorrect program data address.`,
     [PALADIN_GOVERNANCE_ERROR__INCORRECT_PROPOSAL_ADDRESS]: `Incorrect proposal address.`,
     [PALADIN_GOVERNANCE_ERROR__INCORRECT_PROPOSAL_TRANSACTION_ADDRESS]: `Incorrect proposal transaction address.`,
     [PALADIN_GOVERNANCE_ERROR__INCORRECT_PROPOSAL_VOTE_ADDRESS]: `Incorrect proposal vote address.`,
     [PALADIN_GOVERNANCE_ERROR__INCORRECT_STAKE_CONFIG]: `Incorrect stake config.`,
     [PALADIN_GOVERNANCE_ERROR__INCORRECT_TREASURY_ADDRESS]: `Incorrect treasury address.`,
+    [PALADIN_GOVERNANCE_ERROR__INCORRECT_TREASURY_TOKEN_ACCOUNT]: `Incorrect treasury token account.`,
     [PALADIN_GOVERNANCE_ERROR__INSTRUCTION_ALREADY_EXECUTED]: `Instruction already executed.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_COOLDOWN_PERIOD]: `Cooldown period out of bounds.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_PREREQUISITE]: `Invalid prerequisite proposal.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_MINIMUM_QUORUM]: `Proposal minimum quorum out of bounds.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_OPTION]: `Invalid proposal option.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_PASS_THRESHOLD]: `Proposal pass threshold out of bounds.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_PROPOSAL_WINNING_MARGIN]: `Invalid proposal winning margin.`,
     [PALADIN_GOVERNANCE_ERROR__INVALID_TRANSACTION_INDEX]: `Invalid transaction index.`,
+    [PALADIN_GOVERNANCE_ERROR__INVALID_VOTING_PERIOD]: `Voting period out of bounds.`,
+    [PALADIN_GOVERNANCE_ERROR__PAUSE_GUARDIAN_NOT_SET]: `Pause guardian not set.`,
+    [PALADIN_GOVERNANCE_ERROR__PREREQUISITE_CLOSED]: `Prerequisite proposal was deleted, rejected or cancelled.`,
+    [PALADIN_GOVERNANCE_ERROR__PREREQUISITE_NOT_PROCESSED]: `Prerequisite proposal has not been processed.`,
     [PALADIN_GOVERNANCE_ERROR__PREVIOUS_INSTRUCTION_HAS_NOT_BEEN_EXECUTED]: `Previous instruction has not been executed.`,
+    [PALADIN_GOVERNANCE_ERROR__PROPOSAL_COOLDOWN_STARTED]: `Proposal cooldown period has already begun.`,
     [PALADIN_GOVERNANCE_ERROR__PROPOSAL_IS_ACTIVE]: `Proposal is active.`,
     [PALADIN_GOVERNANCE_ERROR__PROPOSAL_IS_IMMUTABLE]: `Proposal is immutable.`,
     [PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_ACCEPTED]: `Proposal not accepted.`,
+    [PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_EXECUTABLE]: `Proposal is not executable.`,
     [PALADIN_GOVERNANCE_ERROR__PROPOSAL_NOT_IN_VOTING_STAGE]: `Proposal not in voting stage.`,
     [PALADIN_GOVERNANCE_ERROR__PROPOSAL_VOTING_PERIOD_STILL_ACTIVE]: `Proposal voting period still active.`,
     [PALADIN_GOVERNANCE_ERROR__STAKE_CONFIG_MISMATCH]: `Stake config accounts mismatch.`,
     [PALADIN_GOVERNANCE_ERROR__TOO_MANY_ACTIVE_PROPOSALS]: `Author has too many active proposals.`,
+    [PALADIN_GOVERNANCE_ERROR__TOO_MANY_PROPOSAL_OPTIONS]: `Proposal has too many options.`,
   };
 }
 
@@ -22,6 +22,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type TransactionSigner,
   type WritableAccount,
@@ -33,6 +34,7 @@ export type BeginVotingInstruction<
   TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountStakeAuthority extends string | IAccountMeta<string> = string,
   TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -45,6 +47,9 @@ export type BeginVotingInstruction<
       TAccountProposal extends string
         ? WritableAccount<TAccountProposal>
         : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -77,22 +82,31 @@ export function getBeginVotingInstructionDataCodec(): Codec<
 export type BeginVotingInput<
   TAccountStakeAuthority extends string = string,
   TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
 > = {
   /** Paladin stake authority account */
   stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
   /** Proposal account */
   proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
 };
 
 export function getBeginVotingInstruction<
   TAccountStakeAuthority extends string,
   TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
 >(
-  input: BeginVotingInput<TAccountStakeAuthority, TAccountProposal>
+  input: BeginVotingInput<
+    TAccountStakeAuthority,
+    TAccountProposal,
+    TAccountGovernanceConfig
+  >
 ): BeginVotingInstruction<
   typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountStakeAuthority,
-  TAccountProposal
+  TAccountProposal,
+  TAccountGovernanceConfig
 > {
   // Program address.
   const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
@@ -101,6 +115,10 @@ export function getBeginVotingInstruction<
   const originalAccounts = {
     stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
     proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -112,13 +130,15 @@ export function getBeginVotingInstruction<
     accounts: [
       getAccountMeta(accounts.stakeAuthority),
       getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
     ],
     programAddress,
     data: getBeginVotingInstructionDataEncoder().encode({}),
   } as BeginVotingInstruction<
     typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
     TAccountStakeAuthority,
-    TAccountProposal
+    TAccountProposal,
+    TAccountGovernanceConfig
   >;
 
   return instruction;
@@ -134,6 +154,8 @@ export type ParsedBeginVotingInstruction<
     stakeAuthority: TAccountMetas[0];
     /** Proposal account */
     proposal: TAccountMetas[1];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[2];
   };
   data: BeginVotingInstructionData;
 };
@@ -146,7 +168,7 @@ export function parseBeginVotingInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBeginVotingInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -161,6 +183,7 @@ export function parseBeginVotingInstruction<
     accounts: {
       stakeAuthority: getNextAccount(),
       proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
     },
     data: getBeginVotingInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type CancelProposalInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountAuthor extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountAuthor extends string
+        ? WritableAccount<TAccountAuthor>
+        : TAccountAuthor,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CancelProposalInstructionData = { discriminator: number };
+
+export type CancelProposalInstructionDataArgs = {};
+
+export function getCancelProposalInstructionDataEncoder(): Encoder<CancelProposalInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 12 })
+  );
+}
+
+export function getCancelProposalInstructionDataDecoder(): Decoder<CancelProposalInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCancelProposalInstructionDataCodec(): Codec<
+  CancelProposalInstructionDataArgs,
+  CancelProposalInstructionData
+> {
+  return combineCodec(
+    getCancelProposalInstructionDataEncoder(),
+    getCancelProposalInstructionDataDecoder()
+  );
+}
+
+export type CancelProposalInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountAuthor extends string = string,
+  TAccountProposal extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Stake authority author account */
+  author: Address<TAccountAuthor>;
+  /** Proposal account */
+  proposal: Address<TAccountProposal>;
+};
+
+export function getCancelProposalInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountAuthor extends string,
+  TAccountProposal extends string,
+>(
+  input: CancelProposalInput<
+    TAccountStakeAuthority,
+    TAccountAuthor,
+    TAccountProposal
+  >
+): CancelProposalInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountAuthor,
+  TAccountProposal
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    author: { value: input.author ?? null, isWritable: true },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.author),
+      getAccountMeta(accounts.proposal),
+    ],
+    programAddress,
+    data: getCancelProposalInstructionDataEncoder().encode({}),
+  } as CancelProposalInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountAuthor,
+    TAccountProposal
+  >;
+
+  return instruction;
+}
+
+export type ParsedCancelProposalInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Stake authority author account */
+    author: TAccountMetas[1];
+    /** Proposal account */
+    proposal: TAccountMetas[2];
+  };
+  data: CancelProposalInstructionData;
+};
+
+export function parseCancelProposalInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCancelProposalInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      author: getNextAccount(),
+      proposal: getNextAccount(),
+    },
+    data: getCancelProposalInstructionDataDecoder().decode(instruction.data),
+  };
+}
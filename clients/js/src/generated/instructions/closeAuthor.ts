@@ -0,0 +1,167 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type CloseAuthorInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountAuthor extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? WritableSignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountAuthor extends string
+        ? WritableAccount<TAccountAuthor>
+        : TAccountAuthor,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseAuthorInstructionData = { discriminator: number };
+
+export type CloseAuthorInstructionDataArgs = {};
+
+export function getCloseAuthorInstructionDataEncoder(): Encoder<CloseAuthorInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 19 })
+  );
+}
+
+export function getCloseAuthorInstructionDataDecoder(): Decoder<CloseAuthorInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCloseAuthorInstructionDataCodec(): Codec<
+  CloseAuthorInstructionDataArgs,
+  CloseAuthorInstructionData
+> {
+  return combineCodec(
+    getCloseAuthorInstructionDataEncoder(),
+    getCloseAuthorInstructionDataDecoder()
+  );
+}
+
+export type CloseAuthorInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountAuthor extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Stake authority author account */
+  author: Address<TAccountAuthor>;
+};
+
+export function getCloseAuthorInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountAuthor extends string,
+>(
+  input: CloseAuthorInput<TAccountStakeAuthority, TAccountAuthor>
+): CloseAuthorInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountAuthor
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: true },
+    author: { value: input.author ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.author),
+    ],
+    programAddress,
+    data: getCloseAuthorInstructionDataEncoder().encode({}),
+  } as CloseAuthorInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountAuthor
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseAuthorInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Stake authority author account */
+    author: TAccountMetas[1];
+  };
+  data: CloseAuthorInstructionData;
+};
+
+export function parseCloseAuthorInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseAuthorInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      author: getNextAccount(),
+    },
+    data: getCloseAuthorInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,224 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type CreateSignalProposalInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountAuthor extends string | IAccountMeta<string> = string,
+  TAccountStake extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountAuthor extends string
+        ? WritableAccount<TAccountAuthor>
+        : TAccountAuthor,
+      TAccountStake extends string
+        ? ReadonlyAccount<TAccountStake>
+        : TAccountStake,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CreateSignalProposalInstructionData = { discriminator: number };
+
+export type CreateSignalProposalInstructionDataArgs = {};
+
+export function getCreateSignalProposalInstructionDataEncoder(): Encoder<CreateSignalProposalInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 18 })
+  );
+}
+
+export function getCreateSignalProposalInstructionDataDecoder(): Decoder<CreateSignalProposalInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCreateSignalProposalInstructionDataCodec(): Codec<
+  CreateSignalProposalInstructionDataArgs,
+  CreateSignalProposalInstructionData
+> {
+  return combineCodec(
+    getCreateSignalProposalInstructionDataEncoder(),
+    getCreateSignalProposalInstructionDataDecoder()
+  );
+}
+
+export type CreateSignalProposalInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountAuthor extends string = string,
+  TAccountStake extends string = string,
+  TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Stake authority author account */
+  author: Address<TAccountAuthor>;
+  /** Paladin stake account */
+  stake: Address<TAccountStake>;
+  /** Proposal account */
+  proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
+};
+
+export function getCreateSignalProposalInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountAuthor extends string,
+  TAccountStake extends string,
+  TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
+>(
+  input: CreateSignalProposalInput<
+    TAccountStakeAuthority,
+    TAccountAuthor,
+    TAccountStake,
+    TAccountProposal,
+    TAccountGovernanceConfig
+  >
+): CreateSignalProposalInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountAuthor,
+  TAccountStake,
+  TAccountProposal,
+  TAccountGovernanceConfig
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    author: { value: input.author ?? null, isWritable: true },
+    stake: { value: input.stake ?? null, isWritable: false },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.author),
+      getAccountMeta(accounts.stake),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
+    ],
+    programAddress,
+    data: getCreateSignalProposalInstructionDataEncoder().encode({}),
+  } as CreateSignalProposalInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountAuthor,
+    TAccountStake,
+    TAccountProposal,
+    TAccountGovernanceConfig
+  >;
+
+  return instruction;
+}
+
+export type ParsedCreateSignalProposalInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Stake authority author account */
+    author: TAccountMetas[1];
+    /** Paladin stake account */
+    stake: TAccountMetas[2];
+    /** Proposal account */
+    proposal: TAccountMetas[3];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[4];
+  };
+  data: CreateSignalProposalInstructionData;
+};
+
+export function parseCreateSignalProposalInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCreateSignalProposalInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      author: getNextAccount(),
+      stake: getNextAccount(),
+      proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
+    },
+    data: getCreateSignalProposalInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
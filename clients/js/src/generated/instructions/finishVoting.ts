@@ -31,6 +31,7 @@ export type FinishVotingInstruction<
   TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountStakeConfig extends string | IAccountMeta<string> = string,
   TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -42,6 +43,9 @@ export type FinishVotingInstruction<
       TAccountProposal extends string
         ? WritableAccount<TAccountProposal>
         : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -74,22 +78,31 @@ export function getFinishVotingInstructionDataCodec(): Codec<
 export type FinishVotingInput<
   TAccountStakeConfig extends string = string,
   TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
 > = {
   /** Stake config account */
   stakeConfig: Address<TAccountStakeConfig>;
   /** Proposal account */
   proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
 };
 
 export function getFinishVotingInstruction<
   TAccountStakeConfig extends string,
   TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
 >(
-  input: FinishVotingInput<TAccountStakeConfig, TAccountProposal>
+  input: FinishVotingInput<
+    TAccountStakeConfig,
+    TAccountProposal,
+    TAccountGovernanceConfig
+  >
 ): FinishVotingInstruction<
   typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountStakeConfig,
-  TAccountProposal
+  TAccountProposal,
+  TAccountGovernanceConfig
 > {
   // Program address.
   const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
@@ -98,6 +111,10 @@ export function getFinishVotingInstruction<
   const originalAccounts = {
     stakeConfig: { value: input.stakeConfig ?? null, isWritable: false },
     proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -109,13 +126,15 @@ export function getFinishVotingInstruction<
     accounts: [
       getAccountMeta(accounts.stakeConfig),
       getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
     ],
     programAddress,
     data: getFinishVotingInstructionDataEncoder().encode({}),
   } as FinishVotingInstruction<
     typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
     TAccountStakeConfig,
-    TAccountProposal
+    TAccountProposal,
+    TAccountGovernanceConfig
   >;
 
   return instruction;
@@ -131,6 +150,8 @@ export type ParsedFinishVotingInstruction<
     stakeConfig: TAccountMetas[0];
     /** Proposal account */
     proposal: TAccountMetas[1];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[2];
   };
   data: FinishVotingInstructionData;
 };
@@ -143,7 +164,7 @@ export function parseFinishVotingInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedFinishVotingInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -158,6 +179,7 @@ export function parseFinishVotingInstruction<
     accounts: {
       stakeConfig: getNextAccount(),
       proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
     },
     data: getFinishVotingInstructionDataDecoder().decode(instruction.data),
   };
@@ -7,14 +7,26 @@
  */
 
 export * from './beginVoting';
+export * from './cancelProposal';
+export * from './closeAuthor';
 export * from './createProposal';
+export * from './createSignalProposal';
 export * from './deleteProposal';
 export * from './deleteVote';
 export * from './finishVoting';
 export * from './initializeAuthor';
 export * from './initializeGovernance';
+export * from './migrateAccount';
+export * from './migrateGovernance';
+export * from './partialUpdateGovernance';
 export * from './processInstruction';
 export * from './pushInstruction';
+export * from './pushOption';
+export * from './pushOptionInstruction';
+export * from './pushPrerequisite';
+export * from './setPaused';
 export * from './switchVote';
+export * from './switchVoteOption';
 export * from './updateGovernance';
 export * from './vote';
+export * from './voteOption';
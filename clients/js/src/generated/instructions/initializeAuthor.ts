@@ -18,10 +18,13 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
 import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
@@ -40,7 +43,8 @@ export type InitializeAuthorInstruction<
   IInstructionWithAccounts<
     [
       TAccountStakeAuthority extends string
-        ? ReadonlyAccount<TAccountStakeAuthority>
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
         : TAccountStakeAuthority,
       TAccountAuthor extends string
         ? WritableAccount<TAccountAuthor>
@@ -82,7 +86,7 @@ export type InitializeAuthorInput<
   TAccountAuthor extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  stakeAuthority: Address<TAccountStakeAuthority>;
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
   author: Address<TAccountAuthor>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
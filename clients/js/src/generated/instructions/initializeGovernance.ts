@@ -22,10 +22,13 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
 import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
@@ -38,6 +41,8 @@ export type InitializeGovernanceInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgramData extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -52,6 +57,13 @@ export type InitializeGovernanceInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountProgramData extends string
+        ? ReadonlyAccount<TAccountProgramData>
+        : TAccountProgramData,
       ...TRemainingAccounts,
     ]
   >;
@@ -120,6 +132,8 @@ export type InitializeGovernanceInput<
   TAccountGovernanceConfig extends string = string,
   TAccountStakeConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountProgramData extends string = string,
 > = {
   /** Governance config account */
   governanceConfig: Address<TAccountGovernanceConfig>;
@@ -127,6 +141,10 @@ export type InitializeGovernanceInput<
   stakeConfig: Address<TAccountStakeConfig>;
   /** System program */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Stake config authority or program upgrade authority */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Program data account */
+  programData: Address<TAccountProgramData>;
   governanceId: InitializeGovernanceInstructionDataArgs['governanceId'];
   cooldownPeriodSeconds: InitializeGovernanceInstructionDataArgs['cooldownPeriodSeconds'];
   proposalMinimumQuorum: InitializeGovernanceInstructionDataArgs['proposalMinimumQuorum'];
@@ -140,17 +158,23 @@ export function getInitializeGovernanceInstruction<
   TAccountGovernanceConfig extends string,
   TAccountStakeConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountAuthority extends string,
+  TAccountProgramData extends string,
 >(
   input: InitializeGovernanceInput<
     TAccountGovernanceConfig,
     TAccountStakeConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuthority,
+    TAccountProgramData
   >
 ): InitializeGovernanceInstruction<
   typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountGovernanceConfig,
   TAccountStakeConfig,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountAuthority,
+  TAccountProgramData
 > {
   // Program address.
   const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
@@ -163,6 +187,8 @@ export function getInitializeGovernanceInstruction<
     },
     stakeConfig: { value: input.stakeConfig ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    programData: { value: input.programData ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -184,6 +210,8 @@ export function getInitializeGovernanceInstruction<
       getAccountMeta(accounts.governanceConfig),
       getAccountMeta(accounts.stakeConfig),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.programData),
     ],
     programAddress,
     data: getInitializeGovernanceInstructionDataEncoder().encode(
@@ -193,7 +221,9 @@ export function getInitializeGovernanceInstruction<
     typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
     TAccountGovernanceConfig,
     TAccountStakeConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuthority,
+    TAccountProgramData
   >;
 
   return instruction;
@@ -211,6 +241,10 @@ export type ParsedInitializeGovernanceInstruction<
     stakeConfig: TAccountMetas[1];
     /** System program */
     systemProgram: TAccountMetas[2];
+    /** Stake config authority or program upgrade authority */
+    authority: TAccountMetas[3];
+    /** Program data account */
+    programData: TAccountMetas[4];
   };
   data: InitializeGovernanceInstructionData;
 };
@@ -223,7 +257,7 @@ export function parseInitializeGovernanceInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitializeGovernanceInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -239,6 +273,8 @@ export function parseInitializeGovernanceInstruction<
       governanceConfig: getNextAccount(),
       stakeConfig: getNextAccount(),
       systemProgram: getNextAccount(),
+      authority: getNextAccount(),
+      programData: getNextAccount(),
     },
     data: getInitializeGovernanceInstructionDataDecoder().decode(
       instruction.data
@@ -0,0 +1,292 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type MigrateGovernanceInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountTreasury extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
+  TAccountNewTreasury extends string | IAccountMeta<string> = string,
+  TAccountNewGovernanceConfig extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountToken2022Program extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTreasury extends string
+        ? WritableSignerAccount<TAccountTreasury> &
+            IAccountSignerMeta<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountGovernanceConfig extends string
+        ? WritableAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
+      TAccountNewTreasury extends string
+        ? WritableAccount<TAccountNewTreasury>
+        : TAccountNewTreasury,
+      TAccountNewGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountNewGovernanceConfig>
+        : TAccountNewGovernanceConfig,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountToken2022Program extends string
+        ? ReadonlyAccount<TAccountToken2022Program>
+        : TAccountToken2022Program,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MigrateGovernanceInstructionData = {
+  discriminator: number;
+  governanceId: bigint;
+  newGovernanceId: bigint;
+};
+
+export type MigrateGovernanceInstructionDataArgs = {
+  governanceId: number | bigint;
+  newGovernanceId: number | bigint;
+};
+
+export function getMigrateGovernanceInstructionDataEncoder(): Encoder<MigrateGovernanceInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['governanceId', getU64Encoder()],
+      ['newGovernanceId', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 23 })
+  );
+}
+
+export function getMigrateGovernanceInstructionDataDecoder(): Decoder<MigrateGovernanceInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['governanceId', getU64Decoder()],
+    ['newGovernanceId', getU64Decoder()],
+  ]);
+}
+
+export function getMigrateGovernanceInstructionDataCodec(): Codec<
+  MigrateGovernanceInstructionDataArgs,
+  MigrateGovernanceInstructionData
+> {
+  return combineCodec(
+    getMigrateGovernanceInstructionDataEncoder(),
+    getMigrateGovernanceInstructionDataDecoder()
+  );
+}
+
+export type MigrateGovernanceInput<
+  TAccountTreasury extends string = string,
+  TAccountGovernanceConfig extends string = string,
+  TAccountNewTreasury extends string = string,
+  TAccountNewGovernanceConfig extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountToken2022Program extends string = string,
+> = {
+  /** Treasury account */
+  treasury: TransactionSigner<TAccountTreasury>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
+  /** New treasury account */
+  newTreasury: Address<TAccountNewTreasury>;
+  /** New governance config account */
+  newGovernanceConfig: Address<TAccountNewGovernanceConfig>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Token program */
+  tokenProgram: Address<TAccountTokenProgram>;
+  /** SPL Token-2022 program */
+  token2022Program: Address<TAccountToken2022Program>;
+  governanceId: MigrateGovernanceInstructionDataArgs['governanceId'];
+  newGovernanceId: MigrateGovernanceInstructionDataArgs['newGovernanceId'];
+};
+
+export function getMigrateGovernanceInstruction<
+  TAccountTreasury extends string,
+  TAccountGovernanceConfig extends string,
+  TAccountNewTreasury extends string,
+  TAccountNewGovernanceConfig extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountToken2022Program extends string,
+>(
+  input: MigrateGovernanceInput<
+    TAccountTreasury,
+    TAccountGovernanceConfig,
+    TAccountNewTreasury,
+    TAccountNewGovernanceConfig,
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountToken2022Program
+  >
+): MigrateGovernanceInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountTreasury,
+  TAccountGovernanceConfig,
+  TAccountNewTreasury,
+  TAccountNewGovernanceConfig,
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountToken2022Program
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: true,
+    },
+    newTreasury: { value: input.newTreasury ?? null, isWritable: true },
+    newGovernanceConfig: {
+      value: input.newGovernanceConfig ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    token2022Program: {
+      value: input.token2022Program ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.governanceConfig),
+      getAccountMeta(accounts.newTreasury),
+      getAccountMeta(accounts.newGovernanceConfig),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.token2022Program),
+    ],
+    programAddress,
+    data: getMigrateGovernanceInstructionDataEncoder().encode(
+      args as MigrateGovernanceInstructionDataArgs
+    ),
+  } as MigrateGovernanceInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountTreasury,
+    TAccountGovernanceConfig,
+    TAccountNewTreasury,
+    TAccountNewGovernanceConfig,
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountToken2022Program
+  >;
+
+  return instruction;
+}
+
+export type ParsedMigrateGovernanceInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Treasury account */
+    treasury: TAccountMetas[0];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[1];
+    /** New treasury account */
+    newTreasury: TAccountMetas[2];
+    /** New governance config account */
+    newGovernanceConfig: TAccountMetas[3];
+    /** System program */
+    systemProgram: TAccountMetas[4];
+    /** SPL Token program */
+    tokenProgram: TAccountMetas[5];
+    /** SPL Token-2022 program */
+    token2022Program: TAccountMetas[6];
+  };
+  data: MigrateGovernanceInstructionData;
+};
+
+export function parseMigrateGovernanceInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMigrateGovernanceInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      treasury: getNextAccount(),
+      governanceConfig: getNextAccount(),
+      newTreasury: getNextAccount(),
+      newGovernanceConfig: getNextAccount(),
+      systemProgram: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      token2022Program: getNextAccount(),
+    },
+    data: getMigrateGovernanceInstructionDataDecoder().decode(instruction.data),
+  };
+}
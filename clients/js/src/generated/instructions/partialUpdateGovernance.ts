@@ -0,0 +1,237 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type PartialUpdateGovernanceInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountTreasury extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTreasury extends string
+        ? ReadonlySignerAccount<TAccountTreasury> &
+            IAccountSignerMeta<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountGovernanceConfig extends string
+        ? WritableAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type PartialUpdateGovernanceInstructionData = {
+  discriminator: number;
+  governanceId: bigint;
+  cooldownPeriodSeconds: Option<bigint>;
+  proposalMinimumQuorum: Option<number>;
+  proposalPassThreshold: Option<number>;
+  votingPeriodSeconds: Option<bigint>;
+  stakePerProposal: Option<bigint>;
+  pauseGuardian: Option<Address>;
+  proposalWinningMargin: Option<number>;
+};
+
+export type PartialUpdateGovernanceInstructionDataArgs = {
+  governanceId: number | bigint;
+  cooldownPeriodSeconds: OptionOrNullable<number | bigint>;
+  proposalMinimumQuorum: OptionOrNullable<number>;
+  proposalPassThreshold: OptionOrNullable<number>;
+  votingPeriodSeconds: OptionOrNullable<number | bigint>;
+  stakePerProposal: OptionOrNullable<number | bigint>;
+  pauseGuardian: OptionOrNullable<Address>;
+  proposalWinningMargin: OptionOrNullable<number>;
+};
+
+export function getPartialUpdateGovernanceInstructionDataEncoder(): Encoder<PartialUpdateGovernanceInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['governanceId', getU64Encoder()],
+      ['cooldownPeriodSeconds', getOptionEncoder(getU64Encoder())],
+      ['proposalMinimumQuorum', getOptionEncoder(getU32Encoder())],
+      ['proposalPassThreshold', getOptionEncoder(getU32Encoder())],
+      ['votingPeriodSeconds', getOptionEncoder(getU64Encoder())],
+      ['stakePerProposal', getOptionEncoder(getU64Encoder())],
+      ['pauseGuardian', getOptionEncoder(getAddressEncoder())],
+      ['proposalWinningMargin', getOptionEncoder(getU32Encoder())],
+    ]),
+    (value) => ({ ...value, discriminator: 20 })
+  );
+}
+
+export function getPartialUpdateGovernanceInstructionDataDecoder(): Decoder<PartialUpdateGovernanceInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['governanceId', getU64Decoder()],
+    ['cooldownPeriodSeconds', getOptionDecoder(getU64Decoder())],
+    ['proposalMinimumQuorum', getOptionDecoder(getU32Decoder())],
+    ['proposalPassThreshold', getOptionDecoder(getU32Decoder())],
+    ['votingPeriodSeconds', getOptionDecoder(getU64Decoder())],
+    ['stakePerProposal', getOptionDecoder(getU64Decoder())],
+    ['pauseGuardian', getOptionDecoder(getAddressDecoder())],
+    ['proposalWinningMargin', getOptionDecoder(getU32Decoder())],
+  ]);
+}
+
+export function getPartialUpdateGovernanceInstructionDataCodec(): Codec<
+  PartialUpdateGovernanceInstructionDataArgs,
+  PartialUpdateGovernanceInstructionData
+> {
+  return combineCodec(
+    getPartialUpdateGovernanceInstructionDataEncoder(),
+    getPartialUpdateGovernanceInstructionDataDecoder()
+  );
+}
+
+export type PartialUpdateGovernanceInput<
+  TAccountTreasury extends string = string,
+  TAccountGovernanceConfig extends string = string,
+> = {
+  /** Treasury account */
+  treasury: TransactionSigner<TAccountTreasury>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
+  governanceId: PartialUpdateGovernanceInstructionDataArgs['governanceId'];
+  cooldownPeriodSeconds: PartialUpdateGovernanceInstructionDataArgs['cooldownPeriodSeconds'];
+  proposalMinimumQuorum: PartialUpdateGovernanceInstructionDataArgs['proposalMinimumQuorum'];
+  proposalPassThreshold: PartialUpdateGovernanceInstructionDataArgs['proposalPassThreshold'];
+  votingPeriodSeconds: PartialUpdateGovernanceInstructionDataArgs['votingPeriodSeconds'];
+  stakePerProposal: PartialUpdateGovernanceInstructionDataArgs['stakePerProposal'];
+  pauseGuardian: PartialUpdateGovernanceInstructionDataArgs['pauseGuardian'];
+  proposalWinningMargin: PartialUpdateGovernanceInstructionDataArgs['proposalWinningMargin'];
+};
+
+export function getPartialUpdateGovernanceInstruction<
+  TAccountTreasury extends string,
+  TAccountGovernanceConfig extends string,
+>(
+  input: PartialUpdateGovernanceInput<
+    TAccountTreasury,
+    TAccountGovernanceConfig
+  >
+): PartialUpdateGovernanceInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountTreasury,
+  TAccountGovernanceConfig
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    treasury: { value: input.treasury ?? null, isWritable: false },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.governanceConfig),
+    ],
+    programAddress,
+    data: getPartialUpdateGovernanceInstructionDataEncoder().encode(
+      args as PartialUpdateGovernanceInstructionDataArgs
+    ),
+  } as PartialUpdateGovernanceInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountTreasury,
+    TAccountGovernanceConfig
+  >;
+
+  return instruction;
+}
+
+export type ParsedPartialUpdateGovernanceInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Treasury account */
+    treasury: TAccountMetas[0];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[1];
+  };
+  data: PartialUpdateGovernanceInstructionData;
+};
+
+export function parsePartialUpdateGovernanceInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedPartialUpdateGovernanceInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      treasury: getNextAccount(),
+      governanceConfig: getNextAccount(),
+    },
+    data: getPartialUpdateGovernanceInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
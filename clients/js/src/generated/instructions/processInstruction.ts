@@ -23,6 +23,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
 import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
@@ -32,6 +33,7 @@ export type ProcessInstructionInstruction<
   TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountProposal extends string | IAccountMeta<string> = string,
   TAccountProposalTransaction extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -43,6 +45,9 @@ export type ProcessInstructionInstruction<
       TAccountProposalTransaction extends string
         ? WritableAccount<TAccountProposalTransaction>
         : TAccountProposalTransaction,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -86,23 +91,32 @@ export function getProcessInstructionInstructionDataCodec(): Codec<
 export type ProcessInstructionInput<
   TAccountProposal extends string = string,
   TAccountProposalTransaction extends string = string,
+  TAccountGovernanceConfig extends string = string,
 > = {
   /** Proposal account */
   proposal: Address<TAccountProposal>;
   /** Proposal transaction account */
   proposalTransaction: Address<TAccountProposalTransaction>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
   instructionIndex: ProcessInstructionInstructionDataArgs['instructionIndex'];
 };
 
 export function getProcessInstructionInstruction<
   TAccountProposal extends string,
   TAccountProposalTransaction extends string,
+  TAccountGovernanceConfig extends string,
 >(
-  input: ProcessInstructionInput<TAccountProposal, TAccountProposalTransaction>
+  input: ProcessInstructionInput<
+    TAccountProposal,
+    TAccountProposalTransaction,
+    TAccountGovernanceConfig
+  >
 ): ProcessInstructionInstruction<
   typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
   TAccountProposal,
-  TAccountProposalTransaction
+  TAccountProposalTransaction,
+  TAccountGovernanceConfig
 > {
   // Program address.
   const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
@@ -114,6 +128,10 @@ export function getProcessInstructionInstruction<
       value: input.proposalTransaction ?? null,
       isWritable: true,
     },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -128,6 +146,7 @@ export function getProcessInstructionInstruction<
     accounts: [
       getAccountMeta(accounts.proposal),
       getAccountMeta(accounts.proposalTransaction),
+      getAccountMeta(accounts.governanceConfig),
     ],
     programAddress,
     data: getProcessInstructionInstructionDataEncoder().encode(
@@ -136,7 +155,8 @@ export function getProcessInstructionInstruction<
   } as ProcessInstructionInstruction<
     typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
     TAccountProposal,
-    TAccountProposalTransaction
+    TAccountProposalTransaction,
+    TAccountGovernanceConfig
   >;
 
   return instruction;
@@ -152,6 +172,8 @@ export type ParsedProcessInstructionInstruction<
     proposal: TAccountMetas[0];
     /** Proposal transaction account */
     proposalTransaction: TAccountMetas[1];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[2];
   };
   data: ProcessInstructionInstructionData;
 };
@@ -164,7 +186,7 @@ export function parseProcessInstructionInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedProcessInstructionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -179,6 +201,7 @@ export function parseProcessInstructionInstruction<
     accounts: {
       proposal: getNextAccount(),
       proposalTransaction: getNextAccount(),
+      governanceConfig: getNextAccount(),
     },
     data: getProcessInstructionInstructionDataDecoder().decode(
       instruction.data
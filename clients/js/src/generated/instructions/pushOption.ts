@@ -0,0 +1,189 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type PushOptionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountProposalTransaction extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountProposalTransaction extends string
+        ? WritableAccount<TAccountProposalTransaction>
+        : TAccountProposalTransaction,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type PushOptionInstructionData = { discriminator: number };
+
+export type PushOptionInstructionDataArgs = {};
+
+export function getPushOptionInstructionDataEncoder(): Encoder<PushOptionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 14 })
+  );
+}
+
+export function getPushOptionInstructionDataDecoder(): Decoder<PushOptionInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getPushOptionInstructionDataCodec(): Codec<
+  PushOptionInstructionDataArgs,
+  PushOptionInstructionData
+> {
+  return combineCodec(
+    getPushOptionInstructionDataEncoder(),
+    getPushOptionInstructionDataDecoder()
+  );
+}
+
+export type PushOptionInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountProposal extends string = string,
+  TAccountProposalTransaction extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Proposal account */
+  proposal: Address<TAccountProposal>;
+  /** Proposal transaction account */
+  proposalTransaction: Address<TAccountProposalTransaction>;
+};
+
+export function getPushOptionInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountProposal extends string,
+  TAccountProposalTransaction extends string,
+>(
+  input: PushOptionInput<
+    TAccountStakeAuthority,
+    TAccountProposal,
+    TAccountProposalTransaction
+  >
+): PushOptionInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountProposal,
+  TAccountProposalTransaction
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    proposalTransaction: {
+      value: input.proposalTransaction ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.proposalTransaction),
+    ],
+    programAddress,
+    data: getPushOptionInstructionDataEncoder().encode({}),
+  } as PushOptionInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountProposal,
+    TAccountProposalTransaction
+  >;
+
+  return instruction;
+}
+
+export type ParsedPushOptionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Proposal account */
+    proposal: TAccountMetas[1];
+    /** Proposal transaction account */
+    proposalTransaction: TAccountMetas[2];
+  };
+  data: PushOptionInstructionData;
+};
+
+export function parsePushOptionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedPushOptionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      proposal: getNextAccount(),
+      proposalTransaction: getNextAccount(),
+    },
+    data: getPushOptionInstructionDataDecoder().decode(instruction.data),
+  };
+}
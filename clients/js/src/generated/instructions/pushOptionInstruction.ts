@@ -0,0 +1,253 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getProposalAccountMetaDecoder,
+  getProposalAccountMetaEncoder,
+  type ProposalAccountMeta,
+  type ProposalAccountMetaArgs,
+} from '../types';
+
+export type PushOptionInstructionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountProposalTransaction extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountProposal extends string
+        ? ReadonlyAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountProposalTransaction extends string
+        ? WritableAccount<TAccountProposalTransaction>
+        : TAccountProposalTransaction,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type PushOptionInstructionInstructionData = {
+  discriminator: number;
+  optionIndex: number;
+  instructionProgramId: Address;
+  instructionAccountMetas: Array<ProposalAccountMeta>;
+  instructionData: ReadonlyUint8Array;
+};
+
+export type PushOptionInstructionInstructionDataArgs = {
+  optionIndex: number;
+  instructionProgramId: Address;
+  instructionAccountMetas: Array<ProposalAccountMetaArgs>;
+  instructionData: ReadonlyUint8Array;
+};
+
+export function getPushOptionInstructionInstructionDataEncoder(): Encoder<PushOptionInstructionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['optionIndex', getU8Encoder()],
+      ['instructionProgramId', getAddressEncoder()],
+      [
+        'instructionAccountMetas',
+        getArrayEncoder(getProposalAccountMetaEncoder()),
+      ],
+      [
+        'instructionData',
+        addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+      ],
+    ]),
+    (value) => ({ ...value, discriminator: 15 })
+  );
+}
+
+export function getPushOptionInstructionInstructionDataDecoder(): Decoder<PushOptionInstructionInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['optionIndex', getU8Decoder()],
+    ['instructionProgramId', getAddressDecoder()],
+    [
+      'instructionAccountMetas',
+      getArrayDecoder(getProposalAccountMetaDecoder()),
+    ],
+    [
+      'instructionData',
+      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+    ],
+  ]);
+}
+
+export function getPushOptionInstructionInstructionDataCodec(): Codec<
+  PushOptionInstructionInstructionDataArgs,
+  PushOptionInstructionInstructionData
+> {
+  return combineCodec(
+    getPushOptionInstructionInstructionDataEncoder(),
+    getPushOptionInstructionInstructionDataDecoder()
+  );
+}
+
+export type PushOptionInstructionInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountProposal extends string = string,
+  TAccountProposalTransaction extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Proposal account */
+  proposal: Address<TAccountProposal>;
+  /** Proposal transaction account */
+  proposalTransaction: Address<TAccountProposalTransaction>;
+  optionIndex: PushOptionInstructionInstructionDataArgs['optionIndex'];
+  instructionProgramId: PushOptionInstructionInstructionDataArgs['instructionProgramId'];
+  instructionAccountMetas: PushOptionInstructionInstructionDataArgs['instructionAccountMetas'];
+  instructionData: PushOptionInstructionInstructionDataArgs['instructionData'];
+};
+
+export function getPushOptionInstructionInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountProposal extends string,
+  TAccountProposalTransaction extends string,
+>(
+  input: PushOptionInstructionInput<
+    TAccountStakeAuthority,
+    TAccountProposal,
+    TAccountProposalTransaction
+  >
+): PushOptionInstructionInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountProposal,
+  TAccountProposalTransaction
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    proposal: { value: input.proposal ?? null, isWritable: false },
+    proposalTransaction: {
+      value: input.proposalTransaction ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.proposalTransaction),
+    ],
+    programAddress,
+    data: getPushOptionInstructionInstructionDataEncoder().encode(
+      args as PushOptionInstructionInstructionDataArgs
+    ),
+  } as PushOptionInstructionInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountProposal,
+    TAccountProposalTransaction
+  >;
+
+  return instruction;
+}
+
+export type ParsedPushOptionInstructionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Proposal account */
+    proposal: TAccountMetas[1];
+    /** Proposal transaction account */
+    proposalTransaction: TAccountMetas[2];
+  };
+  data: PushOptionInstructionInstructionData;
+};
+
+export function parsePushOptionInstructionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedPushOptionInstructionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      proposal: getNextAccount(),
+      proposalTransaction: getNextAccount(),
+    },
+    data: getPushOptionInstructionInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
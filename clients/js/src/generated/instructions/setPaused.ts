@@ -0,0 +1,187 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type SetPausedInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountGovernanceConfig extends string
+        ? WritableAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetPausedInstructionData = {
+  discriminator: number;
+  paused: boolean;
+};
+
+export type SetPausedInstructionDataArgs = { paused: boolean };
+
+export function getSetPausedInstructionDataEncoder(): Encoder<SetPausedInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['paused', getBooleanEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 22 })
+  );
+}
+
+export function getSetPausedInstructionDataDecoder(): Decoder<SetPausedInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['paused', getBooleanDecoder()],
+  ]);
+}
+
+export function getSetPausedInstructionDataCodec(): Codec<
+  SetPausedInstructionDataArgs,
+  SetPausedInstructionData
+> {
+  return combineCodec(
+    getSetPausedInstructionDataEncoder(),
+    getSetPausedInstructionDataDecoder()
+  );
+}
+
+export type SetPausedInput<
+  TAccountAuthority extends string = string,
+  TAccountGovernanceConfig extends string = string,
+> = {
+  /** Treasury or pause guardian account */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
+  paused: SetPausedInstructionDataArgs['paused'];
+};
+
+export function getSetPausedInstruction<
+  TAccountAuthority extends string,
+  TAccountGovernanceConfig extends string,
+>(
+  input: SetPausedInput<TAccountAuthority, TAccountGovernanceConfig>
+): SetPausedInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountAuthority,
+  TAccountGovernanceConfig
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    authority: { value: input.authority ?? null, isWritable: false },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.governanceConfig),
+    ],
+    programAddress,
+    data: getSetPausedInstructionDataEncoder().encode(
+      args as SetPausedInstructionDataArgs
+    ),
+  } as SetPausedInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountAuthority,
+    TAccountGovernanceConfig
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetPausedInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Treasury or pause guardian account */
+    authority: TAccountMetas[0];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[1];
+  };
+  data: SetPausedInstructionData;
+};
+
+export function parseSetPausedInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetPausedInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      authority: getNextAccount(),
+      governanceConfig: getNextAccount(),
+    },
+    data: getSetPausedInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -43,6 +43,7 @@ export type SwitchVoteInstruction<
   TAccountStakeConfig extends string | IAccountMeta<string> = string,
   TAccountVote extends string | IAccountMeta<string> = string,
   TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -64,6 +65,9 @@ export type SwitchVoteInstruction<
       TAccountProposal extends string
         ? WritableAccount<TAccountProposal>
         : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -110,6 +114,7 @@ export type SwitchVoteInput<
   TAccountStakeConfig extends string = string,
   TAccountVote extends string = string,
   TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
 > = {
   /** Paladin stake authority account */
   stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
@@ -121,6 +126,8 @@ export type SwitchVoteInput<
   vote: Address<TAccountVote>;
   /** Proposal account */
   proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
   newElection: SwitchVoteInstructionDataArgs['newElection'];
 };
 
@@ -130,13 +137,15 @@ export function getSwitchVoteInstruction<
   TAccountStakeConfig extends string,
   TAccountVote extends string,
   TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
 >(
   input: SwitchVoteInput<
     TAccountStakeAuthority,
     TAccountStake,
     TAccountStakeConfig,
     TAccountVote,
-    TAccountProposal
+    TAccountProposal,
+    TAccountGovernanceConfig
   >
 ): SwitchVoteInstruction<
   typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
@@ -144,7 +153,8 @@ export function getSwitchVoteInstruction<
   TAccountStake,
   TAccountStakeConfig,
   TAccountVote,
-  TAccountProposal
+  TAccountProposal,
+  TAccountGovernanceConfig
 > {
   // Program address.
   const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
@@ -156,6 +166,10 @@ export function getSwitchVoteInstruction<
     stakeConfig: { value: input.stakeConfig ?? null, isWritable: false },
     vote: { value: input.vote ?? null, isWritable: true },
     proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -173,6 +187,7 @@ export function getSwitchVoteInstruction<
       getAccountMeta(accounts.stakeConfig),
       getAccountMeta(accounts.vote),
       getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
     ],
     programAddress,
     data: getSwitchVoteInstructionDataEncoder().encode(
@@ -184,7 +199,8 @@ export function getSwitchVoteInstruction<
     TAccountStake,
     TAccountStakeConfig,
     TAccountVote,
-    TAccountProposal
+    TAccountProposal,
+    TAccountGovernanceConfig
   >;
 
   return instruction;
@@ -206,6 +222,8 @@ export type ParsedSwitchVoteInstruction<
     vote: TAccountMetas[3];
     /** Proposal account */
     proposal: TAccountMetas[4];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[5];
   };
   data: SwitchVoteInstructionData;
 };
@@ -218,7 +236,7 @@ export function parseSwitchVoteInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedSwitchVoteInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -236,6 +254,7 @@ export function parseSwitchVoteInstruction<
       stakeConfig: getNextAccount(),
       vote: getNextAccount(),
       proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
     },
     data: getSwitchVoteInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,253 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type SwitchVoteOptionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountStake extends string | IAccountMeta<string> = string,
+  TAccountStakeConfig extends string | IAccountMeta<string> = string,
+  TAccountVote extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountStake extends string
+        ? ReadonlyAccount<TAccountStake>
+        : TAccountStake,
+      TAccountStakeConfig extends string
+        ? ReadonlyAccount<TAccountStakeConfig>
+        : TAccountStakeConfig,
+      TAccountVote extends string
+        ? WritableAccount<TAccountVote>
+        : TAccountVote,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SwitchVoteOptionInstructionData = {
+  discriminator: number;
+  newOptionIndex: number;
+};
+
+export type SwitchVoteOptionInstructionDataArgs = { newOptionIndex: number };
+
+export function getSwitchVoteOptionInstructionDataEncoder(): Encoder<SwitchVoteOptionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['newOptionIndex', getU8Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 17 })
+  );
+}
+
+export function getSwitchVoteOptionInstructionDataDecoder(): Decoder<SwitchVoteOptionInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['newOptionIndex', getU8Decoder()],
+  ]);
+}
+
+export function getSwitchVoteOptionInstructionDataCodec(): Codec<
+  SwitchVoteOptionInstructionDataArgs,
+  SwitchVoteOptionInstructionData
+> {
+  return combineCodec(
+    getSwitchVoteOptionInstructionDataEncoder(),
+    getSwitchVoteOptionInstructionDataDecoder()
+  );
+}
+
+export type SwitchVoteOptionInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountStake extends string = string,
+  TAccountStakeConfig extends string = string,
+  TAccountVote extends string = string,
+  TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Paladin stake account */
+  stake: Address<TAccountStake>;
+  /** Paladin stake config account */
+  stakeConfig: Address<TAccountStakeConfig>;
+  /** Proposal vote account */
+  vote: Address<TAccountVote>;
+  /** Proposal account */
+  proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
+  newOptionIndex: SwitchVoteOptionInstructionDataArgs['newOptionIndex'];
+};
+
+export function getSwitchVoteOptionInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountStake extends string,
+  TAccountStakeConfig extends string,
+  TAccountVote extends string,
+  TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
+>(
+  input: SwitchVoteOptionInput<
+    TAccountStakeAuthority,
+    TAccountStake,
+    TAccountStakeConfig,
+    TAccountVote,
+    TAccountProposal,
+    TAccountGovernanceConfig
+  >
+): SwitchVoteOptionInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountStake,
+  TAccountStakeConfig,
+  TAccountVote,
+  TAccountProposal,
+  TAccountGovernanceConfig
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    stake: { value: input.stake ?? null, isWritable: false },
+    stakeConfig: { value: input.stakeConfig ?? null, isWritable: false },
+    vote: { value: input.vote ?? null, isWritable: true },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.stake),
+      getAccountMeta(accounts.stakeConfig),
+      getAccountMeta(accounts.vote),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
+    ],
+    programAddress,
+    data: getSwitchVoteOptionInstructionDataEncoder().encode(
+      args as SwitchVoteOptionInstructionDataArgs
+    ),
+  } as SwitchVoteOptionInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountStake,
+    TAccountStakeConfig,
+    TAccountVote,
+    TAccountProposal,
+    TAccountGovernanceConfig
+  >;
+
+  return instruction;
+}
+
+export type ParsedSwitchVoteOptionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Paladin stake account */
+    stake: TAccountMetas[1];
+    /** Paladin stake config account */
+    stakeConfig: TAccountMetas[2];
+    /** Proposal vote account */
+    vote: TAccountMetas[3];
+    /** Proposal account */
+    proposal: TAccountMetas[4];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[5];
+  };
+  data: SwitchVoteOptionInstructionData;
+};
+
+export function parseSwitchVoteOptionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSwitchVoteOptionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      stake: getNextAccount(),
+      stakeConfig: getNextAccount(),
+      vote: getNextAccount(),
+      proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
+    },
+    data: getSwitchVoteOptionInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -43,6 +43,7 @@ export type VoteInstruction<
   TAccountStakeConfig extends string | IAccountMeta<string> = string,
   TAccountVote extends string | IAccountMeta<string> = string,
   TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -67,6 +68,9 @@ export type VoteInstruction<
       TAccountProposal extends string
         ? WritableAccount<TAccountProposal>
         : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -114,6 +118,7 @@ export type VoteInput<
   TAccountStakeConfig extends string = string,
   TAccountVote extends string = string,
   TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /** Paladin stake authority account */
@@ -126,6 +131,8 @@ export type VoteInput<
   vote: Address<TAccountVote>;
   /** Proposal account */
   proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
   /** System program */
   systemProgram?: Address<TAccountSystemProgram>;
   election: VoteInstructionDataArgs['election'];
@@ -137,6 +144,7 @@ export function getVoteInstruction<
   TAccountStakeConfig extends string,
   TAccountVote extends string,
   TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
   TAccountSystemProgram extends string,
 >(
   input: VoteInput<
@@ -145,6 +153,7 @@ export function getVoteInstruction<
     TAccountStakeConfig,
     TAccountVote,
     TAccountProposal,
+    TAccountGovernanceConfig,
     TAccountSystemProgram
   >
 ): VoteInstruction<
@@ -154,6 +163,7 @@ export function getVoteInstruction<
   TAccountStakeConfig,
   TAccountVote,
   TAccountProposal,
+  TAccountGovernanceConfig,
   TAccountSystemProgram
 > {
   // Program address.
@@ -166,6 +176,10 @@ export function getVoteInstruction<
     stakeConfig: { value: input.stakeConfig ?? null, isWritable: false },
     vote: { value: input.vote ?? null, isWritable: true },
     proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -190,6 +204,7 @@ export function getVoteInstruction<
       getAccountMeta(accounts.stakeConfig),
       getAccountMeta(accounts.vote),
       getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
@@ -203,6 +218,7 @@ export function getVoteInstruction<
     TAccountStakeConfig,
     TAccountVote,
     TAccountProposal,
+    TAccountGovernanceConfig,
     TAccountSystemProgram
   >;
 
@@ -225,8 +241,10 @@ export type ParsedVoteInstruction<
     vote: TAccountMetas[3];
     /** Proposal account */
     proposal: TAccountMetas[4];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[5];
     /** System program */
-    systemProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
   };
   data: VoteInstructionData;
 };
@@ -239,7 +257,7 @@ export function parseVoteInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedVoteInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -257,6 +275,7 @@ export function parseVoteInstruction<
       stakeConfig: getNextAccount(),
       vote: getNextAccount(),
       proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getVoteInstructionDataDecoder().decode(instruction.data),
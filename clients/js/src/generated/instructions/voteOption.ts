@@ -0,0 +1,277 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_GOVERNANCE_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type VoteOptionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountStake extends string | IAccountMeta<string> = string,
+  TAccountStakeConfig extends string | IAccountMeta<string> = string,
+  TAccountVote extends string | IAccountMeta<string> = string,
+  TAccountProposal extends string | IAccountMeta<string> = string,
+  TAccountGovernanceConfig extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountStake extends string
+        ? ReadonlyAccount<TAccountStake>
+        : TAccountStake,
+      TAccountStakeConfig extends string
+        ? ReadonlyAccount<TAccountStakeConfig>
+        : TAccountStakeConfig,
+      TAccountVote extends string
+        ? WritableAccount<TAccountVote>
+        : TAccountVote,
+      TAccountProposal extends string
+        ? WritableAccount<TAccountProposal>
+        : TAccountProposal,
+      TAccountGovernanceConfig extends string
+        ? ReadonlyAccount<TAccountGovernanceConfig>
+        : TAccountGovernanceConfig,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type VoteOptionInstructionData = {
+  discriminator: number;
+  optionIndex: number;
+};
+
+export type VoteOptionInstructionDataArgs = { optionIndex: number };
+
+export function getVoteOptionInstructionDataEncoder(): Encoder<VoteOptionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['optionIndex', getU8Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 16 })
+  );
+}
+
+export function getVoteOptionInstructionDataDecoder(): Decoder<VoteOptionInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['optionIndex', getU8Decoder()],
+  ]);
+}
+
+export function getVoteOptionInstructionDataCodec(): Codec<
+  VoteOptionInstructionDataArgs,
+  VoteOptionInstructionData
+> {
+  return combineCodec(
+    getVoteOptionInstructionDataEncoder(),
+    getVoteOptionInstructionDataDecoder()
+  );
+}
+
+export type VoteOptionInput<
+  TAccountStakeAuthority extends string = string,
+  TAccountStake extends string = string,
+  TAccountStakeConfig extends string = string,
+  TAccountVote extends string = string,
+  TAccountProposal extends string = string,
+  TAccountGovernanceConfig extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Paladin stake authority account */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Paladin stake account */
+  stake: Address<TAccountStake>;
+  /** Paladin stake config account */
+  stakeConfig: Address<TAccountStakeConfig>;
+  /** Proposal vote account */
+  vote: Address<TAccountVote>;
+  /** Proposal account */
+  proposal: Address<TAccountProposal>;
+  /** Governance config account */
+  governanceConfig: Address<TAccountGovernanceConfig>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  optionIndex: VoteOptionInstructionDataArgs['optionIndex'];
+};
+
+export function getVoteOptionInstruction<
+  TAccountStakeAuthority extends string,
+  TAccountStake extends string,
+  TAccountStakeConfig extends string,
+  TAccountVote extends string,
+  TAccountProposal extends string,
+  TAccountGovernanceConfig extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: VoteOptionInput<
+    TAccountStakeAuthority,
+    TAccountStake,
+    TAccountStakeConfig,
+    TAccountVote,
+    TAccountProposal,
+    TAccountGovernanceConfig,
+    TAccountSystemProgram
+  >
+): VoteOptionInstruction<
+  typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountStakeAuthority,
+  TAccountStake,
+  TAccountStakeConfig,
+  TAccountVote,
+  TAccountProposal,
+  TAccountGovernanceConfig,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_GOVERNANCE_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    stake: { value: input.stake ?? null, isWritable: false },
+    stakeConfig: { value: input.stakeConfig ?? null, isWritable: false },
+    vote: { value: input.vote ?? null, isWritable: true },
+    proposal: { value: input.proposal ?? null, isWritable: true },
+    governanceConfig: {
+      value: input.governanceConfig ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.stake),
+      getAccountMeta(accounts.stakeConfig),
+      getAccountMeta(accounts.vote),
+      getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.governanceConfig),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getVoteOptionInstructionDataEncoder().encode(
+      args as VoteOptionInstructionDataArgs
+    ),
+  } as VoteOptionInstruction<
+    typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+    TAccountStakeAuthority,
+    TAccountStake,
+    TAccountStakeConfig,
+    TAccountVote,
+    TAccountProposal,
+    TAccountGovernanceConfig,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedVoteOptionInstruction<
+  TProgram extends string = typeof PALADIN_GOVERNANCE_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Paladin stake authority account */
+    stakeAuthority: TAccountMetas[0];
+    /** Paladin stake account */
+    stake: TAccountMetas[1];
+    /** Paladin stake config account */
+    stakeConfig: TAccountMetas[2];
+    /** Proposal vote account */
+    vote: TAccountMetas[3];
+    /** Proposal account */
+    proposal: TAccountMetas[4];
+    /** Governance config account */
+    governanceConfig: TAccountMetas[5];
+    /** System program */
+    systemProgram: TAccountMetas[6];
+  };
+  data: VoteOptionInstructionData;
+};
+
+export function parseVoteOptionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedVoteOptionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      stakeAuthority: getNextAccount(),
+      stake: getNextAccount(),
+      stakeConfig: getNextAccount(),
+      vote: getNextAccount(),
+      proposal: getNextAccount(),
+      governanceConfig: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getVoteOptionInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -14,17 +14,29 @@ import {
 } from '@solana/web3.js';
 import {
   type ParsedBeginVotingInstruction,
+  type ParsedCancelProposalInstruction,
+  type ParsedCloseAuthorInstruction,
   type ParsedCreateProposalInstruction,
+  type ParsedCreateSignalProposalInstruction,
   type ParsedDeleteProposalInstruction,
   type ParsedDeleteVoteInstruction,
   type ParsedFinishVotingInstruction,
   type ParsedInitializeAuthorInstruction,
   type ParsedInitializeGovernanceInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedMigrateGovernanceInstruction,
+  type ParsedPartialUpdateGovernanceInstruction,
   type ParsedProcessInstructionInstruction,
   type ParsedPushInstructionInstruction,
+  type ParsedPushOptionInstruction,
+  type ParsedPushOptionInstructionInstruction,
+  type ParsedPushPrerequisiteInstruction,
+  type ParsedSetPausedInstruction,
   type ParsedSwitchVoteInstruction,
+  type ParsedSwitchVoteOptionInstruction,
   type ParsedUpdateGovernanceInstruction,
   type ParsedVoteInstruction,
+  type ParsedVoteOptionInstruction,
 } from '../instructions';
 
 export const PALADIN_GOVERNANCE_PROGRAM_ADDRESS =
@@ -50,6 +62,18 @@ export enum PaladinGovernanceInstruction {
   ProcessInstruction,
   InitializeGovernance,
   UpdateGovernance,
+  CancelProposal,
+  PushPrerequisite,
+  PushOption,
+  PushOptionInstruction,
+  VoteOption,
+  SwitchVoteOption,
+  CreateSignalProposal,
+  CloseAuthor,
+  PartialUpdateGovernance,
+  MigrateAccount,
+  SetPaused,
+  MigrateGovernance,
 }
 
 export function identifyPaladinGovernanceInstruction(
@@ -92,6 +116,42 @@ export function identifyPaladinGovernanceInstruction(
   if (containsBytes(data, getU8Encoder().encode(11), 0)) {
     return PaladinGovernanceInstruction.UpdateGovernance;
   }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return PaladinGovernanceInstruction.CancelProposal;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return PaladinGovernanceInstruction.PushPrerequisite;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return PaladinGovernanceInstruction.PushOption;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return PaladinGovernanceInstruction.PushOptionInstruction;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return PaladinGovernanceInstruction.VoteOption;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return PaladinGovernanceInstruction.SwitchVoteOption;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return PaladinGovernanceInstruction.CreateSignalProposal;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return PaladinGovernanceInstruction.CloseAuthor;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return PaladinGovernanceInstruction.PartialUpdateGovernance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return PaladinGovernanceInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return PaladinGovernanceInstruction.SetPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return PaladinGovernanceInstruction.MigrateGovernance;
+  }
   throw new Error(
     'The provided instruction could not be identified as a paladinGovernance instruction.'
   );
@@ -135,4 +195,40 @@ export type ParsedPaladinGovernanceInstruction<
     } & ParsedInitializeGovernanceInstruction<TProgram>)
   | ({
       instructionType: PaladinGovernanceInstruction.UpdateGovernance;
-    } & ParsedUpdateGovernanceInstruction<TProgram>);
+    } & ParsedUpdateGovernanceInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.CancelProposal;
+    } & ParsedCancelProposalInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.PushPrerequisite;
+    } & ParsedPushPrerequisiteInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.PushOption;
+    } & ParsedPushOptionInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.PushOptionInstruction;
+    } & ParsedPushOptionInstructionInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.VoteOption;
+    } & ParsedVoteOptionInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.SwitchVoteOption;
+    } & ParsedSwitchVoteOptionInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.CreateSignalProposal;
+    } & ParsedCreateSignalProposalInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.CloseAuthor;
+    } & ParsedCloseAuthorInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.PartialUpdateGovernance;
+    } & ParsedPartialUpdateGovernanceInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.SetPaused;
+    } & ParsedSetPausedInstruction<TProgram>)
+  | ({
+      instructionType: PaladinGovernanceInstruction.MigrateGovernance;
+    } & ParsedMigrateGovernanceInstruction<TProgram>);
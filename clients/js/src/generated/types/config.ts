@@ -25,48 +25,86 @@ import {
   type Decoder,
   type Encoder,
 } from '@solana/web3.js';
+import {
+  getNullableU64Decoder,
+  getNullableU64Encoder,
+  type NullableU64,
+  type NullableU64Args,
+} from '../../hooked';
 
 export type Config = {
+  discriminator: Array<number>;
   cooldownPeriodSeconds: bigint;
-  proposalAcceptanceThreshold: number;
-  proposalRejectionThreshold: number;
-  signerBumpSeed: number;
-  padding: Array<number>;
+  proposalMinimumQuorum: number;
+  proposalPassThreshold: number;
   stakeConfigAddress: Address;
   votingPeriodSeconds: bigint;
+  stakePerProposal: bigint;
+  governanceConfig: Address;
+  cooldownExpires: bigint;
+  pauseGuardian: Address;
+  pausedTimestamp: NullableU64;
+  pausedSeconds: bigint;
+  successor: Address;
+  proposalWinningMargin: number;
+  padding: Array<number>;
 };
 
 export type ConfigArgs = {
+  discriminator: Array<number>;
   cooldownPeriodSeconds: number | bigint;
-  proposalAcceptanceThreshold: number;
-  proposalRejectionThreshold: number;
-  signerBumpSeed: number;
-  padding: Array<number>;
+  proposalMinimumQuorum: number;
+  proposalPassThreshold: number;
   stakeConfigAddress: Address;
   votingPeriodSeconds: number | bigint;
+  stakePerProposal: number | bigint;
+  governanceConfig: Address;
+  cooldownExpires: number | bigint;
+  pauseGuardian: Address;
+  pausedTimestamp: NullableU64Args;
+  pausedSeconds: number | bigint;
+  successor: Address;
+  proposalWinningMargin: number;
+  padding: Array<number>;
 };
 
 export function getConfigEncoder(): Encoder<ConfigArgs> {
   return getStructEncoder([
+    ['discriminator', getArrayEncoder(getU8Encoder(), { size: 8 })],
     ['cooldownPeriodSeconds', getU64Encoder()],
-    ['proposalAcceptanceThreshold', getU32Encoder()],
-    ['proposalRejectionThreshold', getU32Encoder()],
-    ['signerBumpSeed', getU8Encoder()],
-    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+    ['proposalMinimumQuorum', getU32Encoder()],
+    ['proposalPassThreshold', getU32Encoder()],
     ['stakeConfigAddress', getAddressEncoder()],
     ['votingPeriodSeconds', getU64Encoder()],
+    ['stakePerProposal', getU64Encoder()],
+    ['governanceConfig', getAddressEncoder()],
+    ['cooldownExpires', getU64Encoder()],
+    ['pauseGuardian', getAddressEncoder()],
+    ['pausedTimestamp', getNullableU64Encoder()],
+    ['pausedSeconds', getU64Encoder()],
+    ['successor', getAddressEncoder()],
+    ['proposalWinningMargin', getU32Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 4 })],
   ]);
 }
 
 export function getConfigDecoder(): Decoder<Config> {
   return getStructDecoder([
+    ['discriminator', getArrayDecoder(getU8Decoder(), { size: 8 })],
     ['cooldownPeriodSeconds', getU64Decoder()],
-    ['proposalAcceptanceThreshold', getU32Decoder()],
-    ['proposalRejectionThreshold', getU32Decoder()],
-    ['signerBumpSeed', getU8Decoder()],
-    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+    ['proposalMinimumQuorum', getU32Decoder()],
+    ['proposalPassThreshold', getU32Decoder()],
     ['stakeConfigAddress', getAddressDecoder()],
     ['votingPeriodSeconds', getU64Decoder()],
+    ['stakePerProposal', getU64Decoder()],
+    ['governanceConfig', getAddressDecoder()],
+    ['cooldownExpires', getU64Decoder()],
+    ['pauseGuardian', getAddressDecoder()],
+    ['pausedTimestamp', getNullableU64Decoder()],
+    ['pausedSeconds', getU64Decoder()],
+    ['successor', getAddressDecoder()],
+    ['proposalWinningMargin', getU32Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 4 })],
   ]);
 }
 
@@ -9,6 +9,8 @@
 export * from './config';
 export * from './proposalAccountMeta';
 export * from './proposalInstruction';
+export * from './proposalKind';
+export * from './proposalOption';
 export * from './proposalStatus';
 export * from './proposalTransaction';
 export * from './proposalVoteElection';
@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getEnumDecoder,
+  getEnumEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export enum ProposalKind {
+  Executable,
+  Signal,
+}
+
+export type ProposalKindArgs = ProposalKind;
+
+export function getProposalKindEncoder(): Encoder<ProposalKindArgs> {
+  return getEnumEncoder(ProposalKind);
+}
+
+export function getProposalKindDecoder(): Decoder<ProposalKind> {
+  return getEnumDecoder(ProposalKind);
+}
+
+export function getProposalKindCodec(): Codec<
+  ProposalKindArgs,
+  ProposalKind
+> {
+  return combineCodec(getProposalKindEncoder(), getProposalKindDecoder());
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+import {
+  getProposalInstructionDecoder,
+  getProposalInstructionEncoder,
+  type ProposalInstruction,
+  type ProposalInstructionArgs,
+} from '.';
+
+export type ProposalOption = { instructions: Array<ProposalInstruction> };
+
+export type ProposalOptionArgs = { instructions: Array<ProposalInstructionArgs> };
+
+export function getProposalOptionEncoder(): Encoder<ProposalOptionArgs> {
+  return getStructEncoder([
+    ['instructions', getArrayEncoder(getProposalInstructionEncoder())],
+  ]);
+}
+
+export function getProposalOptionDecoder(): Decoder<ProposalOption> {
+  return getStructDecoder([
+    ['instructions', getArrayDecoder(getProposalInstructionDecoder())],
+  ]);
+}
+
+export function getProposalOptionCodec(): Codec<
+  ProposalOptionArgs,
+  ProposalOption
+> {
+  return combineCodec(getProposalOptionEncoder(), getProposalOptionDecoder());
+}
@@ -21,6 +21,7 @@ export enum ProposalStatus {
   Accepted,
   Rejected,
   Processed,
+  Cancelled,
 }
 
 export type ProposalStatusArgs = ProposalStatus;
@@ -8,10 +8,13 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  type Address,
   type Codec,
   type Decoder,
   type Encoder,
@@ -21,23 +24,37 @@ import {
   getProposalInstructionEncoder,
   type ProposalInstruction,
   type ProposalInstructionArgs,
+  getProposalOptionDecoder,
+  getProposalOptionEncoder,
+  type ProposalOption,
+  type ProposalOptionArgs,
 } from '.';
 
-export type ProposalTransaction = { instructions: Array<ProposalInstruction> };
+export type ProposalTransaction = {
+  instructions: Array<ProposalInstruction>;
+  prerequisites: Array<Address>;
+  options: Array<ProposalOption>;
+};
 
 export type ProposalTransactionArgs = {
   instructions: Array<ProposalInstructionArgs>;
+  prerequisites: Array<Address>;
+  options: Array<ProposalOptionArgs>;
 };
 
 export function getProposalTransactionEncoder(): Encoder<ProposalTransactionArgs> {
   return getStructEncoder([
     ['instructions', getArrayEncoder(getProposalInstructionEncoder())],
+    ['prerequisites', getArrayEncoder(getAddressEncoder())],
+    ['options', getArrayEncoder(getProposalOptionEncoder())],
   ]);
 }
 
 export function getProposalTransactionDecoder(): Decoder<ProposalTransaction> {
   return getStructDecoder([
     ['instructions', getArrayDecoder(getProposalInstructionDecoder())],
+    ['prerequisites', getArrayDecoder(getAddressDecoder())],
+    ['options', getArrayDecoder(getProposalOptionDecoder())],
   ]);
 }
 
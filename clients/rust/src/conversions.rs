@@ -0,0 +1,120 @@
+//! Conversions between the program's account types and the generated client
+//! types.
+//!
+//! The program's accounts are `bytemuck` types whose borsh encoding matches
+//! their in-memory layout, so the conversions go through the account bytes
+//! and are lossless by construction.
+
+use {
+    crate::{
+        accounts::{Author, GovernanceConfig, Proposal, ProposalVote},
+        types::{ProposalAccountMeta, ProposalInstruction, ProposalOption, ProposalTransaction},
+    },
+    borsh::BorshSerialize,
+    paladin_governance_program::state,
+    std::io::{Error, ErrorKind},
+};
+
+macro_rules! impl_pod_conversions {
+    ($client:ty, $program:ty) => {
+        impl TryFrom<&$program> for $client {
+            type Error = Error;
+
+            fn try_from(value: &$program) -> Result<Self, Self::Error> {
+                Self::from_bytes(bytemuck::bytes_of(value))
+            }
+        }
+
+        impl TryFrom<&$client> for $program {
+            type Error = Error;
+
+            fn try_from(value: &$client) -> Result<Self, Self::Error> {
+                bytemuck::try_pod_read_unaligned(&value.try_to_vec()?)
+                    .map_err(|err| Error::new(ErrorKind::InvalidData, format!("{err:?}")))
+            }
+        }
+    };
+}
+
+impl_pod_conversions!(Author, state::Author);
+impl_pod_conversions!(GovernanceConfig, state::GovernanceConfig);
+impl_pod_conversions!(Proposal, state::Proposal);
+impl_pod_conversions!(ProposalVote, state::ProposalVote);
+
+impl From<&state::ProposalAccountMeta> for ProposalAccountMeta {
+    fn from(value: &state::ProposalAccountMeta) -> Self {
+        Self {
+            pubkey: value.pubkey,
+            is_signer: value.is_signer,
+            is_writable: value.is_writable,
+        }
+    }
+}
+
+impl From<&ProposalAccountMeta> for state::ProposalAccountMeta {
+    fn from(value: &ProposalAccountMeta) -> Self {
+        Self {
+            pubkey: value.pubkey,
+            is_signer: value.is_signer,
+            is_writable: value.is_writable,
+        }
+    }
+}
+
+impl From<&state::ProposalInstruction> for ProposalInstruction {
+    fn from(value: &state::ProposalInstruction) -> Self {
+        Self {
+            program_id: value.program_id,
+            accounts: value.accounts.iter().map(Into::into).collect(),
+            data: value.data.clone(),
+            executed: value.executed,
+        }
+    }
+}
+
+impl From<&ProposalInstruction> for state::ProposalInstruction {
+    fn from(value: &ProposalInstruction) -> Self {
+        Self {
+            program_id: value.program_id,
+            accounts: value.accounts.iter().map(Into::into).collect(),
+            data: value.data.clone(),
+            executed: value.executed,
+        }
+    }
+}
+
+impl From<&state::ProposalOption> for ProposalOption {
+    fn from(value: &state::ProposalOption) -> Self {
+        Self {
+            instructions: value.instructions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&ProposalOption> for state::ProposalOption {
+    fn from(value: &ProposalOption) -> Self {
+        Self {
+            instructions: value.instructions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&state::ProposalTransaction> for ProposalTransaction {
+    fn from(value: &state::ProposalTransaction) -> Self {
+        Self {
+            instructions: value.instructions.iter().map(Into::into).collect(),
+            prerequisites: value.prerequisites.clone(),
+            options: value.options.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&ProposalTransaction> for state::ProposalTransaction {
+    fn from(value: &ProposalTransaction) -> Self {
+        Self {
+            instructions: value.instructions.iter().map(Into::into).collect(),
+            prerequisites: value.prerequisites.clone(),
+            options: value.options.iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Human-readable decoding of proposal instructions, from
+//! `paladin-governance-decoder`.
+
+pub use paladin_governance_decoder::*;
+use {
+    crate::types::ProposalInstruction,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+};
+
+impl ProposalInstruction {
+    /// Decodes the instruction, flagging the provided governance treasury.
+    pub fn decode(&self, treasury: Option<&Pubkey>) -> DecodedInstruction {
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: self
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        };
+        decode(&instruction, treasury)
+    }
+}
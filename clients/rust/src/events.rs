@@ -0,0 +1,150 @@
+//! Decoders for the events the program logs on every state transition.
+//!
+//! The program logs each event with `sol_log_data`, as a version byte
+//! followed by the borsh-encoded event, which appears in transaction logs as
+//! a `Program data:` line. These types mirror the program's `event` module.
+
+use {
+    crate::types::{ProposalKind, ProposalStatus, ProposalVoteElection},
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
+
+/// The version of the event encoding these types decode.
+pub const EVENT_VERSION: u8 = 1;
+
+/// A governance state transition.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceEvent {
+    ProposalCreated {
+        proposal: Pubkey,
+        author: Pubkey,
+        governance_config: Pubkey,
+        kind: ProposalKind,
+        creation_timestamp: i64,
+    },
+    InstructionPushed {
+        proposal: Pubkey,
+        option_index: Option<u8>,
+        instruction_index: u32,
+        program_id: Pubkey,
+    },
+    VotingBegan {
+        proposal: Pubkey,
+        voting_start_timestamp: u64,
+    },
+    VoteCast {
+        proposal: Pubkey,
+        vote: Pubkey,
+        authority: Pubkey,
+        election: ProposalVoteElection,
+        option_index: Option<u8>,
+        stake: u64,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    VoteSwitched {
+        proposal: Pubkey,
+        vote: Pubkey,
+        authority: Pubkey,
+        previous_election: ProposalVoteElection,
+        previous_option_index: Option<u8>,
+        previous_stake: u64,
+        election: ProposalVoteElection,
+        option_index: Option<u8>,
+        stake: u64,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    CooldownStarted {
+        proposal: Pubkey,
+        cooldown_timestamp: u64,
+    },
+    /// Voting closed, with the outcome, or because the author cancelled the
+    /// proposal.
+    ProposalFinalized {
+        proposal: Pubkey,
+        status: ProposalStatus,
+        winning_option: Option<u8>,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    InstructionExecuted {
+        proposal: Pubkey,
+        option_index: Option<u8>,
+        instruction_index: u32,
+        status: ProposalStatus,
+    },
+    /// A governance config was initialized or updated.
+    GovernanceUpdated {
+        governance_config: Pubkey,
+        cooldown_period_seconds: u64,
+        proposal_minimum_quorum: u32,
+        proposal_pass_threshold: u32,
+        voting_period_seconds: u64,
+        stake_per_proposal: u64,
+        pause_guardian: Pubkey,
+        proposal_winning_margin: u32,
+    },
+}
+
+impl GovernanceEvent {
+    /// Decodes an event from the fields of a `sol_log_data` log.
+    ///
+    /// Returns `None` for data that isn't an event of this version, such as
+    /// events added to the program after this client.
+    pub fn from_log_data(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [[EVENT_VERSION], data] => Self::try_from_slice(data).ok(),
+            _ => None,
+        }
+    }
+
+    /// Decodes an event from a `Program data:` log line.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let fields = log
+            .strip_prefix("Program data: ")?
+            .split(' ')
+            .map(|field| STANDARD.decode(field).ok())
+            .collect::<Option<Vec<_>>>()?;
+        Self::from_log_data(&fields.iter().map(Vec::as_slice).collect::<Vec<_>>())
+    }
+}
+
+/// Decodes the events in a transaction's logs, in the order they were logged.
+///
+/// Only data logged while the governance program itself is executing is
+/// decoded, including when it invokes itself to execute a proposal, so other
+/// programs can't forge events.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<GovernanceEvent> {
+    let mut invocations = Vec::new();
+    let mut events = Vec::new();
+    for log in logs {
+        let log = log.as_ref();
+        if log.starts_with("Program data: ") {
+            if invocations.last() == Some(&true) {
+                events.extend(GovernanceEvent::from_log(log));
+            }
+            continue;
+        }
+
+        // `Program <program id> invoke [<depth>]`, `Program <program id>
+        // success` or `Program <program id> failed: <error>`.
+        let Some((program_id, status)) = log
+            .strip_prefix("Program ")
+            .and_then(|log| log.split_once(' '))
+        else {
+            continue;
+        };
+        let Ok(program_id) = program_id.parse::<Pubkey>() else {
+            continue;
+        };
+        if status.starts_with("invoke [") {
+            invocations.push(program_id == crate::ID);
+        } else if status == "success" || status.starts_with("failed") {
+            invocations.pop();
+        }
+    }
+    events
+}
@@ -5,7 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 use {
-    crate::generated::types::Config,
+    crate::hooked::NullableU64,
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::pubkey::Pubkey,
 };
@@ -13,6 +13,7 @@ use {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GovernanceConfig {
+    pub discriminator: [u8; 8],
     pub cooldown_period_seconds: u64,
     pub proposal_minimum_quorum: u32,
     pub proposal_pass_threshold: u32,
@@ -23,13 +24,29 @@ pub struct GovernanceConfig {
     pub stake_config_address: Pubkey,
     pub voting_period_seconds: u64,
     pub stake_per_proposal: u64,
-    pub governance_config: Config,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub governance_config: Pubkey,
     pub cooldown_expires: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pause_guardian: Pubkey,
+    pub paused_timestamp: NullableU64,
+    pub paused_seconds: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub successor: Pubkey,
+    pub proposal_winning_margin: u32,
+    pub padding: [u8; 4],
 }
 
 impl GovernanceConfig {
-    pub const LEN: usize = 104;
-
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
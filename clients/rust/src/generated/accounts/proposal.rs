@@ -6,7 +6,7 @@
 
 use {
     crate::{
-        generated::types::{Config, ProposalStatus},
+        generated::types::{Config, ProposalKind, ProposalStatus},
         hooked::NullableU64,
     },
     borsh::{BorshDeserialize, BorshSerialize},
@@ -28,8 +28,13 @@ pub struct Proposal {
     pub stake_against: u64,
     pub stake_for: u64,
     pub status: ProposalStatus,
-    pub padding: [u8; 7],
+    pub option_count: u8,
+    pub winning_option: u8,
+    pub kind: ProposalKind,
+    pub padding: [u8; 4],
     pub voting_start_timestamp: NullableU64,
+    pub option_stake: [u64; 8],
+    pub paused_seconds_checkpoint: u64,
 }
 
 impl Proposal {
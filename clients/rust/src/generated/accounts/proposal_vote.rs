@@ -25,7 +25,8 @@ pub struct ProposalVote {
     )]
     pub authority: Pubkey,
     pub election: ProposalVoteElection,
-    pub padding: [u8; 7],
+    pub option_index: u8,
+    pub padding: [u8; 6],
 }
 
 impl ProposalVote {
@@ -59,6 +59,66 @@ pub enum PaladinGovernanceError {
     /// 16 - Cooldown period is not over yet, cannot create new proposal.
     #[error("Cooldown period is not over yet, cannot create new proposal.")]
     CooldownPeriodNotOver = 0x10,
+    /// 17 - Proposal cooldown period has already begun.
+    #[error("Proposal cooldown period has already begun.")]
+    ProposalCooldownStarted = 0x11,
+    /// 18 - Invalid prerequisite proposal.
+    #[error("Invalid prerequisite proposal.")]
+    InvalidPrerequisite = 0x12,
+    /// 19 - Incorrect prerequisite proposal address.
+    #[error("Incorrect prerequisite proposal address.")]
+    IncorrectPrerequisiteAddress = 0x13,
+    /// 20 - Prerequisite proposal has not been processed.
+    #[error("Prerequisite proposal has not been processed.")]
+    PrerequisiteNotProcessed = 0x14,
+    /// 21 - Proposal has too many options.
+    #[error("Proposal has too many options.")]
+    TooManyProposalOptions = 0x15,
+    /// 22 - Invalid proposal option.
+    #[error("Invalid proposal option.")]
+    InvalidProposalOption = 0x16,
+    /// 23 - Proposal is not executable.
+    #[error("Proposal is not executable.")]
+    ProposalNotExecutable = 0x17,
+    /// 24 - Author has active proposals.
+    #[error("Author has active proposals.")]
+    AuthorHasActiveProposals = 0x18,
+    /// 25 - Incorrect program data address.
+    #[error("Incorrect program data address.")]
+    IncorrectProgramDataAddress = 0x19,
+    /// 26 - Proposal minimum quorum out of bounds.
+    #[error("Proposal minimum quorum out of bounds.")]
+    InvalidProposalMinimumQuorum = 0x1A,
+    /// 27 - Proposal pass threshold out of bounds.
+    #[error("Proposal pass threshold out of bounds.")]
+    InvalidProposalPassThreshold = 0x1B,
+    /// 28 - Voting period out of bounds.
+    #[error("Voting period out of bounds.")]
+    InvalidVotingPeriod = 0x1C,
+    /// 29 - Cooldown period out of bounds.
+    #[error("Cooldown period out of bounds.")]
+    InvalidCooldownPeriod = 0x1D,
+    /// 30 - Account must be migrated to the current layout.
+    #[error("Account must be migrated to the current layout.")]
+    AccountNotMigrated = 0x1E,
+    /// 31 - Governance is paused.
+    #[error("Governance is paused.")]
+    GovernancePaused = 0x1F,
+    /// 32 - Governance config has been retired.
+    #[error("Governance config has been retired.")]
+    GovernanceRetired = 0x20,
+    /// 33 - Incorrect treasury token account.
+    #[error("Incorrect treasury token account.")]
+    IncorrectTreasuryTokenAccount = 0x21,
+    /// 34 - Pause guardian not set.
+    #[error("Pause guardian not set.")]
+    PauseGuardianNotSet = 0x22,
+    /// 35 - Prerequisite proposal was deleted, rejected or cancelled.
+    #[error("Prerequisite proposal was deleted, rejected or cancelled.")]
+    PrerequisiteClosed = 0x23,
+    /// 36 - Invalid proposal winning margin.
+    #[error("Invalid proposal winning margin.")]
+    InvalidProposalWinningMargin = 0x24,
 }
 
 impl solana_program::program_error::PrintProgramError for PaladinGovernanceError {
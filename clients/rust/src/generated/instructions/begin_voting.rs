@@ -12,6 +12,8 @@ pub struct BeginVoting {
     pub stake_authority: solana_program::pubkey::Pubkey,
     /// Proposal account
     pub proposal: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
 }
 
 impl BeginVoting {
@@ -23,7 +25,7 @@ impl BeginVoting {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.stake_authority,
             true,
@@ -32,6 +34,10 @@ impl BeginVoting {
             self.proposal,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = BeginVotingInstructionData::new().try_to_vec().unwrap();
 
@@ -66,10 +72,12 @@ impl Default for BeginVotingInstructionData {
 ///
 ///   0. `[signer]` stake_authority
 ///   1. `[writable]` proposal
+///   2. `[]` governance_config
 #[derive(Clone, Debug, Default)]
 pub struct BeginVotingBuilder {
     stake_authority: Option<solana_program::pubkey::Pubkey>,
     proposal: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -92,6 +100,15 @@ impl BeginVotingBuilder {
         self.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -115,6 +132,9 @@ impl BeginVotingBuilder {
         let accounts = BeginVoting {
             stake_authority: self.stake_authority.expect("stake_authority is not set"),
             proposal: self.proposal.expect("proposal is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -127,6 +147,8 @@ pub struct BeginVotingCpiAccounts<'a, 'b> {
     pub stake_authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `begin_voting` CPI instruction.
@@ -137,6 +159,8 @@ pub struct BeginVotingCpi<'a, 'b> {
     pub stake_authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> BeginVotingCpi<'a, 'b> {
@@ -148,6 +172,7 @@ impl<'a, 'b> BeginVotingCpi<'a, 'b> {
             __program: program,
             stake_authority: accounts.stake_authority,
             proposal: accounts.proposal,
+            governance_config: accounts.governance_config,
         }
     }
     #[inline(always)]
@@ -183,7 +208,7 @@ impl<'a, 'b> BeginVotingCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.stake_authority.key,
             true,
@@ -192,6 +217,10 @@ impl<'a, 'b> BeginVotingCpi<'a, 'b> {
             *self.proposal.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -206,10 +235,11 @@ impl<'a, 'b> BeginVotingCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.stake_authority.clone());
         account_infos.push(self.proposal.clone());
+        account_infos.push(self.governance_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -228,6 +258,7 @@ impl<'a, 'b> BeginVotingCpi<'a, 'b> {
 ///
 ///   0. `[signer]` stake_authority
 ///   1. `[writable]` proposal
+///   2. `[]` governance_config
 #[derive(Clone, Debug)]
 pub struct BeginVotingCpiBuilder<'a, 'b> {
     instruction: Box<BeginVotingCpiBuilderInstruction<'a, 'b>>,
@@ -239,6 +270,7 @@ impl<'a, 'b> BeginVotingCpiBuilder<'a, 'b> {
             __program: program,
             stake_authority: None,
             proposal: None,
+            governance_config: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -261,6 +293,15 @@ impl<'a, 'b> BeginVotingCpiBuilder<'a, 'b> {
         self.instruction.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -312,6 +353,11 @@ impl<'a, 'b> BeginVotingCpiBuilder<'a, 'b> {
                 .expect("stake_authority is not set"),
 
             proposal: self.instruction.proposal.expect("proposal is not set"),
+
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -325,6 +371,7 @@ struct BeginVotingCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     stake_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
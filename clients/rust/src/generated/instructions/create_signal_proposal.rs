@@ -0,0 +1,459 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CreateSignalProposal {
+    /// Paladin stake authority account
+    pub stake_authority: solana_program::pubkey::Pubkey,
+    /// Stake authority author account
+    pub author: solana_program::pubkey::Pubkey,
+    /// Paladin stake account
+    pub stake: solana_program::pubkey::Pubkey,
+    /// Proposal account
+    pub proposal: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
+}
+
+impl CreateSignalProposal {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.stake_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.author,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.stake, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.proposal,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_config,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CreateSignalProposalInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_GOVERNANCE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CreateSignalProposalInstructionData {
+    discriminator: u8,
+}
+
+impl CreateSignalProposalInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 18 }
+    }
+}
+
+impl Default for CreateSignalProposalInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CreateSignalProposal`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` stake_authority
+///   1. `[writable]` author
+///   2. `[]` stake
+///   3. `[writable]` proposal
+///   4. `[]` governance_config
+#[derive(Clone, Debug, Default)]
+pub struct CreateSignalProposalBuilder {
+    stake_authority: Option<solana_program::pubkey::Pubkey>,
+    author: Option<solana_program::pubkey::Pubkey>,
+    stake: Option<solana_program::pubkey::Pubkey>,
+    proposal: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CreateSignalProposalBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Paladin stake authority account
+    #[inline(always)]
+    pub fn stake_authority(
+        &mut self,
+        stake_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    /// Stake authority author account
+    #[inline(always)]
+    pub fn author(&mut self, author: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.author = Some(author);
+        self
+    }
+    /// Paladin stake account
+    #[inline(always)]
+    pub fn stake(&mut self, stake: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Proposal account
+    #[inline(always)]
+    pub fn proposal(&mut self, proposal: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.proposal = Some(proposal);
+        self
+    }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CreateSignalProposal {
+            stake_authority: self.stake_authority.expect("stake_authority is not set"),
+            author: self.author.expect("author is not set"),
+            stake: self.stake.expect("stake is not set"),
+            proposal: self.proposal.expect("proposal is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `create_signal_proposal` CPI accounts.
+pub struct CreateSignalProposalCpiAccounts<'a, 'b> {
+    /// Paladin stake authority account
+    pub stake_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake authority author account
+    pub author: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Paladin stake account
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Proposal account
+    pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `create_signal_proposal` CPI instruction.
+pub struct CreateSignalProposalCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Paladin stake authority account
+    pub stake_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake authority author account
+    pub author: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Paladin stake account
+    pub stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Proposal account
+    pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CreateSignalProposalCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CreateSignalProposalCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            stake_authority: accounts.stake_authority,
+            author: accounts.author,
+            stake: accounts.stake,
+            proposal: accounts.proposal,
+            governance_config: accounts.governance_config,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.stake_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.author.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.stake.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.proposal.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_config.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CreateSignalProposalInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_GOVERNANCE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.stake_authority.clone());
+        account_infos.push(self.author.clone());
+        account_infos.push(self.stake.clone());
+        account_infos.push(self.proposal.clone());
+        account_infos.push(self.governance_config.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CreateSignalProposal` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` stake_authority
+///   1. `[writable]` author
+///   2. `[]` stake
+///   3. `[writable]` proposal
+///   4. `[]` governance_config
+#[derive(Clone, Debug)]
+pub struct CreateSignalProposalCpiBuilder<'a, 'b> {
+    instruction: Box<CreateSignalProposalCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CreateSignalProposalCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CreateSignalProposalCpiBuilderInstruction {
+            __program: program,
+            stake_authority: None,
+            author: None,
+            stake: None,
+            proposal: None,
+            governance_config: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Paladin stake authority account
+    #[inline(always)]
+    pub fn stake_authority(
+        &mut self,
+        stake_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.stake_authority = Some(stake_authority);
+        self
+    }
+    /// Stake authority author account
+    #[inline(always)]
+    pub fn author(
+        &mut self,
+        author: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.author = Some(author);
+        self
+    }
+    /// Paladin stake account
+    #[inline(always)]
+    pub fn stake(&mut self, stake: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.stake = Some(stake);
+        self
+    }
+    /// Proposal account
+    #[inline(always)]
+    pub fn proposal(
+        &mut self,
+        proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.proposal = Some(proposal);
+        self
+    }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CreateSignalProposalCpi {
+            __program: self.instruction.__program,
+
+            stake_authority: self
+                .instruction
+                .stake_authority
+                .expect("stake_authority is not set"),
+
+            author: self.instruction.author.expect("author is not set"),
+
+            stake: self.instruction.stake.expect("stake is not set"),
+
+            proposal: self.instruction.proposal.expect("proposal is not set"),
+
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CreateSignalProposalCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    stake_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    author: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -12,6 +12,8 @@ pub struct FinishVoting {
     pub stake_config: solana_program::pubkey::Pubkey,
     /// Proposal account
     pub proposal: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
 }
 
 impl FinishVoting {
@@ -23,7 +25,7 @@ impl FinishVoting {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.stake_config,
             false,
@@ -32,6 +34,10 @@ impl FinishVoting {
             self.proposal,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = FinishVotingInstructionData::new().try_to_vec().unwrap();
 
@@ -66,10 +72,12 @@ impl Default for FinishVotingInstructionData {
 ///
 ///   0. `[]` stake_config
 ///   1. `[writable]` proposal
+///   2. `[]` governance_config
 #[derive(Clone, Debug, Default)]
 pub struct FinishVotingBuilder {
     stake_config: Option<solana_program::pubkey::Pubkey>,
     proposal: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -89,6 +97,15 @@ impl FinishVotingBuilder {
         self.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -112,6 +129,9 @@ impl FinishVotingBuilder {
         let accounts = FinishVoting {
             stake_config: self.stake_config.expect("stake_config is not set"),
             proposal: self.proposal.expect("proposal is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -124,6 +144,8 @@ pub struct FinishVotingCpiAccounts<'a, 'b> {
     pub stake_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `finish_voting` CPI instruction.
@@ -134,6 +156,8 @@ pub struct FinishVotingCpi<'a, 'b> {
     pub stake_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> FinishVotingCpi<'a, 'b> {
@@ -145,6 +169,7 @@ impl<'a, 'b> FinishVotingCpi<'a, 'b> {
             __program: program,
             stake_config: accounts.stake_config,
             proposal: accounts.proposal,
+            governance_config: accounts.governance_config,
         }
     }
     #[inline(always)]
@@ -180,7 +205,7 @@ impl<'a, 'b> FinishVotingCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.stake_config.key,
             false,
@@ -189,6 +214,10 @@ impl<'a, 'b> FinishVotingCpi<'a, 'b> {
             *self.proposal.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -203,10 +232,11 @@ impl<'a, 'b> FinishVotingCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.stake_config.clone());
         account_infos.push(self.proposal.clone());
+        account_infos.push(self.governance_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -225,6 +255,7 @@ impl<'a, 'b> FinishVotingCpi<'a, 'b> {
 ///
 ///   0. `[]` stake_config
 ///   1. `[writable]` proposal
+///   2. `[]` governance_config
 #[derive(Clone, Debug)]
 pub struct FinishVotingCpiBuilder<'a, 'b> {
     instruction: Box<FinishVotingCpiBuilderInstruction<'a, 'b>>,
@@ -236,6 +267,7 @@ impl<'a, 'b> FinishVotingCpiBuilder<'a, 'b> {
             __program: program,
             stake_config: None,
             proposal: None,
+            governance_config: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -258,6 +290,15 @@ impl<'a, 'b> FinishVotingCpiBuilder<'a, 'b> {
         self.instruction.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -309,6 +350,11 @@ impl<'a, 'b> FinishVotingCpiBuilder<'a, 'b> {
                 .expect("stake_config is not set"),
 
             proposal: self.instruction.proposal.expect("proposal is not set"),
+
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -322,6 +368,7 @@ struct FinishVotingCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     stake_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -27,7 +27,7 @@ impl InitializeAuthor {
         let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.stake_authority,
-            false,
+            true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.author,
@@ -69,7 +69,7 @@ impl Default for InitializeAuthorInstructionData {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` stake_authority
+///   0. `[signer]` stake_authority
 ///   1. `[writable]` author
 ///   2. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
@@ -205,7 +205,7 @@ impl<'a, 'b> InitializeAuthorCpi<'a, 'b> {
         let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.stake_authority.key,
-            false,
+            true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.author.key,
@@ -250,7 +250,7 @@ impl<'a, 'b> InitializeAuthorCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` stake_authority
+///   0. `[signer]` stake_authority
 ///   1. `[writable]` author
 ///   2. `[]` system_program
 #[derive(Clone, Debug)]
@@ -14,6 +14,10 @@ pub struct InitializeGovernance {
     pub stake_config: solana_program::pubkey::Pubkey,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Stake config authority or program upgrade authority
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Program data account
+    pub program_data: solana_program::pubkey::Pubkey,
 }
 
 impl InitializeGovernance {
@@ -29,7 +33,7 @@ impl InitializeGovernance {
         args: InitializeGovernanceInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.governance_config,
             false,
@@ -42,6 +46,14 @@ impl InitializeGovernance {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program_data,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitializeGovernanceInstructionData::new()
             .try_to_vec()
@@ -94,11 +106,15 @@ pub struct InitializeGovernanceInstructionArgs {
 ///   1. `[]` stake_config
 ///   2. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
+///   3. `[signer]` authority
+///   4. `[]` program_data
 #[derive(Clone, Debug, Default)]
 pub struct InitializeGovernanceBuilder {
     governance_config: Option<solana_program::pubkey::Pubkey>,
     stake_config: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    program_data: Option<solana_program::pubkey::Pubkey>,
     governance_id: Option<u64>,
     cooldown_period_seconds: Option<u64>,
     proposal_minimum_quorum: Option<u32>,
@@ -135,6 +151,18 @@ impl InitializeGovernanceBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Stake config authority or program upgrade authority
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Program data account
+    #[inline(always)]
+    pub fn program_data(&mut self, program_data: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
     #[inline(always)]
     pub fn governance_id(&mut self, governance_id: u64) -> &mut Self {
         self.governance_id = Some(governance_id);
@@ -198,6 +226,8 @@ impl InitializeGovernanceBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            authority: self.authority.expect("authority is not set"),
+            program_data: self.program_data.expect("program_data is not set"),
         };
         let args = InitializeGovernanceInstructionArgs {
             governance_id: self
@@ -242,6 +272,10 @@ pub struct InitializeGovernanceCpiAccounts<'a, 'b> {
     pub stake_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake config authority or program upgrade authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program data account
+    pub program_data: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `initialize_governance` CPI instruction.
@@ -254,6 +288,10 @@ pub struct InitializeGovernanceCpi<'a, 'b> {
     pub stake_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake config authority or program upgrade authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Program data account
+    pub program_data: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitializeGovernanceInstructionArgs,
 }
@@ -269,6 +307,8 @@ impl<'a, 'b> InitializeGovernanceCpi<'a, 'b> {
             governance_config: accounts.governance_config,
             stake_config: accounts.stake_config,
             system_program: accounts.system_program,
+            authority: accounts.authority,
+            program_data: accounts.program_data,
             __args: args,
         }
     }
@@ -305,7 +345,7 @@ impl<'a, 'b> InitializeGovernanceCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.governance_config.key,
             false,
@@ -318,6 +358,14 @@ impl<'a, 'b> InitializeGovernanceCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program_data.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -336,11 +384,13 @@ impl<'a, 'b> InitializeGovernanceCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.governance_config.clone());
         account_infos.push(self.stake_config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.program_data.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -360,6 +410,8 @@ impl<'a, 'b> InitializeGovernanceCpi<'a, 'b> {
 ///   0. `[writable]` governance_config
 ///   1. `[]` stake_config
 ///   2. `[]` system_program
+///   3. `[signer]` authority
+///   4. `[]` program_data
 #[derive(Clone, Debug)]
 pub struct InitializeGovernanceCpiBuilder<'a, 'b> {
     instruction: Box<InitializeGovernanceCpiBuilderInstruction<'a, 'b>>,
@@ -372,6 +424,8 @@ impl<'a, 'b> InitializeGovernanceCpiBuilder<'a, 'b> {
             governance_config: None,
             stake_config: None,
             system_program: None,
+            authority: None,
+            program_data: None,
             governance_id: None,
             cooldown_period_seconds: None,
             proposal_minimum_quorum: None,
@@ -410,6 +464,24 @@ impl<'a, 'b> InitializeGovernanceCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Stake config authority or program upgrade authority
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Program data account
+    #[inline(always)]
+    pub fn program_data(
+        &mut self,
+        program_data: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program_data = Some(program_data);
+        self
+    }
     #[inline(always)]
     pub fn governance_id(&mut self, governance_id: u64) -> &mut Self {
         self.instruction.governance_id = Some(governance_id);
@@ -541,6 +613,13 @@ impl<'a, 'b> InitializeGovernanceCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            program_data: self
+                .instruction
+                .program_data
+                .expect("program_data is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -556,6 +635,8 @@ struct InitializeGovernanceCpiBuilderInstruction<'a, 'b> {
     governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     stake_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program_data: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     governance_id: Option<u64>,
     cooldown_period_seconds: Option<u64>,
     proposal_minimum_quorum: Option<u32>,
@@ -0,0 +1,632 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MigrateGovernance {
+    /// Treasury account
+    pub treasury: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
+    /// New treasury account
+    pub new_treasury: solana_program::pubkey::Pubkey,
+    /// New governance config account
+    pub new_governance_config: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// SPL Token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// SPL Token-2022 program
+    pub token2022_program: solana_program::pubkey::Pubkey,
+}
+
+impl MigrateGovernance {
+    pub fn instruction(
+        &self,
+        args: MigrateGovernanceInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigrateGovernanceInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.treasury,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.governance_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.new_treasury,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.new_governance_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token2022_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MigrateGovernanceInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_GOVERNANCE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MigrateGovernanceInstructionData {
+    discriminator: u8,
+}
+
+impl MigrateGovernanceInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 23 }
+    }
+}
+
+impl Default for MigrateGovernanceInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateGovernanceInstructionArgs {
+    pub governance_id: u64,
+    pub new_governance_id: u64,
+}
+
+/// Instruction builder for `MigrateGovernance`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` treasury
+///   1. `[writable]` governance_config
+///   2. `[writable]` new_treasury
+///   3. `[]` new_governance_config
+///   4. `[optional]` system_program (default to
+///      `11111111111111111111111111111111`)
+///   5. `[]` token_program
+///   6. `[]` token2022_program
+#[derive(Clone, Debug, Default)]
+pub struct MigrateGovernanceBuilder {
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
+    new_treasury: Option<solana_program::pubkey::Pubkey>,
+    new_governance_config: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    token2022_program: Option<solana_program::pubkey::Pubkey>,
+    governance_id: Option<u64>,
+    new_governance_id: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MigrateGovernanceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Treasury account
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.treasury = Some(treasury);
+        self
+    }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
+    /// New treasury account
+    #[inline(always)]
+    pub fn new_treasury(&mut self, new_treasury: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.new_treasury = Some(new_treasury);
+        self
+    }
+    /// New governance config account
+    #[inline(always)]
+    pub fn new_governance_config(
+        &mut self,
+        new_governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.new_governance_config = Some(new_governance_config);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// SPL Token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// SPL Token-2022 program
+    #[inline(always)]
+    pub fn token2022_program(
+        &mut self,
+        token2022_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.token2022_program = Some(token2022_program);
+        self
+    }
+    #[inline(always)]
+    pub fn governance_id(&mut self, governance_id: u64) -> &mut Self {
+        self.governance_id = Some(governance_id);
+        self
+    }
+    #[inline(always)]
+    pub fn new_governance_id(&mut self, new_governance_id: u64) -> &mut Self {
+        self.new_governance_id = Some(new_governance_id);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MigrateGovernance {
+            treasury: self.treasury.expect("treasury is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
+            new_treasury: self.new_treasury.expect("new_treasury is not set"),
+            new_governance_config: self
+                .new_governance_config
+                .expect("new_governance_config is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.expect("token_program is not set"),
+            token2022_program: self
+                .token2022_program
+                .expect("token2022_program is not set"),
+        };
+        let args = MigrateGovernanceInstructionArgs {
+            governance_id: self
+                .governance_id
+                .clone()
+                .expect("governance_id is not set"),
+            new_governance_id: self
+                .new_governance_id
+                .clone()
+                .expect("new_governance_id is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate_governance` CPI accounts.
+pub struct MigrateGovernanceCpiAccounts<'a, 'b> {
+    /// Treasury account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// New treasury account
+    pub new_treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// New governance config account
+    pub new_governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token-2022 program
+    pub token2022_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `migrate_governance` CPI instruction.
+pub struct MigrateGovernanceCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Treasury account
+    pub treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// New treasury account
+    pub new_treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    /// New governance config account
+    pub new_governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token-2022 program
+    pub token2022_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MigrateGovernanceInstructionArgs,
+}
+
+impl<'a, 'b> MigrateGovernanceCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MigrateGovernanceCpiAccounts<'a, 'b>,
+        args: MigrateGovernanceInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            treasury: accounts.treasury,
+            governance_config: accounts.governance_config,
+            new_treasury: accounts.new_treasury,
+            new_governance_config: accounts.new_governance_config,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            token2022_program: accounts.token2022_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.treasury.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.governance_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.new_treasury.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.new_governance_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token2022_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MigrateGovernanceInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_GOVERNANCE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.treasury.clone());
+        account_infos.push(self.governance_config.clone());
+        account_infos.push(self.new_treasury.clone());
+        account_infos.push(self.new_governance_config.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.token2022_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigrateGovernance` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` treasury
+///   1. `[writable]` governance_config
+///   2. `[writable]` new_treasury
+///   3. `[]` new_governance_config
+///   4. `[]` system_program
+///   5. `[]` token_program
+///   6. `[]` token2022_program
+#[derive(Clone, Debug)]
+pub struct MigrateGovernanceCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateGovernanceCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigrateGovernanceCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigrateGovernanceCpiBuilderInstruction {
+            __program: program,
+            treasury: None,
+            governance_config: None,
+            new_treasury: None,
+            new_governance_config: None,
+            system_program: None,
+            token_program: None,
+            token2022_program: None,
+            governance_id: None,
+            new_governance_id: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Treasury account
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.treasury = Some(treasury);
+        self
+    }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
+    /// New treasury account
+    #[inline(always)]
+    pub fn new_treasury(
+        &mut self,
+        new_treasury: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_treasury = Some(new_treasury);
+        self
+    }
+    /// New governance config account
+    #[inline(always)]
+    pub fn new_governance_config(
+        &mut self,
+        new_governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_governance_config = Some(new_governance_config);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// SPL Token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// SPL Token-2022 program
+    #[inline(always)]
+    pub fn token2022_program(
+        &mut self,
+        token2022_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token2022_program = Some(token2022_program);
+        self
+    }
+    #[inline(always)]
+    pub fn governance_id(&mut self, governance_id: u64) -> &mut Self {
+        self.instruction.governance_id = Some(governance_id);
+        self
+    }
+    #[inline(always)]
+    pub fn new_governance_id(&mut self, new_governance_id: u64) -> &mut Self {
+        self.instruction.new_governance_id = Some(new_governance_id);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MigrateGovernanceInstructionArgs {
+            governance_id: self
+                .instruction
+                .governance_id
+                .clone()
+                .expect("governance_id is not set"),
+            new_governance_id: self
+                .instruction
+                .new_governance_id
+                .clone()
+                .expect("new_governance_id is not set"),
+        };
+        let instruction = MigrateGovernanceCpi {
+            __program: self.instruction.__program,
+
+            treasury: self.instruction.treasury.expect("treasury is not set"),
+
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
+
+            new_treasury: self
+                .instruction
+                .new_treasury
+                .expect("new_treasury is not set"),
+
+            new_governance_config: self
+                .instruction
+                .new_governance_config
+                .expect("new_governance_config is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            token2022_program: self
+                .instruction
+                .token2022_program
+                .expect("token2022_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MigrateGovernanceCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token2022_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_id: Option<u64>,
+    new_governance_id: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
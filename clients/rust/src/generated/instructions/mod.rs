@@ -5,21 +5,36 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 pub(crate) mod r#begin_voting;
+pub(crate) mod r#cancel_proposal;
+pub(crate) mod r#close_author;
 pub(crate) mod r#create_proposal;
+pub(crate) mod r#create_signal_proposal;
 pub(crate) mod r#delete_proposal;
 pub(crate) mod r#delete_vote;
 pub(crate) mod r#finish_voting;
 pub(crate) mod r#initialize_author;
 pub(crate) mod r#initialize_governance;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#migrate_governance;
+pub(crate) mod r#partial_update_governance;
 pub(crate) mod r#process_instruction;
 pub(crate) mod r#push_instruction;
+pub(crate) mod r#push_option;
+pub(crate) mod r#push_option_instruction;
+pub(crate) mod r#push_prerequisite;
+pub(crate) mod r#set_paused;
 pub(crate) mod r#switch_vote;
+pub(crate) mod r#switch_vote_option;
 pub(crate) mod r#update_governance;
 pub(crate) mod r#vote;
+pub(crate) mod r#vote_option;
 
 pub use self::{
-    r#begin_voting::*, r#create_proposal::*, r#delete_proposal::*, r#delete_vote::*,
-    r#finish_voting::*, r#initialize_author::*, r#initialize_governance::*,
-    r#process_instruction::*, r#push_instruction::*, r#switch_vote::*, r#update_governance::*,
-    r#vote::*,
+    r#begin_voting::*, r#cancel_proposal::*, r#close_author::*, r#create_proposal::*,
+    r#create_signal_proposal::*, r#delete_proposal::*, r#delete_vote::*, r#finish_voting::*,
+    r#initialize_author::*, r#initialize_governance::*, r#migrate_account::*,
+    r#migrate_governance::*, r#partial_update_governance::*, r#process_instruction::*,
+    r#push_instruction::*, r#push_option::*, r#push_option_instruction::*, r#push_prerequisite::*,
+    r#set_paused::*, r#switch_vote::*, r#switch_vote_option::*, r#update_governance::*, r#vote::*,
+    r#vote_option::*,
 };
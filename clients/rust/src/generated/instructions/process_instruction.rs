@@ -12,6 +12,8 @@ pub struct ProcessInstruction {
     pub proposal: solana_program::pubkey::Pubkey,
     /// Proposal transaction account
     pub proposal_transaction: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
 }
 
 impl ProcessInstruction {
@@ -27,7 +29,7 @@ impl ProcessInstruction {
         args: ProcessInstructionInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.proposal,
             false,
@@ -36,6 +38,10 @@ impl ProcessInstruction {
             self.proposal_transaction,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ProcessInstructionInstructionData::new()
             .try_to_vec()
@@ -80,10 +86,12 @@ pub struct ProcessInstructionInstructionArgs {
 ///
 ///   0. `[writable]` proposal
 ///   1. `[writable]` proposal_transaction
+///   2. `[]` governance_config
 #[derive(Clone, Debug, Default)]
 pub struct ProcessInstructionBuilder {
     proposal: Option<solana_program::pubkey::Pubkey>,
     proposal_transaction: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
     instruction_index: Option<u32>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -107,6 +115,15 @@ impl ProcessInstructionBuilder {
         self.proposal_transaction = Some(proposal_transaction);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
     #[inline(always)]
     pub fn instruction_index(&mut self, instruction_index: u32) -> &mut Self {
         self.instruction_index = Some(instruction_index);
@@ -137,6 +154,9 @@ impl ProcessInstructionBuilder {
             proposal_transaction: self
                 .proposal_transaction
                 .expect("proposal_transaction is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
         };
         let args = ProcessInstructionInstructionArgs {
             instruction_index: self
@@ -155,6 +175,8 @@ pub struct ProcessInstructionCpiAccounts<'a, 'b> {
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal transaction account
     pub proposal_transaction: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `process_instruction` CPI instruction.
@@ -165,6 +187,8 @@ pub struct ProcessInstructionCpi<'a, 'b> {
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal transaction account
     pub proposal_transaction: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: ProcessInstructionInstructionArgs,
 }
@@ -179,6 +203,7 @@ impl<'a, 'b> ProcessInstructionCpi<'a, 'b> {
             __program: program,
             proposal: accounts.proposal,
             proposal_transaction: accounts.proposal_transaction,
+            governance_config: accounts.governance_config,
             __args: args,
         }
     }
@@ -215,7 +240,7 @@ impl<'a, 'b> ProcessInstructionCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.proposal.key,
             false,
@@ -224,6 +249,10 @@ impl<'a, 'b> ProcessInstructionCpi<'a, 'b> {
             *self.proposal_transaction.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -242,10 +271,11 @@ impl<'a, 'b> ProcessInstructionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.proposal.clone());
         account_infos.push(self.proposal_transaction.clone());
+        account_infos.push(self.governance_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -264,6 +294,7 @@ impl<'a, 'b> ProcessInstructionCpi<'a, 'b> {
 ///
 ///   0. `[writable]` proposal
 ///   1. `[writable]` proposal_transaction
+///   2. `[]` governance_config
 #[derive(Clone, Debug)]
 pub struct ProcessInstructionCpiBuilder<'a, 'b> {
     instruction: Box<ProcessInstructionCpiBuilderInstruction<'a, 'b>>,
@@ -275,6 +306,7 @@ impl<'a, 'b> ProcessInstructionCpiBuilder<'a, 'b> {
             __program: program,
             proposal: None,
             proposal_transaction: None,
+            governance_config: None,
             instruction_index: None,
             __remaining_accounts: Vec::new(),
         });
@@ -298,6 +330,15 @@ impl<'a, 'b> ProcessInstructionCpiBuilder<'a, 'b> {
         self.instruction.proposal_transaction = Some(proposal_transaction);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
     #[inline(always)]
     pub fn instruction_index(&mut self, instruction_index: u32) -> &mut Self {
         self.instruction.instruction_index = Some(instruction_index);
@@ -361,6 +402,11 @@ impl<'a, 'b> ProcessInstructionCpiBuilder<'a, 'b> {
                 .instruction
                 .proposal_transaction
                 .expect("proposal_transaction is not set"),
+
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -375,6 +421,7 @@ struct ProcessInstructionCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     proposal_transaction: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     instruction_index: Option<u32>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
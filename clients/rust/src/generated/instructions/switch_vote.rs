@@ -21,6 +21,8 @@ pub struct SwitchVote {
     pub vote: solana_program::pubkey::Pubkey,
     /// Proposal account
     pub proposal: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
 }
 
 impl SwitchVote {
@@ -36,7 +38,7 @@ impl SwitchVote {
         args: SwitchVoteInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.stake_authority,
             true,
@@ -55,6 +57,10 @@ impl SwitchVote {
             self.proposal,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SwitchVoteInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -100,6 +106,7 @@ pub struct SwitchVoteInstructionArgs {
 ///   2. `[]` stake_config
 ///   3. `[writable]` vote
 ///   4. `[writable]` proposal
+///   5. `[]` governance_config
 #[derive(Clone, Debug, Default)]
 pub struct SwitchVoteBuilder {
     stake_authority: Option<solana_program::pubkey::Pubkey>,
@@ -107,6 +114,7 @@ pub struct SwitchVoteBuilder {
     stake_config: Option<solana_program::pubkey::Pubkey>,
     vote: Option<solana_program::pubkey::Pubkey>,
     proposal: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
     new_election: Option<ProposalVoteElection>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -148,6 +156,15 @@ impl SwitchVoteBuilder {
         self.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
     #[inline(always)]
     pub fn new_election(&mut self, new_election: ProposalVoteElection) -> &mut Self {
         self.new_election = Some(new_election);
@@ -179,6 +196,9 @@ impl SwitchVoteBuilder {
             stake_config: self.stake_config.expect("stake_config is not set"),
             vote: self.vote.expect("vote is not set"),
             proposal: self.proposal.expect("proposal is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
         };
         let args = SwitchVoteInstructionArgs {
             new_election: self.new_election.clone().expect("new_election is not set"),
@@ -200,6 +220,8 @@ pub struct SwitchVoteCpiAccounts<'a, 'b> {
     pub vote: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `switch_vote` CPI instruction.
@@ -216,6 +238,8 @@ pub struct SwitchVoteCpi<'a, 'b> {
     pub vote: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: SwitchVoteInstructionArgs,
 }
@@ -233,6 +257,7 @@ impl<'a, 'b> SwitchVoteCpi<'a, 'b> {
             stake_config: accounts.stake_config,
             vote: accounts.vote,
             proposal: accounts.proposal,
+            governance_config: accounts.governance_config,
             __args: args,
         }
     }
@@ -269,7 +294,7 @@ impl<'a, 'b> SwitchVoteCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.stake_authority.key,
             true,
@@ -290,6 +315,10 @@ impl<'a, 'b> SwitchVoteCpi<'a, 'b> {
             *self.proposal.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -306,13 +335,14 @@ impl<'a, 'b> SwitchVoteCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.stake_authority.clone());
         account_infos.push(self.stake.clone());
         account_infos.push(self.stake_config.clone());
         account_infos.push(self.vote.clone());
         account_infos.push(self.proposal.clone());
+        account_infos.push(self.governance_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -334,6 +364,7 @@ impl<'a, 'b> SwitchVoteCpi<'a, 'b> {
 ///   2. `[]` stake_config
 ///   3. `[writable]` vote
 ///   4. `[writable]` proposal
+///   5. `[]` governance_config
 #[derive(Clone, Debug)]
 pub struct SwitchVoteCpiBuilder<'a, 'b> {
     instruction: Box<SwitchVoteCpiBuilderInstruction<'a, 'b>>,
@@ -348,6 +379,7 @@ impl<'a, 'b> SwitchVoteCpiBuilder<'a, 'b> {
             stake_config: None,
             vote: None,
             proposal: None,
+            governance_config: None,
             new_election: None,
             __remaining_accounts: Vec::new(),
         });
@@ -392,6 +424,15 @@ impl<'a, 'b> SwitchVoteCpiBuilder<'a, 'b> {
         self.instruction.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
     #[inline(always)]
     pub fn new_election(&mut self, new_election: ProposalVoteElection) -> &mut Self {
         self.instruction.new_election = Some(new_election);
@@ -464,6 +505,11 @@ impl<'a, 'b> SwitchVoteCpiBuilder<'a, 'b> {
             vote: self.instruction.vote.expect("vote is not set"),
 
             proposal: self.instruction.proposal.expect("proposal is not set"),
+
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -481,6 +527,7 @@ struct SwitchVoteCpiBuilderInstruction<'a, 'b> {
     stake_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_election: Option<ProposalVoteElection>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
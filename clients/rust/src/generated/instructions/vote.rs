@@ -21,6 +21,8 @@ pub struct Vote {
     pub vote: solana_program::pubkey::Pubkey,
     /// Proposal account
     pub proposal: solana_program::pubkey::Pubkey,
+    /// Governance config account
+    pub governance_config: solana_program::pubkey::Pubkey,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
 }
@@ -38,7 +40,7 @@ impl Vote {
         args: VoteInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.stake_authority,
             true,
@@ -57,6 +59,10 @@ impl Vote {
             self.proposal,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.governance_config,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -106,7 +112,8 @@ pub struct VoteInstructionArgs {
 ///   2. `[]` stake_config
 ///   3. `[writable]` vote
 ///   4. `[writable]` proposal
-///   5. `[optional]` system_program (default to
+///   5. `[]` governance_config
+///   6. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct VoteBuilder {
@@ -115,6 +122,7 @@ pub struct VoteBuilder {
     stake_config: Option<solana_program::pubkey::Pubkey>,
     vote: Option<solana_program::pubkey::Pubkey>,
     proposal: Option<solana_program::pubkey::Pubkey>,
+    governance_config: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     election: Option<ProposalVoteElection>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -157,6 +165,15 @@ impl VoteBuilder {
         self.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.governance_config = Some(governance_config);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     /// System program
     #[inline(always)]
@@ -195,6 +212,9 @@ impl VoteBuilder {
             stake_config: self.stake_config.expect("stake_config is not set"),
             vote: self.vote.expect("vote is not set"),
             proposal: self.proposal.expect("proposal is not set"),
+            governance_config: self
+                .governance_config
+                .expect("governance_config is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -219,6 +239,8 @@ pub struct VoteCpiAccounts<'a, 'b> {
     pub vote: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
@@ -237,6 +259,8 @@ pub struct VoteCpi<'a, 'b> {
     pub vote: &'b solana_program::account_info::AccountInfo<'a>,
     /// Proposal account
     pub proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Governance config account
+    pub governance_config: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
@@ -256,6 +280,7 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
             stake_config: accounts.stake_config,
             vote: accounts.vote,
             proposal: accounts.proposal,
+            governance_config: accounts.governance_config,
             system_program: accounts.system_program,
             __args: args,
         }
@@ -293,7 +318,7 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.stake_authority.key,
             true,
@@ -314,6 +339,10 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
             *self.proposal.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.governance_config.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -334,13 +363,14 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.stake_authority.clone());
         account_infos.push(self.stake.clone());
         account_infos.push(self.stake_config.clone());
         account_infos.push(self.vote.clone());
         account_infos.push(self.proposal.clone());
+        account_infos.push(self.governance_config.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -363,7 +393,8 @@ impl<'a, 'b> VoteCpi<'a, 'b> {
 ///   2. `[]` stake_config
 ///   3. `[writable]` vote
 ///   4. `[writable]` proposal
-///   5. `[]` system_program
+///   5. `[]` governance_config
+///   6. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct VoteCpiBuilder<'a, 'b> {
     instruction: Box<VoteCpiBuilderInstruction<'a, 'b>>,
@@ -378,6 +409,7 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
             stake_config: None,
             vote: None,
             proposal: None,
+            governance_config: None,
             system_program: None,
             election: None,
             __remaining_accounts: Vec::new(),
@@ -423,6 +455,15 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
         self.instruction.proposal = Some(proposal);
         self
     }
+    /// Governance config account
+    #[inline(always)]
+    pub fn governance_config(
+        &mut self,
+        governance_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.governance_config = Some(governance_config);
+        self
+    }
     /// System program
     #[inline(always)]
     pub fn system_program(
@@ -505,6 +546,11 @@ impl<'a, 'b> VoteCpiBuilder<'a, 'b> {
 
             proposal: self.instruction.proposal.expect("proposal is not set"),
 
+            governance_config: self
+                .instruction
+                .governance_config
+                .expect("governance_config is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -526,6 +572,7 @@ struct VoteCpiBuilderInstruction<'a, 'b> {
     stake_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    governance_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     election: Option<ProposalVoteElection>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 use {
+    crate::hooked::NullableU64,
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::pubkey::Pubkey,
 };
@@ -12,15 +13,35 @@ use {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Config {
+    pub discriminator: [u8; 8],
     pub cooldown_period_seconds: u64,
-    pub proposal_acceptance_threshold: u32,
-    pub proposal_rejection_threshold: u32,
-    pub signer_bump_seed: u8,
-    pub padding: [u8; 7],
+    pub proposal_minimum_quorum: u32,
+    pub proposal_pass_threshold: u32,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub stake_config_address: Pubkey,
     pub voting_period_seconds: u64,
+    pub stake_per_proposal: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub governance_config: Pubkey,
+    pub cooldown_expires: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pause_guardian: Pubkey,
+    pub paused_timestamp: NullableU64,
+    pub paused_seconds: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub successor: Pubkey,
+    pub proposal_winning_margin: u32,
+    pub padding: [u8; 4],
 }
@@ -7,11 +7,14 @@
 pub(crate) mod r#config;
 pub(crate) mod r#proposal_account_meta;
 pub(crate) mod r#proposal_instruction;
+pub(crate) mod r#proposal_kind;
+pub(crate) mod r#proposal_option;
 pub(crate) mod r#proposal_status;
 pub(crate) mod r#proposal_transaction;
 pub(crate) mod r#proposal_vote_election;
 
 pub use self::{
-    r#config::*, r#proposal_account_meta::*, r#proposal_instruction::*, r#proposal_status::*,
-    r#proposal_transaction::*, r#proposal_vote_election::*,
+    r#config::*, r#proposal_account_meta::*, r#proposal_instruction::*, r#proposal_kind::*,
+    r#proposal_option::*, r#proposal_status::*, r#proposal_transaction::*,
+    r#proposal_vote_election::*,
 };
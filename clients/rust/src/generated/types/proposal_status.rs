@@ -28,4 +28,5 @@ pub enum ProposalStatus {
     Accepted,
     Rejected,
     Processed,
+    Cancelled,
 }
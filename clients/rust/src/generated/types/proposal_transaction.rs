@@ -5,12 +5,19 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 use {
-    crate::generated::types::ProposalInstruction,
+    crate::generated::types::{ProposalInstruction, ProposalOption},
     borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
 };
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProposalTransaction {
     pub instructions: Vec<ProposalInstruction>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub prerequisites: Vec<Pubkey>,
+    pub options: Vec<ProposalOption>,
 }
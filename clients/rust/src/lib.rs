@@ -1,8 +1,16 @@
 #![allow(non_local_definitions)]
 
+#[cfg(feature = "conversions")]
+mod conversions;
+#[cfg(feature = "decoder")]
+pub mod decoder;
+pub mod events;
 mod generated;
 mod hooked;
+#[cfg(feature = "outcome")]
+pub mod outcome;
 pub mod pdas;
+pub mod sdk;
 
 pub use {
     generated::{programs::PALADIN_GOVERNANCE_ID as ID, *},
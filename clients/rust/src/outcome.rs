@@ -0,0 +1,89 @@
+//! Predictions of a proposal's outcome, using the program's own rules from
+//! `paladin-governance-outcome`.
+
+pub use paladin_governance_outcome::*;
+use {
+    crate::{
+        accounts::{GovernanceConfig, Proposal},
+        types::{ProposalKind, ProposalStatus},
+    },
+    std::num::NonZeroU64,
+};
+
+impl Proposal {
+    /// The proposal's votes, against the total effective stake of the stake
+    /// config.
+    pub fn tally(&self, total_stake: u64) -> Tally<'_> {
+        Tally {
+            stake_for: self.stake_for,
+            stake_against: self.stake_against,
+            option_stake: &self.option_stake[..self.option_count as usize],
+            total_stake,
+            proposal_minimum_quorum: self.governance_config.proposal_minimum_quorum,
+            proposal_pass_threshold: self.governance_config.proposal_pass_threshold,
+            proposal_winning_margin: self.governance_config.proposal_winning_margin,
+        }
+    }
+
+    /// The proposal's voting and cooldown periods, extended by the time its
+    /// governance config has spent paused as of `now`.
+    ///
+    /// An ongoing pause extends both periods until governance is unpaused.
+    pub fn timeline(&self, governance_config: &GovernanceConfig, now: u64) -> Timeline {
+        let mut timeline = Timeline {
+            voting_start_timestamp: self
+                .voting_start_timestamp
+                .value()
+                .and_then(NonZeroU64::new),
+            voting_period_seconds: self.governance_config.voting_period_seconds,
+            cooldown_timestamp: self.cooldown_timestamp.value().and_then(NonZeroU64::new),
+            cooldown_period_seconds: self.governance_config.cooldown_period_seconds,
+        };
+        let total_paused_seconds = total_paused_seconds(
+            governance_config.paused_seconds,
+            governance_config
+                .paused_timestamp
+                .value()
+                .and_then(NonZeroU64::new),
+            now,
+        );
+        timeline.extend(total_paused_seconds.saturating_sub(self.paused_seconds_checkpoint));
+        timeline
+    }
+
+    /// The status `FinishVoting` would give the proposal at `now`, or `None`
+    /// if voting would still be open.
+    pub fn status_if_finished_at(
+        &self,
+        governance_config: &GovernanceConfig,
+        total_stake: u64,
+        now: u64,
+    ) -> Result<Option<ProposalStatus>, ArithmeticOverflow> {
+        let resolution = resolve(
+            &self.tally(total_stake),
+            &self.timeline(governance_config, now),
+            now,
+        )?;
+        Ok(match resolution {
+            Resolution::StillVoting => None,
+            // Signal proposals have no instructions to process.
+            Resolution::Passed { .. } if self.kind == ProposalKind::Signal => {
+                Some(ProposalStatus::Processed)
+            }
+            Resolution::Passed { .. } => Some(ProposalStatus::Accepted),
+            Resolution::Failed { .. } => Some(ProposalStatus::Rejected),
+        })
+    }
+
+    /// When the proposal's cooldown ends, as of `now`, or `None` if the
+    /// proposal has not reached quorum.
+    pub fn cooldown_ends_at(&self, governance_config: &GovernanceConfig, now: u64) -> Option<u64> {
+        self.timeline(governance_config, now).cooldown_ends_at()
+    }
+
+    /// The least additional `For` stake with which the proposal would pass,
+    /// or `None` if the stake that has not voted yet is not enough.
+    pub fn for_stake_needed(&self, total_stake: u64) -> Option<u64> {
+        self.tally(total_stake).for_stake_needed()
+    }
+}
@@ -1,15 +1,19 @@
 use solana_program::pubkey::Pubkey;
 
-pub fn find_treasury_pda(stake_config_address: &Pubkey) -> (Pubkey, u8) {
+pub fn find_treasury_pda(governance_config_address: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &["piggy_bank".as_bytes(), stake_config_address.as_ref()],
+        &["piggy_bank".as_bytes(), governance_config_address.as_ref()],
         &crate::ID,
     )
 }
 
-pub fn find_governance_pda(stake_config_address: &Pubkey) -> (Pubkey, u8) {
+pub fn find_governance_pda(stake_config_address: &Pubkey, governance_id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &["governance".as_bytes(), stake_config_address.as_ref()],
+        &[
+            "governance".as_bytes(),
+            stake_config_address.as_ref(),
+            &governance_id.to_le_bytes(),
+        ],
         &crate::ID,
     )
 }
@@ -31,3 +35,13 @@ pub fn find_proposal_vote_pda(stake_address: &Pubkey, proposal_address: &Pubkey)
         &crate::ID,
     )
 }
+
+pub fn find_proposal_author_pda(stake_authority_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "proposal_author".as_bytes(),
+            stake_authority_address.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
@@ -0,0 +1,455 @@
+//! High-level helpers for driving a proposal through its lifecycle.
+//!
+//! Every program-derived address is derived internally, so callers only
+//! provide the accounts they own: the governance config, the stake authority
+//! and its stake account, and a fresh keypair for the proposal.
+
+use {
+    crate::{
+        accounts::{GovernanceConfig, Proposal},
+        instructions::{
+            BeginVotingBuilder, CreateProposalBuilder, DeleteVoteBuilder, FinishVotingBuilder,
+            ProcessInstructionBuilder, PushInstructionBuilder, PushPrerequisiteBuilder,
+            SwitchVoteBuilder, VoteBuilder,
+        },
+        pdas::{
+            find_proposal_author_pda, find_proposal_transaction_pda, find_proposal_vote_pda,
+            find_treasury_pda,
+        },
+        types::{
+            ProposalAccountMeta, ProposalInstruction, ProposalTransaction, ProposalVoteElection,
+        },
+    },
+    borsh::BorshSerialize,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        rent::Rent,
+        system_instruction,
+    },
+    std::ops::Range,
+};
+
+// Kinobi only emits `LEN` for accounts of a known size, which excludes those
+// holding hooked types such as `NullableU64`.
+impl GovernanceConfig {
+    pub const LEN: usize = 200;
+}
+
+impl Proposal {
+    pub const LEN: usize = 360;
+}
+
+/// Builds the instructions to create a proposal, push its instructions and
+/// prerequisites, and begin voting.
+///
+/// ```ignore
+/// let instructions = ProposalBuilder::new(governance_config, stake_authority)
+///     .stake(stake)
+///     .proposal(proposal.pubkey())
+///     .with_instruction(instruction)
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProposalBuilder {
+    governance_config: Pubkey,
+    stake_authority: Pubkey,
+    stake: Option<Pubkey>,
+    proposal: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    rent: Rent,
+    prerequisites: Vec<Pubkey>,
+    instructions: Vec<Instruction>,
+}
+
+impl ProposalBuilder {
+    pub fn new(governance_config: Pubkey, stake_authority: Pubkey) -> Self {
+        Self {
+            governance_config,
+            stake_authority,
+            stake: None,
+            proposal: None,
+            payer: None,
+            rent: Rent::default(),
+            prerequisites: Vec::new(),
+            instructions: Vec::new(),
+        }
+    }
+    /// Paladin stake account of the stake authority.
+    #[inline(always)]
+    pub fn stake(&mut self, stake: Pubkey) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Address of the new proposal account, which must sign its creation.
+    #[inline(always)]
+    pub fn proposal(&mut self, proposal: Pubkey) -> &mut Self {
+        self.proposal = Some(proposal);
+        self
+    }
+    /// `[optional, default to the stake authority]`
+    /// Account funding the proposal and proposal transaction accounts.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional, default to Rent::default()]`
+    /// Rent used to fund the new accounts.
+    #[inline(always)]
+    pub fn rent(&mut self, rent: Rent) -> &mut Self {
+        self.rent = rent;
+        self
+    }
+    /// Add a proposal that must be processed before this one can execute.
+    #[inline(always)]
+    pub fn with_prerequisite(&mut self, prerequisite_proposal: Pubkey) -> &mut Self {
+        self.prerequisites.push(prerequisite_proposal);
+        self
+    }
+    /// Add an instruction to execute if the proposal is accepted.
+    #[inline(always)]
+    pub fn with_instruction(&mut self, instruction: Instruction) -> &mut Self {
+        self.instructions.push(instruction);
+        self
+    }
+    /// The proposal transaction as it will be stored once every instruction
+    /// and prerequisite has been pushed.
+    pub fn proposal_transaction(&self) -> ProposalTransaction {
+        ProposalTransaction {
+            instructions: self
+                .instructions
+                .iter()
+                .map(|instruction| ProposalInstruction {
+                    program_id: instruction.program_id,
+                    accounts: proposal_account_metas(&instruction.accounts),
+                    data: instruction.data.clone(),
+                    executed: false,
+                })
+                .collect(),
+            prerequisites: self.prerequisites.clone(),
+            options: Vec::new(),
+        }
+    }
+    pub fn build(&self) -> ProposalInstructions {
+        let stake = self.stake.expect("stake is not set");
+        let proposal = self.proposal.expect("proposal is not set");
+        let payer = self.payer.unwrap_or(self.stake_authority);
+        let (proposal_transaction, _) = find_proposal_transaction_pda(&proposal);
+
+        // The proposal transaction account is reallocated on every push, so
+        // fund it up front for its final size.
+        let proposal_transaction_len = self
+            .proposal_transaction()
+            .try_to_vec()
+            .expect("proposal transaction serializes")
+            .len();
+        let fund = vec![
+            system_instruction::create_account(
+                &payer,
+                &proposal,
+                self.rent.minimum_balance(Proposal::LEN),
+                Proposal::LEN as u64,
+                &crate::ID,
+            ),
+            system_instruction::transfer(
+                &payer,
+                &proposal_transaction,
+                self.rent.minimum_balance(proposal_transaction_len),
+            ),
+        ];
+
+        let create = CreateProposalBuilder::new()
+            .stake_authority(self.stake_authority)
+            .author(find_proposal_author_pda(&self.stake_authority).0)
+            .stake(stake)
+            .proposal(proposal)
+            .proposal_transaction(proposal_transaction)
+            .governance_config(self.governance_config)
+            .instruction();
+
+        let mut push: Vec<Instruction> = self
+            .prerequisites
+            .iter()
+            .map(|prerequisite_proposal| {
+                PushPrerequisiteBuilder::new()
+                    .stake_authority(self.stake_authority)
+                    .proposal(proposal)
+                    .proposal_transaction(proposal_transaction)
+                    .prerequisite_proposal(*prerequisite_proposal)
+                    .instruction()
+            })
+            .collect();
+        push.extend(self.instructions.iter().map(|instruction| {
+            PushInstructionBuilder::new()
+                .stake_authority(self.stake_authority)
+                .proposal(proposal)
+                .proposal_transaction(proposal_transaction)
+                .instruction_program_id(instruction.program_id)
+                .instruction_account_metas(proposal_account_metas(&instruction.accounts))
+                .instruction_data(instruction.data.clone())
+                .instruction()
+        }));
+
+        let begin_voting = BeginVotingBuilder::new()
+            .stake_authority(self.stake_authority)
+            .proposal(proposal)
+            .governance_config(self.governance_config)
+            .instruction();
+
+        ProposalInstructions {
+            proposal,
+            proposal_transaction,
+            fund,
+            create,
+            push,
+            begin_voting,
+        }
+    }
+}
+
+/// Instructions returned by [`ProposalBuilder::build`], in execution order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalInstructions {
+    pub proposal: Pubkey,
+    pub proposal_transaction: Pubkey,
+    /// Creates the proposal account and funds the proposal transaction
+    /// account.
+    pub fund: Vec<Instruction>,
+    pub create: Instruction,
+    /// Pushes the prerequisites, then the instructions.
+    pub push: Vec<Instruction>,
+    pub begin_voting: Instruction,
+}
+
+impl ProposalInstructions {
+    pub fn into_vec(self) -> Vec<Instruction> {
+        let mut instructions = self.fund;
+        instructions.push(self.create);
+        instructions.extend(self.push);
+        instructions.push(self.begin_voting);
+        instructions
+    }
+}
+
+/// Creates a `Vote` instruction for the stake account's vote on a proposal.
+pub fn vote(
+    stake_authority: Pubkey,
+    stake: Pubkey,
+    stake_config: Pubkey,
+    proposal: Pubkey,
+    governance_config: Pubkey,
+    election: ProposalVoteElection,
+) -> Instruction {
+    VoteBuilder::new()
+        .stake_authority(stake_authority)
+        .stake(stake)
+        .stake_config(stake_config)
+        .vote(find_proposal_vote_pda(&stake, &proposal).0)
+        .proposal(proposal)
+        .governance_config(governance_config)
+        .election(election)
+        .instruction()
+}
+
+/// Creates a `SwitchVote` instruction for the stake account's vote on a
+/// proposal.
+pub fn switch_vote(
+    stake_authority: Pubkey,
+    stake: Pubkey,
+    stake_config: Pubkey,
+    proposal: Pubkey,
+    governance_config: Pubkey,
+    new_election: ProposalVoteElection,
+) -> Instruction {
+    SwitchVoteBuilder::new()
+        .stake_authority(stake_authority)
+        .stake(stake)
+        .stake_config(stake_config)
+        .vote(find_proposal_vote_pda(&stake, &proposal).0)
+        .proposal(proposal)
+        .governance_config(governance_config)
+        .new_election(new_election)
+        .instruction()
+}
+
+/// Creates a `FinishVoting` instruction.
+pub fn finish_voting(
+    stake_config: Pubkey,
+    proposal: Pubkey,
+    governance_config: Pubkey,
+) -> Instruction {
+    FinishVotingBuilder::new()
+        .stake_config(stake_config)
+        .proposal(proposal)
+        .governance_config(governance_config)
+        .instruction()
+}
+
+/// Creates a `ProcessInstruction` instruction executing `instruction`, the
+/// proposal's instruction at `instruction_index`.
+///
+/// `prerequisites` must list the proposal's prerequisites in order.
+pub fn execute(
+    proposal: Pubkey,
+    governance_config: Pubkey,
+    prerequisites: &[Pubkey],
+    instruction: &Instruction,
+    instruction_index: u32,
+) -> Instruction {
+    let (treasury, _) = find_treasury_pda(&governance_config);
+    let mut remaining_accounts: Vec<AccountMeta> = prerequisites
+        .iter()
+        .map(|prerequisite| AccountMeta::new_readonly(*prerequisite, false))
+        .collect();
+    remaining_accounts.extend(instruction_account_metas(&treasury, instruction));
+
+    ProcessInstructionBuilder::new()
+        .proposal(proposal)
+        .proposal_transaction(find_proposal_transaction_pda(&proposal).0)
+        .governance_config(governance_config)
+        .instruction_index(instruction_index)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
+
+/// Resolves the `ProcessInstruction` instruction executing the instruction at
+/// `instruction_index` of a decoded proposal transaction.
+///
+/// Multiple-choice proposals execute the instructions of their winning
+/// option, so the decoded proposal is required to select them. Returns `None`
+/// if the index is out of range.
+pub fn resolve_process_instruction(
+    proposal_address: Pubkey,
+    governance_config: Pubkey,
+    proposal: &Proposal,
+    proposal_transaction: &ProposalTransaction,
+    instruction_index: u32,
+) -> Option<Instruction> {
+    let instruction =
+        outcome_instructions(proposal, proposal_transaction)?.get(instruction_index as usize)?;
+
+    Some(execute(
+        proposal_address,
+        governance_config,
+        &proposal_transaction.prerequisites,
+        &Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        },
+        instruction_index,
+    ))
+}
+
+/// Resolves a `ProcessInstruction` instruction for every index in
+/// `instruction_indices`, in order.
+///
+/// Returns `None` if any index is out of range.
+pub fn resolve_process_instructions(
+    proposal_address: Pubkey,
+    governance_config: Pubkey,
+    proposal: &Proposal,
+    proposal_transaction: &ProposalTransaction,
+    instruction_indices: Range<u32>,
+) -> Option<Vec<Instruction>> {
+    instruction_indices
+        .map(|instruction_index| {
+            resolve_process_instruction(
+                proposal_address,
+                governance_config,
+                proposal,
+                proposal_transaction,
+                instruction_index,
+            )
+        })
+        .collect()
+}
+
+/// The instructions executed for the outcome of the provided proposal.
+fn outcome_instructions<'a>(
+    proposal: &Proposal,
+    proposal_transaction: &'a ProposalTransaction,
+) -> Option<&'a [ProposalInstruction]> {
+    if proposal.option_count == 0 {
+        return Some(&proposal_transaction.instructions);
+    }
+    proposal_transaction
+        .options
+        .get(proposal.winning_option as usize)
+        .map(|option| option.instructions.as_slice())
+}
+
+/// The accounts the governance program needs to invoke `instruction`:
+/// its deduplicated accounts, followed by the invoked program.
+///
+/// The treasury signs through the governance program, so it is never a
+/// transaction signer. These are the accounts to pass after the
+/// prerequisites when building a `ProcessInstruction` instruction by hand.
+pub fn instruction_account_metas(treasury: &Pubkey, instruction: &Instruction) -> Vec<AccountMeta> {
+    let mut metas: Vec<AccountMeta> = Vec::with_capacity(instruction.accounts.len() + 1);
+    let program = AccountMeta::new_readonly(instruction.program_id, false);
+
+    for meta in instruction.accounts.iter().chain(std::iter::once(&program)) {
+        let is_signer = meta.is_signer && meta.pubkey != *treasury;
+        match metas
+            .iter_mut()
+            .find(|existing| existing.pubkey == meta.pubkey)
+        {
+            Some(existing) => {
+                existing.is_signer |= is_signer;
+                existing.is_writable |= meta.is_writable;
+            }
+            None => metas.push(AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer,
+                is_writable: meta.is_writable,
+            }),
+        }
+    }
+
+    metas
+}
+
+/// Creates a transfer from `payer` topping `address` up from its current
+/// `lamports` to `minimum_balance`, or `None` if it already holds enough.
+pub fn fund_rent(
+    payer: Pubkey,
+    address: Pubkey,
+    lamports: u64,
+    minimum_balance: u64,
+) -> Option<Instruction> {
+    match minimum_balance.saturating_sub(lamports) {
+        0 => None,
+        shortfall => Some(system_instruction::transfer(&payer, &address, shortfall)),
+    }
+}
+
+/// Creates a `DeleteVote` instruction, refunding the vote's rent to the stake
+/// authority.
+pub fn delete_vote(stake_authority: Pubkey, stake: Pubkey, proposal: Pubkey) -> Instruction {
+    DeleteVoteBuilder::new()
+        .proposal(proposal)
+        .vote(find_proposal_vote_pda(&stake, &proposal).0)
+        .authority(stake_authority)
+        .instruction()
+}
+
+fn proposal_account_metas(accounts: &[AccountMeta]) -> Vec<ProposalAccountMeta> {
+    accounts
+        .iter()
+        .map(|meta| ProposalAccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect()
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "conversions")]
+
+use {
+    borsh::BorshSerialize,
+    paladin_governance_program::state,
+    paladin_governance_program_client::{
+        accounts::{Author, GovernanceConfig, Proposal, ProposalVote},
+        types::{
+            Config, ProposalAccountMeta, ProposalInstruction, ProposalKind, ProposalOption,
+            ProposalStatus, ProposalTransaction, ProposalVoteElection,
+        },
+        NullableU64,
+    },
+    proptest::prelude::*,
+    solana_program::{borsh1::try_from_slice_unchecked, pubkey::Pubkey},
+};
+
+fn pubkey() -> impl Strategy<Value = Pubkey> {
+    any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+}
+
+fn nullable_u64() -> impl Strategy<Value = NullableU64> {
+    any::<u64>().prop_map(NullableU64::from)
+}
+
+prop_compose! {
+    fn governance_config()(
+        (discriminator, cooldown_period_seconds, proposal_minimum_quorum, proposal_pass_threshold)
+            in (any::<[u8; 8]>(), any::<u64>(), any::<u32>(), any::<u32>()),
+        (stake_config_address, voting_period_seconds, stake_per_proposal, governance_config)
+            in (pubkey(), any::<u64>(), any::<u64>(), pubkey()),
+        (cooldown_expires, pause_guardian, paused_timestamp, paused_seconds, successor)
+            in (any::<u64>(), pubkey(), nullable_u64(), any::<u64>(), pubkey()),
+        (proposal_winning_margin, padding) in (any::<u32>(), any::<[u8; 4]>()),
+    ) -> GovernanceConfig {
+        GovernanceConfig {
+            discriminator,
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            stake_config_address,
+            voting_period_seconds,
+            stake_per_proposal,
+            governance_config,
+            cooldown_expires,
+            pause_guardian,
+            paused_timestamp,
+            paused_seconds,
+            successor,
+            proposal_winning_margin,
+            padding,
+        }
+    }
+}
+
+prop_compose! {
+    fn proposal()(
+        (discriminator, author, cooldown_timestamp, creation_timestamp)
+            in (any::<[u8; 8]>(), pubkey(), nullable_u64(), any::<i64>()),
+        governance_config in governance_config(),
+        (stake_against, stake_for, status, option_count, winning_option, kind, padding)
+            in (
+                any::<u64>(),
+                any::<u64>(),
+                prop_oneof![
+                    Just(ProposalStatus::Draft),
+                    Just(ProposalStatus::Voting),
+                    Just(ProposalStatus::Accepted),
+                    Just(ProposalStatus::Rejected),
+                    Just(ProposalStatus::Processed),
+                    Just(ProposalStatus::Cancelled),
+                ],
+                any::<u8>(),
+                any::<u8>(),
+                prop_oneof![Just(ProposalKind::Executable), Just(ProposalKind::Signal)],
+                any::<[u8; 4]>(),
+            ),
+        (voting_start_timestamp, option_stake, paused_seconds_checkpoint)
+            in (nullable_u64(), any::<[u64; 8]>(), any::<u64>()),
+    ) -> Proposal {
+        Proposal {
+            discriminator,
+            author,
+            cooldown_timestamp,
+            creation_timestamp,
+            governance_config: Config {
+                discriminator: governance_config.discriminator,
+                cooldown_period_seconds: governance_config.cooldown_period_seconds,
+                proposal_minimum_quorum: governance_config.proposal_minimum_quorum,
+                proposal_pass_threshold: governance_config.proposal_pass_threshold,
+                stake_config_address: governance_config.stake_config_address,
+                voting_period_seconds: governance_config.voting_period_seconds,
+                stake_per_proposal: governance_config.stake_per_proposal,
+                governance_config: governance_config.governance_config,
+                cooldown_expires: governance_config.cooldown_expires,
+                pause_guardian: governance_config.pause_guardian,
+                paused_timestamp: governance_config.paused_timestamp,
+                paused_seconds: governance_config.paused_seconds,
+                successor: governance_config.successor,
+                proposal_winning_margin: governance_config.proposal_winning_margin,
+                padding: governance_config.padding,
+            },
+            stake_against,
+            stake_for,
+            status,
+            option_count,
+            winning_option,
+            kind,
+            padding,
+            voting_start_timestamp,
+            option_stake,
+            paused_seconds_checkpoint,
+        }
+    }
+}
+
+prop_compose! {
+    fn proposal_vote()(
+        proposal in pubkey(),
+        stake in any::<u64>(),
+        authority in pubkey(),
+        election in prop_oneof![
+            Just(ProposalVoteElection::For),
+            Just(ProposalVoteElection::Against),
+        ],
+        option_index in any::<u8>(),
+        padding in any::<[u8; 6]>(),
+    ) -> ProposalVote {
+        ProposalVote {
+            proposal,
+            stake,
+            authority,
+            election,
+            option_index,
+            padding,
+        }
+    }
+}
+
+prop_compose! {
+    fn proposal_instruction()(
+        program_id in pubkey(),
+        accounts in prop::collection::vec(
+            (pubkey(), any::<bool>(), any::<bool>()).prop_map(|(pubkey, is_signer, is_writable)| {
+                ProposalAccountMeta {
+                    pubkey,
+                    is_signer,
+                    is_writable,
+                }
+            }),
+            0..4,
+        ),
+        data in prop::collection::vec(any::<u8>(), 0..32),
+        executed in any::<bool>(),
+    ) -> ProposalInstruction {
+        ProposalInstruction {
+            program_id,
+            accounts,
+            data,
+            executed,
+        }
+    }
+}
+
+prop_compose! {
+    fn proposal_transaction()(
+        instructions in prop::collection::vec(proposal_instruction(), 0..4),
+        prerequisites in prop::collection::vec(pubkey(), 0..4),
+        options in prop::collection::vec(
+            prop::collection::vec(proposal_instruction(), 0..3)
+                .prop_map(|instructions| ProposalOption { instructions }),
+            0..3,
+        ),
+    ) -> ProposalTransaction {
+        ProposalTransaction {
+            instructions,
+            prerequisites,
+            options,
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn author_round_trip(active_proposals in any::<u64>()) {
+        let author = Author { active_proposals };
+        let program = state::Author::try_from(&author).unwrap();
+        prop_assert_eq!(bytemuck::bytes_of(&program), author.try_to_vec().unwrap());
+        prop_assert_eq!(Author::try_from(&program).unwrap(), author);
+    }
+
+    #[test]
+    fn governance_config_round_trip(governance_config in governance_config()) {
+        let program = state::GovernanceConfig::try_from(&governance_config).unwrap();
+        prop_assert_eq!(
+            bytemuck::bytes_of(&program),
+            governance_config.try_to_vec().unwrap()
+        );
+        prop_assert_eq!(
+            program.paused_timestamp.map(u64::from),
+            governance_config.paused_timestamp.value()
+        );
+        prop_assert_eq!(GovernanceConfig::try_from(&program).unwrap(), governance_config);
+    }
+
+    #[test]
+    fn proposal_round_trip(proposal in proposal()) {
+        let program = state::Proposal::try_from(&proposal).unwrap();
+        prop_assert_eq!(bytemuck::bytes_of(&program), proposal.try_to_vec().unwrap());
+        prop_assert_eq!(
+            program.governance_config.governance_config,
+            proposal.governance_config.governance_config
+        );
+        prop_assert_eq!(Proposal::try_from(&program).unwrap(), proposal);
+    }
+
+    #[test]
+    fn proposal_vote_round_trip(proposal_vote in proposal_vote()) {
+        let program = state::ProposalVote::try_from(&proposal_vote).unwrap();
+        prop_assert_eq!(bytemuck::bytes_of(&program), proposal_vote.try_to_vec().unwrap());
+        prop_assert_eq!(ProposalVote::try_from(&program).unwrap(), proposal_vote);
+    }
+
+    #[test]
+    fn proposal_transaction_round_trip(proposal_transaction in proposal_transaction()) {
+        let program = state::ProposalTransaction::from(&proposal_transaction);
+        // The client's encoding decodes to the same program state.
+        prop_assert_eq!(
+            &try_from_slice_unchecked::<state::ProposalTransaction>(
+                &proposal_transaction.try_to_vec().unwrap()
+            )
+            .unwrap(),
+            &program
+        );
+        prop_assert_eq!(ProposalTransaction::from(&program), proposal_transaction);
+    }
+}
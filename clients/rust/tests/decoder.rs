@@ -0,0 +1,40 @@
+#![cfg(feature = "decoder")]
+
+use {
+    paladin_governance_program_client::{
+        pdas::find_treasury_pda,
+        types::{ProposalAccountMeta, ProposalInstruction},
+    },
+    solana_program::{pubkey::Pubkey, system_instruction},
+};
+
+#[test]
+fn decode_proposal_instruction() {
+    let governance_config = Pubkey::new_unique();
+    let (treasury, _) = find_treasury_pda(&governance_config);
+    let recipient = Pubkey::new_unique();
+    let transfer = system_instruction::transfer(&treasury, &recipient, 1_000);
+
+    let instruction = ProposalInstruction {
+        program_id: transfer.program_id,
+        accounts: transfer
+            .accounts
+            .iter()
+            .map(|meta| ProposalAccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: transfer.data,
+        executed: false,
+    };
+
+    let decoded = instruction.decode(Some(&treasury));
+    assert_eq!(decoded.program, Some("System Program"));
+    assert_eq!(decoded.name.as_deref(), Some("Transfer"));
+    assert_eq!(decoded.accounts[0].name, "from");
+    assert!(decoded.accounts[0].is_treasury);
+    assert_eq!(decoded.accounts[1].name, "to");
+    assert!(decoded.touches_treasury);
+}
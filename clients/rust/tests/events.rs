@@ -0,0 +1,139 @@
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::BorshSerialize,
+    paladin_governance_program::{event, state},
+    paladin_governance_program_client::{
+        events::{parse_logs, GovernanceEvent, EVENT_VERSION},
+        types::{ProposalStatus, ProposalVoteElection},
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+/// The `Program data:` log of an event, as logged with the provided version.
+fn data_log(version: u8, event: &GovernanceEvent) -> String {
+    format!(
+        "Program data: {} {}",
+        STANDARD.encode([version]),
+        STANDARD.encode(event.try_to_vec().unwrap())
+    )
+}
+
+#[test]
+fn parse_logs_of_governance_program() {
+    let governance = paladin_governance_program_client::ID;
+    let other_program = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let updated = GovernanceEvent::GovernanceUpdated {
+        governance_config: Pubkey::new_unique(),
+        cooldown_period_seconds: 1,
+        proposal_minimum_quorum: 2,
+        proposal_pass_threshold: 3,
+        voting_period_seconds: 4,
+        stake_per_proposal: 5,
+        pause_guardian: Pubkey::default(),
+        proposal_winning_margin: 0,
+    };
+    let executed = GovernanceEvent::InstructionExecuted {
+        proposal,
+        option_index: None,
+        instruction_index: 0,
+        status: ProposalStatus::Processed,
+    };
+    let forged = GovernanceEvent::CooldownStarted {
+        proposal,
+        cooldown_timestamp: 1,
+    };
+
+    let logs = [
+        // Another program logging what looks like an event.
+        format!("Program {other_program} invoke [1]"),
+        data_log(EVENT_VERSION, &forged),
+        format!("Program {other_program} success"),
+        // A proposal executing a governance update.
+        format!("Program {governance} invoke [1]"),
+        "Program log: Instruction: ProcessInstruction".to_string(),
+        format!("Program {governance} invoke [2]"),
+        "Program log: Instruction: UpdateGovernance".to_string(),
+        data_log(EVENT_VERSION, &updated),
+        format!("Program {governance} consumed 1000 of 200000 compute units"),
+        format!("Program {governance} success"),
+        // An event of a future version.
+        data_log(EVENT_VERSION + 1, &forged),
+        data_log(EVENT_VERSION, &executed),
+        format!("Program {governance} success"),
+        // The other program, after the governance program returned.
+        format!("Program {other_program} invoke [1]"),
+        "Program log: success".to_string(),
+        data_log(EVENT_VERSION, &forged),
+        format!("Program {other_program} failed: custom program error: 0x0"),
+    ];
+
+    assert_eq!(parse_logs(&logs), vec![updated, executed]);
+}
+
+#[test]
+fn from_log_data_matches_program_encoding() {
+    let proposal = Pubkey::new_unique();
+    let vote = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let cases = [
+        (
+            GovernanceEvent::VoteSwitched {
+                proposal,
+                vote,
+                authority,
+                previous_election: ProposalVoteElection::For,
+                previous_option_index: Some(2),
+                previous_stake: 10,
+                election: ProposalVoteElection::Against,
+                option_index: Some(3),
+                stake: 20,
+                stake_for: 30,
+                stake_against: 40,
+            },
+            event::GovernanceEvent::VoteSwitched {
+                proposal,
+                vote,
+                authority,
+                previous_election: state::ProposalVoteElection::For,
+                previous_option_index: Some(2),
+                previous_stake: 10,
+                election: state::ProposalVoteElection::Against,
+                option_index: Some(3),
+                stake: 20,
+                stake_for: 30,
+                stake_against: 40,
+            },
+        ),
+        (
+            GovernanceEvent::ProposalFinalized {
+                proposal,
+                status: ProposalStatus::Cancelled,
+                winning_option: None,
+                stake_for: 1,
+                stake_against: 2,
+            },
+            event::GovernanceEvent::ProposalFinalized {
+                proposal,
+                status: state::ProposalStatus::Cancelled,
+                winning_option: None,
+                stake_for: 1,
+                stake_against: 2,
+            },
+        ),
+    ];
+
+    for (client, program) in cases {
+        let data = client.try_to_vec().unwrap();
+        assert_eq!(
+            event::GovernanceEvent::from_log_data(&[&[event::EVENT_VERSION], &data]),
+            Some(program)
+        );
+        assert_eq!(
+            GovernanceEvent::from_log_data(&[&[EVENT_VERSION], &data]),
+            Some(client)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "outcome")]
+
+use {
+    paladin_governance_program_client::{
+        accounts::{GovernanceConfig, Proposal},
+        outcome::THRESHOLD_SCALING_FACTOR,
+        types::{Config, ProposalKind, ProposalStatus},
+        NullableU64,
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+fn governance_config(paused_seconds: u64, paused_timestamp: Option<u64>) -> GovernanceConfig {
+    GovernanceConfig {
+        discriminator: [0; 8],
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR / 2,
+        proposal_pass_threshold: THRESHOLD_SCALING_FACTOR / 2,
+        stake_config_address: Pubkey::new_unique(),
+        voting_period_seconds: 100,
+        stake_per_proposal: 0,
+        governance_config: Pubkey::new_unique(),
+        cooldown_expires: 0,
+        pause_guardian: Pubkey::default(),
+        paused_timestamp: NullableU64::from(paused_timestamp),
+        paused_seconds,
+        successor: Pubkey::default(),
+        proposal_winning_margin: 0,
+        padding: [0; 4],
+    }
+}
+
+/// A yes/no proposal that started voting at 1000 and reached quorum at 1050.
+fn proposal(governance_config: &GovernanceConfig, stake_for: u64, stake_against: u64) -> Proposal {
+    Proposal {
+        discriminator: [0; 8],
+        author: Pubkey::new_unique(),
+        cooldown_timestamp: NullableU64::from(1050),
+        creation_timestamp: 0,
+        governance_config: Config {
+            discriminator: governance_config.discriminator,
+            cooldown_period_seconds: governance_config.cooldown_period_seconds,
+            proposal_minimum_quorum: governance_config.proposal_minimum_quorum,
+            proposal_pass_threshold: governance_config.proposal_pass_threshold,
+            stake_config_address: governance_config.stake_config_address,
+            voting_period_seconds: governance_config.voting_period_seconds,
+            stake_per_proposal: governance_config.stake_per_proposal,
+            governance_config: governance_config.governance_config,
+            cooldown_expires: governance_config.cooldown_expires,
+            pause_guardian: governance_config.pause_guardian,
+            paused_timestamp: NullableU64::from(None),
+            paused_seconds: 0,
+            successor: governance_config.successor,
+            proposal_winning_margin: governance_config.proposal_winning_margin,
+            padding: [0; 4],
+        },
+        stake_against,
+        stake_for,
+        status: ProposalStatus::Voting,
+        option_count: 0,
+        winning_option: 0,
+        kind: ProposalKind::Executable,
+        padding: [0; 4],
+        voting_start_timestamp: NullableU64::from(1000),
+        option_stake: [0; 8],
+        paused_seconds_checkpoint: 0,
+    }
+}
+
+#[test]
+fn status_if_finished_at() {
+    let governance_config = governance_config(0, None);
+    let total_stake = 1_000;
+
+    let mut proposal = proposal(&governance_config, 600, 400);
+    assert_eq!(
+        proposal.status_if_finished_at(&governance_config, total_stake, 1059),
+        Ok(None)
+    );
+    assert_eq!(
+        proposal.status_if_finished_at(&governance_config, total_stake, 1060),
+        Ok(Some(ProposalStatus::Accepted))
+    );
+
+    proposal.kind = ProposalKind::Signal;
+    assert_eq!(
+        proposal.status_if_finished_at(&governance_config, total_stake, 1060),
+        Ok(Some(ProposalStatus::Processed))
+    );
+
+    // Dropped below the pass threshold during the cooldown.
+    let proposal = self::proposal(&governance_config, 600, 700);
+    assert_eq!(
+        proposal.status_if_finished_at(&governance_config, total_stake, 1060),
+        Ok(Some(ProposalStatus::Rejected))
+    );
+}
+
+#[test]
+fn cooldown_ends_at_accounts_for_pauses() {
+    let governance_config = governance_config(5, None);
+    let proposal = proposal(&governance_config, 600, 400);
+    assert_eq!(
+        proposal.cooldown_ends_at(&governance_config, 1000),
+        Some(1065)
+    );
+
+    // An ongoing pause keeps pushing the end back.
+    let governance_config = self::governance_config(5, Some(1062));
+    assert_eq!(
+        proposal.cooldown_ends_at(&governance_config, 1070),
+        Some(1073)
+    );
+    assert_eq!(
+        proposal.status_if_finished_at(&governance_config, 1_000, 1070),
+        Ok(None)
+    );
+}
+
+#[test]
+fn for_stake_needed() {
+    let governance_config = governance_config(0, None);
+
+    // Already passing.
+    let proposal = self::proposal(&governance_config, 500, 300);
+    assert_eq!(proposal.for_stake_needed(1_000), Some(0));
+
+    // Quorum needs 400 more.
+    let proposal = self::proposal(&governance_config, 100, 50);
+    assert_eq!(proposal.for_stake_needed(1_000), Some(400));
+
+    // The pass threshold needs 100 more.
+    let mut proposal = self::proposal(&governance_config, 500, 600);
+    proposal.governance_config.proposal_minimum_quorum = THRESHOLD_SCALING_FACTOR / 10;
+    assert_eq!(proposal.for_stake_needed(2_000), Some(100));
+
+    // Not enough stake left to reach quorum.
+    let proposal = self::proposal(&governance_config, 100, 600);
+    assert_eq!(proposal.for_stake_needed(1_000), None);
+}
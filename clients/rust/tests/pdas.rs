@@ -0,0 +1,62 @@
+use {
+    paladin_governance_program::state::{
+        get_governance_address_and_bump_seed, get_proposal_author_address_and_bump,
+        get_proposal_transaction_address_and_bump_seed, get_proposal_vote_address_and_bump_seed,
+        get_treasury_address_and_bump_seed,
+    },
+    paladin_governance_program_client::{pdas::*, ID},
+    solana_program::pubkey::Pubkey,
+};
+
+#[test]
+fn program_id_matches() {
+    assert_eq!(ID, paladin_governance_program::id());
+}
+
+#[test]
+fn treasury_pda_matches() {
+    let governance_config = Pubkey::new_unique();
+    assert_eq!(
+        find_treasury_pda(&governance_config),
+        get_treasury_address_and_bump_seed(&governance_config, &ID)
+    );
+}
+
+#[test]
+fn governance_pda_matches() {
+    let stake_config = Pubkey::new_unique();
+    for governance_id in [0, 1, 256, u64::MAX] {
+        assert_eq!(
+            find_governance_pda(&stake_config, governance_id),
+            get_governance_address_and_bump_seed(&stake_config, &governance_id, &ID)
+        );
+    }
+}
+
+#[test]
+fn proposal_transaction_pda_matches() {
+    let proposal = Pubkey::new_unique();
+    assert_eq!(
+        find_proposal_transaction_pda(&proposal),
+        get_proposal_transaction_address_and_bump_seed(&proposal, &ID)
+    );
+}
+
+#[test]
+fn proposal_vote_pda_matches() {
+    let stake = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    assert_eq!(
+        find_proposal_vote_pda(&stake, &proposal),
+        get_proposal_vote_address_and_bump_seed(&stake, &proposal, &ID)
+    );
+}
+
+#[test]
+fn proposal_author_pda_matches() {
+    let stake_authority = Pubkey::new_unique();
+    assert_eq!(
+        find_proposal_author_pda(&stake_authority),
+        get_proposal_author_address_and_bump(&stake_authority, &ID)
+    );
+}
@@ -0,0 +1,367 @@
+use {
+    borsh::BorshSerialize,
+    paladin_governance_program::state::{
+        get_proposal_author_address, get_proposal_transaction_address, get_proposal_vote_address,
+        get_treasury_address, Proposal, ProposalTransaction,
+    },
+    paladin_governance_program_client::{
+        accounts::{GovernanceConfig, Proposal as ClientProposal},
+        sdk::{
+            delete_vote, execute, fund_rent, instruction_account_metas,
+            resolve_process_instruction, resolve_process_instructions, vote, ProposalBuilder,
+        },
+        types::{
+            Config, ProposalAccountMeta, ProposalInstruction as ClientProposalInstruction,
+            ProposalKind, ProposalOption, ProposalStatus,
+            ProposalTransaction as ClientProposalTransaction, ProposalVoteElection,
+        },
+        ID,
+    },
+    solana_program::{
+        borsh1::get_instance_packed_len,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+};
+
+fn transfer_instruction(treasury: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        solana_program::system_program::id(),
+        &[2, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0],
+        vec![
+            AccountMeta::new(*treasury, true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+        ],
+    )
+}
+
+#[test]
+fn account_lengths_match() {
+    assert_eq!(ClientProposal::LEN, Proposal::LEN);
+    assert_eq!(
+        GovernanceConfig::LEN,
+        std::mem::size_of::<paladin_governance_program::state::GovernanceConfig>()
+    );
+}
+
+#[test]
+fn proposal_builder_derives_accounts() {
+    let governance_config = Pubkey::new_unique();
+    let stake_authority = Pubkey::new_unique();
+    let stake = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let prerequisite = Pubkey::new_unique();
+    let instruction = transfer_instruction(&Pubkey::new_unique());
+
+    let instructions = ProposalBuilder::new(governance_config, stake_authority)
+        .stake(stake)
+        .proposal(proposal)
+        .with_prerequisite(prerequisite)
+        .with_instruction(instruction.clone())
+        .build();
+
+    let proposal_transaction = get_proposal_transaction_address(&proposal, &ID);
+    assert_eq!(instructions.proposal_transaction, proposal_transaction);
+    assert_eq!(instructions.fund.len(), 2);
+    assert_eq!(instructions.push.len(), 2);
+
+    // Create.
+    assert_eq!(
+        instructions.create.accounts[1].pubkey,
+        get_proposal_author_address(&stake_authority, &ID)
+    );
+    assert_eq!(instructions.create.accounts[4].pubkey, proposal_transaction);
+
+    // Prerequisites are pushed before instructions.
+    assert_eq!(instructions.push[0].data[0], 13);
+    assert_eq!(instructions.push[1].data[0], 2);
+
+    // Fund, create, push and begin voting, in order.
+    let all = instructions.clone().into_vec();
+    assert_eq!(all.len(), 6);
+    assert_eq!(all[2], instructions.create);
+    assert_eq!(all[5], instructions.begin_voting);
+}
+
+#[test]
+fn proposal_transaction_len_matches() {
+    let treasury = Pubkey::new_unique();
+    let prerequisite = Pubkey::new_unique();
+    let instruction = transfer_instruction(&treasury);
+
+    let builder = ProposalBuilder::new(Pubkey::new_unique(), Pubkey::new_unique())
+        .with_prerequisite(prerequisite)
+        .with_instruction(instruction.clone())
+        .clone();
+
+    let expected = ProposalTransaction {
+        instructions: vec![(&instruction).into()],
+        prerequisites: vec![prerequisite],
+        ..ProposalTransaction::default()
+    };
+    assert_eq!(
+        builder.proposal_transaction().try_to_vec().unwrap().len(),
+        get_instance_packed_len(&expected).unwrap()
+    );
+}
+
+#[test]
+fn vote_helpers_derive_vote_address() {
+    let stake_authority = Pubkey::new_unique();
+    let stake = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let vote_address = get_proposal_vote_address(&stake, &proposal, &ID);
+
+    let instruction = vote(
+        stake_authority,
+        stake,
+        Pubkey::new_unique(),
+        proposal,
+        Pubkey::new_unique(),
+        ProposalVoteElection::For,
+    );
+    assert_eq!(instruction.accounts[3].pubkey, vote_address);
+
+    let instruction = delete_vote(stake_authority, stake, proposal);
+    assert_eq!(instruction.accounts[1].pubkey, vote_address);
+    assert_eq!(instruction.accounts[2].pubkey, stake_authority);
+}
+
+#[test]
+fn execute_appends_instruction_accounts() {
+    let proposal = Pubkey::new_unique();
+    let governance_config = Pubkey::new_unique();
+    let prerequisite = Pubkey::new_unique();
+    let treasury = get_treasury_address(&governance_config, &ID);
+    let instruction = transfer_instruction(&treasury);
+
+    let execute = execute(
+        proposal,
+        governance_config,
+        &[prerequisite],
+        &instruction,
+        0,
+    );
+
+    assert_eq!(
+        execute.accounts[1].pubkey,
+        get_proposal_transaction_address(&proposal, &ID)
+    );
+    assert_eq!(
+        execute.accounts[3..],
+        [
+            AccountMeta::new_readonly(prerequisite, false),
+            AccountMeta::new(treasury, false), // Signs through the program.
+            instruction.accounts[1].clone(),
+            AccountMeta::new_readonly(instruction.program_id, false),
+        ]
+    );
+}
+
+#[test]
+fn fund_rent_transfers_shortfall() {
+    let payer = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+
+    assert_eq!(
+        fund_rent(payer, address, 40, 100),
+        Some(solana_program::system_instruction::transfer(
+            &payer, &address, 60
+        ))
+    );
+    assert_eq!(fund_rent(payer, address, 100, 100), None);
+    assert_eq!(fund_rent(payer, address, 150, 100), None);
+}
+
+#[test]
+fn instruction_account_metas_merges_duplicates() {
+    let treasury = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let instruction = Instruction::new_with_bytes(
+        solana_program::system_program::id(),
+        &[],
+        vec![
+            AccountMeta::new(treasury, true),
+            AccountMeta::new_readonly(recipient, false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+    );
+
+    assert_eq!(
+        instruction_account_metas(&treasury, &instruction),
+        [
+            AccountMeta::new(treasury, false), // Signs through the program.
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ]
+    );
+}
+
+fn client_proposal(option_count: u8, winning_option: u8) -> ClientProposal {
+    ClientProposal {
+        discriminator: [0; 8],
+        author: Pubkey::new_unique(),
+        cooldown_timestamp: None.into(),
+        creation_timestamp: 0,
+        governance_config: Config {
+            discriminator: [0; 8],
+            cooldown_period_seconds: 0,
+            proposal_minimum_quorum: 0,
+            proposal_pass_threshold: 0,
+            stake_config_address: Pubkey::new_unique(),
+            voting_period_seconds: 0,
+            stake_per_proposal: 0,
+            governance_config: Pubkey::new_unique(),
+            cooldown_expires: 0,
+            pause_guardian: Pubkey::default(),
+            paused_timestamp: None.into(),
+            paused_seconds: 0,
+            successor: Pubkey::default(),
+            proposal_winning_margin: 0,
+            padding: [0; 4],
+        },
+        stake_against: 0,
+        stake_for: 0,
+        status: ProposalStatus::Accepted,
+        option_count,
+        winning_option,
+        kind: ProposalKind::Executable,
+        padding: [0; 4],
+        voting_start_timestamp: None.into(),
+        option_stake: [0; 8],
+        paused_seconds_checkpoint: 0,
+    }
+}
+
+fn client_instruction(program_id: Pubkey, accounts: &[AccountMeta]) -> ClientProposalInstruction {
+    ClientProposalInstruction {
+        program_id,
+        accounts: accounts
+            .iter()
+            .map(|meta| ProposalAccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: vec![1, 2, 3],
+        executed: false,
+    }
+}
+
+#[test]
+fn resolve_process_instruction_deduplicates_accounts() {
+    let proposal_address = Pubkey::new_unique();
+    let governance_config = Pubkey::new_unique();
+    let treasury = get_treasury_address(&governance_config, &ID);
+    let prerequisite = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    let proposal_transaction = ClientProposalTransaction {
+        instructions: vec![client_instruction(
+            program_id,
+            &[
+                AccountMeta::new(treasury, true),
+                AccountMeta::new_readonly(account, false),
+                AccountMeta::new(account, false), // Duplicate, writable.
+                AccountMeta::new_readonly(program_id, false), // The program itself.
+            ],
+        )],
+        prerequisites: vec![prerequisite],
+        options: vec![],
+    };
+
+    let instruction = resolve_process_instruction(
+        proposal_address,
+        governance_config,
+        &client_proposal(0, 0),
+        &proposal_transaction,
+        0,
+    )
+    .unwrap();
+
+    assert_eq!(instruction.data, [9, 0, 0, 0, 0]);
+    assert_eq!(
+        instruction.accounts,
+        [
+            AccountMeta::new(proposal_address, false),
+            AccountMeta::new(
+                get_proposal_transaction_address(&proposal_address, &ID),
+                false
+            ),
+            AccountMeta::new_readonly(governance_config, false),
+            AccountMeta::new_readonly(prerequisite, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(account, false),
+            AccountMeta::new_readonly(program_id, false),
+        ]
+    );
+
+    // Out of range.
+    assert_eq!(
+        resolve_process_instruction(
+            proposal_address,
+            governance_config,
+            &client_proposal(0, 0),
+            &proposal_transaction,
+            1,
+        ),
+        None
+    );
+}
+
+#[test]
+fn resolve_process_instructions_uses_winning_option() {
+    let proposal_address = Pubkey::new_unique();
+    let governance_config = Pubkey::new_unique();
+    let losing_program_id = Pubkey::new_unique();
+    let winning_program_id = Pubkey::new_unique();
+
+    let proposal_transaction = ClientProposalTransaction {
+        instructions: vec![],
+        prerequisites: vec![],
+        options: vec![
+            ProposalOption {
+                instructions: vec![client_instruction(losing_program_id, &[])],
+            },
+            ProposalOption {
+                instructions: vec![
+                    client_instruction(winning_program_id, &[]),
+                    client_instruction(winning_program_id, &[]),
+                ],
+            },
+        ],
+    };
+
+    let instructions = resolve_process_instructions(
+        proposal_address,
+        governance_config,
+        &client_proposal(2, 1),
+        &proposal_transaction,
+        0..2,
+    )
+    .unwrap();
+
+    assert_eq!(instructions.len(), 2);
+    for (index, instruction) in instructions.iter().enumerate() {
+        assert_eq!(instruction.data, [9, index as u8, 0, 0, 0]);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&AccountMeta::new_readonly(winning_program_id, false))
+        );
+    }
+
+    // The range exceeds the winning option's instructions.
+    assert_eq!(
+        resolve_process_instructions(
+            proposal_address,
+            governance_config,
+            &client_proposal(2, 1),
+            &proposal_transaction,
+            0..3,
+        ),
+        None
+    );
+}
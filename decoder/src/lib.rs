@@ -0,0 +1,161 @@
+//! Human-readable decoding of Paladin Governance proposal instructions.
+//!
+//! Instructions stored in a `ProposalTransaction` are opaque bytes. This crate
+//! recognizes the instructions of common programs and renders them with their
+//! arguments and named accounts:
+//!
+//! * The Paladin Governance program.
+//! * The system program.
+//! * SPL Token and Token-2022. Token-2022 extension instructions are left
+//!   undecoded.
+//! * The BPF upgradeable loader.
+//! * The Paladin stake program. Only the instructions a governance proposal
+//!   would carry have their arguments and accounts named.
+//!
+//! Any account or argument matching the governance treasury is flagged, since
+//! the treasury signs for every instruction of an accepted proposal.
+
+mod programs;
+
+use {
+    serde::Serialize,
+    serde_with::{serde_as, DisplayFromStr},
+    solana_program::{instruction::Instruction, pubkey::Pubkey},
+    std::fmt::{self, Write},
+};
+
+/// A decoded instruction.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedInstruction {
+    #[serde_as(as = "DisplayFromStr")]
+    pub program_id: Pubkey,
+    /// The name of the program, if recognized.
+    pub program: Option<&'static str>,
+    /// The name of the instruction, if its data could be decoded.
+    pub name: Option<String>,
+    /// The instruction's arguments. Undecoded instructions have their data,
+    /// in hex, as a single `data` argument.
+    pub arguments: Vec<Argument>,
+    pub accounts: Vec<DecodedAccount>,
+    /// Whether any account or argument is the governance treasury.
+    pub touches_treasury: bool,
+}
+
+/// A named instruction argument, rendered for display.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Argument {
+    pub name: String,
+    pub value: String,
+}
+
+/// An account of a decoded instruction.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedAccount {
+    /// The account's name, or `account_<index>` if the instruction doesn't
+    /// name it.
+    pub name: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub is_treasury: bool,
+}
+
+/// Decodes an instruction, flagging the provided governance treasury.
+///
+/// Without a treasury, nothing is flagged.
+pub fn decode(instruction: &Instruction, treasury: Option<&Pubkey>) -> DecodedInstruction {
+    let program = programs::program_name(&instruction.program_id);
+    let (name, arguments, account_names) = match programs::decode(instruction) {
+        Some((name, arguments, account_names)) => (Some(name), arguments, account_names),
+        None => (
+            None,
+            vec![Argument {
+                name: "data".to_string(),
+                value: hex(&instruction.data),
+            }],
+            &[][..],
+        ),
+    };
+
+    let accounts: Vec<DecodedAccount> = instruction
+        .accounts
+        .iter()
+        .enumerate()
+        .map(|(index, meta)| DecodedAccount {
+            name: account_names
+                .get(index)
+                .map_or_else(|| format!("account_{index}"), ToString::to_string),
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+            is_treasury: treasury == Some(&meta.pubkey),
+        })
+        .collect();
+
+    let touches_treasury = treasury.is_some_and(|treasury| {
+        let treasury = treasury.to_string();
+        accounts.iter().any(|account| account.is_treasury)
+            || arguments
+                .iter()
+                .any(|argument| argument.value.contains(&treasury))
+    });
+
+    DecodedInstruction {
+        program_id: instruction.program_id,
+        program,
+        name,
+        arguments,
+        accounts,
+        touches_treasury,
+    }
+}
+
+impl fmt::Display for DecodedInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.program {
+            Some(program) => write!(f, "{program}")?,
+            None => write!(f, "Unknown program {}", self.program_id)?,
+        }
+        write!(
+            f,
+            ": {}",
+            self.name.as_deref().unwrap_or("unknown instruction")
+        )?;
+        if self.touches_treasury {
+            write!(f, " [touches treasury]")?;
+        }
+        writeln!(f)?;
+
+        for argument in &self.arguments {
+            writeln!(f, "  {}: {}", argument.name, argument.value)?;
+        }
+        for account in &self.accounts {
+            let flags = [
+                (account.is_signer, "signer"),
+                (account.is_writable, "writable"),
+                (account.is_treasury, "treasury"),
+            ]
+            .iter()
+            .filter_map(|(set, flag)| set.then_some(*flag))
+            .collect::<Vec<_>>();
+            write!(f, "  {}: {}", account.name, account.pubkey)?;
+            if !flags.is_empty() {
+                write!(f, " ({})", flags.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
@@ -0,0 +1,675 @@
+//! Decoders for the programs recognized by this crate.
+
+use {
+    crate::{hex, Argument},
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        instruction::PaladinGovernanceInstruction, state::ProposalAccountMeta,
+    },
+    paladin_stake_program::instruction::StakeInstruction,
+    solana_program::{
+        bpf_loader_upgradeable::{self, UpgradeableLoaderInstruction},
+        instruction::Instruction,
+        pubkey::Pubkey,
+        system_instruction::SystemInstruction,
+        system_program,
+    },
+    spl_token::instruction::TokenInstruction,
+    std::fmt,
+};
+
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// The name, arguments and account names of a decoded instruction.
+pub(crate) type Decoded = (String, Vec<Argument>, &'static [&'static str]);
+
+pub(crate) fn program_name(program_id: &Pubkey) -> Option<&'static str> {
+    if *program_id == paladin_governance_program::id() {
+        Some("Paladin Governance")
+    } else if *program_id == paladin_stake_program::id() {
+        Some("Paladin Stake")
+    } else if *program_id == system_program::id() {
+        Some("System Program")
+    } else if *program_id == spl_token::id() {
+        Some("SPL Token")
+    } else if *program_id == TOKEN_2022_PROGRAM_ID {
+        Some("Token-2022")
+    } else if *program_id == bpf_loader_upgradeable::id() {
+        Some("BPF Upgradeable Loader")
+    } else {
+        None
+    }
+}
+
+pub(crate) fn decode(instruction: &Instruction) -> Option<Decoded> {
+    let program_id = &instruction.program_id;
+    let data = &instruction.data;
+
+    if *program_id == paladin_governance_program::id() {
+        let instruction = PaladinGovernanceInstruction::unpack(data).ok()?;
+        let (name, arguments) = governance_instruction(&instruction);
+        Some((
+            name.to_string(),
+            arguments,
+            governance_accounts(&instruction),
+        ))
+    } else if *program_id == paladin_stake_program::id() {
+        let instruction = StakeInstruction::try_from_slice(data).ok()?;
+        let (name, arguments) = match stake_instruction(&instruction) {
+            Some((name, arguments)) => (name.to_string(), arguments),
+            None => (
+                variant_name(&instruction),
+                vec![argument("data", hex(data))],
+            ),
+        };
+        Some((name, arguments, stake_accounts(&instruction)))
+    } else if *program_id == system_program::id() {
+        let instruction = bincode::deserialize::<SystemInstruction>(data).ok()?;
+        let (name, arguments) = system_instruction(&instruction);
+        Some((name.to_string(), arguments, system_accounts(&instruction)))
+    } else if *program_id == spl_token::id() || *program_id == TOKEN_2022_PROGRAM_ID {
+        // Token-2022 shares SPL Token's encoding for every instruction but its
+        // extensions.
+        let instruction = TokenInstruction::unpack(data).ok()?;
+        let (name, arguments) = token_instruction(&instruction);
+        Some((name.to_string(), arguments, token_accounts(&instruction)))
+    } else if *program_id == bpf_loader_upgradeable::id() {
+        let instruction = bincode::deserialize::<UpgradeableLoaderInstruction>(data).ok()?;
+        let (name, arguments) = match loader_instruction(&instruction) {
+            Some((name, arguments)) => (name.to_string(), arguments),
+            None => (variant_name(&instruction), vec![]),
+        };
+        Some((name, arguments, loader_accounts(&instruction)))
+    } else {
+        None
+    }
+}
+
+fn argument(name: &str, value: impl fmt::Display) -> Argument {
+    Argument {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn optional(value: Option<impl fmt::Display>) -> String {
+    value.map_or_else(|| "None".to_string(), |value| value.to_string())
+}
+
+fn account_metas(metas: &[ProposalAccountMeta]) -> String {
+    let metas = metas
+        .iter()
+        .map(|meta| {
+            let flags = [(meta.is_signer, "signer"), (meta.is_writable, "writable")]
+                .iter()
+                .filter_map(|(set, flag)| set.then_some(*flag))
+                .collect::<Vec<_>>();
+            if flags.is_empty() {
+                meta.pubkey.to_string()
+            } else {
+                format!("{} ({})", meta.pubkey, flags.join(", "))
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", metas.join(", "))
+}
+
+fn governance_instruction(
+    instruction: &PaladinGovernanceInstruction,
+) -> (&'static str, Vec<Argument>) {
+    use PaladinGovernanceInstruction::*;
+    match instruction {
+        InitializeAuthor => ("InitializeAuthor", vec![]),
+        CreateProposal => ("CreateProposal", vec![]),
+        PushInstruction {
+            instruction_program_id,
+            instruction_account_metas,
+            instruction_data,
+        } => (
+            "PushInstruction",
+            vec![
+                argument("instruction_program_id", instruction_program_id),
+                argument(
+                    "instruction_account_metas",
+                    account_metas(instruction_account_metas),
+                ),
+                argument("instruction_data", hex(instruction_data)),
+            ],
+        ),
+        DeleteProposal => ("DeleteProposal", vec![]),
+        BeginVoting => ("BeginVoting", vec![]),
+        Vote { election } => ("Vote", vec![argument("election", format!("{election:?}"))]),
+        SwitchVote { new_election } => (
+            "SwitchVote",
+            vec![argument("new_election", format!("{new_election:?}"))],
+        ),
+        FinishVoting => ("FinishVoting", vec![]),
+        DeleteVote => ("DeleteVote", vec![]),
+        ProcessInstruction { instruction_index } => (
+            "ProcessInstruction",
+            vec![argument("instruction_index", instruction_index)],
+        ),
+        InitializeGovernance {
+            governance_id,
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            voting_period_seconds,
+            stake_per_proposal,
+            cooldown_seconds,
+        } => (
+            "InitializeGovernance",
+            vec![
+                argument("governance_id", governance_id),
+                argument("cooldown_period_seconds", cooldown_period_seconds),
+                argument("proposal_minimum_quorum", proposal_minimum_quorum),
+                argument("proposal_pass_threshold", proposal_pass_threshold),
+                argument("voting_period_seconds", voting_period_seconds),
+                argument("stake_per_proposal", stake_per_proposal),
+                argument("cooldown_seconds", cooldown_seconds),
+            ],
+        ),
+        UpdateGovernance {
+            governance_id,
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            voting_period_seconds,
+            stake_per_proposal,
+        } => (
+            "UpdateGovernance",
+            vec![
+                argument("governance_id", governance_id),
+                argument("cooldown_period_seconds", cooldown_period_seconds),
+                argument("proposal_minimum_quorum", proposal_minimum_quorum),
+                argument("proposal_pass_threshold", proposal_pass_threshold),
+                argument("voting_period_seconds", voting_period_seconds),
+                argument("stake_per_proposal", stake_per_proposal),
+            ],
+        ),
+        CancelProposal => ("CancelProposal", vec![]),
+        PushPrerequisite {
+            prerequisite_proposal,
+        } => (
+            "PushPrerequisite",
+            vec![argument("prerequisite_proposal", prerequisite_proposal)],
+        ),
+        PushOption => ("PushOption", vec![]),
+        PushOptionInstruction {
+            option_index,
+            instruction_program_id,
+            instruction_account_metas,
+            instruction_data,
+        } => (
+            "PushOptionInstruction",
+            vec![
+                argument("option_index", option_index),
+                argument("instruction_program_id", instruction_program_id),
+                argument(
+                    "instruction_account_metas",
+                    account_metas(instruction_account_metas),
+                ),
+                argument("instruction_data", hex(instruction_data)),
+            ],
+        ),
+        VoteOption { option_index } => ("VoteOption", vec![argument("option_index", option_index)]),
+        SwitchVoteOption { new_option_index } => (
+            "SwitchVoteOption",
+            vec![argument("new_option_index", new_option_index)],
+        ),
+        CreateSignalProposal => ("CreateSignalProposal", vec![]),
+        CloseAuthor => ("CloseAuthor", vec![]),
+        PartialUpdateGovernance {
+            governance_id,
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            voting_period_seconds,
+            stake_per_proposal,
+            pause_guardian,
+            proposal_winning_margin,
+        } => (
+            "PartialUpdateGovernance",
+            vec![
+                argument("governance_id", governance_id),
+                argument(
+                    "cooldown_period_seconds",
+                    optional(*cooldown_period_seconds),
+                ),
+                argument(
+                    "proposal_minimum_quorum",
+                    optional(*proposal_minimum_quorum),
+                ),
+                argument(
+                    "proposal_pass_threshold",
+                    optional(*proposal_pass_threshold),
+                ),
+                argument("voting_period_seconds", optional(*voting_period_seconds)),
+                argument("stake_per_proposal", optional(*stake_per_proposal)),
+                argument("pause_guardian", optional(pause_guardian.as_ref())),
+                argument(
+                    "proposal_winning_margin",
+                    optional(*proposal_winning_margin),
+                ),
+            ],
+        ),
+        MigrateAccount { governance_id } => (
+            "MigrateAccount",
+            vec![argument("governance_id", governance_id)],
+        ),
+        SetPaused { paused } => ("SetPaused", vec![argument("paused", paused)]),
+        MigrateGovernance {
+            governance_id,
+            new_governance_id,
+        } => (
+            "MigrateGovernance",
+            vec![
+                argument("governance_id", governance_id),
+                argument("new_governance_id", new_governance_id),
+            ],
+        ),
+    }
+}
+
+/// Only the instructions a governance proposal would carry are decoded. `None`
+/// for the others, which keep their data as a single `data` argument.
+fn stake_instruction(instruction: &StakeInstruction) -> Option<(&'static str, Vec<Argument>)> {
+    use StakeInstruction::*;
+    let decoded = match instruction {
+        SlashValidatorStake(amount) => ("SlashValidatorStake", vec![argument("amount", amount)]),
+        SetAuthority(authority_type) => (
+            "SetAuthority",
+            vec![argument("authority_type", format!("{authority_type:?}"))],
+        ),
+        UpdateConfig(field) => (
+            "UpdateConfig",
+            vec![argument("field", format!("{field:?}"))],
+        ),
+        DistributeRewards(amount) => ("DistributeRewards", vec![argument("amount", amount)]),
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    Some(decoded)
+}
+
+/// The name of an enum variant, from the identifier that starts its `Debug`
+/// output.
+fn variant_name(instruction: &impl fmt::Debug) -> String {
+    let mut name = format!("{instruction:?}");
+    if let Some(end) = name.find(|c: char| !c.is_alphanumeric() && c != '_') {
+        name.truncate(end);
+    }
+    name
+}
+
+fn system_instruction(instruction: &SystemInstruction) -> (&'static str, Vec<Argument>) {
+    use SystemInstruction::*;
+    match instruction {
+        CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => (
+            "CreateAccount",
+            vec![
+                argument("lamports", lamports),
+                argument("space", space),
+                argument("owner", owner),
+            ],
+        ),
+        Assign { owner } => ("Assign", vec![argument("owner", owner)]),
+        Transfer { lamports } => ("Transfer", vec![argument("lamports", lamports)]),
+        CreateAccountWithSeed {
+            base,
+            seed,
+            lamports,
+            space,
+            owner,
+        } => (
+            "CreateAccountWithSeed",
+            vec![
+                argument("base", base),
+                argument("seed", seed),
+                argument("lamports", lamports),
+                argument("space", space),
+                argument("owner", owner),
+            ],
+        ),
+        AdvanceNonceAccount => ("AdvanceNonceAccount", vec![]),
+        WithdrawNonceAccount(lamports) => {
+            ("WithdrawNonceAccount", vec![argument("lamports", lamports)])
+        }
+        InitializeNonceAccount(authority) => (
+            "InitializeNonceAccount",
+            vec![argument("authority", authority)],
+        ),
+        AuthorizeNonceAccount(authority) => (
+            "AuthorizeNonceAccount",
+            vec![argument("authority", authority)],
+        ),
+        Allocate { space } => ("Allocate", vec![argument("space", space)]),
+        AllocateWithSeed {
+            base,
+            seed,
+            space,
+            owner,
+        } => (
+            "AllocateWithSeed",
+            vec![
+                argument("base", base),
+                argument("seed", seed),
+                argument("space", space),
+                argument("owner", owner),
+            ],
+        ),
+        AssignWithSeed { base, seed, owner } => (
+            "AssignWithSeed",
+            vec![
+                argument("base", base),
+                argument("seed", seed),
+                argument("owner", owner),
+            ],
+        ),
+        TransferWithSeed {
+            lamports,
+            from_seed,
+            from_owner,
+        } => (
+            "TransferWithSeed",
+            vec![
+                argument("lamports", lamports),
+                argument("from_seed", from_seed),
+                argument("from_owner", from_owner),
+            ],
+        ),
+        UpgradeNonceAccount => ("UpgradeNonceAccount", vec![]),
+    }
+}
+
+fn token_instruction(instruction: &TokenInstruction) -> (&'static str, Vec<Argument>) {
+    use TokenInstruction::*;
+    let amount_and_decimals = |amount: &u64, decimals: &u8| {
+        vec![argument("amount", amount), argument("decimals", decimals)]
+    };
+    match instruction {
+        InitializeMint {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => (
+            "InitializeMint",
+            vec![
+                argument("decimals", decimals),
+                argument("mint_authority", mint_authority),
+                argument(
+                    "freeze_authority",
+                    optional(Option::from(*freeze_authority)),
+                ),
+            ],
+        ),
+        InitializeMint2 {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => (
+            "InitializeMint2",
+            vec![
+                argument("decimals", decimals),
+                argument("mint_authority", mint_authority),
+                argument(
+                    "freeze_authority",
+                    optional(Option::from(*freeze_authority)),
+                ),
+            ],
+        ),
+        InitializeAccount => ("InitializeAccount", vec![]),
+        InitializeAccount2 { owner } => ("InitializeAccount2", vec![argument("owner", owner)]),
+        InitializeAccount3 { owner } => ("InitializeAccount3", vec![argument("owner", owner)]),
+        InitializeMultisig { m } => ("InitializeMultisig", vec![argument("m", m)]),
+        InitializeMultisig2 { m } => ("InitializeMultisig2", vec![argument("m", m)]),
+        Transfer { amount } => ("Transfer", vec![argument("amount", amount)]),
+        TransferChecked { amount, decimals } => {
+            ("TransferChecked", amount_and_decimals(amount, decimals))
+        }
+        Approve { amount } => ("Approve", vec![argument("amount", amount)]),
+        ApproveChecked { amount, decimals } => {
+            ("ApproveChecked", amount_and_decimals(amount, decimals))
+        }
+        Revoke => ("Revoke", vec![]),
+        SetAuthority {
+            authority_type,
+            new_authority,
+        } => (
+            "SetAuthority",
+            vec![
+                argument("authority_type", format!("{authority_type:?}")),
+                argument("new_authority", optional(Option::from(*new_authority))),
+            ],
+        ),
+        MintTo { amount } => ("MintTo", vec![argument("amount", amount)]),
+        MintToChecked { amount, decimals } => {
+            ("MintToChecked", amount_and_decimals(amount, decimals))
+        }
+        Burn { amount } => ("Burn", vec![argument("amount", amount)]),
+        BurnChecked { amount, decimals } => ("BurnChecked", amount_and_decimals(amount, decimals)),
+        CloseAccount => ("CloseAccount", vec![]),
+        FreezeAccount => ("FreezeAccount", vec![]),
+        ThawAccount => ("ThawAccount", vec![]),
+        SyncNative => ("SyncNative", vec![]),
+        GetAccountDataSize => ("GetAccountDataSize", vec![]),
+        InitializeImmutableOwner => ("InitializeImmutableOwner", vec![]),
+        AmountToUiAmount { amount } => ("AmountToUiAmount", vec![argument("amount", amount)]),
+        UiAmountToAmount { ui_amount } => {
+            ("UiAmountToAmount", vec![argument("ui_amount", ui_amount)])
+        }
+    }
+}
+
+/// `None` for instructions added to the loader after this crate's version of
+/// `solana-program`.
+fn loader_instruction(
+    instruction: &UpgradeableLoaderInstruction,
+) -> Option<(&'static str, Vec<Argument>)> {
+    use UpgradeableLoaderInstruction::*;
+    let decoded = match instruction {
+        InitializeBuffer => ("InitializeBuffer", vec![]),
+        Write { offset, bytes } => (
+            "Write",
+            vec![argument("offset", offset), argument("bytes", hex(bytes))],
+        ),
+        DeployWithMaxDataLen { max_data_len } => (
+            "DeployWithMaxDataLen",
+            vec![argument("max_data_len", max_data_len)],
+        ),
+        Upgrade => ("Upgrade", vec![]),
+        SetAuthority => ("SetAuthority", vec![]),
+        Close => ("Close", vec![]),
+        ExtendProgram { additional_bytes } => (
+            "ExtendProgram",
+            vec![argument("additional_bytes", additional_bytes)],
+        ),
+        SetAuthorityChecked => ("SetAuthorityChecked", vec![]),
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    Some(decoded)
+}
+
+fn governance_accounts(instruction: &PaladinGovernanceInstruction) -> &'static [&'static str] {
+    use PaladinGovernanceInstruction::*;
+    match instruction {
+        InitializeAuthor => &["stake_authority", "author", "system_program"],
+        CreateProposal => &[
+            "stake_authority",
+            "author",
+            "stake",
+            "proposal",
+            "proposal_transaction",
+            "governance_config",
+            "system_program",
+        ],
+        PushInstruction { .. }
+        | PushPrerequisite { .. }
+        | PushOption
+        | PushOptionInstruction { .. } => &["stake_authority", "proposal", "proposal_transaction"],
+        DeleteProposal => &[
+            "stake_authority",
+            "author",
+            "proposal",
+            "proposal_transaction",
+        ],
+        BeginVoting => &["stake_authority", "proposal", "governance_config"],
+        Vote { .. } | VoteOption { .. } => &[
+            "stake_authority",
+            "stake",
+            "stake_config",
+            "vote",
+            "proposal",
+            "governance_config",
+            "system_program",
+        ],
+        SwitchVote { .. } | SwitchVoteOption { .. } => &[
+            "stake_authority",
+            "stake",
+            "stake_config",
+            "vote",
+            "proposal",
+            "governance_config",
+        ],
+        FinishVoting => &["stake_config", "proposal", "governance_config"],
+        DeleteVote => &["proposal", "vote", "authority"],
+        ProcessInstruction { .. } => &["proposal", "proposal_transaction", "governance_config"],
+        InitializeGovernance { .. } => &[
+            "governance_config",
+            "stake_config",
+            "system_program",
+            "authority",
+            "program_data",
+        ],
+        UpdateGovernance { .. } | PartialUpdateGovernance { .. } => {
+            &["treasury", "governance_config"]
+        }
+        CancelProposal => &["stake_authority", "author", "proposal"],
+        CreateSignalProposal => &[
+            "stake_authority",
+            "author",
+            "stake",
+            "proposal",
+            "governance_config",
+        ],
+        CloseAuthor => &["stake_authority", "author"],
+        MigrateAccount { .. } => &["account", "payer", "system_program"],
+        SetPaused { .. } => &["authority", "governance_config"],
+        MigrateGovernance { .. } => &[
+            "treasury",
+            "governance_config",
+            "new_treasury",
+            "new_governance_config",
+            "system_program",
+            "token_program",
+            "token_2022_program",
+        ],
+    }
+}
+
+fn stake_accounts(instruction: &StakeInstruction) -> &'static [&'static str] {
+    use StakeInstruction::*;
+    match instruction {
+        SlashValidatorStake { .. } => &[
+            "config",
+            "validator_stake",
+            "slash_authority",
+            "vault",
+            "mint",
+            "vault_authority",
+            "vault_holder_rewards",
+            "token_program",
+        ],
+        SetAuthority { .. } => &["account", "authority", "new_authority"],
+        UpdateConfig { .. } => &["config", "config_authority"],
+        DistributeRewards { .. } => &["payer", "config", "system_program"],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
+
+fn system_accounts(instruction: &SystemInstruction) -> &'static [&'static str] {
+    use SystemInstruction::*;
+    match instruction {
+        CreateAccount { .. } => &["funding", "new_account"],
+        Assign { .. } | Allocate { .. } => &["account"],
+        Transfer { .. } => &["from", "to"],
+        CreateAccountWithSeed { .. } => &["funding", "new_account", "base"],
+        AdvanceNonceAccount => &["nonce", "recent_blockhashes", "nonce_authority"],
+        WithdrawNonceAccount(_) => &[
+            "nonce",
+            "to",
+            "recent_blockhashes",
+            "rent",
+            "nonce_authority",
+        ],
+        InitializeNonceAccount(_) => &["nonce", "recent_blockhashes", "rent"],
+        AuthorizeNonceAccount(_) => &["nonce", "nonce_authority"],
+        AllocateWithSeed { .. } | AssignWithSeed { .. } => &["account", "base"],
+        TransferWithSeed { .. } => &["from", "base", "to"],
+        UpgradeNonceAccount => &["nonce"],
+    }
+}
+
+fn token_accounts(instruction: &TokenInstruction) -> &'static [&'static str] {
+    use TokenInstruction::*;
+    match instruction {
+        InitializeMint { .. } => &["mint", "rent"],
+        InitializeMint2 { .. } => &["mint"],
+        InitializeAccount => &["account", "mint", "owner", "rent"],
+        InitializeAccount2 { .. } => &["account", "mint", "rent"],
+        InitializeAccount3 { .. } => &["account", "mint"],
+        InitializeMultisig { .. } => &["multisig", "rent"],
+        InitializeMultisig2 { .. } => &["multisig"],
+        Transfer { .. } => &["source", "destination", "authority"],
+        TransferChecked { .. } => &["source", "mint", "destination", "authority"],
+        Approve { .. } => &["source", "delegate", "owner"],
+        ApproveChecked { .. } => &["source", "mint", "delegate", "owner"],
+        Revoke => &["source", "owner"],
+        SetAuthority { .. } => &["account", "authority"],
+        MintTo { .. } | MintToChecked { .. } => &["mint", "destination", "authority"],
+        Burn { .. } | BurnChecked { .. } => &["account", "mint", "authority"],
+        CloseAccount => &["account", "destination", "authority"],
+        FreezeAccount | ThawAccount => &["account", "mint", "authority"],
+        SyncNative | InitializeImmutableOwner => &["account"],
+        // `GetAccountDataSize`, `AmountToUiAmount` and `UiAmountToAmount`.
+        _ => &["mint"],
+    }
+}
+
+fn loader_accounts(instruction: &UpgradeableLoaderInstruction) -> &'static [&'static str] {
+    use UpgradeableLoaderInstruction::*;
+    match instruction {
+        InitializeBuffer | Write { .. } => &["buffer", "authority"],
+        DeployWithMaxDataLen { .. } => &[
+            "payer",
+            "program_data",
+            "program",
+            "buffer",
+            "rent",
+            "clock",
+            "system_program",
+            "authority",
+        ],
+        Upgrade => &[
+            "program_data",
+            "program",
+            "buffer",
+            "spill",
+            "rent",
+            "clock",
+            "authority",
+        ],
+        SetAuthority | SetAuthorityChecked => &["account", "current_authority", "new_authority"],
+        Close => &["account", "recipient", "authority", "program"],
+        ExtendProgram { .. } => &["program_data", "program", "system_program", "payer"],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
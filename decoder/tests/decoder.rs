@@ -0,0 +1,340 @@
+use {
+    paladin_governance_decoder::{decode, Argument, DecodedAccount},
+    paladin_governance_program::{
+        instruction::{partial_update_governance, push_instruction, update_governance},
+        state::{get_treasury_address, ProposalAccountMeta},
+    },
+    solana_program::{
+        bpf_loader_upgradeable,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_instruction,
+    },
+    spl_token::instruction::AuthorityType,
+};
+
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+fn treasury() -> Pubkey {
+    get_treasury_address(&Pubkey::new_unique(), &paladin_governance_program::id())
+}
+
+fn argument(name: &str, value: impl ToString) -> Argument {
+    Argument {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn decode_system_transfer() {
+    let treasury = treasury();
+    let recipient = Pubkey::new_unique();
+    let instruction = system_instruction::transfer(&treasury, &recipient, 1_000);
+
+    let decoded = decode(&instruction, Some(&treasury));
+    assert_eq!(decoded.program, Some("System Program"));
+    assert_eq!(decoded.name.as_deref(), Some("Transfer"));
+    assert_eq!(decoded.arguments, vec![argument("lamports", 1_000)]);
+    assert_eq!(
+        decoded.accounts,
+        vec![
+            DecodedAccount {
+                name: "from".to_string(),
+                pubkey: treasury,
+                is_signer: true,
+                is_writable: true,
+                is_treasury: true,
+            },
+            DecodedAccount {
+                name: "to".to_string(),
+                pubkey: recipient,
+                is_signer: false,
+                is_writable: true,
+                is_treasury: false,
+            },
+        ]
+    );
+    assert!(decoded.touches_treasury);
+
+    // Without a treasury, nothing is flagged.
+    let decoded = decode(&instruction, None);
+    assert!(!decoded.touches_treasury);
+    assert!(decoded.accounts.iter().all(|account| !account.is_treasury));
+}
+
+#[test]
+fn decode_token_transfer_checked() {
+    let treasury = treasury();
+    let source = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    for program_id in [spl_token::id(), TOKEN_2022_PROGRAM_ID] {
+        let mut instruction = spl_token::instruction::transfer_checked(
+            &spl_token::id(),
+            &source,
+            &mint,
+            &destination,
+            &treasury,
+            &[],
+            500,
+            6,
+        )
+        .unwrap();
+        instruction.program_id = program_id;
+
+        let decoded = decode(&instruction, Some(&treasury));
+        assert_eq!(decoded.name.as_deref(), Some("TransferChecked"));
+        assert_eq!(
+            decoded.arguments,
+            vec![argument("amount", 500), argument("decimals", 6)]
+        );
+        assert_eq!(
+            decoded
+                .accounts
+                .iter()
+                .map(|account| account.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["source", "mint", "destination", "authority"]
+        );
+        assert!(decoded.accounts[3].is_treasury);
+        assert!(decoded.touches_treasury);
+    }
+}
+
+#[test]
+fn decode_token_set_authority_to_treasury() {
+    let treasury = treasury();
+    let mint = Pubkey::new_unique();
+    let instruction = spl_token::instruction::set_authority(
+        &spl_token::id(),
+        &mint,
+        Some(&treasury),
+        AuthorityType::MintTokens,
+        &Pubkey::new_unique(),
+        &[],
+    )
+    .unwrap();
+
+    // The treasury is only an argument, not an account.
+    let decoded = decode(&instruction, Some(&treasury));
+    assert_eq!(decoded.name.as_deref(), Some("SetAuthority"));
+    assert!(decoded.accounts.iter().all(|account| !account.is_treasury));
+    assert!(decoded.touches_treasury);
+}
+
+#[test]
+fn decode_update_governance() {
+    let governance = Pubkey::new_unique();
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let instruction = update_governance(&treasury, &governance, 0, 100, 1_000, 2_000, 200, 10);
+
+    let decoded = decode(&instruction, Some(&treasury));
+    assert_eq!(decoded.program, Some("Paladin Governance"));
+    assert_eq!(decoded.name.as_deref(), Some("UpdateGovernance"));
+    assert_eq!(
+        decoded.arguments,
+        vec![
+            argument("governance_id", 0),
+            argument("cooldown_period_seconds", 100),
+            argument("proposal_minimum_quorum", 1_000),
+            argument("proposal_pass_threshold", 2_000),
+            argument("voting_period_seconds", 200),
+            argument("stake_per_proposal", 10),
+        ]
+    );
+    assert_eq!(decoded.accounts[0].name, "treasury");
+    assert_eq!(decoded.accounts[1].name, "governance_config");
+    assert!(decoded.touches_treasury);
+}
+
+#[test]
+fn decode_partial_update_governance() {
+    let governance = Pubkey::new_unique();
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let pause_guardian = Pubkey::new_unique();
+    let instruction = partial_update_governance(
+        &treasury,
+        &governance,
+        0,
+        None,
+        Some(1_000),
+        None,
+        None,
+        None,
+        Some(pause_guardian),
+        Some(7),
+    );
+
+    let decoded = decode(&instruction, None);
+    assert_eq!(decoded.name.as_deref(), Some("PartialUpdateGovernance"));
+    assert_eq!(
+        decoded.arguments,
+        vec![
+            argument("governance_id", 0),
+            argument("cooldown_period_seconds", "None"),
+            argument("proposal_minimum_quorum", 1_000),
+            argument("proposal_pass_threshold", "None"),
+            argument("voting_period_seconds", "None"),
+            argument("stake_per_proposal", "None"),
+            argument("pause_guardian", pause_guardian),
+            argument("proposal_winning_margin", 7),
+        ]
+    );
+}
+
+#[test]
+fn decode_push_instruction() {
+    let treasury = treasury();
+    let recipient = Pubkey::new_unique();
+    let instruction = push_instruction(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &solana_program::system_program::id(),
+        vec![
+            ProposalAccountMeta {
+                pubkey: treasury,
+                is_signer: true,
+                is_writable: true,
+            },
+            ProposalAccountMeta {
+                pubkey: recipient,
+                is_signer: false,
+                is_writable: false,
+            },
+        ],
+        vec![0x02, 0x00],
+    );
+
+    let decoded = decode(&instruction, Some(&treasury));
+    assert_eq!(decoded.name.as_deref(), Some("PushInstruction"));
+    assert_eq!(
+        decoded.arguments,
+        vec![
+            argument(
+                "instruction_program_id",
+                solana_program::system_program::id()
+            ),
+            argument(
+                "instruction_account_metas",
+                format!("[{treasury} (signer, writable), {recipient}]")
+            ),
+            argument("instruction_data", "0200"),
+        ]
+    );
+    assert!(decoded.touches_treasury);
+}
+
+#[test]
+fn decode_seed_with_brackets_and_quotes() {
+    let treasury = treasury();
+    let base = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let seed = r#"a", b: [(c"#;
+    let instruction = system_instruction::create_account_with_seed(
+        &treasury,
+        &Pubkey::new_unique(),
+        &base,
+        seed,
+        1_000,
+        8,
+        &owner,
+    );
+
+    let decoded = decode(&instruction, Some(&treasury));
+    assert_eq!(decoded.name.as_deref(), Some("CreateAccountWithSeed"));
+    assert_eq!(
+        decoded.arguments,
+        vec![
+            argument("base", base),
+            argument("seed", seed),
+            argument("lamports", 1_000),
+            argument("space", 8),
+            argument("owner", owner),
+        ]
+    );
+    assert_eq!(
+        decoded
+            .accounts
+            .iter()
+            .map(|account| account.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["funding", "new_account", "base"]
+    );
+}
+
+#[test]
+fn decode_program_upgrade() {
+    let treasury = treasury();
+    let program = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let instruction = bpf_loader_upgradeable::upgrade(&program, &buffer, &treasury, &treasury);
+
+    let decoded = decode(&instruction, Some(&treasury));
+    assert_eq!(decoded.program, Some("BPF Upgradeable Loader"));
+    assert_eq!(decoded.name.as_deref(), Some("Upgrade"));
+    assert!(decoded.arguments.is_empty());
+    assert_eq!(
+        decoded
+            .accounts
+            .iter()
+            .filter(|account| account.is_treasury)
+            .map(|account| account.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["spill", "authority"]
+    );
+}
+
+#[test]
+fn decode_unknown_instruction() {
+    let program_id = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let instruction = Instruction::new_with_bytes(
+        program_id,
+        &[0x0a, 0x0b],
+        vec![AccountMeta::new(account, false)],
+    );
+
+    let decoded = decode(&instruction, Some(&treasury()));
+    assert_eq!(decoded.program, None);
+    assert_eq!(decoded.name, None);
+    assert_eq!(decoded.arguments, vec![argument("data", "0a0b")]);
+    assert_eq!(decoded.accounts[0].name, "account_0");
+    assert!(!decoded.touches_treasury);
+
+    assert_eq!(
+        decoded.to_string(),
+        format!(
+            "Unknown program {program_id}: unknown instruction\n  data: 0a0b\n  account_0: \
+             {account} (writable)\n"
+        )
+    );
+}
+
+#[test]
+fn decode_invalid_data_of_known_program() {
+    let instruction = Instruction::new_with_bytes(paladin_governance_program::id(), &[255], vec![]);
+
+    let decoded = decode(&instruction, None);
+    assert_eq!(decoded.program, Some("Paladin Governance"));
+    assert_eq!(decoded.name, None);
+    assert_eq!(decoded.arguments, vec![argument("data", "ff")]);
+}
+
+#[test]
+fn display() {
+    let treasury = treasury();
+    let recipient = Pubkey::new_unique();
+    let instruction = system_instruction::transfer(&treasury, &recipient, 1_000);
+
+    assert_eq!(
+        decode(&instruction, Some(&treasury)).to_string(),
+        format!(
+            "System Program: Transfer [touches treasury]\n  lamports: 1000\n  from: {treasury} \
+             (signer, writable, treasury)\n  to: {recipient} (writable)\n"
+        )
+    );
+}
@@ -0,0 +1,24 @@
+//! Unpacks arbitrary bytes as an instruction, and checks that packing it
+//! unpacks back to the same instruction.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target,
+    paladin_governance_program::instruction::PaladinGovernanceInstruction,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(instruction) = PaladinGovernanceInstruction::unpack(data) else {
+        return;
+    };
+
+    let packed = instruction.pack();
+    let unpacked = PaladinGovernanceInstruction::unpack(&packed).unwrap_or_else(|err| {
+        panic!("{instruction:?} packed to {packed:?}, which fails to unpack: {err}")
+    });
+    assert_eq!(unpacked, instruction);
+
+    // Packing is canonical, even when the input had trailing bytes.
+    assert_eq!(unpacked.pack(), packed);
+});
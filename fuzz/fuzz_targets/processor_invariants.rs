@@ -0,0 +1,20 @@
+//! Runs arbitrary sequences of instructions against the processor, and checks
+//! that proposals only move forward through their lifecycle, that open tallies
+//! match the votes cast and that lamports are conserved.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target,
+    paladin_governance_fuzz::world::{Scenario, World},
+};
+
+/// Longer sequences mostly repeat failing instructions.
+const MAX_ACTIONS: usize = 64;
+
+fuzz_target!(|scenario: Scenario| {
+    let mut world = World::new(&scenario.parameters);
+    for action in scenario.actions.iter().take(MAX_ACTIONS) {
+        world.apply(action);
+    }
+});
@@ -0,0 +1,4 @@
+//! Shared pieces of the Paladin Governance fuzz targets.
+
+pub mod runtime;
+pub mod world;
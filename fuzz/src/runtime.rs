@@ -0,0 +1,393 @@
+//! A minimal in-process stand-in for the Solana runtime.
+//!
+//! Accounts are serialized into the same input buffer the BPF loader builds,
+//! so `AccountInfo::realloc` and `AccountInfo::assign` behave as they do
+//! on-chain. Syscalls are stubbed: the clock and rent sysvars are served from
+//! here, logs are dropped, and cross-program invocations of the system
+//! program's `Allocate`, `Assign` and `Transfer` are emulated. Any other
+//! invoked program fails with `IncorrectProgramId`.
+
+use {
+    solana_program::{
+        account_info::AccountInfo,
+        clock::Clock,
+        entrypoint::{
+            deserialize, ProgramResult, BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE,
+            NON_DUP_MARKER, SUCCESS,
+        },
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+        pubkey::Pubkey,
+        rent::Rent,
+        system_instruction::SystemInstruction,
+        system_program,
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        sync::{LazyLock, Mutex, MutexGuard, Once},
+    },
+};
+
+/// An account as stored between instructions.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MockAccount {
+    pub key: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl MockAccount {
+    /// A rent-exempt account holding `data`.
+    pub fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner,
+            executable: false,
+        }
+    }
+}
+
+/// Why an instruction was not applied.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Failure {
+    /// The program returned an error.
+    Program(ProgramError),
+    /// The program succeeded, but made a change the runtime would reject.
+    Runtime(&'static str),
+}
+
+/// State shared with the syscall stubs.
+#[derive(Default)]
+struct Shared {
+    clock: Clock,
+    /// Lamports debited by system program transfers during the current
+    /// instruction.
+    system_debits: HashMap<Pubkey, u64>,
+    /// Accounts allocated or assigned by the system program during the
+    /// current instruction.
+    system_modified: HashSet<Pubkey>,
+}
+
+static SHARED: LazyLock<Mutex<Shared>> = LazyLock::new(Mutex::default);
+
+fn shared() -> MutexGuard<'static, Shared> {
+    SHARED.lock().unwrap()
+}
+
+/// Resets the clock to `unix_timestamp`, installing the syscall stubs on
+/// first use.
+pub fn reset(unix_timestamp: i64) {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(Stubs));
+    });
+
+    shared().clock = Clock {
+        unix_timestamp,
+        ..Clock::default()
+    };
+}
+
+/// Moves the clock forward by `seconds`.
+pub fn warp(seconds: u64) {
+    let mut shared = shared();
+    shared.clock.slot = shared.clock.slot.saturating_add(1);
+    shared.clock.unix_timestamp = shared.clock.unix_timestamp.saturating_add(seconds as i64);
+}
+
+/// Runs the governance program's `process` on `instruction`, applying its
+/// changes to `accounts` only if it succeeds and the runtime would accept
+/// them, as a single-instruction transaction would.
+///
+/// Every account the instruction references must be in `accounts`. The
+/// signer and writable flags of duplicate references are merged, as the
+/// runtime does.
+///
+/// Panics if a successful instruction does not conserve lamports.
+pub fn execute(accounts: &mut [MockAccount], instruction: &Instruction) -> Result<(), Failure> {
+    let program_id = paladin_governance_program::id();
+
+    // Deduplicate the referenced accounts, merging their privileges. Each
+    // duplicate reference points to the position of the first one.
+    let mut unique: Vec<(usize, bool, bool)> = vec![];
+    let mut first_positions: Vec<usize> = vec![];
+    let mut positions = Vec::with_capacity(instruction.accounts.len());
+    for (meta_position, meta) in instruction.accounts.iter().enumerate() {
+        let index = accounts
+            .iter()
+            .position(|account| account.key == meta.pubkey)
+            .unwrap_or_else(|| panic!("instruction references unknown account {}", meta.pubkey));
+        match unique
+            .iter()
+            .position(|(existing, _, _)| *existing == index)
+        {
+            Some(position) => {
+                unique[position].1 |= meta.is_signer;
+                unique[position].2 |= meta.is_writable;
+                positions.push(Some(first_positions[position]));
+            }
+            None => {
+                unique.push((index, meta.is_signer, meta.is_writable));
+                first_positions.push(meta_position);
+                positions.push(None);
+            }
+        }
+    }
+
+    let mut input = serialize(
+        accounts,
+        &unique,
+        &positions,
+        &instruction.data,
+        &program_id,
+    );
+    {
+        let mut shared = shared();
+        shared.system_debits.clear();
+        shared.system_modified.clear();
+    }
+
+    let (result, post) = {
+        // SAFETY: `input` is laid out as the loader's input buffer, and
+        // outlives the account infos pointing into it.
+        let (program_id, account_infos, data) =
+            unsafe { deserialize(input.as_mut_ptr() as *mut u8) };
+        let result =
+            paladin_governance_program::processor::process(program_id, &account_infos, data);
+
+        // The first reference to each account holds its state.
+        let post: Vec<MockAccount> = positions
+            .iter()
+            .zip(&account_infos)
+            .filter(|(position, _)| position.is_none())
+            .map(|(_, info)| MockAccount {
+                key: *info.key,
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+                executable: info.executable,
+            })
+            .collect();
+        (result, post)
+    };
+    result.map_err(Failure::Program)?;
+
+    let pre_lamports: u128 = unique
+        .iter()
+        .map(|(index, _, _)| accounts[*index].lamports as u128)
+        .sum();
+    let post_lamports: u128 = post.iter().map(|account| account.lamports as u128).sum();
+    assert_eq!(
+        pre_lamports, post_lamports,
+        "{instruction:?} did not conserve lamports"
+    );
+
+    {
+        let shared = shared();
+        for ((index, _, is_writable), post) in unique.iter().zip(&post) {
+            check_changes(&program_id, &accounts[*index], post, *is_writable, &shared)
+                .map_err(Failure::Runtime)?;
+        }
+    }
+
+    for ((index, _, _), post) in unique.iter().zip(post) {
+        accounts[*index] = post;
+    }
+    Ok(())
+}
+
+/// Serializes the referenced accounts and instruction data as the BPF loader
+/// does, into an 8-byte aligned buffer.
+fn serialize(
+    accounts: &[MockAccount],
+    unique: &[(usize, bool, bool)],
+    positions: &[Option<usize>],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> Vec<u64> {
+    let mut bytes = vec![];
+    bytes.extend_from_slice(&(positions.len() as u64).to_le_bytes());
+
+    let mut next_unique = 0;
+    for position in positions {
+        if let Some(position) = position {
+            bytes.push(*position as u8);
+            bytes.extend_from_slice(&[0; 7]);
+            continue;
+        }
+        let (index, is_signer, is_writable) = unique[next_unique];
+        next_unique += 1;
+        let account = &accounts[index];
+
+        bytes.push(NON_DUP_MARKER);
+        bytes.push(is_signer as u8);
+        bytes.push(is_writable as u8);
+        bytes.push(account.executable as u8);
+        bytes.extend_from_slice(&[0; 4]); // Original data length.
+        bytes.extend_from_slice(account.key.as_ref());
+        bytes.extend_from_slice(account.owner.as_ref());
+        bytes.extend_from_slice(&account.lamports.to_le_bytes());
+        bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&account.data);
+        bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        bytes.resize(bytes.len().next_multiple_of(BPF_ALIGN_OF_U128), 0);
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes()); // Rent epoch.
+    }
+
+    bytes.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(data);
+    bytes.extend_from_slice(program_id.as_ref());
+
+    let mut input = vec![0u64; bytes.len().div_ceil(8)];
+    bytemuck::cast_slice_mut::<u64, u8>(&mut input)[..bytes.len()].copy_from_slice(&bytes);
+    input
+}
+
+/// The runtime's checks on the changes an instruction made to an account.
+fn check_changes(
+    program_id: &Pubkey,
+    pre: &MockAccount,
+    post: &MockAccount,
+    is_writable: bool,
+    shared: &Shared,
+) -> Result<(), &'static str> {
+    if pre == post {
+        return Ok(());
+    }
+    if !is_writable {
+        return Err("modified a read-only account");
+    }
+    if pre.executable {
+        return Err("modified an executable account");
+    }
+
+    if pre.owner == *program_id {
+        if post.owner != pre.owner && !post.data.iter().all(|byte| *byte == 0) {
+            return Err("reassigned an account holding data");
+        }
+        return Ok(());
+    }
+
+    // Only the system program may change accounts the program does not own.
+    let modified = pre.data != post.data || pre.owner != post.owner;
+    if modified && !shared.system_modified.contains(&pre.key) {
+        return Err("modified the data of an account it does not own");
+    }
+    let debited = pre.lamports.saturating_sub(post.lamports);
+    if debited > shared.system_debits.get(&pre.key).copied().unwrap_or(0) {
+        return Err("debited an account it does not own");
+    }
+    Ok(())
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = shared().clock.clone();
+        // SAFETY: `Clock::get` passes a pointer to a `Clock`.
+        unsafe { std::ptr::write(var_addr as *mut Clock, clock) };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // SAFETY: `Rent::get` passes a pointer to a `Rent`.
+        unsafe { std::ptr::write(var_addr as *mut Rent, Rent::default()) };
+        SUCCESS
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        if instruction.program_id != system_program::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        invoke_system(instruction, account_infos, signers_seeds)
+    }
+}
+
+fn invoke_system(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    // The account at `position`, checking it signed if required, either in
+    // the transaction or through the governance program's seeds.
+    let account = |position: usize| -> Result<&AccountInfo, ProgramError> {
+        let meta = instruction
+            .accounts
+            .get(position)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let info = account_infos
+            .iter()
+            .find(|info| *info.key == meta.pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let signed = info.is_signer
+            || signers_seeds.iter().any(|seeds| {
+                Pubkey::create_program_address(seeds, &paladin_governance_program::id())
+                    .is_ok_and(|address| address == meta.pubkey)
+            });
+        if meta.is_signer && !signed {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Ok(info)
+    };
+
+    let system_instruction: SystemInstruction = bincode::deserialize(&instruction.data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    match system_instruction {
+        SystemInstruction::Allocate { space } => {
+            let info = account(0)?;
+            if info.owner != &system_program::id() || info.data_len() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            info.realloc(space as usize, true)?;
+            shared().system_modified.insert(*info.key);
+        }
+        SystemInstruction::Assign { owner } => {
+            let info = account(0)?;
+            if info.owner != &owner {
+                if info.owner != &system_program::id() {
+                    return Err(ProgramError::InvalidAccountOwner);
+                }
+                info.assign(&owner);
+                shared().system_modified.insert(*info.key);
+            }
+        }
+        SystemInstruction::Transfer { lamports } => {
+            let from = account(0)?;
+            let to = account(1)?;
+            if from.owner != &system_program::id() || from.data_len() != 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let from_lamports = from
+                .lamports()
+                .checked_sub(lamports)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            **from.try_borrow_mut_lamports()? = from_lamports;
+            let to_lamports = to
+                .lamports()
+                .checked_add(lamports)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            **to.try_borrow_mut_lamports()? = to_lamports;
+
+            let mut shared = shared();
+            let debits = shared.system_debits.entry(*from.key).or_default();
+            *debits = debits.saturating_add(lamports);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    Ok(())
+}
@@ -0,0 +1,733 @@
+//! A governance config with a few stakers and proposal accounts, driven by
+//! fuzzed actions, and the invariants checked after each of them.
+
+use {
+    crate::runtime::{self, MockAccount},
+    arbitrary::Arbitrary,
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        instruction::{
+            begin_voting, cancel_proposal, create_proposal, create_signal_proposal,
+            delete_proposal, delete_vote, finish_voting, initialize_author, process_instruction,
+            push_instruction, push_option, push_option_instruction, push_prerequisite, set_paused,
+            switch_vote, switch_vote_option, vote, vote_option,
+        },
+        outcome::THRESHOLD_SCALING_FACTOR,
+        state::{
+            get_governance_address, get_proposal_author_address, get_proposal_transaction_address,
+            get_proposal_vote_address, get_treasury_address, Author, GovernanceConfig, Proposal,
+            ProposalAccountMeta, ProposalStatus, ProposalTransaction, ProposalVote,
+            ProposalVoteElection, MAX_PROPOSAL_OPTIONS,
+        },
+    },
+    paladin_governance_program_client::sdk,
+    paladin_stake_program::state::{
+        find_validator_stake_pda, Config as StakeConfig, Delegation, ValidatorStake,
+    },
+    solana_program::{
+        bpf_loader,
+        instruction::{AccountMeta, Instruction},
+        native_loader,
+        pubkey::Pubkey,
+        rent::Rent,
+        system_instruction, system_program,
+    },
+    spl_discriminator::SplDiscriminate,
+};
+
+pub const STAKERS: usize = 3;
+pub const PROPOSALS: usize = 3;
+
+/// Room for the instructions pushed to a proposal transaction.
+const PROPOSAL_TRANSACTION_FUNDING_LEN: usize = 10_240;
+
+/// 2024-01-01 00:00 UTC.
+const START_TIMESTAMP: i64 = 1_704_067_200;
+
+/// The fuzzed input: the world's parameters and the actions taken in it.
+#[derive(Arbitrary, Debug)]
+pub struct Scenario {
+    pub parameters: Parameters,
+    pub actions: Vec<Action>,
+}
+
+/// Governance parameters and balances, clamped into valid ranges.
+#[derive(Arbitrary, Debug)]
+pub struct Parameters {
+    pub cooldown_period_seconds: u16,
+    pub voting_period_seconds: u16,
+    pub proposal_minimum_quorum: u32,
+    pub proposal_pass_threshold: u32,
+    pub stakes: [u32; STAKERS],
+    /// Stake beyond the stakers', which counts toward the total stake.
+    pub other_stake: u32,
+    pub treasury_lamports: u32,
+}
+
+/// An instruction sent to the program, or the clock moving forward.
+///
+/// Staker and proposal indices wrap around.
+#[derive(Arbitrary, Debug)]
+pub enum Action {
+    InitializeAuthor {
+        staker: u8,
+    },
+    CreateProposal {
+        staker: u8,
+        proposal: u8,
+    },
+    CreateSignalProposal {
+        staker: u8,
+        proposal: u8,
+    },
+    PushTransfer {
+        staker: u8,
+        proposal: u8,
+        lamports: u32,
+    },
+    PushOption {
+        staker: u8,
+        proposal: u8,
+    },
+    PushOptionTransfer {
+        staker: u8,
+        proposal: u8,
+        option_index: u8,
+        lamports: u32,
+    },
+    PushPrerequisite {
+        staker: u8,
+        proposal: u8,
+        prerequisite: u8,
+    },
+    BeginVoting {
+        staker: u8,
+        proposal: u8,
+    },
+    Vote {
+        staker: u8,
+        proposal: u8,
+        election: bool,
+    },
+    VoteOption {
+        staker: u8,
+        proposal: u8,
+        option_index: u8,
+    },
+    SwitchVote {
+        staker: u8,
+        proposal: u8,
+        election: bool,
+    },
+    SwitchVoteOption {
+        staker: u8,
+        proposal: u8,
+        option_index: u8,
+    },
+    DeleteVote {
+        staker: u8,
+        proposal: u8,
+    },
+    FinishVoting {
+        proposal: u8,
+    },
+    ProcessInstruction {
+        proposal: u8,
+        index: u8,
+    },
+    CancelProposal {
+        staker: u8,
+        proposal: u8,
+    },
+    DeleteProposal {
+        staker: u8,
+        proposal: u8,
+    },
+    SetPaused {
+        paused: bool,
+    },
+    Warp {
+        seconds: u32,
+    },
+    /// Arbitrary instruction data with an arbitrary ordering of the world's
+    /// accounts.
+    Raw {
+        data: Vec<u8>,
+        accounts: Vec<RawAccount>,
+    },
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct RawAccount {
+    pub index: u8,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Where a proposal is in its lifecycle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Stage {
+    Uncreated,
+    Created(ProposalStatus),
+    Deleted,
+}
+
+impl Stage {
+    fn of(account: &MockAccount) -> Self {
+        if account.data.is_empty() {
+            Self::Deleted
+        } else if account.data.len() == Proposal::LEN
+            && account.data[..8] == *Proposal::SPL_DISCRIMINATOR_SLICE
+        {
+            Self::Created(bytemuck::pod_read_unaligned::<Proposal>(&account.data).status)
+        } else {
+            Self::Uncreated
+        }
+    }
+
+    /// Whether a proposal may move from this stage to `next`.
+    fn may_become(self, next: Self) -> bool {
+        use ProposalStatus::*;
+        match (self, next) {
+            _ if self == next => true,
+            (Self::Uncreated, Self::Created(Draft))
+            | (Self::Created(Draft), Self::Created(Voting))
+            | (Self::Created(Voting), Self::Created(Accepted | Rejected | Processed | Cancelled))
+            | (Self::Created(Accepted), Self::Created(Processed)) => true,
+            (Self::Created(status), Self::Deleted) => !status.is_active(),
+            _ => false,
+        }
+    }
+}
+
+struct Staker {
+    authority: Pubkey,
+    stake: Pubkey,
+}
+
+pub struct World {
+    accounts: Vec<MockAccount>,
+    governance: Pubkey,
+    stake_config: Pubkey,
+    treasury: Pubkey,
+    stakers: Vec<Staker>,
+    proposals: Vec<Pubkey>,
+    stages: Vec<Stage>,
+    total_lamports: u128,
+}
+
+impl World {
+    pub fn new(parameters: &Parameters) -> Self {
+        runtime::reset(START_TIMESTAMP);
+
+        let program_id = paladin_governance_program::id();
+        let key = |tag: u8, index: usize| {
+            let mut bytes = [0; 32];
+            bytes[0] = tag;
+            bytes[1] = index as u8;
+            Pubkey::new_from_array(bytes)
+        };
+        let mut accounts = vec![MockAccount {
+            key: system_program::id(),
+            lamports: 1,
+            owner: native_loader::id(),
+            executable: true,
+            ..MockAccount::default()
+        }];
+
+        // Stakers, with their stakes and author accounts.
+        let stake_config = key(1, 0);
+        let mut stakers = Vec::with_capacity(STAKERS);
+        for (index, amount) in parameters.stakes.iter().enumerate() {
+            let authority = key(2, index);
+            let validator_vote = key(3, index);
+            let stake = find_validator_stake_pda(
+                &validator_vote,
+                &stake_config,
+                &paladin_stake_program::id(),
+            )
+            .0;
+            let state = ValidatorStake {
+                _discriminator: ValidatorStake::SPL_DISCRIMINATOR.into(),
+                delegation: Delegation {
+                    staked_amount: *amount as u64,
+                    effective_amount: *amount as u64,
+                    authority,
+                    validator_vote,
+                    ..Default::default()
+                },
+                total_staked_lamports_amount: 0,
+                total_staked_lamports_amount_min: 0,
+            };
+            accounts.push(MockAccount::new(authority, system_program::id(), vec![]));
+            accounts.push(MockAccount::new(
+                stake,
+                paladin_stake_program::id(),
+                bytemuck::bytes_of(&state).to_vec(),
+            ));
+            accounts.push(MockAccount {
+                lamports: Rent::default().minimum_balance(Author::LEN),
+                ..MockAccount::new(
+                    get_proposal_author_address(&authority, &program_id),
+                    system_program::id(),
+                    vec![],
+                )
+            });
+            stakers.push(Staker { authority, stake });
+        }
+
+        let total_stake = parameters
+            .stakes
+            .iter()
+            .map(|amount| *amount as u64)
+            .sum::<u64>()
+            .saturating_add(parameters.other_stake as u64);
+        let stake_config_state = StakeConfig {
+            discriminator: StakeConfig::SPL_DISCRIMINATOR.into(),
+            authority: Some(key(4, 0)).try_into().unwrap(),
+            slash_authority: Some(key(4, 1)).try_into().unwrap(),
+            vault: key(4, 2),
+            cooldown_time_seconds: 0,
+            max_deactivation_basis_points: 0,
+            sync_rewards_lamports: 0,
+            vault_authority_bump: 0,
+            lamports_last: 0,
+            token_amount_effective: total_stake,
+            accumulated_stake_rewards_per_token: 0.into(),
+            duna_document_hash: [0; 32],
+            _padding: [0; 5],
+        };
+        accounts.push(MockAccount::new(
+            stake_config,
+            paladin_stake_program::id(),
+            bytemuck::bytes_of(&stake_config_state).to_vec(),
+        ));
+
+        // The governance config, paused by the first staker.
+        let governance = get_governance_address(&stake_config, &0, &program_id);
+        let voting_period_seconds = (parameters.voting_period_seconds as u64).max(1);
+        let governance_config = GovernanceConfig {
+            cooldown_period_seconds: 1 + parameters.cooldown_period_seconds as u64
+                % voting_period_seconds,
+            proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR / 1000
+                + parameters.proposal_minimum_quorum
+                    % (THRESHOLD_SCALING_FACTOR - THRESHOLD_SCALING_FACTOR / 1000),
+            proposal_pass_threshold: THRESHOLD_SCALING_FACTOR / 10
+                + parameters.proposal_pass_threshold
+                    % (THRESHOLD_SCALING_FACTOR - THRESHOLD_SCALING_FACTOR / 10),
+            stake_config_address: stake_config,
+            voting_period_seconds,
+            governance_config: governance,
+            pause_guardian: stakers[0].authority,
+            ..GovernanceConfig::default()
+        };
+        governance_config.validate().unwrap();
+        accounts.push(MockAccount::new(
+            governance,
+            program_id,
+            bytemuck::bytes_of(&governance_config).to_vec(),
+        ));
+
+        let treasury = get_treasury_address(&governance, &program_id);
+        accounts.push(MockAccount {
+            lamports: parameters.treasury_lamports as u64,
+            ..MockAccount::new(treasury, system_program::id(), vec![])
+        });
+
+        // Proposal accounts, as allocated by their authors before creating
+        // proposals, and the accounts funded for their transactions and
+        // votes.
+        let mut proposals = Vec::with_capacity(PROPOSALS);
+        for index in 0..PROPOSALS {
+            let proposal = key(5, index);
+            accounts.push(MockAccount::new(
+                proposal,
+                program_id,
+                vec![0; Proposal::LEN],
+            ));
+            accounts.push(MockAccount {
+                lamports: Rent::default().minimum_balance(PROPOSAL_TRANSACTION_FUNDING_LEN),
+                ..MockAccount::new(
+                    get_proposal_transaction_address(&proposal, &program_id),
+                    system_program::id(),
+                    vec![],
+                )
+            });
+            for staker in &stakers {
+                accounts.push(MockAccount {
+                    lamports: Rent::default().minimum_balance(std::mem::size_of::<ProposalVote>()),
+                    ..MockAccount::new(
+                        get_proposal_vote_address(&staker.stake, &proposal, &program_id),
+                        system_program::id(),
+                        vec![],
+                    )
+                });
+            }
+            proposals.push(proposal);
+        }
+
+        // An executable account, so the program is handed one.
+        accounts.push(MockAccount {
+            key: key(6, 0),
+            lamports: 1,
+            owner: bpf_loader::id(),
+            executable: true,
+            ..MockAccount::default()
+        });
+
+        let total_lamports = accounts
+            .iter()
+            .map(|account| account.lamports as u128)
+            .sum();
+        Self {
+            accounts,
+            governance,
+            stake_config,
+            treasury,
+            stakers,
+            proposals,
+            stages: vec![Stage::Uncreated; PROPOSALS],
+            total_lamports,
+        }
+    }
+
+    /// Takes `action`, then checks the invariants.
+    pub fn apply(&mut self, action: &Action) {
+        if let Action::Warp { seconds } = action {
+            runtime::warp(*seconds as u64);
+            return;
+        }
+        let Some(instruction) = self.instruction(action) else {
+            return;
+        };
+        // Instructions pushed by `Raw` actions may reference accounts
+        // outside the world.
+        if !instruction.accounts.iter().all(|meta| {
+            self.accounts
+                .iter()
+                .any(|account| account.key == meta.pubkey)
+        }) {
+            return;
+        }
+
+        if runtime::execute(&mut self.accounts, &instruction).is_ok() {
+            self.check_invariants(&instruction);
+        }
+    }
+
+    fn instruction(&self, action: &Action) -> Option<Instruction> {
+        let program_id = paladin_governance_program::id();
+        let staker = |index: &u8| &self.stakers[*index as usize % STAKERS];
+        let proposal = |index: &u8| &self.proposals[*index as usize % PROPOSALS];
+        let proposal_transaction =
+            |index: &u8| get_proposal_transaction_address(proposal(index), &program_id);
+        let proposal_vote = |staker_index: &u8, proposal_index: &u8| {
+            get_proposal_vote_address(
+                &staker(staker_index).stake,
+                proposal(proposal_index),
+                &program_id,
+            )
+        };
+        let transfer = |staker_index: &u8, lamports: &u32| {
+            system_instruction::transfer(
+                &self.treasury,
+                &staker(staker_index).authority,
+                *lamports as u64,
+            )
+        };
+
+        Some(match action {
+            Action::InitializeAuthor { staker: s } => initialize_author(staker(s).authority),
+            Action::CreateProposal {
+                staker: s,
+                proposal: p,
+            } => create_proposal(
+                &staker(s).authority,
+                &staker(s).stake,
+                proposal(p),
+                &proposal_transaction(p),
+                &self.governance,
+            ),
+            Action::CreateSignalProposal {
+                staker: s,
+                proposal: p,
+            } => create_signal_proposal(
+                &staker(s).authority,
+                &staker(s).stake,
+                proposal(p),
+                &self.governance,
+            ),
+            Action::PushTransfer {
+                staker: s,
+                proposal: p,
+                lamports,
+            } => {
+                let transfer = transfer(s, lamports);
+                push_instruction(
+                    &staker(s).authority,
+                    proposal(p),
+                    &proposal_transaction(p),
+                    &transfer.program_id,
+                    transfer
+                        .accounts
+                        .iter()
+                        .map(ProposalAccountMeta::from)
+                        .collect(),
+                    transfer.data,
+                )
+            }
+            Action::PushOption {
+                staker: s,
+                proposal: p,
+            } => push_option(&staker(s).authority, proposal(p), &proposal_transaction(p)),
+            Action::PushOptionTransfer {
+                staker: s,
+                proposal: p,
+                option_index,
+                lamports,
+            } => {
+                let transfer = transfer(s, lamports);
+                push_option_instruction(
+                    &staker(s).authority,
+                    proposal(p),
+                    &proposal_transaction(p),
+                    *option_index,
+                    &transfer.program_id,
+                    transfer
+                        .accounts
+                        .iter()
+                        .map(ProposalAccountMeta::from)
+                        .collect(),
+                    transfer.data,
+                )
+            }
+            Action::PushPrerequisite {
+                staker: s,
+                proposal: p,
+                prerequisite,
+            } => push_prerequisite(
+                &staker(s).authority,
+                proposal(p),
+                &proposal_transaction(p),
+                proposal(prerequisite),
+            ),
+            Action::BeginVoting {
+                staker: s,
+                proposal: p,
+            } => begin_voting(&staker(s).authority, proposal(p), &self.governance),
+            Action::Vote {
+                staker: s,
+                proposal: p,
+                election,
+            } => vote(
+                &staker(s).authority,
+                &staker(s).stake,
+                &self.stake_config,
+                &proposal_vote(s, p),
+                proposal(p),
+                &self.governance,
+                election_of(*election),
+            ),
+            Action::VoteOption {
+                staker: s,
+                proposal: p,
+                option_index,
+            } => vote_option(
+                &staker(s).authority,
+                &staker(s).stake,
+                &self.stake_config,
+                &proposal_vote(s, p),
+                proposal(p),
+                &self.governance,
+                *option_index,
+            ),
+            Action::SwitchVote {
+                staker: s,
+                proposal: p,
+                election,
+            } => switch_vote(
+                &staker(s).authority,
+                &staker(s).stake,
+                &self.stake_config,
+                &proposal_vote(s, p),
+                proposal(p),
+                &self.governance,
+                election_of(*election),
+            ),
+            Action::SwitchVoteOption {
+                staker: s,
+                proposal: p,
+                option_index,
+            } => switch_vote_option(
+                &staker(s).authority,
+                &staker(s).stake,
+                &self.stake_config,
+                &proposal_vote(s, p),
+                proposal(p),
+                &self.governance,
+                *option_index,
+            ),
+            Action::DeleteVote {
+                staker: s,
+                proposal: p,
+            } => delete_vote(*proposal(p), proposal_vote(s, p), staker(s).authority),
+            Action::FinishVoting { proposal: p } => {
+                finish_voting(self.stake_config, proposal(p), &self.governance)
+            }
+            Action::ProcessInstruction { proposal: p, index } => {
+                self.process_instruction(proposal(p), *index)?
+            }
+            Action::CancelProposal {
+                staker: s,
+                proposal: p,
+            } => cancel_proposal(&staker(s).authority, proposal(p)),
+            Action::DeleteProposal {
+                staker: s,
+                proposal: p,
+            } => delete_proposal(staker(s).authority, *proposal(p)),
+            Action::SetPaused { paused } => {
+                set_paused(&self.stakers[0].authority, &self.governance, *paused)
+            }
+            Action::Warp { .. } => return None,
+            Action::Raw { data, accounts } => Instruction {
+                program_id,
+                accounts: accounts
+                    .iter()
+                    .map(|raw| {
+                        let account = &self.accounts[raw.index as usize % self.accounts.len()];
+                        AccountMeta {
+                            pubkey: account.key,
+                            // Only accounts with a keypair can sign a
+                            // transaction.
+                            is_signer: raw.is_signer && self.has_keypair(&account.key),
+                            is_writable: raw.is_writable,
+                        }
+                    })
+                    .collect(),
+                data: data.clone(),
+            },
+        })
+    }
+
+    /// Executes the instruction at `index` of the proposal's outcome, as the
+    /// CLI does.
+    fn process_instruction(&self, proposal: &Pubkey, index: u8) -> Option<Instruction> {
+        let program_id = paladin_governance_program::id();
+        let proposal_state = bytemuck::pod_read_unaligned::<Proposal>(
+            self.account(proposal)?.data.get(..Proposal::LEN)?,
+        );
+        let proposal_transaction = get_proposal_transaction_address(proposal, &program_id);
+        let mut proposal_transaction_state =
+            ProposalTransaction::try_from_slice(&self.account(&proposal_transaction)?.data).ok()?;
+        let prerequisites = proposal_transaction_state.prerequisites.clone();
+        let instructions = proposal_transaction_state
+            .outcome_instructions_mut(&proposal_state)
+            .ok()?;
+        let index = index as usize % instructions.len().max(1);
+        let instruction = Instruction::from(instructions.get(index)?);
+
+        Some(process_instruction(
+            proposal,
+            &proposal_transaction,
+            &self.governance,
+            &prerequisites,
+            &sdk::instruction_account_metas(&self.treasury, &instruction),
+            index as u32,
+        ))
+    }
+
+    fn account(&self, address: &Pubkey) -> Option<&MockAccount> {
+        self.accounts.iter().find(|account| account.key == *address)
+    }
+
+    fn has_keypair(&self, address: &Pubkey) -> bool {
+        self.stakers
+            .iter()
+            .any(|staker| staker.authority == *address)
+            || self.proposals.contains(address)
+    }
+
+    fn check_invariants(&mut self, instruction: &Instruction) {
+        // Lamports are only moved between accounts.
+        let total_lamports: u128 = self
+            .accounts
+            .iter()
+            .map(|account| account.lamports as u128)
+            .sum();
+        assert_eq!(
+            total_lamports, self.total_lamports,
+            "{instruction:?} did not conserve lamports"
+        );
+
+        let votes: Vec<ProposalVote> = self
+            .accounts
+            .iter()
+            .filter(|account| {
+                account.owner == paladin_governance_program::id()
+                    && account.data.len() == std::mem::size_of::<ProposalVote>()
+            })
+            .map(|account| bytemuck::pod_read_unaligned(&account.data))
+            .collect();
+
+        for (index, address) in self.proposals.iter().enumerate() {
+            let account = self.account(address).unwrap();
+
+            // Statuses only move forward.
+            let stage = Stage::of(account);
+            assert!(
+                self.stages[index].may_become(stage),
+                "{instruction:?} moved proposal {address} from {:?} to {stage:?}",
+                self.stages[index]
+            );
+            self.stages[index] = stage;
+
+            // Open tallies equal the stake of the votes cast. Votes can only
+            // be deleted once a proposal is no longer active.
+            if !matches!(
+                stage,
+                Stage::Created(ProposalStatus::Voting | ProposalStatus::Accepted)
+            ) {
+                continue;
+            }
+            let proposal = bytemuck::pod_read_unaligned::<Proposal>(&account.data);
+            let votes = votes.iter().filter(|vote| vote.proposal == *address);
+            let stake = |election: ProposalVoteElection| -> u64 {
+                votes
+                    .clone()
+                    .filter(|vote| vote.election == election)
+                    .map(|vote| vote.stake)
+                    .sum()
+            };
+            assert_eq!(
+                (proposal.stake_for, proposal.stake_against),
+                (
+                    stake(ProposalVoteElection::For),
+                    stake(ProposalVoteElection::Against)
+                ),
+                "{instruction:?} left proposal {address} with a tally that differs from its votes"
+            );
+            if proposal.is_multiple_choice() {
+                for option_index in 0..MAX_PROPOSAL_OPTIONS {
+                    let option_stake: u64 = votes
+                        .clone()
+                        .filter(|vote| vote.option_index as usize == option_index)
+                        .map(|vote| vote.stake)
+                        .sum();
+                    assert_eq!(
+                        proposal.option_stake[option_index], option_stake,
+                        "{instruction:?} left option {option_index} of proposal {address} with a \
+                         tally that differs from its votes"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn election_of(election: bool) -> ProposalVoteElection {
+    if election {
+        ProposalVoteElection::For
+    } else {
+        ProposalVoteElection::Against
+    }
+}
@@ -0,0 +1,30 @@
+//! Indexer error types.
+
+use thiserror::Error;
+
+/// Errors that prevent a snapshot from being indexed.
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    /// The feed could not be read.
+    #[error("Failed to read feed: {0}")]
+    Io(#[from] std::io::Error),
+    /// A feed line is not a valid snapshot.
+    #[error("Invalid snapshot: {0}")]
+    Json(#[from] serde_json::Error),
+    /// An account's data is not valid base64.
+    #[error("Invalid account data: {0}")]
+    Base64(#[from] base64::DecodeError),
+    /// An account's data uses an encoding other than `base64`.
+    #[error("Unsupported account data encoding: {0}")]
+    UnsupportedEncoding(String),
+    /// A snapshot is not newer than the last one indexed.
+    #[error("Snapshot at slot {slot} is not newer than the last indexed slot {last_slot}")]
+    OutOfOrder { slot: u64, last_slot: u64 },
+    /// The database could not be read or written.
+    #[error("Database error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    /// The banks client failed to read an account.
+    #[cfg(feature = "banks")]
+    #[error("Banks client error: {0}")]
+    Banks(#[from] solana_program_test::BanksClientError),
+}
@@ -0,0 +1,117 @@
+//! Off-chain indexer of Paladin Governance accounts.
+//!
+//! The chain only keeps the current state of each account. The indexer
+//! ingests snapshots of every account the program owns, decodes them with the
+//! client types, and keeps a history in SQLite: who voted when, how tallies
+//! moved, and when each proposal changed status.
+//!
+//! Snapshots are read from a JSON-lines feed, one snapshot per line, or from a
+//! `solana-program-test` banks client with the `banks` feature. Each snapshot
+//! is compared to the previous one, and the resulting [`Transition`]s are
+//! recorded alongside normalized tables of configs, proposals, instructions,
+//! votes and authors.
+
+mod error;
+mod snapshot;
+mod state;
+mod store;
+mod transition;
+
+pub use {
+    error::IndexerError,
+    snapshot::{read_feed, Snapshot},
+    state::GovernanceState,
+    transition::{detect_transitions, Transition},
+};
+use {
+    rusqlite::{types::Type, Connection},
+    solana_sdk::pubkey::Pubkey,
+    std::path::Path,
+};
+
+/// Indexes snapshots into a SQLite database.
+pub struct Indexer {
+    connection: Connection,
+    last_slot: Option<u64>,
+    state: GovernanceState,
+}
+
+impl Indexer {
+    /// Opens the database at `path`, creating it if needed, and resumes from
+    /// its last snapshot.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, IndexerError> {
+        Self::new(Connection::open(path)?)
+    }
+
+    /// Opens an empty in-memory database.
+    pub fn open_in_memory() -> Result<Self, IndexerError> {
+        Self::new(Connection::open_in_memory()?)
+    }
+
+    fn new(connection: Connection) -> Result<Self, IndexerError> {
+        connection.execute_batch(store::SCHEMA)?;
+        let last_slot =
+            connection.query_row("SELECT MAX(slot) FROM snapshots", [], |row| row.get(0))?;
+
+        let accounts = connection
+            .prepare("SELECT address, data FROM accounts")?
+            .query_map([], |row| {
+                let address = row.get::<_, String>(0)?.parse::<Pubkey>().map_err(|err| {
+                    rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(err))
+                })?;
+                Ok((address, row.get::<_, Vec<u8>>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        let state = GovernanceState::decode(
+            accounts
+                .iter()
+                .map(|(address, data)| (*address, data.as_slice())),
+        );
+
+        Ok(Self {
+            connection,
+            last_slot,
+            state,
+        })
+    }
+
+    /// The database, for queries.
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// The slot of the last snapshot indexed.
+    pub fn last_slot(&self) -> Option<u64> {
+        self.last_slot
+    }
+
+    /// The decoded accounts of the last snapshot indexed.
+    pub fn state(&self) -> &GovernanceState {
+        &self.state
+    }
+
+    /// Indexes a snapshot, returning its transitions from the last snapshot.
+    ///
+    /// Snapshots must be ingested in slot order.
+    pub fn ingest(&mut self, snapshot: &Snapshot) -> Result<Vec<Transition>, IndexerError> {
+        if let Some(last_slot) = self.last_slot {
+            if snapshot.slot <= last_slot {
+                return Err(IndexerError::OutOfOrder {
+                    slot: snapshot.slot,
+                    last_slot,
+                });
+            }
+        }
+
+        let state = GovernanceState::from_snapshot(snapshot);
+        let transitions = detect_transitions(&self.state, &state);
+
+        let transaction = self.connection.transaction()?;
+        store::write_snapshot(&transaction, snapshot, &self.state, &state, &transitions)?;
+        transaction.commit()?;
+
+        self.last_slot = Some(snapshot.slot);
+        self.state = state;
+        Ok(transitions)
+    }
+}
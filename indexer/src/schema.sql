@@ -0,0 +1,129 @@
+-- Every indexed snapshot.
+CREATE TABLE IF NOT EXISTS snapshots (
+    slot INTEGER PRIMARY KEY,
+    timestamp INTEGER
+);
+
+-- The data of every program account in the last snapshot, to detect
+-- transitions across restarts.
+CREATE TABLE IF NOT EXISTS accounts (
+    address TEXT PRIMARY KEY,
+    data BLOB NOT NULL
+);
+
+-- Rows of accounts no longer in the last snapshot are kept, with the slot
+-- they were first missing from in `closed_slot`.
+CREATE TABLE IF NOT EXISTS configs (
+    address TEXT PRIMARY KEY,
+    stake_config TEXT NOT NULL,
+    cooldown_period_seconds INTEGER NOT NULL,
+    proposal_minimum_quorum INTEGER NOT NULL,
+    proposal_pass_threshold INTEGER NOT NULL,
+    voting_period_seconds INTEGER NOT NULL,
+    stake_per_proposal INTEGER NOT NULL,
+    cooldown_expires INTEGER NOT NULL,
+    pause_guardian TEXT NOT NULL,
+    paused_timestamp INTEGER,
+    paused_seconds INTEGER NOT NULL,
+    successor TEXT NOT NULL,
+    proposal_winning_margin INTEGER NOT NULL,
+    first_seen_slot INTEGER NOT NULL,
+    last_seen_slot INTEGER NOT NULL,
+    closed_slot INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS proposals (
+    address TEXT PRIMARY KEY,
+    governance_config TEXT NOT NULL,
+    author TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    status TEXT NOT NULL,
+    creation_timestamp INTEGER NOT NULL,
+    voting_start_timestamp INTEGER,
+    cooldown_timestamp INTEGER,
+    stake_for INTEGER NOT NULL,
+    stake_against INTEGER NOT NULL,
+    option_count INTEGER NOT NULL,
+    winning_option INTEGER NOT NULL,
+    first_seen_slot INTEGER NOT NULL,
+    last_seen_slot INTEGER NOT NULL,
+    closed_slot INTEGER
+);
+
+-- The stake on each option of a multiple-choice proposal.
+CREATE TABLE IF NOT EXISTS proposal_options (
+    proposal TEXT NOT NULL,
+    option_index INTEGER NOT NULL,
+    stake INTEGER NOT NULL,
+    PRIMARY KEY (proposal, option_index)
+);
+
+-- The tally of a proposal at every snapshot it changed in.
+CREATE TABLE IF NOT EXISTS proposal_tallies (
+    slot INTEGER NOT NULL,
+    proposal TEXT NOT NULL,
+    stake_for INTEGER NOT NULL,
+    stake_against INTEGER NOT NULL,
+    PRIMARY KEY (slot, proposal)
+);
+
+CREATE TABLE IF NOT EXISTS prerequisites (
+    proposal TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    prerequisite TEXT NOT NULL,
+    PRIMARY KEY (proposal, position)
+);
+
+-- `option_index` is NULL for the instructions of a yes/no proposal.
+CREATE TABLE IF NOT EXISTS instructions (
+    proposal TEXT NOT NULL,
+    option_index INTEGER,
+    instruction_index INTEGER NOT NULL,
+    program_id TEXT NOT NULL,
+    data BLOB NOT NULL,
+    executed INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS instruction_accounts (
+    proposal TEXT NOT NULL,
+    option_index INTEGER,
+    instruction_index INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    pubkey TEXT NOT NULL,
+    is_signer INTEGER NOT NULL,
+    is_writable INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS votes (
+    address TEXT PRIMARY KEY,
+    proposal TEXT NOT NULL,
+    authority TEXT NOT NULL,
+    stake INTEGER NOT NULL,
+    election TEXT NOT NULL,
+    option_index INTEGER NOT NULL,
+    first_seen_slot INTEGER NOT NULL,
+    last_seen_slot INTEGER NOT NULL,
+    closed_slot INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS authors (
+    address TEXT PRIMARY KEY,
+    active_proposals INTEGER NOT NULL,
+    first_seen_slot INTEGER NOT NULL,
+    last_seen_slot INTEGER NOT NULL,
+    closed_slot INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS transitions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slot INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    address TEXT NOT NULL,
+    proposal TEXT,
+    details TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS instructions_proposal ON instructions (proposal);
+CREATE INDEX IF NOT EXISTS instruction_accounts_proposal ON instruction_accounts (proposal);
+CREATE INDEX IF NOT EXISTS votes_proposal ON votes (proposal);
+CREATE INDEX IF NOT EXISTS transitions_proposal ON transitions (proposal);
@@ -0,0 +1,139 @@
+//! Snapshots of the program's accounts at a slot.
+
+use {
+    crate::IndexerError,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    serde::{Deserialize, Serialize},
+    serde_with::{serde_as, DisplayFromStr},
+    solana_sdk::{account::Account, pubkey::Pubkey},
+    std::{collections::HashMap, io::BufRead},
+};
+
+/// A snapshot as one line of a JSON-lines feed.
+#[derive(Deserialize, Serialize)]
+struct FeedLine {
+    slot: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp: Option<i64>,
+    accounts: Vec<KeyedAccount>,
+}
+
+/// An account in the format returned by `getProgramAccounts`.
+#[serde_as]
+#[derive(Deserialize, Serialize)]
+struct KeyedAccount {
+    #[serde_as(as = "DisplayFromStr")]
+    pubkey: Pubkey,
+    account: UiAccount,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UiAccount {
+    lamports: u64,
+    /// The account data and its encoding, which must be `base64`.
+    data: (String, String),
+    #[serde_as(as = "DisplayFromStr")]
+    owner: Pubkey,
+    executable: bool,
+    rent_epoch: u64,
+}
+
+/// Every account owned by the program at a slot.
+///
+/// Accounts missing from a snapshot are considered closed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    pub slot: u64,
+    /// The Unix timestamp of the slot, if known.
+    pub timestamp: Option<i64>,
+    pub accounts: HashMap<Pubkey, Account>,
+}
+
+impl Snapshot {
+    /// Parses a snapshot from one line of a JSON-lines feed.
+    pub fn from_json(json: &str) -> Result<Self, IndexerError> {
+        let line: FeedLine = serde_json::from_str(json)?;
+        let mut accounts = HashMap::with_capacity(line.accounts.len());
+        for KeyedAccount { pubkey, account } in line.accounts {
+            let (data, encoding) = account.data;
+            if encoding != "base64" {
+                return Err(IndexerError::UnsupportedEncoding(encoding));
+            }
+            accounts.insert(
+                pubkey,
+                Account {
+                    lamports: account.lamports,
+                    data: STANDARD.decode(data)?,
+                    owner: account.owner,
+                    executable: account.executable,
+                    rent_epoch: account.rent_epoch,
+                },
+            );
+        }
+        Ok(Self {
+            slot: line.slot,
+            timestamp: line.timestamp,
+            accounts,
+        })
+    }
+
+    /// Serializes the snapshot as one line of a JSON-lines feed.
+    pub fn to_json(&self) -> Result<String, IndexerError> {
+        Ok(serde_json::to_string(&FeedLine {
+            slot: self.slot,
+            timestamp: self.timestamp,
+            accounts: self
+                .accounts
+                .iter()
+                .map(|(pubkey, account)| KeyedAccount {
+                    pubkey: *pubkey,
+                    account: UiAccount {
+                        lamports: account.lamports,
+                        data: (STANDARD.encode(&account.data), "base64".to_string()),
+                        owner: account.owner,
+                        executable: account.executable,
+                        rent_epoch: account.rent_epoch,
+                    },
+                })
+                .collect(),
+        })?)
+    }
+
+    /// Reads the provided accounts from a `solana-program-test` banks client,
+    /// at its current slot.
+    ///
+    /// A banks client cannot list the accounts a program owns, so every
+    /// account to index must be provided. Accounts that don't exist are left
+    /// out.
+    #[cfg(feature = "banks")]
+    pub async fn from_banks(
+        banks_client: &mut solana_program_test::BanksClient,
+        addresses: &[Pubkey],
+    ) -> Result<Self, IndexerError> {
+        let clock = banks_client
+            .get_sysvar::<solana_sdk::clock::Clock>()
+            .await?;
+        let mut accounts = HashMap::with_capacity(addresses.len());
+        for address in addresses {
+            if let Some(account) = banks_client.get_account(*address).await? {
+                accounts.insert(*address, account);
+            }
+        }
+        Ok(Self {
+            slot: clock.slot,
+            timestamp: Some(clock.unix_timestamp),
+            accounts,
+        })
+    }
+}
+
+/// Reads a JSON-lines feed of snapshots, one per line, skipping blank lines.
+pub fn read_feed(reader: impl BufRead) -> impl Iterator<Item = Result<Snapshot, IndexerError>> {
+    reader.lines().filter_map(|line| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        Ok(line) => Some(Snapshot::from_json(&line)),
+        Err(err) => Some(Err(err.into())),
+    })
+}
@@ -0,0 +1,100 @@
+//! Program accounts decoded with the client types.
+
+use {
+    crate::Snapshot,
+    borsh::BorshDeserialize,
+    paladin_governance_program::state as program,
+    paladin_governance_program_client::{
+        accounts::{Author, GovernanceConfig, Proposal, ProposalVote},
+        pdas::find_proposal_transaction_pda,
+        types::ProposalTransaction,
+    },
+    solana_sdk::pubkey::Pubkey,
+    spl_discriminator::SplDiscriminate,
+    std::collections::{BTreeMap, BTreeSet, HashMap},
+};
+
+/// The decoded program accounts of a snapshot, by address.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GovernanceState {
+    pub configs: BTreeMap<Pubkey, GovernanceConfig>,
+    pub proposals: BTreeMap<Pubkey, Proposal>,
+    /// Proposal transactions, by the address of their proposal.
+    pub transactions: BTreeMap<Pubkey, ProposalTransaction>,
+    pub votes: BTreeMap<Pubkey, ProposalVote>,
+    pub authors: BTreeMap<Pubkey, Author>,
+    /// Program accounts that could not be decoded, such as accounts in a
+    /// legacy layout awaiting migration, or proposal transactions whose
+    /// proposal is missing.
+    pub unknown: BTreeSet<Pubkey>,
+}
+
+impl GovernanceState {
+    /// Decodes the accounts of a snapshot owned by the program.
+    pub fn from_snapshot(snapshot: &Snapshot) -> Self {
+        let program_id = paladin_governance_program::id();
+        Self::decode(
+            snapshot
+                .accounts
+                .iter()
+                .filter(|(_, account)| account.owner == program_id)
+                .map(|(address, account)| (*address, account.data.as_slice())),
+        )
+    }
+
+    /// Decodes program accounts from their data.
+    pub fn decode<'a>(accounts: impl IntoIterator<Item = (Pubkey, &'a [u8])>) -> Self {
+        let mut state = Self::default();
+
+        // Configs and proposals carry a discriminator.
+        let mut rest = Vec::new();
+        for (address, data) in accounts {
+            let discriminator = data.get(..8);
+            if data.len() == GovernanceConfig::LEN
+                && discriminator == Some(program::GovernanceConfig::SPL_DISCRIMINATOR_SLICE)
+            {
+                if let Ok(config) = GovernanceConfig::from_bytes(data) {
+                    state.configs.insert(address, config);
+                    continue;
+                }
+            } else if data.len() == Proposal::LEN
+                && discriminator == Some(program::Proposal::SPL_DISCRIMINATOR_SLICE)
+            {
+                if let Ok(proposal) = Proposal::from_bytes(data) {
+                    state.proposals.insert(address, proposal);
+                    continue;
+                }
+            }
+            rest.push((address, data));
+        }
+
+        // Proposal transactions are found at the address derived from their
+        // proposal, and the remaining accounts by their length.
+        let transactions: HashMap<Pubkey, Pubkey> = state
+            .proposals
+            .keys()
+            .map(|proposal| (find_proposal_transaction_pda(proposal).0, *proposal))
+            .collect();
+        for (address, data) in rest {
+            if let Some(proposal) = transactions.get(&address) {
+                if let Ok(transaction) = ProposalTransaction::try_from_slice(data) {
+                    state.transactions.insert(*proposal, transaction);
+                    continue;
+                }
+            } else if data.len() == ProposalVote::LEN {
+                if let Ok(vote) = ProposalVote::from_bytes(data) {
+                    state.votes.insert(address, vote);
+                    continue;
+                }
+            } else if data.len() == Author::LEN {
+                if let Ok(author) = Author::from_bytes(data) {
+                    state.authors.insert(address, author);
+                    continue;
+                }
+            }
+            state.unknown.insert(address);
+        }
+
+        state
+    }
+}
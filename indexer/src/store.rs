@@ -0,0 +1,327 @@
+//! Writes snapshots to the SQLite tables.
+
+use {
+    crate::{GovernanceState, Snapshot, Transition},
+    paladin_governance_program_client::types::ProposalTransaction,
+    rusqlite::{params, Transaction},
+    solana_sdk::pubkey::Pubkey,
+    std::collections::BTreeMap,
+};
+
+pub(crate) const SCHEMA: &str = include_str!("schema.sql");
+
+/// Writes a snapshot, decoded into `current`, along with its transitions from
+/// `previous`.
+pub(crate) fn write_snapshot(
+    transaction: &Transaction,
+    snapshot: &Snapshot,
+    previous: &GovernanceState,
+    current: &GovernanceState,
+    transitions: &[Transition],
+) -> rusqlite::Result<()> {
+    let slot = snapshot.slot;
+    transaction.execute(
+        "INSERT INTO snapshots (slot, timestamp) VALUES (?1, ?2)",
+        params![slot, snapshot.timestamp],
+    )?;
+
+    write_accounts(transaction, snapshot)?;
+    write_configs(transaction, slot, previous, current)?;
+    write_proposals(transaction, slot, previous, current)?;
+    write_votes(transaction, slot, previous, current)?;
+    write_authors(transaction, slot, previous, current)?;
+
+    for transition in transitions {
+        if let Transition::ProposalCreated { proposal, .. }
+        | Transition::TallyChanged { proposal, .. } = transition
+        {
+            let state = &current.proposals[proposal];
+            transaction.execute(
+                "INSERT OR REPLACE INTO proposal_tallies (slot, proposal, stake_for, \
+                 stake_against) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    slot,
+                    proposal.to_string(),
+                    state.stake_for,
+                    state.stake_against
+                ],
+            )?;
+        }
+        transaction.execute(
+            "INSERT INTO transitions (slot, kind, address, proposal, details) VALUES (?1, ?2, ?3, \
+             ?4, ?5)",
+            params![
+                slot,
+                transition.kind(),
+                transition.address().to_string(),
+                transition.proposal().map(Pubkey::to_string),
+                transition.to_string(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_accounts(transaction: &Transaction, snapshot: &Snapshot) -> rusqlite::Result<()> {
+    let program_id = paladin_governance_program::id();
+    transaction.execute("DELETE FROM accounts", [])?;
+    let mut insert = transaction.prepare("INSERT INTO accounts (address, data) VALUES (?1, ?2)")?;
+    for (address, account) in &snapshot.accounts {
+        if account.owner == program_id {
+            insert.execute(params![address.to_string(), account.data])?;
+        }
+    }
+    Ok(())
+}
+
+/// Marks the accounts of `previous` missing from `current` as closed.
+fn close_missing<'a, V>(
+    transaction: &Transaction,
+    table: &str,
+    slot: u64,
+    previous: impl IntoIterator<Item = &'a Pubkey>,
+    current: &BTreeMap<Pubkey, V>,
+) -> rusqlite::Result<()> {
+    let mut close = transaction.prepare(&format!(
+        "UPDATE {table} SET closed_slot = ?1 WHERE address = ?2"
+    ))?;
+    for address in previous {
+        if !current.contains_key(address) {
+            close.execute(params![slot, address.to_string()])?;
+        }
+    }
+    Ok(())
+}
+
+fn write_configs(
+    transaction: &Transaction,
+    slot: u64,
+    previous: &GovernanceState,
+    current: &GovernanceState,
+) -> rusqlite::Result<()> {
+    let mut upsert = transaction.prepare(
+        "INSERT INTO configs (address, stake_config, cooldown_period_seconds, \
+         proposal_minimum_quorum, proposal_pass_threshold, voting_period_seconds, \
+         stake_per_proposal, cooldown_expires, pause_guardian, paused_timestamp, paused_seconds, \
+         successor, proposal_winning_margin, first_seen_slot, last_seen_slot) VALUES (?1, ?2, ?3, \
+         ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?14) ON CONFLICT (address) DO UPDATE \
+         SET stake_config = excluded.stake_config, cooldown_period_seconds = \
+         excluded.cooldown_period_seconds, proposal_minimum_quorum = \
+         excluded.proposal_minimum_quorum, proposal_pass_threshold = \
+         excluded.proposal_pass_threshold, voting_period_seconds = \
+         excluded.voting_period_seconds, stake_per_proposal = excluded.stake_per_proposal, \
+         cooldown_expires = excluded.cooldown_expires, pause_guardian = excluded.pause_guardian, \
+         paused_timestamp = excluded.paused_timestamp, paused_seconds = excluded.paused_seconds, \
+         successor = excluded.successor, proposal_winning_margin = \
+         excluded.proposal_winning_margin, last_seen_slot = excluded.last_seen_slot, closed_slot \
+         = NULL",
+    )?;
+    for (address, config) in &current.configs {
+        upsert.execute(params![
+            address.to_string(),
+            config.stake_config_address.to_string(),
+            config.cooldown_period_seconds,
+            config.proposal_minimum_quorum,
+            config.proposal_pass_threshold,
+            config.voting_period_seconds,
+            config.stake_per_proposal,
+            config.cooldown_expires,
+            config.pause_guardian.to_string(),
+            config.paused_timestamp.value(),
+            config.paused_seconds,
+            config.successor.to_string(),
+            config.proposal_winning_margin,
+            slot,
+        ])?;
+    }
+    close_missing(
+        transaction,
+        "configs",
+        slot,
+        previous.configs.keys(),
+        &current.configs,
+    )
+}
+
+fn write_proposals(
+    transaction: &Transaction,
+    slot: u64,
+    previous: &GovernanceState,
+    current: &GovernanceState,
+) -> rusqlite::Result<()> {
+    let mut upsert = transaction.prepare(
+        "INSERT INTO proposals (address, governance_config, author, kind, status, \
+         creation_timestamp, voting_start_timestamp, cooldown_timestamp, stake_for, \
+         stake_against, option_count, winning_option, first_seen_slot, last_seen_slot) VALUES \
+         (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?13) ON CONFLICT (address) DO \
+         UPDATE SET governance_config = excluded.governance_config, author = excluded.author, \
+         kind = excluded.kind, status = excluded.status, creation_timestamp = \
+         excluded.creation_timestamp, voting_start_timestamp = excluded.voting_start_timestamp, \
+         cooldown_timestamp = excluded.cooldown_timestamp, stake_for = excluded.stake_for, \
+         stake_against = excluded.stake_against, option_count = excluded.option_count, \
+         winning_option = excluded.winning_option, last_seen_slot = excluded.last_seen_slot, \
+         closed_slot = NULL",
+    )?;
+    let mut delete_options =
+        transaction.prepare("DELETE FROM proposal_options WHERE proposal = ?1")?;
+    let mut insert_option = transaction.prepare(
+        "INSERT INTO proposal_options (proposal, option_index, stake) VALUES (?1, ?2, ?3)",
+    )?;
+    for (address, proposal) in &current.proposals {
+        let proposal_address = address.to_string();
+        upsert.execute(params![
+            proposal_address,
+            proposal.governance_config.governance_config.to_string(),
+            proposal.author.to_string(),
+            format!("{:?}", proposal.kind),
+            format!("{:?}", proposal.status),
+            proposal.creation_timestamp,
+            proposal.voting_start_timestamp.value(),
+            proposal.cooldown_timestamp.value(),
+            proposal.stake_for,
+            proposal.stake_against,
+            proposal.option_count,
+            proposal.winning_option,
+            slot,
+        ])?;
+
+        delete_options.execute([&proposal_address])?;
+        for (index, stake) in proposal
+            .option_stake
+            .iter()
+            .take(proposal.option_count as usize)
+            .enumerate()
+        {
+            insert_option.execute(params![proposal_address, index, stake])?;
+        }
+
+        if let Some(proposal_transaction) = current.transactions.get(address) {
+            write_proposal_transaction(transaction, &proposal_address, proposal_transaction)?;
+        }
+    }
+    close_missing(
+        transaction,
+        "proposals",
+        slot,
+        previous.proposals.keys(),
+        &current.proposals,
+    )
+}
+
+/// Replaces the prerequisites and instructions of a proposal.
+fn write_proposal_transaction(
+    transaction: &Transaction,
+    proposal: &str,
+    proposal_transaction: &ProposalTransaction,
+) -> rusqlite::Result<()> {
+    for table in ["prerequisites", "instructions", "instruction_accounts"] {
+        transaction.execute(
+            &format!("DELETE FROM {table} WHERE proposal = ?1"),
+            [proposal],
+        )?;
+    }
+
+    let mut insert_prerequisite = transaction.prepare(
+        "INSERT INTO prerequisites (proposal, position, prerequisite) VALUES (?1, ?2, ?3)",
+    )?;
+    for (position, prerequisite) in proposal_transaction.prerequisites.iter().enumerate() {
+        insert_prerequisite.execute(params![proposal, position, prerequisite.to_string()])?;
+    }
+
+    let mut insert_instruction = transaction.prepare(
+        "INSERT INTO instructions (proposal, option_index, instruction_index, program_id, data, \
+         executed) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )?;
+    let mut insert_account = transaction.prepare(
+        "INSERT INTO instruction_accounts (proposal, option_index, instruction_index, position, \
+         pubkey, is_signer, is_writable) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    let lists = std::iter::once((None, &proposal_transaction.instructions)).chain(
+        proposal_transaction
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| (Some(index), &option.instructions)),
+    );
+    for (option_index, instructions) in lists {
+        for (instruction_index, instruction) in instructions.iter().enumerate() {
+            insert_instruction.execute(params![
+                proposal,
+                option_index,
+                instruction_index,
+                instruction.program_id.to_string(),
+                instruction.data,
+                instruction.executed,
+            ])?;
+            for (position, meta) in instruction.accounts.iter().enumerate() {
+                insert_account.execute(params![
+                    proposal,
+                    option_index,
+                    instruction_index,
+                    position,
+                    meta.pubkey.to_string(),
+                    meta.is_signer,
+                    meta.is_writable,
+                ])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_votes(
+    transaction: &Transaction,
+    slot: u64,
+    previous: &GovernanceState,
+    current: &GovernanceState,
+) -> rusqlite::Result<()> {
+    let mut upsert = transaction.prepare(
+        "INSERT INTO votes (address, proposal, authority, stake, election, option_index, \
+         first_seen_slot, last_seen_slot) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7) ON CONFLICT \
+         (address) DO UPDATE SET proposal = excluded.proposal, authority = excluded.authority, \
+         stake = excluded.stake, election = excluded.election, option_index = \
+         excluded.option_index, last_seen_slot = excluded.last_seen_slot, closed_slot = NULL",
+    )?;
+    for (address, vote) in &current.votes {
+        upsert.execute(params![
+            address.to_string(),
+            vote.proposal.to_string(),
+            vote.authority.to_string(),
+            vote.stake,
+            format!("{:?}", vote.election),
+            vote.option_index,
+            slot,
+        ])?;
+    }
+    close_missing(
+        transaction,
+        "votes",
+        slot,
+        previous.votes.keys(),
+        &current.votes,
+    )
+}
+
+fn write_authors(
+    transaction: &Transaction,
+    slot: u64,
+    previous: &GovernanceState,
+    current: &GovernanceState,
+) -> rusqlite::Result<()> {
+    let mut upsert = transaction.prepare(
+        "INSERT INTO authors (address, active_proposals, first_seen_slot, last_seen_slot) VALUES \
+         (?1, ?2, ?3, ?3) ON CONFLICT (address) DO UPDATE SET active_proposals = \
+         excluded.active_proposals, last_seen_slot = excluded.last_seen_slot, closed_slot = NULL",
+    )?;
+    for (address, author) in &current.authors {
+        upsert.execute(params![address.to_string(), author.active_proposals, slot])?;
+    }
+    close_missing(
+        transaction,
+        "authors",
+        slot,
+        previous.authors.keys(),
+        &current.authors,
+    )
+}
@@ -0,0 +1,385 @@
+//! Changes between two consecutive snapshots.
+
+use {
+    crate::GovernanceState,
+    paladin_governance_program_client::{
+        accounts::GovernanceConfig,
+        types::{ProposalInstruction, ProposalStatus, ProposalTransaction, ProposalVoteElection},
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::fmt,
+};
+
+/// A change to the program's accounts between two snapshots.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Transition {
+    ConfigCreated {
+        config: Pubkey,
+    },
+    /// A governance parameter changed.
+    ConfigUpdated {
+        config: Pubkey,
+    },
+    ConfigPaused {
+        config: Pubkey,
+    },
+    ConfigUnpaused {
+        config: Pubkey,
+    },
+    ProposalCreated {
+        proposal: Pubkey,
+        author: Pubkey,
+        status: ProposalStatus,
+    },
+    ProposalStatusChanged {
+        proposal: Pubkey,
+        from: ProposalStatus,
+        to: ProposalStatus,
+    },
+    /// The stake for, against or on an option of a proposal changed.
+    TallyChanged {
+        proposal: Pubkey,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    ProposalClosed {
+        proposal: Pubkey,
+    },
+    InstructionPushed {
+        proposal: Pubkey,
+        /// The option of a multiple-choice proposal the instruction belongs
+        /// to.
+        option_index: Option<u8>,
+        instruction_index: u32,
+    },
+    InstructionExecuted {
+        proposal: Pubkey,
+        option_index: Option<u8>,
+        instruction_index: u32,
+    },
+    VoteCast {
+        vote: Pubkey,
+        proposal: Pubkey,
+        authority: Pubkey,
+        election: ProposalVoteElection,
+        option_index: u8,
+        stake: u64,
+    },
+    /// A vote's election, option or stake changed.
+    VoteSwitched {
+        vote: Pubkey,
+        proposal: Pubkey,
+        election: ProposalVoteElection,
+        option_index: u8,
+        stake: u64,
+    },
+    VoteDeleted {
+        vote: Pubkey,
+        proposal: Pubkey,
+    },
+    /// An author's number of active proposals changed, including when the
+    /// author account is created.
+    AuthorUpdated {
+        author: Pubkey,
+        active_proposals: u64,
+    },
+    AuthorClosed {
+        author: Pubkey,
+    },
+}
+
+impl Transition {
+    /// The name of the transition, as stored in the `transitions` table.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ConfigCreated { .. } => "config_created",
+            Self::ConfigUpdated { .. } => "config_updated",
+            Self::ConfigPaused { .. } => "config_paused",
+            Self::ConfigUnpaused { .. } => "config_unpaused",
+            Self::ProposalCreated { .. } => "proposal_created",
+            Self::ProposalStatusChanged { .. } => "proposal_status_changed",
+            Self::TallyChanged { .. } => "tally_changed",
+            Self::ProposalClosed { .. } => "proposal_closed",
+            Self::InstructionPushed { .. } => "instruction_pushed",
+            Self::InstructionExecuted { .. } => "instruction_executed",
+            Self::VoteCast { .. } => "vote_cast",
+            Self::VoteSwitched { .. } => "vote_switched",
+            Self::VoteDeleted { .. } => "vote_deleted",
+            Self::AuthorUpdated { .. } => "author_updated",
+            Self::AuthorClosed { .. } => "author_closed",
+        }
+    }
+
+    /// The account that changed.
+    pub fn address(&self) -> &Pubkey {
+        match self {
+            Self::ConfigCreated { config }
+            | Self::ConfigUpdated { config }
+            | Self::ConfigPaused { config }
+            | Self::ConfigUnpaused { config } => config,
+            Self::ProposalCreated { proposal, .. }
+            | Self::ProposalStatusChanged { proposal, .. }
+            | Self::TallyChanged { proposal, .. }
+            | Self::ProposalClosed { proposal }
+            | Self::InstructionPushed { proposal, .. }
+            | Self::InstructionExecuted { proposal, .. } => proposal,
+            Self::VoteCast { vote, .. }
+            | Self::VoteSwitched { vote, .. }
+            | Self::VoteDeleted { vote, .. } => vote,
+            Self::AuthorUpdated { author, .. } | Self::AuthorClosed { author } => author,
+        }
+    }
+
+    /// The proposal the transition belongs to, if any.
+    pub fn proposal(&self) -> Option<&Pubkey> {
+        match self {
+            Self::ProposalCreated { proposal, .. }
+            | Self::ProposalStatusChanged { proposal, .. }
+            | Self::TallyChanged { proposal, .. }
+            | Self::ProposalClosed { proposal }
+            | Self::InstructionPushed { proposal, .. }
+            | Self::InstructionExecuted { proposal, .. }
+            | Self::VoteCast { proposal, .. }
+            | Self::VoteSwitched { proposal, .. }
+            | Self::VoteDeleted { proposal, .. } => Some(proposal),
+            _ => None,
+        }
+    }
+}
+
+/// The details of the transition, as stored in the `transitions` table.
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ProposalCreated { author, status, .. } => {
+                write!(f, "author {author}, {status:?}")
+            }
+            Self::ProposalStatusChanged { from, to, .. } => write!(f, "{from:?} -> {to:?}"),
+            Self::TallyChanged {
+                stake_for,
+                stake_against,
+                ..
+            } => write!(f, "for {stake_for}, against {stake_against}"),
+            Self::InstructionPushed {
+                option_index,
+                instruction_index,
+                ..
+            }
+            | Self::InstructionExecuted {
+                option_index,
+                instruction_index,
+                ..
+            } => match option_index {
+                Some(option_index) => {
+                    write!(f, "option {option_index}, instruction {instruction_index}")
+                }
+                None => write!(f, "instruction {instruction_index}"),
+            },
+            Self::VoteCast {
+                authority,
+                election,
+                option_index,
+                stake,
+                ..
+            } => write!(
+                f,
+                "authority {authority}, {election:?}, option {option_index}, stake {stake}"
+            ),
+            Self::VoteSwitched {
+                election,
+                option_index,
+                stake,
+                ..
+            } => write!(f, "{election:?}, option {option_index}, stake {stake}"),
+            Self::AuthorUpdated {
+                active_proposals, ..
+            } => write!(f, "{active_proposals} active proposals"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Lists the transitions from `previous` to `current`, in a stable order:
+/// configs, proposals and their instructions, votes, then authors.
+pub fn detect_transitions(
+    previous: &GovernanceState,
+    current: &GovernanceState,
+) -> Vec<Transition> {
+    let mut transitions = Vec::new();
+
+    for (address, config) in &current.configs {
+        let config_address = *address;
+        match previous.configs.get(address) {
+            None => transitions.push(Transition::ConfigCreated {
+                config: config_address,
+            }),
+            Some(before) => {
+                let paused = |config: &GovernanceConfig| config.paused_timestamp.value().is_some();
+                match (paused(before), paused(config)) {
+                    (false, true) => transitions.push(Transition::ConfigPaused {
+                        config: config_address,
+                    }),
+                    (true, false) => transitions.push(Transition::ConfigUnpaused {
+                        config: config_address,
+                    }),
+                    _ => {}
+                }
+                // Pausing is not a parameter change.
+                let parameters = |config: &GovernanceConfig| {
+                    let mut config = config.clone();
+                    config.paused_timestamp = None.into();
+                    config.paused_seconds = 0;
+                    config
+                };
+                if parameters(before) != parameters(config) {
+                    transitions.push(Transition::ConfigUpdated {
+                        config: config_address,
+                    });
+                }
+            }
+        }
+    }
+
+    for (address, proposal) in &current.proposals {
+        match previous.proposals.get(address) {
+            None => {
+                transitions.push(Transition::ProposalCreated {
+                    proposal: *address,
+                    author: proposal.author,
+                    status: proposal.status,
+                });
+                if proposal.stake_for > 0 || proposal.stake_against > 0 {
+                    transitions.push(Transition::TallyChanged {
+                        proposal: *address,
+                        stake_for: proposal.stake_for,
+                        stake_against: proposal.stake_against,
+                    });
+                }
+            }
+            Some(before) => {
+                if before.status != proposal.status {
+                    transitions.push(Transition::ProposalStatusChanged {
+                        proposal: *address,
+                        from: before.status,
+                        to: proposal.status,
+                    });
+                }
+                if before.stake_for != proposal.stake_for
+                    || before.stake_against != proposal.stake_against
+                    || before.option_stake != proposal.option_stake
+                {
+                    transitions.push(Transition::TallyChanged {
+                        proposal: *address,
+                        stake_for: proposal.stake_for,
+                        stake_against: proposal.stake_against,
+                    });
+                }
+            }
+        }
+
+        if let Some(transaction) = current.transactions.get(address) {
+            instruction_transitions(
+                address,
+                previous.transactions.get(address),
+                transaction,
+                &mut transitions,
+            );
+        }
+    }
+    for address in previous.proposals.keys() {
+        if !current.proposals.contains_key(address) {
+            transitions.push(Transition::ProposalClosed { proposal: *address });
+        }
+    }
+
+    for (address, vote) in &current.votes {
+        match previous.votes.get(address) {
+            None => transitions.push(Transition::VoteCast {
+                vote: *address,
+                proposal: vote.proposal,
+                authority: vote.authority,
+                election: vote.election,
+                option_index: vote.option_index,
+                stake: vote.stake,
+            }),
+            Some(before) if before != vote => transitions.push(Transition::VoteSwitched {
+                vote: *address,
+                proposal: vote.proposal,
+                election: vote.election,
+                option_index: vote.option_index,
+                stake: vote.stake,
+            }),
+            Some(_) => {}
+        }
+    }
+    for (address, vote) in &previous.votes {
+        if !current.votes.contains_key(address) {
+            transitions.push(Transition::VoteDeleted {
+                vote: *address,
+                proposal: vote.proposal,
+            });
+        }
+    }
+
+    for (address, author) in &current.authors {
+        if previous.authors.get(address) != Some(author) {
+            transitions.push(Transition::AuthorUpdated {
+                author: *address,
+                active_proposals: author.active_proposals,
+            });
+        }
+    }
+    for address in previous.authors.keys() {
+        if !current.authors.contains_key(address) {
+            transitions.push(Transition::AuthorClosed { author: *address });
+        }
+    }
+
+    transitions
+}
+
+/// Instructions pushed to or executed from a proposal transaction.
+fn instruction_transitions(
+    proposal: &Pubkey,
+    previous: Option<&ProposalTransaction>,
+    current: &ProposalTransaction,
+    transitions: &mut Vec<Transition>,
+) {
+    let lists = std::iter::once((None, &current.instructions)).chain(
+        current
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| (Some(index as u8), &option.instructions)),
+    );
+    for (option_index, instructions) in lists {
+        let before: &[ProposalInstruction] = previous
+            .and_then(|previous| match option_index {
+                None => Some(&previous.instructions),
+                Some(index) => previous
+                    .options
+                    .get(index as usize)
+                    .map(|option| &option.instructions),
+            })
+            .map_or(&[], Vec::as_slice);
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            let instruction_index = index as u32;
+            let before = before.get(index);
+            if before.is_none() {
+                transitions.push(Transition::InstructionPushed {
+                    proposal: *proposal,
+                    option_index,
+                    instruction_index,
+                });
+            }
+            if instruction.executed && !before.is_some_and(|before| before.executed) {
+                transitions.push(Transition::InstructionExecuted {
+                    proposal: *proposal,
+                    option_index,
+                    instruction_index,
+                });
+            }
+        }
+    }
+}
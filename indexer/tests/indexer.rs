@@ -0,0 +1,447 @@
+use {
+    borsh::BorshSerialize,
+    paladin_governance_indexer::{read_feed, Indexer, IndexerError, Snapshot, Transition},
+    paladin_governance_program::state::{
+        get_proposal_transaction_address, Author, GovernanceConfig, Proposal, ProposalStatus,
+        ProposalVote, ProposalVoteElection,
+    },
+    paladin_governance_program_client::types::{
+        ProposalAccountMeta, ProposalInstruction, ProposalStatus as ClientProposalStatus,
+        ProposalTransaction, ProposalVoteElection as ClientProposalVoteElection,
+    },
+    solana_sdk::{account::Account, pubkey::Pubkey},
+};
+
+struct Setup {
+    governance: Pubkey,
+    governance_config: GovernanceConfig,
+    proposal: Pubkey,
+    author: Pubkey,
+    vote: Pubkey,
+    authority: Pubkey,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let governance = Pubkey::new_unique();
+        Self {
+            governance,
+            governance_config: GovernanceConfig {
+                governance_config: governance,
+                proposal_minimum_quorum: 500_000_000,
+                proposal_pass_threshold: 500_000_000,
+                ..GovernanceConfig::default()
+            },
+            proposal: Pubkey::new_unique(),
+            author: Pubkey::new_unique(),
+            vote: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+        }
+    }
+
+    fn proposal_state(&self, status: ProposalStatus, stake_for: u64) -> Proposal {
+        let mut proposal = Proposal::new(&self.author, 0, self.governance_config);
+        proposal.status = status;
+        proposal.stake_for = stake_for;
+        proposal
+    }
+
+    fn vote_state(&self, election: ProposalVoteElection, stake: u64) -> ProposalVote {
+        ProposalVote {
+            proposal: self.proposal,
+            stake,
+            authority: self.authority,
+            election,
+            option_index: 0,
+            _padding: [0; 6],
+        }
+    }
+
+    fn proposal_transaction(&self, executed: &[bool]) -> ProposalTransaction {
+        ProposalTransaction {
+            instructions: executed
+                .iter()
+                .map(|executed| ProposalInstruction {
+                    program_id: Pubkey::new_unique(),
+                    accounts: vec![ProposalAccountMeta {
+                        pubkey: self.governance,
+                        is_signer: false,
+                        is_writable: true,
+                    }],
+                    data: vec![1, 2, 3],
+                    executed: *executed,
+                })
+                .collect(),
+            prerequisites: vec![],
+            options: vec![],
+        }
+    }
+}
+
+fn program_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: 1_000_000,
+        data,
+        owner: paladin_governance_program::id(),
+        ..Account::default()
+    }
+}
+
+/// A snapshot at `slot` with the provided proposal, transaction, vote and
+/// author accounts, alongside the governance config.
+fn snapshot(
+    setup: &Setup,
+    slot: u64,
+    proposal: Option<Proposal>,
+    transaction: Option<&ProposalTransaction>,
+    vote: Option<ProposalVote>,
+    author: Option<Author>,
+) -> Snapshot {
+    let mut snapshot = Snapshot {
+        slot,
+        timestamp: Some(slot as i64 * 10),
+        ..Snapshot::default()
+    };
+    snapshot.accounts.insert(
+        setup.governance,
+        program_account(bytemuck::bytes_of(&setup.governance_config).to_vec()),
+    );
+    if let Some(proposal) = proposal {
+        snapshot.accounts.insert(
+            setup.proposal,
+            program_account(bytemuck::bytes_of(&proposal).to_vec()),
+        );
+    }
+    if let Some(transaction) = transaction {
+        snapshot.accounts.insert(
+            get_proposal_transaction_address(&setup.proposal, &paladin_governance_program::id()),
+            program_account(transaction.try_to_vec().unwrap()),
+        );
+    }
+    if let Some(vote) = vote {
+        snapshot.accounts.insert(
+            setup.vote,
+            program_account(bytemuck::bytes_of(&vote).to_vec()),
+        );
+    }
+    if let Some(author) = author {
+        snapshot.accounts.insert(
+            setup.author,
+            program_account(bytemuck::bytes_of(&author).to_vec()),
+        );
+    }
+    snapshot
+}
+
+#[test]
+fn proposal_lifecycle() {
+    let setup = Setup::new();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+
+    // A draft proposal with one instruction.
+    let transaction = setup.proposal_transaction(&[false]);
+    let transitions = indexer
+        .ingest(&snapshot(
+            &setup,
+            1,
+            Some(setup.proposal_state(ProposalStatus::Draft, 0)),
+            Some(&transaction),
+            None,
+            Some(Author {
+                active_proposals: 1,
+            }),
+        ))
+        .unwrap();
+    assert_eq!(
+        transitions,
+        vec![
+            Transition::ConfigCreated {
+                config: setup.governance
+            },
+            Transition::ProposalCreated {
+                proposal: setup.proposal,
+                author: setup.author,
+                status: ClientProposalStatus::Draft,
+            },
+            Transition::InstructionPushed {
+                proposal: setup.proposal,
+                option_index: None,
+                instruction_index: 0,
+            },
+            Transition::AuthorUpdated {
+                author: setup.author,
+                active_proposals: 1,
+            },
+        ]
+    );
+
+    // Voting begins and a vote is cast.
+    let transitions = indexer
+        .ingest(&snapshot(
+            &setup,
+            2,
+            Some(setup.proposal_state(ProposalStatus::Voting, 100)),
+            Some(&transaction),
+            Some(setup.vote_state(ProposalVoteElection::For, 100)),
+            Some(Author {
+                active_proposals: 1,
+            }),
+        ))
+        .unwrap();
+    assert_eq!(
+        transitions,
+        vec![
+            Transition::ProposalStatusChanged {
+                proposal: setup.proposal,
+                from: ClientProposalStatus::Draft,
+                to: ClientProposalStatus::Voting,
+            },
+            Transition::TallyChanged {
+                proposal: setup.proposal,
+                stake_for: 100,
+                stake_against: 0,
+            },
+            Transition::VoteCast {
+                vote: setup.vote,
+                proposal: setup.proposal,
+                authority: setup.authority,
+                election: ClientProposalVoteElection::For,
+                option_index: 0,
+                stake: 100,
+            },
+        ]
+    );
+
+    // The proposal is accepted and executed, and the vote is deleted.
+    let transaction = setup.proposal_transaction(&[true]);
+    let transitions = indexer
+        .ingest(&snapshot(
+            &setup,
+            3,
+            Some(setup.proposal_state(ProposalStatus::Processed, 100)),
+            Some(&transaction),
+            None,
+            Some(Author {
+                active_proposals: 0,
+            }),
+        ))
+        .unwrap();
+    assert_eq!(
+        transitions,
+        vec![
+            Transition::ProposalStatusChanged {
+                proposal: setup.proposal,
+                from: ClientProposalStatus::Voting,
+                to: ClientProposalStatus::Processed,
+            },
+            Transition::InstructionExecuted {
+                proposal: setup.proposal,
+                option_index: None,
+                instruction_index: 0,
+            },
+            Transition::VoteDeleted {
+                vote: setup.vote,
+                proposal: setup.proposal,
+            },
+            Transition::AuthorUpdated {
+                author: setup.author,
+                active_proposals: 0,
+            },
+        ]
+    );
+
+    let connection = indexer.connection();
+    let (status, stake_for): (String, u64) = connection
+        .query_row(
+            "SELECT status, stake_for FROM proposals WHERE address = ?1",
+            [setup.proposal.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(status, "Processed");
+    assert_eq!(stake_for, 100);
+
+    let tallies: Vec<(u64, u64)> = connection
+        .prepare("SELECT slot, stake_for FROM proposal_tallies ORDER BY slot")
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(tallies, vec![(1, 0), (2, 100)]);
+
+    let (first_seen_slot, closed_slot): (u64, Option<u64>) = connection
+        .query_row(
+            "SELECT first_seen_slot, closed_slot FROM votes WHERE address = ?1",
+            [setup.vote.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(first_seen_slot, 2);
+    assert_eq!(closed_slot, Some(3));
+
+    let (executed, accounts): (bool, u64) = connection
+        .query_row(
+            "SELECT executed, (SELECT COUNT(*) FROM instruction_accounts WHERE proposal = ?1) \
+             FROM instructions WHERE proposal = ?1",
+            [setup.proposal.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert!(executed);
+    assert_eq!(accounts, 1);
+
+    let kinds: Vec<String> = connection
+        .prepare("SELECT kind FROM transitions WHERE proposal = ?1 ORDER BY id")
+        .unwrap()
+        .query_map([setup.proposal.to_string()], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        kinds,
+        vec![
+            "proposal_created",
+            "instruction_pushed",
+            "proposal_status_changed",
+            "tally_changed",
+            "vote_cast",
+            "proposal_status_changed",
+            "instruction_executed",
+            "vote_deleted",
+        ]
+    );
+}
+
+#[test]
+fn closed_and_reopened_accounts() {
+    let setup = Setup::new();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    let proposal = setup.proposal_state(ProposalStatus::Draft, 0);
+
+    indexer
+        .ingest(&snapshot(&setup, 1, Some(proposal), None, None, None))
+        .unwrap();
+    let transitions = indexer
+        .ingest(&snapshot(&setup, 2, None, None, None, None))
+        .unwrap();
+    assert_eq!(
+        transitions,
+        vec![Transition::ProposalClosed {
+            proposal: setup.proposal
+        }]
+    );
+    let closed_slot: Option<u64> = indexer
+        .connection()
+        .query_row("SELECT closed_slot FROM proposals", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(closed_slot, Some(2));
+
+    indexer
+        .ingest(&snapshot(&setup, 3, Some(proposal), None, None, None))
+        .unwrap();
+    let closed_slot: Option<u64> = indexer
+        .connection()
+        .query_row("SELECT closed_slot FROM proposals", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(closed_slot, None);
+}
+
+#[test]
+fn unknown_and_foreign_accounts() {
+    let setup = Setup::new();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+
+    let legacy = Pubkey::new_unique();
+    let foreign = Pubkey::new_unique();
+    let mut snapshot = snapshot(&setup, 1, None, None, None, None);
+    snapshot
+        .accounts
+        .insert(legacy, program_account(vec![7; 100]));
+    snapshot.accounts.insert(
+        foreign,
+        Account {
+            data: bytemuck::bytes_of(&setup.governance_config).to_vec(),
+            ..Account::default()
+        },
+    );
+    indexer.ingest(&snapshot).unwrap();
+
+    let state = indexer.state();
+    assert_eq!(
+        state.configs.keys().collect::<Vec<_>>(),
+        vec![&setup.governance]
+    );
+    assert_eq!(state.unknown.iter().collect::<Vec<_>>(), vec![&legacy]);
+}
+
+#[test]
+fn resume_from_database() {
+    let setup = Setup::new();
+    let path = std::env::temp_dir().join(format!("indexer-{}.sqlite", Pubkey::new_unique()));
+    let proposal = setup.proposal_state(ProposalStatus::Voting, 100);
+
+    let mut indexer = Indexer::open(&path).unwrap();
+    indexer
+        .ingest(&snapshot(&setup, 5, Some(proposal), None, None, None))
+        .unwrap();
+    drop(indexer);
+
+    let mut indexer = Indexer::open(&path).unwrap();
+    assert_eq!(indexer.last_slot(), Some(5));
+    assert!(matches!(
+        indexer.ingest(&snapshot(&setup, 5, Some(proposal), None, None, None)),
+        Err(IndexerError::OutOfOrder {
+            slot: 5,
+            last_slot: 5
+        })
+    ));
+    // Nothing changed since the last snapshot before the restart.
+    let transitions = indexer
+        .ingest(&snapshot(&setup, 6, Some(proposal), None, None, None))
+        .unwrap();
+    assert!(transitions.is_empty());
+
+    drop(indexer);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn feed_round_trip() {
+    let setup = Setup::new();
+    let transaction = setup.proposal_transaction(&[false, true]);
+    let snapshots = [
+        snapshot(&setup, 1, None, None, None, None),
+        snapshot(
+            &setup,
+            2,
+            Some(setup.proposal_state(ProposalStatus::Voting, 0)),
+            Some(&transaction),
+            Some(setup.vote_state(ProposalVoteElection::Against, 50)),
+            None,
+        ),
+    ];
+
+    let feed = snapshots
+        .iter()
+        .map(|snapshot| snapshot.to_json().unwrap())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let read = read_feed(feed.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(read, snapshots);
+}
+
+#[test]
+fn fail_unsupported_encoding() {
+    let line = format!(
+        r#"{{"slot":1,"accounts":[{{"pubkey":"{}","account":{{"lamports":1,"data":["","base58"],"owner":"{}","executable":false,"rentEpoch":0}}}}]}}"#,
+        Pubkey::new_unique(),
+        paladin_governance_program::id(),
+    );
+    assert!(matches!(
+        Snapshot::from_json(&line),
+        Err(IndexerError::UnsupportedEncoding(encoding)) if encoding == "base58"
+    ));
+}
@@ -0,0 +1,259 @@
+//! Manifest actions.
+
+use {
+    crate::ManifestError,
+    paladin_governance_program::{
+        instruction::{update_governance, PaladinGovernanceInstruction},
+        state::get_treasury_address,
+    },
+    serde::{Deserialize, Serialize},
+    serde_with::{hex::Hex, serde_as, DisplayFromStr},
+    solana_program::{
+        bpf_loader_upgradeable,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_instruction::{self, SystemInstruction},
+        system_program,
+    },
+    spl_associated_token_account_client::address::get_associated_token_address,
+    spl_token::instruction::TokenInstruction,
+};
+
+/// An action a proposal performs, signed by the governance treasury.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum Action {
+    /// Transfer lamports from the treasury.
+    SystemTransfer {
+        #[serde_as(as = "DisplayFromStr")]
+        to: Pubkey,
+        lamports: u64,
+    },
+    /// Transfer SPL tokens from a token account owned by the treasury.
+    TokenTransfer {
+        #[serde_as(as = "DisplayFromStr")]
+        mint: Pubkey,
+        /// The destination token account.
+        #[serde_as(as = "DisplayFromStr")]
+        destination: Pubkey,
+        amount: u64,
+        decimals: u8,
+        /// The source token account, defaulting to the treasury's
+        /// associated token account.
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Option<Pubkey>,
+    },
+    /// Update the parameters of the governance config.
+    UpdateGovernance {
+        #[serde(default)]
+        governance_id: u64,
+        cooldown_period_seconds: u64,
+        proposal_minimum_quorum: u32,
+        proposal_pass_threshold: u32,
+        voting_period_seconds: u64,
+        stake_per_proposal: u64,
+    },
+    /// Upgrade a program whose upgrade authority is the treasury.
+    ProgramUpgrade {
+        #[serde_as(as = "DisplayFromStr")]
+        program: Pubkey,
+        /// The buffer holding the new program data, with the treasury as
+        /// its authority.
+        #[serde_as(as = "DisplayFromStr")]
+        buffer: Pubkey,
+        /// Receives the buffer's lamports, defaulting to the treasury.
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        spill: Option<Pubkey>,
+    },
+    /// Any other instruction.
+    Raw {
+        #[serde_as(as = "DisplayFromStr")]
+        program_id: Pubkey,
+        #[serde(default)]
+        accounts: Vec<RawAccount>,
+        /// The instruction data, in hex.
+        #[serde_as(as = "Hex")]
+        #[serde(default)]
+        data: Vec<u8>,
+    },
+}
+
+/// An account of a `raw` action.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RawAccount {
+    #[serde_as(as = "DisplayFromStr")]
+    pub pubkey: Pubkey,
+    #[serde(default)]
+    pub signer: bool,
+    #[serde(default)]
+    pub writable: bool,
+}
+
+impl From<&RawAccount> for AccountMeta {
+    fn from(account: &RawAccount) -> Self {
+        Self {
+            pubkey: account.pubkey,
+            is_signer: account.signer,
+            is_writable: account.writable,
+        }
+    }
+}
+
+impl From<&AccountMeta> for RawAccount {
+    fn from(meta: &AccountMeta) -> Self {
+        Self {
+            pubkey: meta.pubkey,
+            signer: meta.is_signer,
+            writable: meta.is_writable,
+        }
+    }
+}
+
+impl Action {
+    /// The instruction performing the action for the provided governance
+    /// config.
+    pub fn instruction(&self, governance: &Pubkey) -> Result<Instruction, ManifestError> {
+        let treasury = get_treasury_address(governance, &paladin_governance_program::id());
+
+        Ok(match self {
+            Self::SystemTransfer { to, lamports } => {
+                system_instruction::transfer(&treasury, to, *lamports)
+            }
+            Self::TokenTransfer {
+                mint,
+                destination,
+                amount,
+                decimals,
+                source,
+            } => spl_token::instruction::transfer_checked(
+                &spl_token::id(),
+                &source.unwrap_or_else(|| get_associated_token_address(&treasury, mint)),
+                mint,
+                destination,
+                &treasury,
+                &[],
+                *amount,
+                *decimals,
+            )?,
+            Self::UpdateGovernance {
+                governance_id,
+                cooldown_period_seconds,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                voting_period_seconds,
+                stake_per_proposal,
+            } => update_governance(
+                &treasury,
+                governance,
+                *governance_id,
+                *cooldown_period_seconds,
+                *proposal_minimum_quorum,
+                *proposal_pass_threshold,
+                *voting_period_seconds,
+                *stake_per_proposal,
+            ),
+            Self::ProgramUpgrade {
+                program,
+                buffer,
+                spill,
+            } => bpf_loader_upgradeable::upgrade(
+                program,
+                buffer,
+                &treasury,
+                &spill.unwrap_or(treasury),
+            ),
+            Self::Raw {
+                program_id,
+                accounts,
+                data,
+            } => Instruction::new_with_bytes(
+                *program_id,
+                data,
+                accounts.iter().map(Into::into).collect(),
+            ),
+        })
+    }
+
+    /// Reverses an instruction into the action performing it for the
+    /// provided governance config.
+    ///
+    /// Instructions that aren't exactly what a known action compiles to are
+    /// returned as `raw` actions, so reversing never loses information.
+    pub fn from_instruction(governance: &Pubkey, instruction: &Instruction) -> Self {
+        Self::decode(governance, instruction)
+            .filter(|action| action.instruction(governance).ok().as_ref() == Some(instruction))
+            .unwrap_or_else(|| Self::Raw {
+                program_id: instruction.program_id,
+                accounts: instruction.accounts.iter().map(Into::into).collect(),
+                data: instruction.data.clone(),
+            })
+    }
+
+    /// Decodes the action an instruction appears to perform, without
+    /// checking its accounts.
+    fn decode(governance: &Pubkey, instruction: &Instruction) -> Option<Self> {
+        let treasury = get_treasury_address(governance, &paladin_governance_program::id());
+        let account = |index: usize| instruction.accounts.get(index).map(|meta| meta.pubkey);
+
+        if instruction.program_id == system_program::id() {
+            match bincode::deserialize(&instruction.data).ok()? {
+                SystemInstruction::Transfer { lamports } => Some(Self::SystemTransfer {
+                    to: account(1)?,
+                    lamports,
+                }),
+                _ => None,
+            }
+        } else if instruction.program_id == spl_token::id() {
+            match TokenInstruction::unpack(&instruction.data).ok()? {
+                TokenInstruction::TransferChecked { amount, decimals } => {
+                    let mint = account(1)?;
+                    let source = account(0)?;
+                    Some(Self::TokenTransfer {
+                        mint,
+                        destination: account(2)?,
+                        amount,
+                        decimals,
+                        source: (source != get_associated_token_address(&treasury, &mint))
+                            .then_some(source),
+                    })
+                }
+                _ => None,
+            }
+        } else if instruction.program_id == paladin_governance_program::id() {
+            match PaladinGovernanceInstruction::unpack(&instruction.data).ok()? {
+                PaladinGovernanceInstruction::UpdateGovernance {
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                } => Some(Self::UpdateGovernance {
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                }),
+                _ => None,
+            }
+        } else if instruction.program_id == bpf_loader_upgradeable::id()
+            && bpf_loader_upgradeable::is_upgrade_instruction(&instruction.data)
+        {
+            let spill = account(3)?;
+            Some(Self::ProgramUpgrade {
+                program: account(1)?,
+                buffer: account(2)?,
+                spill: (spill != treasury).then_some(spill),
+            })
+        } else {
+            None
+        }
+    }
+}
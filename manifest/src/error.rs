@@ -0,0 +1,20 @@
+//! Manifest error types.
+
+use {solana_program::program_error::ProgramError, thiserror::Error};
+
+/// Errors that can be returned while reading or compiling a manifest.
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    /// The TOML manifest could not be parsed.
+    #[error("Invalid TOML manifest: {0}")]
+    ParseToml(#[from] toml::de::Error),
+    /// The manifest could not be written as TOML.
+    #[error("Failed to write TOML manifest: {0}")]
+    WriteToml(#[from] toml::ser::Error),
+    /// The JSON manifest could not be parsed or written.
+    #[error("Invalid JSON manifest: {0}")]
+    Json(#[from] serde_json::Error),
+    /// An action could not be compiled into an instruction.
+    #[error("Invalid action: {0}")]
+    InvalidAction(#[from] ProgramError),
+}
@@ -0,0 +1,144 @@
+//! Declarative proposal manifests for the Paladin Governance program.
+//!
+//! A manifest lists the actions a proposal performs by name, with typed
+//! arguments, instead of raw instructions. It is compiled into the
+//! `PushPrerequisite` and `PushInstruction` instructions that build the
+//! proposal, with the governance treasury resolved automatically.
+//!
+//! ```toml
+//! governance = "..."
+//!
+//! [[actions]]
+//! type = "system-transfer"
+//! to = "..."
+//! lamports = 1000000000
+//! ```
+//!
+//! A stored `ProposalTransaction` can be reversed back into a manifest, with
+//! any instruction that isn't a known action kept as a `raw` action.
+
+mod action;
+mod error;
+
+pub use {action::*, error::ManifestError};
+use {
+    paladin_governance_program::{
+        instruction::{push_instruction, push_prerequisite},
+        state::{get_proposal_transaction_address, get_treasury_address, ProposalTransaction},
+    },
+    serde::{Deserialize, Serialize},
+    serde_with::{serde_as, DisplayFromStr},
+    solana_program::{instruction::Instruction, pubkey::Pubkey},
+};
+
+/// A proposal, described by its actions.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// The governance config the proposal is created for.
+    #[serde_as(as = "DisplayFromStr")]
+    pub governance: Pubkey,
+    /// Proposals that must be processed before this one can execute.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prerequisites: Vec<Pubkey>,
+    /// The actions to execute, in order.
+    #[serde(default)]
+    pub actions: Vec<Action>,
+}
+
+impl Manifest {
+    pub fn from_toml(manifest: &str) -> Result<Self, ManifestError> {
+        Ok(toml::from_str(manifest)?)
+    }
+
+    pub fn from_json(manifest: &str) -> Result<Self, ManifestError> {
+        Ok(serde_json::from_str(manifest)?)
+    }
+
+    pub fn to_toml(&self) -> Result<String, ManifestError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, ManifestError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// The address of the governance treasury, which signs for the actions.
+    pub fn treasury(&self) -> Pubkey {
+        get_treasury_address(&self.governance, &paladin_governance_program::id())
+    }
+
+    /// The instructions the proposal executes, in order.
+    pub fn instructions(&self) -> Result<Vec<Instruction>, ManifestError> {
+        self.actions
+            .iter()
+            .map(|action| action.instruction(&self.governance))
+            .collect()
+    }
+
+    /// The proposal transaction the manifest compiles to.
+    pub fn proposal_transaction(&self) -> Result<ProposalTransaction, ManifestError> {
+        Ok(ProposalTransaction {
+            instructions: self.instructions()?.iter().map(Into::into).collect(),
+            prerequisites: self.prerequisites.clone(),
+            options: vec![],
+        })
+    }
+
+    /// Compiles the manifest into the instructions that push its
+    /// prerequisites and actions to a draft proposal.
+    ///
+    /// The proposal transaction account must be funded for its new length
+    /// before each instruction; see `proposal_transaction` for its final
+    /// contents.
+    pub fn compile(
+        &self,
+        stake_authority: &Pubkey,
+        proposal: &Pubkey,
+    ) -> Result<Vec<Instruction>, ManifestError> {
+        let proposal_transaction =
+            get_proposal_transaction_address(proposal, &paladin_governance_program::id());
+
+        let prerequisites = self.prerequisites.iter().map(|prerequisite| {
+            push_prerequisite(
+                stake_authority,
+                proposal,
+                &proposal_transaction,
+                prerequisite,
+            )
+        });
+        let actions = self.instructions()?.into_iter().map(|instruction| {
+            push_instruction(
+                stake_authority,
+                proposal,
+                &proposal_transaction,
+                &instruction.program_id,
+                instruction.accounts.iter().map(Into::into).collect(),
+                instruction.data,
+            )
+        });
+
+        Ok(prerequisites.chain(actions).collect())
+    }
+
+    /// Reverses a stored proposal transaction into a manifest.
+    ///
+    /// Only the instructions of yes/no proposals are read; the options of a
+    /// multiple-choice proposal are not part of the manifest format.
+    pub fn from_proposal_transaction(
+        governance: Pubkey,
+        proposal_transaction: &ProposalTransaction,
+    ) -> Self {
+        Self {
+            governance,
+            prerequisites: proposal_transaction.prerequisites.clone(),
+            actions: proposal_transaction
+                .instructions
+                .iter()
+                .map(|instruction| Action::from_instruction(&governance, &instruction.into()))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use {
+    paladin_governance_manifest::{Action, Manifest, RawAccount},
+    paladin_governance_program::{
+        instruction::{update_governance, PaladinGovernanceInstruction},
+        state::{get_proposal_transaction_address, get_treasury_address},
+    },
+    solana_program::{
+        bpf_loader_upgradeable,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_instruction,
+    },
+    spl_associated_token_account_client::address::get_associated_token_address,
+};
+
+fn manifest() -> Manifest {
+    Manifest {
+        governance: Pubkey::new_unique(),
+        prerequisites: vec![Pubkey::new_unique()],
+        actions: vec![
+            Action::SystemTransfer {
+                to: Pubkey::new_unique(),
+                lamports: 1_000_000_000,
+            },
+            Action::TokenTransfer {
+                mint: Pubkey::new_unique(),
+                destination: Pubkey::new_unique(),
+                amount: 500,
+                decimals: 6,
+                source: None,
+            },
+            Action::TokenTransfer {
+                mint: Pubkey::new_unique(),
+                destination: Pubkey::new_unique(),
+                amount: 500,
+                decimals: 6,
+                source: Some(Pubkey::new_unique()),
+            },
+            Action::UpdateGovernance {
+                governance_id: 0,
+                cooldown_period_seconds: 100,
+                proposal_minimum_quorum: 100_000_000,
+                proposal_pass_threshold: 500_000_000,
+                voting_period_seconds: 200,
+                stake_per_proposal: 10,
+            },
+            Action::ProgramUpgrade {
+                program: Pubkey::new_unique(),
+                buffer: Pubkey::new_unique(),
+                spill: None,
+            },
+            Action::Raw {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![RawAccount {
+                    pubkey: Pubkey::new_unique(),
+                    signer: false,
+                    writable: true,
+                }],
+                data: vec![1, 2, 3],
+            },
+        ],
+    }
+}
+
+#[test]
+fn parse_toml() {
+    let governance = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+
+    let manifest = Manifest::from_toml(&format!(
+        r#"
+        governance = "{governance}"
+
+        [[actions]]
+        type = "system-transfer"
+        to = "{recipient}"
+        lamports = 1000
+
+        [[actions]]
+        type = "token-transfer"
+        mint = "{mint}"
+        destination = "{destination}"
+        amount = 5
+        decimals = 9
+
+        [[actions]]
+        type = "raw"
+        program_id = "{program_id}"
+        accounts = [{{ pubkey = "{recipient}", writable = true }}]
+        data = "0a0b"
+        "#
+    ))
+    .unwrap();
+
+    assert_eq!(
+        manifest,
+        Manifest {
+            governance,
+            prerequisites: vec![],
+            actions: vec![
+                Action::SystemTransfer {
+                    to: recipient,
+                    lamports: 1000,
+                },
+                Action::TokenTransfer {
+                    mint,
+                    destination,
+                    amount: 5,
+                    decimals: 9,
+                    source: None,
+                },
+                Action::Raw {
+                    program_id,
+                    accounts: vec![RawAccount {
+                        pubkey: recipient,
+                        signer: false,
+                        writable: true,
+                    }],
+                    data: vec![0x0a, 0x0b],
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn fail_parse_unknown_action() {
+    let manifest = format!(
+        r#"
+        governance = "{}"
+
+        [[actions]]
+        type = "mint-tokens"
+        "#,
+        Pubkey::new_unique()
+    );
+    assert!(Manifest::from_toml(&manifest).is_err());
+}
+
+#[test]
+fn toml_and_json_round_trip() {
+    let manifest = manifest();
+    assert_eq!(
+        Manifest::from_toml(&manifest.to_toml().unwrap()).unwrap(),
+        manifest
+    );
+    assert_eq!(
+        Manifest::from_json(&manifest.to_json().unwrap()).unwrap(),
+        manifest
+    );
+}
+
+#[test]
+fn instructions_resolve_treasury() {
+    let manifest = manifest();
+    let treasury = get_treasury_address(&manifest.governance, &paladin_governance_program::id());
+    assert_eq!(manifest.treasury(), treasury);
+
+    let instructions = manifest.instructions().unwrap();
+    assert_eq!(instructions.len(), manifest.actions.len());
+
+    let Action::SystemTransfer { to, lamports } = manifest.actions[0] else {
+        unreachable!()
+    };
+    assert_eq!(
+        instructions[0],
+        system_instruction::transfer(&treasury, &to, lamports)
+    );
+
+    let Action::TokenTransfer { mint, .. } = manifest.actions[1] else {
+        unreachable!()
+    };
+    assert_eq!(
+        instructions[1].accounts[0].pubkey,
+        get_associated_token_address(&treasury, &mint)
+    );
+    assert_eq!(
+        instructions[1].accounts[3],
+        AccountMeta::new_readonly(treasury, true)
+    );
+
+    assert_eq!(
+        instructions[3],
+        update_governance(
+            &treasury,
+            &manifest.governance,
+            0,
+            100,
+            100_000_000,
+            500_000_000,
+            200,
+            10
+        )
+    );
+
+    let Action::ProgramUpgrade {
+        program, buffer, ..
+    } = manifest.actions[4]
+    else {
+        unreachable!()
+    };
+    assert_eq!(
+        instructions[4],
+        bpf_loader_upgradeable::upgrade(&program, &buffer, &treasury, &treasury)
+    );
+}
+
+#[test]
+fn compile() {
+    let manifest = manifest();
+    let stake_authority = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let compiled = manifest.compile(&stake_authority, &proposal).unwrap();
+    assert_eq!(
+        compiled.len(),
+        manifest.prerequisites.len() + manifest.actions.len()
+    );
+
+    for instruction in &compiled {
+        assert_eq!(instruction.program_id, paladin_governance_program::id());
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(stake_authority, true),
+                AccountMeta::new_readonly(proposal, false),
+                AccountMeta::new(proposal_transaction, false),
+            ]
+        );
+    }
+
+    assert_eq!(
+        PaladinGovernanceInstruction::unpack(&compiled[0].data).unwrap(),
+        PaladinGovernanceInstruction::PushPrerequisite {
+            prerequisite_proposal: manifest.prerequisites[0],
+        }
+    );
+    for (push, instruction) in compiled[1..].iter().zip(manifest.instructions().unwrap()) {
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&push.data).unwrap(),
+            PaladinGovernanceInstruction::PushInstruction {
+                instruction_program_id: instruction.program_id,
+                instruction_account_metas: instruction.accounts.iter().map(Into::into).collect(),
+                instruction_data: instruction.data,
+            }
+        );
+    }
+}
+
+#[test]
+fn reverse_proposal_transaction() {
+    let manifest = manifest();
+    let proposal_transaction = manifest.proposal_transaction().unwrap();
+
+    assert_eq!(
+        Manifest::from_proposal_transaction(manifest.governance, &proposal_transaction),
+        manifest
+    );
+}
+
+#[test]
+fn reverse_unknown_instructions_as_raw() {
+    let governance = Pubkey::new_unique();
+    let not_treasury = Pubkey::new_unique();
+
+    // A transfer that isn't from the treasury.
+    let transfer = system_instruction::transfer(&not_treasury, &Pubkey::new_unique(), 1);
+    assert_eq!(
+        Action::from_instruction(&governance, &transfer),
+        Action::Raw {
+            program_id: transfer.program_id,
+            accounts: transfer.accounts.iter().map(Into::into).collect(),
+            data: transfer.data.clone(),
+        }
+    );
+
+    // An update of another governance config.
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let update = update_governance(&treasury, &Pubkey::new_unique(), 0, 1, 2, 3, 4, 5);
+    assert!(matches!(
+        Action::from_instruction(&governance, &update),
+        Action::Raw { .. }
+    ));
+
+    // Another system instruction.
+    let assign = system_instruction::assign(&treasury, &Pubkey::new_unique());
+    assert!(matches!(
+        Action::from_instruction(&governance, &assign),
+        Action::Raw { .. }
+    ));
+
+    let instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]);
+    assert_eq!(
+        Action::from_instruction(&governance, &instruction)
+            .instruction(&governance)
+            .unwrap(),
+        instruction
+    );
+}
@@ -0,0 +1,521 @@
+//! Proposal outcome rules, shared by the program and its clients.
+//!
+//! Votes and deadlines are evaluated from plain integers and timestamps, using
+//! only `core`, so clients can predict what `FinishVoting` will do with exactly
+//! the arithmetic the program uses.
+
+#![cfg_attr(not(test), no_std)]
+
+use core::num::NonZeroU64;
+
+/// Vote shares and thresholds are stored scaled by 1e9, to keep 9 decimal
+/// places of precision.
+pub const THRESHOLD_SCALING_FACTOR: u32 = 10u32.pow(9); // 1e9
+
+/// An intermediate calculation overflowed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArithmeticOverflow;
+
+/// Calculation: numerator / denominator
+///
+/// Scaled by 1e9 to store 9 decimal places of precision. Zero if the
+/// denominator is zero.
+fn scaled_ratio(numerator: u64, denominator: u64) -> Result<u32, ArithmeticOverflow> {
+    if denominator == 0 {
+        return Ok(0);
+    }
+
+    u128::from(numerator)
+        .checked_mul(u128::from(THRESHOLD_SCALING_FACTOR))
+        .and_then(|scaled_numerator| scaled_numerator.checked_div(u128::from(denominator)))
+        .and_then(|result| u32::try_from(result).ok())
+        .ok_or(ArithmeticOverflow)
+}
+
+/// Returns the share of the total stake voting `For` (in 1e9 scaled format).
+pub fn voter_turnout(stake_for: u64, total_stake: u64) -> Result<u32, ArithmeticOverflow> {
+    scaled_ratio(stake_for, total_stake)
+}
+
+/// Returns the share of the cast stake voting `For` (in 1e9 scaled format).
+pub fn for_percentage(stake_for: u64, stake_against: u64) -> Result<u32, ArithmeticOverflow> {
+    let cast_stake = stake_for
+        .checked_add(stake_against)
+        .ok_or(ArithmeticOverflow)?;
+    scaled_ratio(stake_for, cast_stake)
+}
+
+/// Returns the index of the option with the most stake, along with the share
+/// of the total stake (in 1e9 scaled format) by which it leads the runner-up.
+///
+/// Ties are resolved in favor of the lowest index, with a margin of zero.
+pub fn winning_option(option_stake: &[u64]) -> Result<(u8, u32), ArithmeticOverflow> {
+    let mut winner = 0;
+    let mut winner_stake = 0;
+    let mut runner_up_stake = 0;
+    let mut total_stake = 0u64;
+    for (index, &stake) in option_stake.iter().enumerate() {
+        total_stake = total_stake.checked_add(stake).ok_or(ArithmeticOverflow)?;
+        if index == 0 || stake > winner_stake {
+            runner_up_stake = winner_stake;
+            winner = index;
+            winner_stake = stake;
+        } else if stake > runner_up_stake {
+            runner_up_stake = stake;
+        }
+    }
+    let winner = u8::try_from(winner).map_err(|_| ArithmeticOverflow)?;
+
+    let margin = scaled_ratio(winner_stake.saturating_sub(runner_up_stake), total_stake)?;
+
+    Ok((winner, margin))
+}
+
+/// Total time governance has spent paused as of `now`, including an ongoing
+/// pause.
+pub fn total_paused_seconds(
+    paused_seconds: u64,
+    paused_timestamp: Option<NonZeroU64>,
+    now: u64,
+) -> u64 {
+    let ongoing = paused_timestamp.map_or(0, |paused_timestamp| {
+        now.saturating_sub(paused_timestamp.get())
+    });
+    paused_seconds.saturating_add(ongoing)
+}
+
+/// The votes on a proposal and the thresholds they are held to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Tally<'a> {
+    pub stake_for: u64,
+    pub stake_against: u64,
+    /// The stake of each option of a multiple-choice proposal, empty for a
+    /// yes/no proposal.
+    pub option_stake: &'a [u64],
+    /// The total effective stake eligible to vote.
+    pub total_stake: u64,
+    pub proposal_minimum_quorum: u32,
+    pub proposal_pass_threshold: u32,
+    /// The minimum lead of a multiple-choice proposal's winning option over
+    /// the runner-up.
+    pub proposal_winning_margin: u32,
+}
+
+/// The result of counting a proposal's votes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Verdict {
+    pub passed: bool,
+    /// The option with the most stake, for a multiple-choice proposal.
+    pub winning_option: Option<u8>,
+}
+
+impl Tally<'_> {
+    pub fn is_multiple_choice(&self) -> bool {
+        !self.option_stake.is_empty()
+    }
+
+    /// Whether the stake voting `For` meets the minimum quorum.
+    pub fn reached_quorum(&self) -> Result<bool, ArithmeticOverflow> {
+        Ok(voter_turnout(self.stake_for, self.total_stake)? >= self.proposal_minimum_quorum)
+    }
+
+    /// Counts the votes.
+    ///
+    /// The proposal passes if it reached quorum and its share of `For` stake
+    /// meets the pass threshold or, for a multiple-choice proposal, its winning
+    /// option strictly leads the runner-up by at least the winning margin.
+    pub fn verdict(&self) -> Result<Verdict, ArithmeticOverflow> {
+        let reached_quorum = self.reached_quorum()?;
+        let (passed, winning_option) = if self.is_multiple_choice() {
+            let (winning_option, margin) = winning_option(self.option_stake)?;
+            (
+                margin > 0 && margin >= self.proposal_winning_margin,
+                Some(winning_option),
+            )
+        } else {
+            (
+                for_percentage(self.stake_for, self.stake_against)? >= self.proposal_pass_threshold,
+                None,
+            )
+        };
+
+        Ok(Verdict {
+            passed: reached_quorum && passed,
+            winning_option,
+        })
+    }
+
+    /// The least additional `For` stake with which the proposal would pass,
+    /// zero if it already does, or `None` if the stake that has not voted yet
+    /// is not enough.
+    ///
+    /// For a multiple-choice proposal, `For` stake only counts toward quorum,
+    /// so this is the stake needed to reach it.
+    pub fn for_stake_needed(&self) -> Option<u64> {
+        let uncast_stake = self
+            .total_stake
+            .saturating_sub(self.stake_for)
+            .saturating_sub(self.stake_against);
+        if !self.passes_with(uncast_stake) {
+            return None;
+        }
+
+        // Passing is monotonic in `For` stake, so search for the least amount.
+        let (mut low, mut high) = (0, uncast_stake);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.passes_with(mid) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        Some(low)
+    }
+
+    fn passes_with(&self, additional_stake_for: u64) -> bool {
+        self.stake_for
+            .checked_add(additional_stake_for)
+            .is_some_and(|stake_for| {
+                Tally { stake_for, ..*self }
+                    .verdict()
+                    .is_ok_and(|verdict| verdict.passed)
+            })
+    }
+}
+
+/// A proposal's voting and cooldown periods.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timeline {
+    pub voting_start_timestamp: Option<NonZeroU64>,
+    pub voting_period_seconds: u64,
+    /// Set once the proposal reaches quorum.
+    pub cooldown_timestamp: Option<NonZeroU64>,
+    pub cooldown_period_seconds: u64,
+}
+
+impl Timeline {
+    /// When voting ends, if it has begun.
+    pub fn voting_ends_at(&self) -> Option<u64> {
+        period_end(self.voting_start_timestamp, self.voting_period_seconds)
+    }
+
+    /// When the cooldown ends, if it has begun.
+    pub fn cooldown_ends_at(&self) -> Option<u64> {
+        period_end(self.cooldown_timestamp, self.cooldown_period_seconds)
+    }
+
+    pub fn voting_has_ended(&self, now: u64) -> bool {
+        self.voting_ends_at().is_some_and(|end| now >= end)
+    }
+
+    pub fn cooldown_has_ended(&self, now: u64) -> bool {
+        self.cooldown_ends_at().is_some_and(|end| now >= end)
+    }
+
+    /// Extends both periods by shifting their start timestamps forward.
+    pub fn extend(&mut self, seconds: u64) {
+        self.voting_start_timestamp = self
+            .voting_start_timestamp
+            .map(|timestamp| timestamp.saturating_add(seconds));
+        self.cooldown_timestamp = self
+            .cooldown_timestamp
+            .map(|timestamp| timestamp.saturating_add(seconds));
+    }
+}
+
+fn period_end(start: Option<NonZeroU64>, period_seconds: u64) -> Option<u64> {
+    start.and_then(|start| start.get().checked_add(period_seconds))
+}
+
+/// What finishing voting on a proposal does at a given time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resolution {
+    /// Voting is still open, so the proposal cannot be finished yet.
+    StillVoting,
+    /// The cooldown ended with the proposal passing.
+    Passed { winning_option: Option<u8> },
+    /// The cooldown ended with the proposal failing, or voting ended without
+    /// the proposal ever reaching quorum.
+    Failed { winning_option: Option<u8> },
+}
+
+impl Resolution {
+    /// The option with the most stake, once a multiple-choice proposal's
+    /// cooldown has ended.
+    pub fn winning_option(&self) -> Option<u8> {
+        match self {
+            Self::StillVoting => None,
+            Self::Passed { winning_option } | Self::Failed { winning_option } => *winning_option,
+        }
+    }
+}
+
+/// Resolves a proposal finished at `now`.
+///
+/// The timeline must already be extended by any time governance spent
+/// paused.
+pub fn resolve(
+    tally: &Tally,
+    timeline: &Timeline,
+    now: u64,
+) -> Result<Resolution, ArithmeticOverflow> {
+    if timeline.cooldown_timestamp.is_some() {
+        if !timeline.cooldown_has_ended(now) {
+            return Ok(Resolution::StillVoting);
+        }
+        let verdict = tally.verdict()?;
+        Ok(if verdict.passed {
+            Resolution::Passed {
+                winning_option: verdict.winning_option,
+            }
+        } else {
+            Resolution::Failed {
+                winning_option: verdict.winning_option,
+            }
+        })
+    } else if timeline.voting_has_ended(now) {
+        Ok(Resolution::Failed {
+            winning_option: None,
+        })
+    } else {
+        Ok(Resolution::StillVoting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, proptest::prelude::*};
+
+    // Ensures the (intermediate) stake value is never greater than the total
+    // stake value, within the range 0 to the max value provided.
+    prop_compose! {
+        fn total_and_intermediate(max_value: u64)(total in 0..=max_value)
+                        (intermediate in 0..=total, total in Just(total))
+                        -> (u64, u64) {
+           (intermediate, total)
+       }
+    }
+
+    // Votes cast never exceed the total stake.
+    prop_compose! {
+        fn votes(max_value: u64)((stake_for, total) in total_and_intermediate(max_value))
+                        (stake_against in 0..=total - stake_for, stake_for in Just(stake_for), total in Just(total))
+                        -> (u64, u64, u64) {
+           (stake_for, stake_against, total)
+       }
+    }
+
+    proptest! {
+        #[test]
+        fn test_calculate_proposal_vote_threshold(
+            (stake, total_stake) in total_and_intermediate(u64::MAX)
+        ) {
+            // Calculate.
+            //
+            // Since we've configured limits on the input values, we can safely
+            // unwrap the result.
+            let result = voter_turnout(stake, total_stake).unwrap();
+            // Evaluate.
+            if total_stake == 0 {
+                prop_assert_eq!(result, 0);
+            } else {
+                // The scaling multiplication and subsequent division should
+                // always succeed, thanks to the limits on the input values.
+                let scaled_stake_ratio = u128::from(stake)
+                    .checked_mul(u128::from(THRESHOLD_SCALING_FACTOR))
+                    .and_then(|scaled_stake| scaled_stake.checked_div(total_stake as u128))
+                    .unwrap();
+
+                // Since a failure to convert to `u32` can only occur if the
+                // stake is greater than the total stake, which is not possible
+                // thanks to our inputs, we can safely unwrap here.
+                let expected = u32::try_from(scaled_stake_ratio).unwrap();
+
+                // The result should be the expected value.
+                prop_assert_eq!(result, expected);
+            }
+        }
+
+        #[test]
+        fn test_for_stake_needed_is_least_passing_stake(
+            (stake_for, stake_against, total_stake) in votes(u64::MAX / 4),
+            proposal_minimum_quorum in (THRESHOLD_SCALING_FACTOR / 1000)..THRESHOLD_SCALING_FACTOR,
+            proposal_pass_threshold in (THRESHOLD_SCALING_FACTOR / 10)..THRESHOLD_SCALING_FACTOR,
+        ) {
+            let tally = Tally {
+                stake_for,
+                stake_against,
+                option_stake: &[],
+                total_stake,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                proposal_winning_margin: 0,
+            };
+            let passes_with = |additional: u64| {
+                Tally {
+                    stake_for: stake_for + additional,
+                    ..tally
+                }
+                .verdict()
+                .unwrap()
+                .passed
+            };
+
+            match tally.for_stake_needed() {
+                Some(needed) => {
+                    prop_assert!(passes_with(needed));
+                    if needed > 0 {
+                        prop_assert!(!passes_with(needed - 1));
+                    }
+                }
+                // Not even every remaining staker voting `For` is enough.
+                None => prop_assert!(!passes_with(total_stake - stake_for - stake_against)),
+            }
+        }
+
+        #[test]
+        fn test_period_end_matches_has_ended(
+            start in 1..=u64::MAX,
+            period_seconds: u64,
+            now: u64,
+        ) {
+            let timeline = Timeline {
+                voting_start_timestamp: NonZeroU64::new(start),
+                voting_period_seconds: period_seconds,
+                cooldown_timestamp: NonZeroU64::new(start),
+                cooldown_period_seconds: period_seconds,
+            };
+
+            // The original rule: the period has ended once at least
+            // `period_seconds` have passed since it started.
+            let expected = now.saturating_sub(period_seconds) >= start;
+            prop_assert_eq!(timeline.voting_has_ended(now), expected);
+            prop_assert_eq!(timeline.cooldown_has_ended(now), expected);
+            if let Some(end) = timeline.cooldown_ends_at() {
+                prop_assert!(timeline.cooldown_has_ended(end));
+                prop_assert!(!timeline.cooldown_has_ended(end - 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_winning_option() {
+        // No votes.
+        assert_eq!(winning_option(&[0, 0, 0]).unwrap(), (0, 0));
+
+        // Plurality winner leads the runner-up by 20% of the cast votes.
+        assert_eq!(
+            winning_option(&[30, 50, 20]).unwrap(),
+            (1, THRESHOLD_SCALING_FACTOR / 5)
+        );
+
+        // Ties resolve to the lowest index with no margin.
+        assert_eq!(winning_option(&[10, 40, 40]).unwrap(), (1, 0));
+
+        // Unanimous.
+        assert_eq!(
+            winning_option(&[0, 0, 25]).unwrap(),
+            (2, THRESHOLD_SCALING_FACTOR)
+        );
+    }
+
+    #[test]
+    fn test_multiple_choice_verdict() {
+        let tally = Tally {
+            stake_for: 100,
+            stake_against: 0,
+            option_stake: &[30, 50, 20],
+            total_stake: 100,
+            proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR / 2,
+            proposal_pass_threshold: THRESHOLD_SCALING_FACTOR / 2,
+            proposal_winning_margin: THRESHOLD_SCALING_FACTOR / 5,
+        };
+        let passed = |tally: Tally| tally.verdict().unwrap().passed;
+
+        // A 20% lead meets a 20% margin, regardless of the pass threshold.
+        assert_eq!(
+            tally.verdict().unwrap(),
+            Verdict {
+                passed: true,
+                winning_option: Some(1),
+            }
+        );
+
+        // But falls short of a higher margin.
+        assert!(!passed(Tally {
+            proposal_winning_margin: THRESHOLD_SCALING_FACTOR / 5 + 1,
+            ..tally
+        }));
+
+        // Without a margin, a plurality wins but a tie never does.
+        assert!(passed(Tally {
+            proposal_winning_margin: 0,
+            ..tally
+        }));
+        assert!(!passed(Tally {
+            option_stake: &[10, 45, 45],
+            proposal_winning_margin: 0,
+            ..tally
+        }));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let tally = Tally {
+            stake_for: 60,
+            stake_against: 40,
+            option_stake: &[],
+            total_stake: 100,
+            proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR / 2,
+            proposal_pass_threshold: THRESHOLD_SCALING_FACTOR / 2,
+            proposal_winning_margin: 0,
+        };
+        let timeline = Timeline {
+            voting_start_timestamp: NonZeroU64::new(100),
+            voting_period_seconds: 50,
+            cooldown_timestamp: NonZeroU64::new(120),
+            cooldown_period_seconds: 10,
+        };
+
+        // Cooldown still running.
+        assert_eq!(
+            resolve(&tally, &timeline, 129).unwrap(),
+            Resolution::StillVoting
+        );
+        // Cooldown ended, passing.
+        assert_eq!(
+            resolve(&tally, &timeline, 130).unwrap(),
+            Resolution::Passed {
+                winning_option: None
+            }
+        );
+        // Cooldown ended, below the pass threshold.
+        let against = Tally {
+            stake_against: 70,
+            ..tally
+        };
+        assert_eq!(
+            resolve(&against, &timeline, 130).unwrap(),
+            Resolution::Failed {
+                winning_option: None
+            }
+        );
+
+        // Without a cooldown, the proposal fails once voting ends.
+        let timeline = Timeline {
+            cooldown_timestamp: None,
+            ..timeline
+        };
+        assert_eq!(
+            resolve(&tally, &timeline, 149).unwrap(),
+            Resolution::StillVoting
+        );
+        assert_eq!(
+            resolve(&tally, &timeline, 150).unwrap(),
+            Resolution::Failed {
+                winning_option: None
+            }
+        );
+    }
+}
@@ -66,6 +66,66 @@ pub enum PaladinGovernanceError {
     /// Cooldown period is not over yet, cannot create new proposal.
     #[error("Cooldown period is not over yet, cannot create new proposal.")]
     CooldownPeriodNotOver,
+    /// Proposal cooldown period has already begun.
+    #[error("Proposal cooldown period has already begun.")]
+    ProposalCooldownStarted,
+    /// Invalid prerequisite proposal.
+    #[error("Invalid prerequisite proposal.")]
+    InvalidPrerequisite,
+    /// Incorrect prerequisite proposal address.
+    #[error("Incorrect prerequisite proposal address.")]
+    IncorrectPrerequisiteAddress,
+    /// Prerequisite proposal has not been processed.
+    #[error("Prerequisite proposal has not been processed.")]
+    PrerequisiteNotProcessed,
+    /// Proposal has too many options.
+    #[error("Proposal has too many options.")]
+    TooManyProposalOptions,
+    /// Invalid proposal option.
+    #[error("Invalid proposal option.")]
+    InvalidProposalOption,
+    /// Proposal is not executable.
+    #[error("Proposal is not executable.")]
+    ProposalNotExecutable,
+    /// Author has active proposals.
+    #[error("Author has active proposals.")]
+    AuthorHasActiveProposals,
+    /// Incorrect program data address.
+    #[error("Incorrect program data address.")]
+    IncorrectProgramDataAddress,
+    /// Proposal minimum quorum out of bounds.
+    #[error("Proposal minimum quorum out of bounds.")]
+    InvalidProposalMinimumQuorum,
+    /// Proposal pass threshold out of bounds.
+    #[error("Proposal pass threshold out of bounds.")]
+    InvalidProposalPassThreshold,
+    /// Voting period out of bounds.
+    #[error("Voting period out of bounds.")]
+    InvalidVotingPeriod,
+    /// Cooldown period out of bounds.
+    #[error("Cooldown period out of bounds.")]
+    InvalidCooldownPeriod,
+    /// Account must be migrated to the current layout.
+    #[error("Account must be migrated to the current layout.")]
+    AccountNotMigrated,
+    /// Governance is paused.
+    #[error("Governance is paused.")]
+    GovernancePaused,
+    /// Governance config has been retired.
+    #[error("Governance config has been retired.")]
+    GovernanceRetired,
+    /// Incorrect treasury token account.
+    #[error("Incorrect treasury token account.")]
+    IncorrectTreasuryTokenAccount,
+    /// Pause guardian not set.
+    #[error("Pause guardian not set.")]
+    PauseGuardianNotSet,
+    /// Prerequisite proposal was deleted, rejected or cancelled.
+    #[error("Prerequisite proposal was deleted, rejected or cancelled.")]
+    PrerequisiteClosed,
+    /// Invalid proposal winning margin.
+    #[error("Invalid proposal winning margin.")]
+    InvalidProposalWinningMargin,
 }
 
 impl PrintProgramError for PaladinGovernanceError {
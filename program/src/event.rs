@@ -0,0 +1,140 @@
+//! Program events.
+//!
+//! Every state transition logs a [`GovernanceEvent`] with `sol_log_data`, so
+//! indexers can follow governance from transaction logs instead of diffing
+//! accounts. Each event is logged as two fields: the [`EVENT_VERSION`] byte,
+//! and the borsh-encoded event.
+//!
+//! New events are only ever appended, so decoders of a version can skip the
+//! events they don't know. The version changes if an existing event does.
+
+use {
+    crate::state::{GovernanceConfig, ProposalKind, ProposalStatus, ProposalVoteElection},
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        clock::UnixTimestamp, entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey,
+    },
+};
+
+/// The version of the event encoding, logged before each event.
+pub const EVENT_VERSION: u8 = 1;
+
+/// A governance state transition.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum GovernanceEvent {
+    /// A proposal was created, in draft.
+    ProposalCreated {
+        proposal: Pubkey,
+        author: Pubkey,
+        governance_config: Pubkey,
+        kind: ProposalKind,
+        creation_timestamp: UnixTimestamp,
+    },
+    /// An instruction was pushed to a proposal transaction.
+    InstructionPushed {
+        proposal: Pubkey,
+        /// The option of a multiple-choice proposal the instruction was
+        /// pushed to.
+        option_index: Option<u8>,
+        instruction_index: u32,
+        program_id: Pubkey,
+    },
+    /// A proposal left draft and opened for voting.
+    VotingBegan {
+        proposal: Pubkey,
+        voting_start_timestamp: u64,
+    },
+    /// A vote was cast, with the resulting tally of the proposal.
+    VoteCast {
+        proposal: Pubkey,
+        vote: Pubkey,
+        authority: Pubkey,
+        election: ProposalVoteElection,
+        option_index: Option<u8>,
+        stake: u64,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    /// A vote was switched, or updated to the voter's current stake, with
+    /// the resulting tally of the proposal.
+    VoteSwitched {
+        proposal: Pubkey,
+        vote: Pubkey,
+        authority: Pubkey,
+        previous_election: ProposalVoteElection,
+        previous_option_index: Option<u8>,
+        previous_stake: u64,
+        election: ProposalVoteElection,
+        option_index: Option<u8>,
+        stake: u64,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    /// A proposal reached quorum, starting its cooldown period.
+    CooldownStarted {
+        proposal: Pubkey,
+        cooldown_timestamp: u64,
+    },
+    /// Voting on a proposal closed, either because its outcome was decided
+    /// or because its author cancelled it.
+    ProposalFinalized {
+        proposal: Pubkey,
+        status: ProposalStatus,
+        /// The winning option of a multiple-choice proposal.
+        winning_option: Option<u8>,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    /// An instruction of an accepted proposal was executed.
+    InstructionExecuted {
+        proposal: Pubkey,
+        option_index: Option<u8>,
+        instruction_index: u32,
+        /// The status of the proposal after the instruction, `Processed` once
+        /// its last instruction was executed.
+        status: ProposalStatus,
+    },
+    /// A governance config was initialized or its parameters were updated,
+    /// with the resulting parameters.
+    GovernanceUpdated {
+        governance_config: Pubkey,
+        cooldown_period_seconds: u64,
+        proposal_minimum_quorum: u32,
+        proposal_pass_threshold: u32,
+        voting_period_seconds: u64,
+        stake_per_proposal: u64,
+        pause_guardian: Pubkey,
+        proposal_winning_margin: u32,
+    },
+}
+
+impl GovernanceEvent {
+    /// The event for a governance config's current parameters.
+    pub fn governance_updated(config: &GovernanceConfig) -> Self {
+        Self::GovernanceUpdated {
+            governance_config: config.governance_config,
+            cooldown_period_seconds: config.cooldown_period_seconds,
+            proposal_minimum_quorum: config.proposal_minimum_quorum,
+            proposal_pass_threshold: config.proposal_pass_threshold,
+            voting_period_seconds: config.voting_period_seconds,
+            stake_per_proposal: config.stake_per_proposal,
+            pause_guardian: config.pause_guardian,
+            proposal_winning_margin: config.proposal_winning_margin,
+        }
+    }
+
+    /// Logs the event.
+    pub fn emit(&self) -> ProgramResult {
+        sol_log_data(&[&[EVENT_VERSION], &borsh::to_vec(self)?]);
+        Ok(())
+    }
+
+    /// Decodes an event from the fields of a `sol_log_data` log, if they hold
+    /// an event of this version.
+    pub fn from_log_data(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [[EVENT_VERSION], data] => Self::try_from_slice(data).ok(),
+            _ => None,
+        }
+    }
+}
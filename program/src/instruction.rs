@@ -6,6 +6,7 @@ use {
     borsh::{BorshDeserialize, BorshSerialize},
     shank::ShankInstruction,
     solana_program::{
+        bpf_loader_upgradeable::get_program_data_address,
         instruction::{AccountMeta, Instruction},
         program_error::ProgramError,
         pubkey::Pubkey,
@@ -17,7 +18,17 @@ use {
 #[rustfmt::skip]
 #[derive(Clone, Debug, PartialEq, ShankInstruction)]
 pub enum PaladinGovernanceInstruction {
-    #[account(0, name = "stake_authority")]
+    /// Initialize the author account of a stake authority.
+    ///
+    /// Expects an uninitialized author account with enough rent-exempt
+    /// lamports to store author state.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[w]` Author account.
+    /// 2. `[ ]` System program.
+    #[account(0, name = "stake_authority", signer)]
     #[account(1, name = "author", writable)]
     #[account(2, name = "system_program")]
     InitializeAuthor,
@@ -121,6 +132,8 @@ pub enum PaladinGovernanceInstruction {
     /// 0. `[s,w]` Paladin stake authority account.
     /// 1. `[w]` Author account.
     /// 2. `[w]` Proposal account.
+    /// 3. `[w]` Proposal transaction account, left untouched for signal
+    ///    proposals, which never create one.
     #[account(
         0,
         signer,
@@ -155,6 +168,7 @@ pub enum PaladinGovernanceInstruction {
     ///
     /// 0. `[s]` Paladin stake authority account.
     /// 1. `[w]` Proposal account.
+    /// 2. `[ ]` Governance config account.
     #[account(
         0,
         signer,
@@ -167,6 +181,11 @@ pub enum PaladinGovernanceInstruction {
         name = "proposal",
         description = "Proposal account"
     )]
+    #[account(
+        2,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
     BeginVoting,
     /// Vote on a governance proposal.
     ///
@@ -180,7 +199,8 @@ pub enum PaladinGovernanceInstruction {
     /// 2. `[ ]` Paladin stake config account.
     /// 3. `[w]` Proposal vote account.
     /// 4. `[w]` Proposal account.
-    /// 5. `[ ]` System program.
+    /// 5. `[ ]` Governance config account.
+    /// 6. `[ ]` System program.
     #[account(
         0,
         signer,
@@ -211,6 +231,11 @@ pub enum PaladinGovernanceInstruction {
     )]
     #[account(
         5,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    #[account(
+        6,
         name = "system_program",
         description = "System program"
     )]
@@ -235,6 +260,7 @@ pub enum PaladinGovernanceInstruction {
     /// 2. `[ ]` Paladin stake config account.
     /// 3. `[w]` Proposal vote account.
     /// 4. `[w]` Proposal account.
+    /// 5. `[ ]` Governance config account.
     #[account(
         0,
         signer,
@@ -263,6 +289,11 @@ pub enum PaladinGovernanceInstruction {
         name = "proposal",
         description = "Proposal account"
     )]
+    #[account(
+        5,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
     SwitchVote {
         /// New proposal vote election.
         new_election: ProposalVoteElection,
@@ -280,10 +311,14 @@ pub enum PaladinGovernanceInstruction {
     /// This way, accepted or expired proposals can be finalized without the
     /// need for an additional vote or vote switch to be cast.
     ///
+    /// Time governance spends paused while the proposal is voting extends
+    /// its voting and cooldown periods.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[ ]` Paladin stake config account.
     /// 1. `[w]` Proposal account.
+    /// 2. `[ ]` Governance config account.
     #[account(
         0,
         name = "stake_config",
@@ -295,6 +330,11 @@ pub enum PaladinGovernanceInstruction {
         name = "proposal",
         description = "Proposal account"
     )]
+    #[account(
+        2,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
     FinishVoting,
     /// Deletes a vote account and recovers its rent once the proposal has
     /// been finalized.
@@ -311,13 +351,17 @@ pub enum PaladinGovernanceInstruction {
     /// instruction as executed.
     ///
     /// Note: Returns an error if the previous instruction in this proposal has
-    /// not been executed.
+    /// not been executed, or if any prerequisite proposal has not been
+    /// processed. Prerequisites are only checked until the first instruction
+    /// has been executed.
     ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[ ]` Proposal account.
     /// 1. `[w]` Proposal transaction account.
-    /// 2..N.    Instruction accounts.
+    /// 2. `[ ]` Governance config account.
+    /// 3..3+P.  Prerequisite proposal accounts, in the order they were pushed.
+    /// 3+P..N.  Instruction accounts.
     #[account(
         0,
         writable,
@@ -330,6 +374,11 @@ pub enum PaladinGovernanceInstruction {
         name = "proposal_transaction",
         description = "Proposal transaction account"
     )]
+    #[account(
+        2,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
     ProcessInstruction {
         /// The index of the instruction to execute.
         instruction_index: u32,
@@ -342,13 +391,17 @@ pub enum PaladinGovernanceInstruction {
     /// * The cooldown period for proposal execution.
     /// * Minimum required majority threshold.
     ///
-    /// This instruction can only be invoked once.
+    /// This instruction can only be invoked once, and must be signed by
+    /// either the Paladin stake config authority or the upgrade authority
+    /// of this program.
     ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Governance config account.
     /// 1. `[ ]` Paladin stake config account.
     /// 2. `[ ]` System program.
+    /// 3. `[s]` Stake config authority or program upgrade authority.
+    /// 4. `[ ]` Program data account.
     #[account(
         0,
         writable,
@@ -365,6 +418,17 @@ pub enum PaladinGovernanceInstruction {
         name = "system_program",
         description = "System program"
     )]
+    #[account(
+        3,
+        signer,
+        name = "authority",
+        description = "Stake config authority or program upgrade authority"
+    )]
+    #[account(
+        4,
+        name = "program_data",
+        description = "Program data account"
+    )]
     InitializeGovernance {
         governance_id: u64,
         cooldown_period_seconds: u64,
@@ -407,281 +471,1184 @@ pub enum PaladinGovernanceInstruction {
         voting_period_seconds: u64,
         stake_per_proposal: u64,
     },
-}
-
-impl PaladinGovernanceInstruction {
-    /// Packs a
-    /// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html)
-    /// into a byte buffer.
-    pub fn pack(&self) -> Vec<u8> {
-        match self {
-            Self::InitializeAuthor => vec![0],
-            Self::CreateProposal => vec![1],
-            Self::PushInstruction {
-                instruction_program_id,
-                instruction_account_metas,
-                instruction_data,
-            } => {
-                let mut buf = vec![2];
-                instruction_program_id.serialize(&mut buf).unwrap();
-                instruction_account_metas.serialize(&mut buf).unwrap();
-                instruction_data.serialize(&mut buf).unwrap();
-                buf
-            }
-            Self::DeleteProposal => vec![3],
-            Self::BeginVoting => vec![4],
-            Self::Vote { election } => vec![5, (*election).into()],
-            Self::SwitchVote { new_election } => vec![6, (*new_election).into()],
-            Self::FinishVoting => vec![7],
-            Self::DeleteVote => vec![8],
-            Self::ProcessInstruction { instruction_index } => {
-                let mut buf = vec![9];
-                buf.extend_from_slice(&instruction_index.to_le_bytes());
-                buf
-            }
-            Self::InitializeGovernance {
-                governance_id,
-                cooldown_period_seconds,
-                proposal_minimum_quorum,
-                proposal_pass_threshold,
-                voting_period_seconds,
-                stake_per_proposal,
-                cooldown_seconds,
-            } => {
-                let mut buf = vec![10];
-                buf.extend_from_slice(&governance_id.to_le_bytes());
-                buf.extend_from_slice(&cooldown_period_seconds.to_le_bytes());
-                buf.extend_from_slice(&proposal_minimum_quorum.to_le_bytes());
-                buf.extend_from_slice(&proposal_pass_threshold.to_le_bytes());
-                buf.extend_from_slice(&voting_period_seconds.to_le_bytes());
-                buf.extend_from_slice(&stake_per_proposal.to_le_bytes());
-                buf.extend_from_slice(&cooldown_seconds.to_le_bytes());
-                buf
-            }
-            Self::UpdateGovernance {
-                governance_id,
-                cooldown_period_seconds,
-                proposal_minimum_quorum,
-                proposal_pass_threshold,
-                voting_period_seconds,
-                stake_per_proposal,
-            } => {
-                let mut buf = vec![11];
-                buf.extend_from_slice(&governance_id.to_le_bytes());
-                buf.extend_from_slice(&cooldown_period_seconds.to_le_bytes());
-                buf.extend_from_slice(&proposal_minimum_quorum.to_le_bytes());
-                buf.extend_from_slice(&proposal_pass_threshold.to_le_bytes());
-                buf.extend_from_slice(&voting_period_seconds.to_le_bytes());
-                buf.extend_from_slice(&stake_per_proposal.to_le_bytes());
-                buf
-            }
-        }
-    }
-
-    /// Unpacks a byte buffer into a
-    /// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html).
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        match input.split_first() {
-            Some((&0, _)) => Ok(Self::InitializeAuthor),
-            Some((&1, _)) => Ok(Self::CreateProposal),
-            Some((&2, rest)) => {
-                #[derive(BorshDeserialize)]
-                struct Instruction {
-                    instruction_program_id: Pubkey,
-                    instruction_account_metas: Vec<ProposalAccountMeta>,
-                    instruction_data: Vec<u8>,
-                }
-                let Instruction {
-                    instruction_program_id,
-                    instruction_account_metas,
-                    instruction_data,
-                } = Instruction::try_from_slice(rest)
-                    .map_err(|_| ProgramError::InvalidInstructionData)?;
-                Ok(Self::PushInstruction {
-                    instruction_program_id,
-                    instruction_account_metas,
-                    instruction_data,
-                })
-            }
-            Some((&3, _)) => Ok(Self::DeleteProposal),
-            Some((&4, _)) => Ok(Self::BeginVoting),
-            Some((&5, rest)) if rest.len() == 1 => {
-                let election = rest[0]
-                    .try_into()
-                    .map_err(|_| ProgramError::InvalidInstructionData)?;
-                Ok(Self::Vote { election })
-            }
-            Some((&6, rest)) if rest.len() == 1 => {
-                let new_election = rest[0]
-                    .try_into()
-                    .map_err(|_| ProgramError::InvalidInstructionData)?;
-                Ok(Self::SwitchVote { new_election })
-            }
-            Some((&7, _)) => Ok(Self::FinishVoting),
-            Some((&8, _)) => Ok(Self::DeleteVote),
-            Some((&9, rest)) if rest.len() == 4 => {
-                let instruction_index = u32::from_le_bytes(rest.try_into().unwrap());
-                Ok(Self::ProcessInstruction { instruction_index })
-            }
-            Some((&10, rest)) if rest.len() == 48 => {
-                let rest = array_ref![rest, 0, 48];
-                let (
-                    governance_id,
-                    cooldown_period_seconds,
-                    proposal_minimum_quorum,
-                    proposal_pass_threshold,
-                    voting_period_seconds,
-                    stake_per_proposal,
-                    cooldown_seconds,
-                ) = array_refs![rest, 8, 8, 4, 4, 8, 8, 8];
-
-                let governance_id = u64::from_le_bytes(*governance_id);
-                let cooldown_period_seconds = u64::from_le_bytes(*cooldown_period_seconds);
-                let proposal_minimum_quorum = u32::from_le_bytes(*proposal_minimum_quorum);
-                let proposal_pass_threshold = u32::from_le_bytes(*proposal_pass_threshold);
-                let voting_period_seconds = u64::from_le_bytes(*voting_period_seconds);
-                let stake_per_proposal = u64::from_le_bytes(*stake_per_proposal);
-                let cooldown_seconds = u64::from_le_bytes(*cooldown_seconds);
-
-                Ok(Self::InitializeGovernance {
-                    governance_id,
-                    cooldown_period_seconds,
-                    proposal_minimum_quorum,
-                    proposal_pass_threshold,
-                    voting_period_seconds,
-                    stake_per_proposal,
-                    cooldown_seconds,
-                })
-            }
-            Some((&11, rest)) if rest.len() == 40 => {
-                let rest = array_ref![rest, 0, 40];
-                let (
-                    governance_id,
-                    cooldown_period_seconds,
-                    proposal_minimum_quorum,
-                    proposal_pass_threshold,
-                    voting_period_seconds,
-                    stake_per_proposal,
-                ) = array_refs![rest, 8, 8, 4, 4, 8, 8];
-
-                let governance_id = u64::from_le_bytes(*governance_id);
-                let cooldown_period_seconds = u64::from_le_bytes(*cooldown_period_seconds);
-                let proposal_minimum_quorum = u32::from_le_bytes(*proposal_minimum_quorum);
-                let proposal_pass_threshold = u32::from_le_bytes(*proposal_pass_threshold);
-                let voting_period_seconds = u64::from_le_bytes(*voting_period_seconds);
-                let stake_per_proposal = u64::from_le_bytes(*stake_per_proposal);
-
-                Ok(Self::UpdateGovernance {
-                    governance_id,
-                    cooldown_period_seconds,
-                    proposal_minimum_quorum,
-                    proposal_pass_threshold,
-                    voting_period_seconds,
-                    stake_per_proposal,
-                })
-            }
-            _ => Err(ProgramError::InvalidInstructionData),
-        }
-    }
-}
-
-/// Creates a
-/// [CreateProposal](enum.PaladinGovernanceInstruction.html)
-/// instruction.
-pub fn create_proposal(
-    stake_authority_address: &Pubkey,
-    stake_address: &Pubkey,
-    proposal_address: &Pubkey,
-    proposal_transaction_address: &Pubkey,
-    governance_config_address: &Pubkey,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new_readonly(*stake_authority_address, true),
-        AccountMeta::new(
-            crate::state::get_proposal_author_address(stake_authority_address, &crate::id()),
-            false,
-        ),
-        AccountMeta::new_readonly(*stake_address, false),
-        AccountMeta::new(*proposal_address, false),
-        AccountMeta::new(*proposal_transaction_address, false),
-        AccountMeta::new_readonly(*governance_config_address, false),
-        AccountMeta::new_readonly(system_program::id(), false),
-    ];
-    let data = PaladinGovernanceInstruction::CreateProposal.pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-/// Creates a
-/// [PushInstruction](enum.PaladinGovernanceInstruction.html)
-/// instruction.
-pub fn push_instruction(
-    stake_authority_address: &Pubkey,
-    proposal_address: &Pubkey,
-    proposal_transaction_address: &Pubkey,
-    instruction_program_id: &Pubkey,
-    instruction_account_metas: Vec<ProposalAccountMeta>,
+    /// Cancel a governance proposal that is in the voting stage.
+    ///
+    /// Authority account provided must be the proposal creator. Only
+    /// proposals whose cooldown period has not yet begun can be cancelled.
+    ///
+    /// Moves the proposal to the terminal `Cancelled` state and releases the
+    /// author's active proposal slot. Votes cast on a cancelled proposal can
+    /// then be closed with `DeleteVote`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[w]` Author account.
+    /// 2. `[w]` Proposal account.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "author",
+        description = "Stake authority author account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    CancelProposal,
+    /// Insert a prerequisite into a governance proposal.
+    ///
+    /// The proposal's instructions cannot be processed until the prerequisite
+    /// proposal has been processed.
+    ///
+    /// Expects an initialized proposal and proposal transaction account.
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "proposal_transaction",
+        description = "Proposal transaction account"
+    )]
+    PushPrerequisite {
+        /// The proposal that must be processed first.
+        prerequisite_proposal: Pubkey,
+    },
+    /// Insert an option into a governance proposal, making it a
+    /// multiple-choice proposal.
+    ///
+    /// Expects an initialized proposal and proposal transaction account.
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[w]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "proposal_transaction",
+        description = "Proposal transaction account"
+    )]
+    PushOption,
+    /// Insert an instruction into an option of a multiple-choice governance
+    /// proposal.
+    ///
+    /// Expects an initialized proposal and proposal transaction account.
+    ///
+    /// Authority account provided must be the proposal creator.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Proposal account.
+    /// 2. `[w]` Proposal transaction account.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "proposal_transaction",
+        description = "Proposal transaction account"
+    )]
+    PushOptionInstruction {
+        /// The index of the option.
+        option_index: u8,
+        /// The program ID to invoke.
+        instruction_program_id: Pubkey,
+        /// The accounts to pass to the program.
+        instruction_account_metas: Vec<ProposalAccountMeta>,
+        /// The data to pass to the program.
+        instruction_data: Vec<u8>,
+    },
+    /// Vote for an option of a multiple-choice governance proposal.
+    ///
+    /// Expects an uninitialized proposal vote account with enough rent-exempt
+    /// lamports to store proposal vote state.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Paladin stake account.
+    /// 2. `[ ]` Paladin stake config account.
+    /// 3. `[w]` Proposal vote account.
+    /// 4. `[w]` Proposal account.
+    /// 5. `[ ]` Governance config account.
+    /// 6. `[ ]` System program.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        name = "stake",
+        description = "Paladin stake account"
+    )]
+    #[account(
+        2,
+        name = "stake_config",
+        description = "Paladin stake config account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "vote",
+        description = "Proposal vote account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    #[account(
+        5,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        description = "System program"
+    )]
+    VoteOption {
+        /// The index of the option.
+        option_index: u8,
+    },
+    /// Switch a vote to another option of a multiple-choice governance
+    /// proposal.
+    ///
+    /// Expects an existing proposal vote account, representing a previously
+    /// cast proposal vote.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[ ]` Paladin stake account.
+    /// 2. `[ ]` Paladin stake config account.
+    /// 3. `[w]` Proposal vote account.
+    /// 4. `[w]` Proposal account.
+    /// 5. `[ ]` Governance config account.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        name = "stake",
+        description = "Paladin stake account"
+    )]
+    #[account(
+        2,
+        name = "stake_config",
+        description = "Paladin stake config account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "vote",
+        description = "Proposal vote account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    #[account(
+        5,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    SwitchVoteOption {
+        /// The index of the new option.
+        new_option_index: u8,
+    },
+    /// Create a signal governance proposal.
+    ///
+    /// Signal proposals only record the outcome of the vote and have no
+    /// proposal transaction account. Once accepted, they are marked processed.
+    ///
+    /// Expects an uninitialized proposal account with enough rent-exempt
+    /// lamports to store proposal state.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Paladin stake authority account.
+    /// 1. `[w]` Stake authority author account.
+    /// 2. `[ ]` Paladin stake account.
+    /// 3. `[w]` Proposal account.
+    /// 4. `[ ]` Governance config account.
+    #[account(
+        0,
+        signer,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "author",
+        description = "Stake authority author account"
+    )]
+    #[account(
+        2,
+        name = "stake",
+        description = "Paladin stake account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "proposal",
+        description = "Proposal account"
+    )]
+    #[account(
+        4,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    CreateSignalProposal,
+    /// Close the author account of a stake authority, refunding its rent.
+    ///
+    /// The author must have no active proposals.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s,w]` Paladin stake authority account.
+    /// 1. `[w]` Author account.
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "stake_authority",
+        description = "Paladin stake authority account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "author",
+        description = "Stake authority author account"
+    )]
+    CloseAuthor,
+    /// Partially update the governance config.
+    ///
+    /// Like `UpdateGovernance`, but each parameter is optional and only the
+    /// provided parameters are written. Parameters left unset keep whatever
+    /// value the config holds when the proposal is executed.
+    ///
+    /// This instruction can only be executed from an accepted proposal, thus
+    /// it requires the PDA signature of the treasury.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Treasury account.
+    /// 1. `[w]` Governance config account.
+    #[account(
+        0,
+        signer,
+        name = "treasury",
+        description = "Treasury account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    PartialUpdateGovernance {
+        governance_id: u64,
+        cooldown_period_seconds: Option<u64>,
+        proposal_minimum_quorum: Option<u32>,
+        proposal_pass_threshold: Option<u32>,
+        voting_period_seconds: Option<u64>,
+        stake_per_proposal: Option<u64>,
+        /// The key allowed to pause and unpause governance, or
+        /// `Pubkey::default()` to remove it.
+        pause_guardian: Option<Pubkey>,
+        /// The minimum lead of a multiple-choice proposal's winning option over
+        /// the runner-up.
+        proposal_winning_margin: Option<u32>,
+    },
+    /// Migrate a governance config or proposal account from its legacy
+    /// layout to the current one.
+    ///
+    /// Governance config accounts created before the addition of the
+    /// discriminator, and proposal accounts embedding such a config, are
+    /// reallocated and rewritten in place. Until then, the program only
+    /// accepts them for reads.
+    ///
+    /// This instruction is permissionless. The payer tops the account up to
+    /// be rent-exempt at its new size, if needed. The governance ID is only
+    /// used to verify the address of governance config accounts.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Governance config or proposal account.
+    /// 1. `[s,w]` Payer account.
+    /// 2. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        name = "account",
+        description = "Governance config or proposal account"
+    )]
+    #[account(
+        1,
+        signer,
+        writable,
+        name = "payer",
+        description = "Payer account"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "System program"
+    )]
+    MigrateAccount { governance_id: u64 },
+    /// Pause or unpause governance.
+    ///
+    /// While governance is paused, proposals can't be created, begin voting,
+    /// be voted on or have their instructions processed, except for accepted
+    /// proposals whose only instruction unpauses governance. Time spent
+    /// paused while a proposal is voting extends its voting and cooldown
+    /// periods.
+    ///
+    /// Must be signed by either the treasury, through an accepted proposal,
+    /// or the governance config's pause guardian, if one is set. The treasury
+    /// can pause governance whether or not a pause guardian is set.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Treasury or pause guardian account.
+    /// 1. `[w]` Governance config account.
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        description = "Treasury or pause guardian account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    SetPaused { paused: bool },
+    #[allow(clippy::doc_lazy_continuation)]
+    /// Migrate to another governance config of the same stake config,
+    /// handing the treasury over to it.
+    ///
+    /// Retires the governance config, so no new proposals can be created
+    /// against it, then transfers the full balance of each provided treasury
+    /// token account, followed by all of the treasury's lamports, to the new
+    /// governance config's treasury. Token accounts may belong to either the
+    /// SPL Token or the SPL Token-2022 program, and are transferred with
+    /// `TransferChecked` against their mint.
+    ///
+    /// This instruction can only be executed from an accepted proposal, thus
+    /// it requires the PDA signature of the treasury.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s,w]` Treasury account.
+    /// 1. `[w]` Governance config account.
+    /// 2. `[w]` New treasury account.
+    /// 3. `[ ]` New governance config account.
+    /// 4. `[ ]` System program.
+    /// 5. `[ ]` SPL Token program.
+    /// 6. `[ ]` SPL Token-2022 program.
+    /// 7..7+3N. Triples of a `[w]` treasury token account, the `[w]` new
+    ///    treasury token account and their `[ ]` mint.
+    #[account(
+        0,
+        signer,
+        writable,
+        name = "treasury",
+        description = "Treasury account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "governance_config",
+        description = "Governance config account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "new_treasury",
+        description = "New treasury account"
+    )]
+    #[account(
+        3,
+        name = "new_governance_config",
+        description = "New governance config account"
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        description = "System program"
+    )]
+    #[account(
+        5,
+        name = "token_program",
+        description = "SPL Token program"
+    )]
+    #[account(
+        6,
+        name = "token_2022_program",
+        description = "SPL Token-2022 program"
+    )]
+    MigrateGovernance {
+        governance_id: u64,
+        new_governance_id: u64,
+    },
+}
+
+impl PaladinGovernanceInstruction {
+    /// Packs a
+    /// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html)
+    /// into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        match self {
+            Self::InitializeAuthor => vec![0],
+            Self::CreateProposal => vec![1],
+            Self::PushInstruction {
+                instruction_program_id,
+                instruction_account_metas,
+                instruction_data,
+            } => {
+                let mut buf = vec![2];
+                instruction_program_id.serialize(&mut buf).unwrap();
+                instruction_account_metas.serialize(&mut buf).unwrap();
+                instruction_data.serialize(&mut buf).unwrap();
+                buf
+            }
+            Self::DeleteProposal => vec![3],
+            Self::BeginVoting => vec![4],
+            Self::Vote { election } => vec![5, (*election).into()],
+            Self::SwitchVote { new_election } => vec![6, (*new_election).into()],
+            Self::FinishVoting => vec![7],
+            Self::DeleteVote => vec![8],
+            Self::ProcessInstruction { instruction_index } => {
+                let mut buf = vec![9];
+                buf.extend_from_slice(&instruction_index.to_le_bytes());
+                buf
+            }
+            Self::InitializeGovernance {
+                governance_id,
+                cooldown_period_seconds,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                voting_period_seconds,
+                stake_per_proposal,
+                cooldown_seconds,
+            } => {
+                let mut buf = vec![10];
+                buf.extend_from_slice(&governance_id.to_le_bytes());
+                buf.extend_from_slice(&cooldown_period_seconds.to_le_bytes());
+                buf.extend_from_slice(&proposal_minimum_quorum.to_le_bytes());
+                buf.extend_from_slice(&proposal_pass_threshold.to_le_bytes());
+                buf.extend_from_slice(&voting_period_seconds.to_le_bytes());
+                buf.extend_from_slice(&stake_per_proposal.to_le_bytes());
+                buf.extend_from_slice(&cooldown_seconds.to_le_bytes());
+                buf
+            }
+            Self::UpdateGovernance {
+                governance_id,
+                cooldown_period_seconds,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                voting_period_seconds,
+                stake_per_proposal,
+            } => {
+                let mut buf = vec![11];
+                buf.extend_from_slice(&governance_id.to_le_bytes());
+                buf.extend_from_slice(&cooldown_period_seconds.to_le_bytes());
+                buf.extend_from_slice(&proposal_minimum_quorum.to_le_bytes());
+                buf.extend_from_slice(&proposal_pass_threshold.to_le_bytes());
+                buf.extend_from_slice(&voting_period_seconds.to_le_bytes());
+                buf.extend_from_slice(&stake_per_proposal.to_le_bytes());
+                buf
+            }
+            Self::CancelProposal => vec![12],
+            Self::PushPrerequisite {
+                prerequisite_proposal,
+            } => {
+                let mut buf = vec![13];
+                buf.extend_from_slice(prerequisite_proposal.as_ref());
+                buf
+            }
+            Self::PushOption => vec![14],
+            Self::PushOptionInstruction {
+                option_index,
+                instruction_program_id,
+                instruction_account_metas,
+                instruction_data,
+            } => {
+                let mut buf = vec![15, *option_index];
+                instruction_program_id.serialize(&mut buf).unwrap();
+                instruction_account_metas.serialize(&mut buf).unwrap();
+                instruction_data.serialize(&mut buf).unwrap();
+                buf
+            }
+            Self::VoteOption { option_index } => vec![16, *option_index],
+            Self::SwitchVoteOption { new_option_index } => vec![17, *new_option_index],
+            Self::CreateSignalProposal => vec![18],
+            Self::CloseAuthor => vec![19],
+            Self::PartialUpdateGovernance {
+                governance_id,
+                cooldown_period_seconds,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                voting_period_seconds,
+                stake_per_proposal,
+                pause_guardian,
+                proposal_winning_margin,
+            } => {
+                let mut buf = vec![20];
+                buf.extend_from_slice(&governance_id.to_le_bytes());
+                cooldown_period_seconds.serialize(&mut buf).unwrap();
+                proposal_minimum_quorum.serialize(&mut buf).unwrap();
+                proposal_pass_threshold.serialize(&mut buf).unwrap();
+                voting_period_seconds.serialize(&mut buf).unwrap();
+                stake_per_proposal.serialize(&mut buf).unwrap();
+                pause_guardian.serialize(&mut buf).unwrap();
+                proposal_winning_margin.serialize(&mut buf).unwrap();
+                buf
+            }
+            Self::MigrateAccount { governance_id } => {
+                let mut buf = vec![21];
+                buf.extend_from_slice(&governance_id.to_le_bytes());
+                buf
+            }
+            Self::SetPaused { paused } => vec![22, (*paused).into()],
+            Self::MigrateGovernance {
+                governance_id,
+                new_governance_id,
+            } => {
+                let mut buf = vec![23];
+                buf.extend_from_slice(&governance_id.to_le_bytes());
+                buf.extend_from_slice(&new_governance_id.to_le_bytes());
+                buf
+            }
+        }
+    }
+
+    /// Unpacks a byte buffer into a
+    /// [PaladinGovernanceInstruction](enum.PaladinGovernanceInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.split_first() {
+            Some((&0, _)) => Ok(Self::InitializeAuthor),
+            Some((&1, _)) => Ok(Self::CreateProposal),
+            Some((&2, rest)) => {
+                #[derive(BorshDeserialize)]
+                struct Instruction {
+                    instruction_program_id: Pubkey,
+                    instruction_account_metas: Vec<ProposalAccountMeta>,
+                    instruction_data: Vec<u8>,
+                }
+                let Instruction {
+                    instruction_program_id,
+                    instruction_account_metas,
+                    instruction_data,
+                } = Instruction::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::PushInstruction {
+                    instruction_program_id,
+                    instruction_account_metas,
+                    instruction_data,
+                })
+            }
+            Some((&3, _)) => Ok(Self::DeleteProposal),
+            Some((&4, _)) => Ok(Self::BeginVoting),
+            Some((&5, rest)) if rest.len() == 1 => {
+                let election = rest[0]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::Vote { election })
+            }
+            Some((&6, rest)) if rest.len() == 1 => {
+                let new_election = rest[0]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::SwitchVote { new_election })
+            }
+            Some((&7, _)) => Ok(Self::FinishVoting),
+            Some((&8, _)) => Ok(Self::DeleteVote),
+            Some((&9, rest)) if rest.len() == 4 => {
+                let instruction_index = u32::from_le_bytes(rest.try_into().unwrap());
+                Ok(Self::ProcessInstruction { instruction_index })
+            }
+            Some((&10, rest)) if rest.len() == 48 => {
+                let rest = array_ref![rest, 0, 48];
+                let (
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                    cooldown_seconds,
+                ) = array_refs![rest, 8, 8, 4, 4, 8, 8, 8];
+
+                let governance_id = u64::from_le_bytes(*governance_id);
+                let cooldown_period_seconds = u64::from_le_bytes(*cooldown_period_seconds);
+                let proposal_minimum_quorum = u32::from_le_bytes(*proposal_minimum_quorum);
+                let proposal_pass_threshold = u32::from_le_bytes(*proposal_pass_threshold);
+                let voting_period_seconds = u64::from_le_bytes(*voting_period_seconds);
+                let stake_per_proposal = u64::from_le_bytes(*stake_per_proposal);
+                let cooldown_seconds = u64::from_le_bytes(*cooldown_seconds);
+
+                Ok(Self::InitializeGovernance {
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                    cooldown_seconds,
+                })
+            }
+            Some((&11, rest)) if rest.len() == 40 => {
+                let rest = array_ref![rest, 0, 40];
+                let (
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                ) = array_refs![rest, 8, 8, 4, 4, 8, 8];
+
+                let governance_id = u64::from_le_bytes(*governance_id);
+                let cooldown_period_seconds = u64::from_le_bytes(*cooldown_period_seconds);
+                let proposal_minimum_quorum = u32::from_le_bytes(*proposal_minimum_quorum);
+                let proposal_pass_threshold = u32::from_le_bytes(*proposal_pass_threshold);
+                let voting_period_seconds = u64::from_le_bytes(*voting_period_seconds);
+                let stake_per_proposal = u64::from_le_bytes(*stake_per_proposal);
+
+                Ok(Self::UpdateGovernance {
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                })
+            }
+            Some((&12, _)) => Ok(Self::CancelProposal),
+            Some((&13, rest)) if rest.len() == 32 => {
+                let prerequisite_proposal = Pubkey::new_from_array(*array_ref![rest, 0, 32]);
+                Ok(Self::PushPrerequisite {
+                    prerequisite_proposal,
+                })
+            }
+            Some((&14, _)) => Ok(Self::PushOption),
+            Some((&15, rest)) => {
+                #[derive(BorshDeserialize)]
+                struct Instruction {
+                    option_index: u8,
+                    instruction_program_id: Pubkey,
+                    instruction_account_metas: Vec<ProposalAccountMeta>,
+                    instruction_data: Vec<u8>,
+                }
+                let Instruction {
+                    option_index,
+                    instruction_program_id,
+                    instruction_account_metas,
+                    instruction_data,
+                } = Instruction::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::PushOptionInstruction {
+                    option_index,
+                    instruction_program_id,
+                    instruction_account_metas,
+                    instruction_data,
+                })
+            }
+            Some((&16, rest)) if rest.len() == 1 => Ok(Self::VoteOption {
+                option_index: rest[0],
+            }),
+            Some((&17, rest)) if rest.len() == 1 => Ok(Self::SwitchVoteOption {
+                new_option_index: rest[0],
+            }),
+            Some((&18, _)) => Ok(Self::CreateSignalProposal),
+            Some((&19, _)) => Ok(Self::CloseAuthor),
+            Some((&20, rest)) => {
+                #[derive(BorshDeserialize)]
+                struct Instruction {
+                    governance_id: u64,
+                    cooldown_period_seconds: Option<u64>,
+                    proposal_minimum_quorum: Option<u32>,
+                    proposal_pass_threshold: Option<u32>,
+                    voting_period_seconds: Option<u64>,
+                    stake_per_proposal: Option<u64>,
+                    pause_guardian: Option<Pubkey>,
+                    proposal_winning_margin: Option<u32>,
+                }
+                let Instruction {
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                    pause_guardian,
+                    proposal_winning_margin,
+                } = Instruction::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Ok(Self::PartialUpdateGovernance {
+                    governance_id,
+                    cooldown_period_seconds,
+                    proposal_minimum_quorum,
+                    proposal_pass_threshold,
+                    voting_period_seconds,
+                    stake_per_proposal,
+                    pause_guardian,
+                    proposal_winning_margin,
+                })
+            }
+            Some((&21, rest)) if rest.len() == 8 => {
+                let governance_id = u64::from_le_bytes(rest.try_into().unwrap());
+                Ok(Self::MigrateAccount { governance_id })
+            }
+            Some((&22, [paused])) if *paused <= 1 => Ok(Self::SetPaused {
+                paused: *paused == 1,
+            }),
+            Some((&23, rest)) if rest.len() == 16 => {
+                let (governance_id, new_governance_id) = rest.split_at(8);
+                Ok(Self::MigrateGovernance {
+                    governance_id: u64::from_le_bytes(governance_id.try_into().unwrap()),
+                    new_governance_id: u64::from_le_bytes(new_governance_id.try_into().unwrap()),
+                })
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Creates a
+/// [CreateProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn create_proposal(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    governance_config_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new(
+            crate::state::get_proposal_author_address(stake_authority_address, &crate::id()),
+            false,
+        ),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::CreateProposal.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [PushInstruction](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn push_instruction(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    instruction_program_id: &Pubkey,
+    instruction_account_metas: Vec<ProposalAccountMeta>,
     instruction_data: Vec<u8>,
 ) -> Instruction {
     let accounts = vec![
-        AccountMeta::new_readonly(*stake_authority_address, true),
-        AccountMeta::new_readonly(*proposal_address, false),
-        AccountMeta::new(*proposal_transaction_address, false),
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::PushInstruction {
+        instruction_program_id: *instruction_program_id,
+        instruction_account_metas,
+        instruction_data,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [DeleteProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn delete_proposal(stake_authority_address: Pubkey, proposal_address: Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(stake_authority_address, true),
+        AccountMeta::new(
+            crate::state::get_proposal_author_address(&stake_authority_address, &crate::id()),
+            false,
+        ),
+        AccountMeta::new(proposal_address, false),
+        AccountMeta::new(
+            crate::state::get_proposal_transaction_address_and_bump_seed(
+                &proposal_address,
+                &crate::id(),
+            )
+            .0,
+            false,
+        ),
+    ];
+    let data = PaladinGovernanceInstruction::DeleteProposal.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [BeginVoting](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn begin_voting(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    governance_config_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::BeginVoting.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [Vote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn vote(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    election: ProposalVoteElection,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new(*proposal_vote_address, false),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::Vote { election }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [SwitchVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn switch_vote(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    proposal_vote_address: &Pubkey,
+    proposal_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    new_election: ProposalVoteElection,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*stake_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new(*proposal_vote_address, false),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::SwitchVote { new_election }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [SwitchVote](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn delete_vote(proposal: Pubkey, vote: Pubkey, authority: Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(proposal, false),
+        AccountMeta::new(vote, false),
+        AccountMeta::new(authority, false),
+    ];
+    let data = PaladinGovernanceInstruction::DeleteVote.pack();
+
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [FinishVoting](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn finish_voting(
+    stake_config: Pubkey,
+    proposal_address: &Pubkey,
+    governance_config_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(stake_config, false),
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::FinishVoting.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [ProcessInstruction](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn process_instruction(
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    prerequisite_addresses: &[Pubkey],
+    account_metas: &[AccountMeta],
+    instruction_index: u32,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
+    ];
+    accounts.extend(
+        prerequisite_addresses
+            .iter()
+            .map(|address| AccountMeta::new_readonly(*address, false)),
+    );
+    accounts.extend_from_slice(account_metas);
+    let data = PaladinGovernanceInstruction::ProcessInstruction { instruction_index }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+pub fn initialize_author(stake_authority_address: Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(stake_authority_address, true),
+        AccountMeta::new(
+            crate::state::get_proposal_author_address(&stake_authority_address, &crate::ID),
+            false,
+        ),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    let data = PaladinGovernanceInstruction::InitializeAuthor.pack();
+
+    Instruction::new_with_bytes(crate::ID, &data, accounts)
+}
+
+/// Creates a
+/// [InitializeGovernance](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_governance(
+    governance_config_address: &Pubkey,
+    stake_config_address: &Pubkey,
+    authority_address: &Pubkey,
+    governance_id: u64,
+    cooldown_period_seconds: u64,
+    proposal_minimum_quorum: u32,
+    proposal_pass_threshold: u32,
+    voting_period_seconds: u64,
+    stake_per_proposal: u64,
+    cooldown_seconds: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*governance_config_address, false),
+        AccountMeta::new_readonly(*stake_config_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new_readonly(get_program_data_address(&crate::id()), false),
+    ];
+    let data = PaladinGovernanceInstruction::InitializeGovernance {
+        governance_id,
+        cooldown_period_seconds,
+        proposal_minimum_quorum,
+        proposal_pass_threshold,
+        voting_period_seconds,
+        stake_per_proposal,
+        cooldown_seconds,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [UpdateGovernance](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn update_governance(
+    treasury_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    governance_id: u64,
+    cooldown_period_seconds: u64,
+    proposal_minimum_quorum: u32,
+    proposal_pass_threshold: u32,
+    voting_period_seconds: u64,
+    stake_per_proposal: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*treasury_address, true),
+        AccountMeta::new(*governance_config_address, false),
     ];
-    let data = PaladinGovernanceInstruction::PushInstruction {
-        instruction_program_id: *instruction_program_id,
-        instruction_account_metas,
-        instruction_data,
+    let data = PaladinGovernanceInstruction::UpdateGovernance {
+        governance_id,
+        cooldown_period_seconds,
+        proposal_minimum_quorum,
+        proposal_pass_threshold,
+        voting_period_seconds,
+        stake_per_proposal,
     }
     .pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [DeleteProposal](enum.PaladinGovernanceInstruction.html)
+/// [CancelProposal](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn delete_proposal(stake_authority_address: Pubkey, proposal_address: Pubkey) -> Instruction {
+pub fn cancel_proposal(stake_authority_address: &Pubkey, proposal_address: &Pubkey) -> Instruction {
     let accounts = vec![
-        AccountMeta::new(stake_authority_address, true),
-        AccountMeta::new(
-            crate::state::get_proposal_author_address(&stake_authority_address, &crate::id()),
-            false,
-        ),
-        AccountMeta::new(proposal_address, false),
+        AccountMeta::new_readonly(*stake_authority_address, true),
         AccountMeta::new(
-            crate::state::get_proposal_transaction_address_and_bump_seed(
-                &proposal_address,
-                &crate::id(),
-            )
-            .0,
+            crate::state::get_proposal_author_address(stake_authority_address, &crate::id()),
             false,
         ),
+        AccountMeta::new(*proposal_address, false),
     ];
-    let data = PaladinGovernanceInstruction::DeleteProposal.pack();
+    let data = PaladinGovernanceInstruction::CancelProposal.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [BeginVoting](enum.PaladinGovernanceInstruction.html)
+/// [PushPrerequisite](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn push_prerequisite(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    prerequisite_proposal_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::PushPrerequisite {
+        prerequisite_proposal: *prerequisite_proposal_address,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [PushOption](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn begin_voting(stake_authority_address: &Pubkey, proposal_address: &Pubkey) -> Instruction {
+pub fn push_option(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*stake_authority_address, true),
         AccountMeta::new(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
     ];
-    let data = PaladinGovernanceInstruction::BeginVoting.pack();
+    let data = PaladinGovernanceInstruction::PushOption.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [Vote](enum.PaladinGovernanceInstruction.html)
+/// [PushOptionInstruction](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn vote(
+pub fn push_option_instruction(
+    stake_authority_address: &Pubkey,
+    proposal_address: &Pubkey,
+    proposal_transaction_address: &Pubkey,
+    option_index: u8,
+    instruction_program_id: &Pubkey,
+    instruction_account_metas: Vec<ProposalAccountMeta>,
+    instruction_data: Vec<u8>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new_readonly(*proposal_address, false),
+        AccountMeta::new(*proposal_transaction_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::PushOptionInstruction {
+        option_index,
+        instruction_program_id: *instruction_program_id,
+        instruction_account_metas,
+        instruction_data,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [VoteOption](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn vote_option(
     stake_authority_address: &Pubkey,
     stake_address: &Pubkey,
     stake_config_address: &Pubkey,
     proposal_vote_address: &Pubkey,
     proposal_address: &Pubkey,
-    election: ProposalVoteElection,
+    governance_config_address: &Pubkey,
+    option_index: u8,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*stake_authority_address, true),
@@ -689,22 +1656,24 @@ pub fn vote(
         AccountMeta::new_readonly(*stake_config_address, false),
         AccountMeta::new(*proposal_vote_address, false),
         AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
-    let data = PaladinGovernanceInstruction::Vote { election }.pack();
+    let data = PaladinGovernanceInstruction::VoteOption { option_index }.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [SwitchVote](enum.PaladinGovernanceInstruction.html)
+/// [SwitchVoteOption](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn switch_vote(
+pub fn switch_vote_option(
     stake_authority_address: &Pubkey,
     stake_address: &Pubkey,
     stake_config_address: &Pubkey,
     proposal_vote_address: &Pubkey,
     proposal_address: &Pubkey,
-    new_election: ProposalVoteElection,
+    governance_config_address: &Pubkey,
+    new_option_index: u8,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*stake_authority_address, true),
@@ -712,127 +1681,147 @@ pub fn switch_vote(
         AccountMeta::new_readonly(*stake_config_address, false),
         AccountMeta::new(*proposal_vote_address, false),
         AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
     ];
-    let data = PaladinGovernanceInstruction::SwitchVote { new_election }.pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-/// Creates a
-/// [SwitchVote](enum.PaladinGovernanceInstruction.html)
-/// instruction.
-pub fn delete_vote(proposal: Pubkey, vote: Pubkey, authority: Pubkey) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new_readonly(proposal, false),
-        AccountMeta::new(vote, false),
-        AccountMeta::new(authority, false),
-    ];
-    let data = PaladinGovernanceInstruction::DeleteVote.pack();
-
+    let data = PaladinGovernanceInstruction::SwitchVoteOption { new_option_index }.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [FinishVoting](enum.PaladinGovernanceInstruction.html)
+/// [CreateSignalProposal](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn finish_voting(stake_config: Pubkey, proposal_address: &Pubkey) -> Instruction {
+pub fn create_signal_proposal(
+    stake_authority_address: &Pubkey,
+    stake_address: &Pubkey,
+    proposal_address: &Pubkey,
+    governance_config_address: &Pubkey,
+) -> Instruction {
     let accounts = vec![
-        AccountMeta::new_readonly(stake_config, false),
+        AccountMeta::new_readonly(*stake_authority_address, true),
+        AccountMeta::new(
+            crate::state::get_proposal_author_address(stake_authority_address, &crate::id()),
+            false,
+        ),
+        AccountMeta::new_readonly(*stake_address, false),
         AccountMeta::new(*proposal_address, false),
+        AccountMeta::new_readonly(*governance_config_address, false),
     ];
-    let data = PaladinGovernanceInstruction::FinishVoting.pack();
+    let data = PaladinGovernanceInstruction::CreateSignalProposal.pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [ProcessInstruction](enum.PaladinGovernanceInstruction.html)
+/// [CloseAuthor](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-pub fn process_instruction(
-    proposal_address: &Pubkey,
-    proposal_transaction_address: &Pubkey,
-    account_metas: &[AccountMeta],
-    instruction_index: u32,
-) -> Instruction {
-    let mut accounts = vec![
-        AccountMeta::new(*proposal_address, false),
-        AccountMeta::new(*proposal_transaction_address, false),
-    ];
-    accounts.extend_from_slice(account_metas);
-    let data = PaladinGovernanceInstruction::ProcessInstruction { instruction_index }.pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-pub fn initialize_author(stake_authority_address: Pubkey) -> Instruction {
+pub fn close_author(stake_authority_address: Pubkey) -> Instruction {
     let accounts = vec![
-        AccountMeta::new_readonly(system_program::ID, false),
-        AccountMeta::new_readonly(stake_authority_address, false),
+        AccountMeta::new(stake_authority_address, true),
         AccountMeta::new(
             crate::state::get_proposal_author_address(&stake_authority_address, &crate::ID),
             false,
         ),
     ];
-    let data = PaladinGovernanceInstruction::InitializeAuthor.pack();
-
+    let data = PaladinGovernanceInstruction::CloseAuthor.pack();
     Instruction::new_with_bytes(crate::ID, &data, accounts)
 }
 
 /// Creates a
-/// [InitializeGovernance](enum.PaladinGovernanceInstruction.html)
+/// [PartialUpdateGovernance](enum.PaladinGovernanceInstruction.html)
 /// instruction.
 #[allow(clippy::too_many_arguments)]
-pub fn initialize_governance(
+pub fn partial_update_governance(
+    treasury_address: &Pubkey,
     governance_config_address: &Pubkey,
-    stake_config_address: &Pubkey,
     governance_id: u64,
-    cooldown_period_seconds: u64,
-    proposal_minimum_quorum: u32,
-    proposal_pass_threshold: u32,
-    voting_period_seconds: u64,
-    stake_per_proposal: u64,
-    cooldown_seconds: u64,
+    cooldown_period_seconds: Option<u64>,
+    proposal_minimum_quorum: Option<u32>,
+    proposal_pass_threshold: Option<u32>,
+    voting_period_seconds: Option<u64>,
+    stake_per_proposal: Option<u64>,
+    pause_guardian: Option<Pubkey>,
+    proposal_winning_margin: Option<u32>,
 ) -> Instruction {
     let accounts = vec![
+        AccountMeta::new_readonly(*treasury_address, true),
         AccountMeta::new(*governance_config_address, false),
-        AccountMeta::new_readonly(*stake_config_address, false),
-        AccountMeta::new_readonly(system_program::id(), false),
     ];
-    let data = PaladinGovernanceInstruction::InitializeGovernance {
+    let data = PaladinGovernanceInstruction::PartialUpdateGovernance {
         governance_id,
         cooldown_period_seconds,
         proposal_minimum_quorum,
         proposal_pass_threshold,
         voting_period_seconds,
         stake_per_proposal,
-        cooldown_seconds,
+        pause_guardian,
+        proposal_winning_margin,
     }
     .pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
 }
 
 /// Creates a
-/// [UpdateGovernance](enum.PaladinGovernanceInstruction.html)
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-#[allow(clippy::too_many_arguments)]
-pub fn update_governance(
+pub fn migrate_account(
+    account_address: &Pubkey,
+    payer_address: &Pubkey,
+    governance_id: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account_address, false),
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinGovernanceInstruction::MigrateAccount { governance_id }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [SetPaused](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn set_paused(
+    authority_address: &Pubkey,
+    governance_config_address: &Pubkey,
+    paused: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new(*governance_config_address, false),
+    ];
+    let data = PaladinGovernanceInstruction::SetPaused { paused }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a
+/// [MigrateGovernance](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+pub fn migrate_governance(
     treasury_address: &Pubkey,
     governance_config_address: &Pubkey,
+    new_treasury_address: &Pubkey,
+    new_governance_config_address: &Pubkey,
+    token_account_addresses: &[(Pubkey, Pubkey, Pubkey)],
     governance_id: u64,
-    cooldown_period_seconds: u64,
-    proposal_minimum_quorum: u32,
-    proposal_pass_threshold: u32,
-    voting_period_seconds: u64,
-    stake_per_proposal: u64,
+    new_governance_id: u64,
 ) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new_readonly(*treasury_address, true),
+    let mut accounts = vec![
+        AccountMeta::new(*treasury_address, true),
         AccountMeta::new(*governance_config_address, false),
+        AccountMeta::new(*new_treasury_address, false),
+        AccountMeta::new_readonly(*new_governance_config_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_token_2022::id(), false),
     ];
-    let data = PaladinGovernanceInstruction::UpdateGovernance {
+    for (token_account_address, new_token_account_address, mint_address) in token_account_addresses
+    {
+        accounts.push(AccountMeta::new(*token_account_address, false));
+        accounts.push(AccountMeta::new(*new_token_account_address, false));
+        accounts.push(AccountMeta::new_readonly(*mint_address, false));
+    }
+    let data = PaladinGovernanceInstruction::MigrateGovernance {
         governance_id,
-        cooldown_period_seconds,
-        proposal_minimum_quorum,
-        proposal_pass_threshold,
-        voting_period_seconds,
-        stake_per_proposal,
+        new_governance_id,
     }
     .pack();
     Instruction::new_with_bytes(crate::id(), &data, accounts)
@@ -877,7 +1866,7 @@ mod tests {
     }
 
     #[test]
-    fn test_pack_unpack_cancel_proposal() {
+    fn test_pack_unpack_delete_proposal() {
         test_pack_unpack(PaladinGovernanceInstruction::DeleteProposal);
     }
 
@@ -942,4 +1931,126 @@ mod tests {
             stake_per_proposal: 6,
         });
     }
+
+    #[test]
+    fn test_pack_unpack_cancel_proposal() {
+        test_pack_unpack(PaladinGovernanceInstruction::CancelProposal);
+    }
+
+    #[test]
+    fn test_pack_unpack_push_prerequisite() {
+        test_pack_unpack(PaladinGovernanceInstruction::PushPrerequisite {
+            prerequisite_proposal: Pubkey::new_unique(),
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_push_option() {
+        test_pack_unpack(PaladinGovernanceInstruction::PushOption);
+    }
+
+    #[test]
+    fn test_pack_unpack_push_option_instruction() {
+        test_pack_unpack(PaladinGovernanceInstruction::PushOptionInstruction {
+            option_index: 2,
+            instruction_program_id: Pubkey::new_unique(),
+            instruction_account_metas: vec![ProposalAccountMeta {
+                pubkey: Pubkey::new_unique(),
+                is_signer: false,
+                is_writable: true,
+            }],
+            instruction_data: vec![1, 2, 3],
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_vote_option() {
+        test_pack_unpack(PaladinGovernanceInstruction::VoteOption { option_index: 3 });
+    }
+
+    #[test]
+    fn test_pack_unpack_switch_vote_option() {
+        test_pack_unpack(PaladinGovernanceInstruction::SwitchVoteOption {
+            new_option_index: 1,
+        });
+    }
+    #[test]
+    fn test_pack_unpack_create_signal_proposal() {
+        test_pack_unpack(PaladinGovernanceInstruction::CreateSignalProposal);
+    }
+
+    #[test]
+    fn test_pack_unpack_close_author() {
+        test_pack_unpack(PaladinGovernanceInstruction::CloseAuthor);
+    }
+
+    #[test]
+    fn test_pack_unpack_partial_update_governance() {
+        test_pack_unpack(PaladinGovernanceInstruction::PartialUpdateGovernance {
+            governance_id: 1,
+            cooldown_period_seconds: None,
+            proposal_minimum_quorum: Some(3),
+            proposal_pass_threshold: None,
+            voting_period_seconds: Some(5),
+            stake_per_proposal: None,
+            pause_guardian: None,
+            proposal_winning_margin: None,
+        });
+        test_pack_unpack(PaladinGovernanceInstruction::PartialUpdateGovernance {
+            governance_id: 1,
+            cooldown_period_seconds: Some(2),
+            proposal_minimum_quorum: Some(3),
+            proposal_pass_threshold: Some(4),
+            voting_period_seconds: Some(5),
+            stake_per_proposal: Some(6),
+            pause_guardian: Some(Pubkey::new_unique()),
+            proposal_winning_margin: Some(7),
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_account() {
+        test_pack_unpack(PaladinGovernanceInstruction::MigrateAccount { governance_id: 1 });
+    }
+
+    #[test]
+    fn test_pack_unpack_set_paused() {
+        test_pack_unpack(PaladinGovernanceInstruction::SetPaused { paused: true });
+        test_pack_unpack(PaladinGovernanceInstruction::SetPaused { paused: false });
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&[22, 2]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_governance() {
+        test_pack_unpack(PaladinGovernanceInstruction::MigrateGovernance {
+            governance_id: 1,
+            new_governance_id: 2,
+        });
+    }
+
+    #[test]
+    fn test_unpack_update_governance_layouts_are_distinct() {
+        // A full update packs to the fixed 40-byte layout, which the partial
+        // update's discriminator keeps from being misread.
+        let full = PaladinGovernanceInstruction::UpdateGovernance {
+            governance_id: 1,
+            cooldown_period_seconds: 2,
+            proposal_minimum_quorum: 3,
+            proposal_pass_threshold: 4,
+            voting_period_seconds: 5,
+            stake_per_proposal: 6,
+        }
+        .pack();
+        assert_eq!(full.len(), 41);
+
+        let mut partial = full.clone();
+        partial[0] = 20;
+        assert_eq!(
+            PaladinGovernanceInstruction::unpack(&partial),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
 }
@@ -13,8 +13,12 @@
 #[cfg(all(target_os = "solana", feature = "bpf-entrypoint"))]
 mod entrypoint;
 pub mod error;
+pub mod event;
 pub mod instruction;
 pub mod processor;
 pub mod state;
 
+/// Proposal outcome rules, from `paladin-governance-outcome`.
+pub use paladin_governance_outcome as outcome;
+
 solana_program::declare_id!("8WAFLJeTHWK9a4tZYonvVRJPVFWRnDpejSrabNYR1QGf");
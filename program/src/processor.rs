@@ -1,8 +1,11 @@
 //! Program processor.
+pub use crate::outcome::THRESHOLD_SCALING_FACTOR;
 use {
     crate::{
         error::PaladinGovernanceError,
+        event::GovernanceEvent,
         instruction::PaladinGovernanceInstruction,
+        outcome::{self, Resolution},
         state::{
             collect_governance_signer_seeds, collect_proposal_transaction_signer_seeds,
             collect_proposal_vote_signer_seeds, collect_treasury_signer_seeds,
@@ -10,8 +13,9 @@ use {
             get_proposal_transaction_address, get_proposal_transaction_address_and_bump_seed,
             get_proposal_vote_address, get_proposal_vote_address_and_bump_seed,
             get_treasury_address, get_treasury_address_and_bump_seed, Author, GovernanceConfig,
-            Proposal, ProposalAccountMeta, ProposalInstruction, ProposalStatus,
-            ProposalTransaction, ProposalVote, ProposalVoteElection,
+            LegacyGovernanceConfig, LegacyProposal, Proposal, ProposalAccountMeta,
+            ProposalInstruction, ProposalKind, ProposalOption, ProposalStatus, ProposalTransaction,
+            ProposalVote, ProposalVoteElection, MAX_PROPOSAL_OPTIONS,
         },
     },
     borsh::BorshDeserialize,
@@ -22,11 +26,12 @@ use {
     solana_program::{
         account_info::{next_account_info, AccountInfo},
         borsh1::get_instance_packed_len,
+        bpf_loader_upgradeable::{self, get_program_data_address, UpgradeableLoaderState},
         clock::Clock,
         entrypoint::ProgramResult,
         instruction::Instruction,
         msg,
-        program::invoke_signed,
+        program::{invoke, invoke_signed},
         program_error::ProgramError,
         pubkey::Pubkey,
         rent::Rent,
@@ -34,11 +39,13 @@ use {
         sysvar::Sysvar,
     },
     spl_discriminator::{ArrayDiscriminator, SplDiscriminate},
+    spl_token_2022::{
+        extension::StateWithExtensions,
+        state::{Account as TokenAccount, Mint},
+    },
     std::num::NonZeroU64,
 };
 
-pub const THRESHOLD_SCALING_FACTOR: u32 = 10u32.pow(9); // 1e9
-
 #[allow(clippy::arithmetic_side_effects)]
 fn calculate_maximum_proposals(governance_config: &GovernanceConfig, author_stake: u64) -> u64 {
     if governance_config.stake_per_proposal == 0 {
@@ -50,42 +57,6 @@ fn calculate_maximum_proposals(governance_config: &GovernanceConfig, author_stak
     author_stake / governance_config.stake_per_proposal
 }
 
-fn calculate_voter_turnout(
-    proposal_state: &Proposal,
-    total_stake: u64,
-) -> Result<u32, ProgramError> {
-    if total_stake == 0 {
-        return Ok(0);
-    }
-
-    // Calculation: stake / total_stake
-    //
-    // Scaled by 1e9 to store 9 decimal places of precision.
-    u128::from(proposal_state.stake_for)
-        .checked_mul(u128::from(THRESHOLD_SCALING_FACTOR))
-        .and_then(|scaled_stake| scaled_stake.checked_div(total_stake as u128))
-        .and_then(|result| u32::try_from(result).ok())
-        .ok_or(ProgramError::ArithmeticOverflow)
-}
-
-fn calculate_for_percentage(stake_for: u64, stake_against: u64) -> Result<u32, ProgramError> {
-    let total_stake = stake_for
-        .checked_add(stake_against)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    if total_stake == 0 {
-        return Ok(0);
-    }
-
-    // Calculation: stake_for / total_stake
-    //
-    // Scaled by 1e9 to store 9 decimal places of precision.
-    u128::from(stake_for)
-        .checked_mul(u128::from(THRESHOLD_SCALING_FACTOR))
-        .and_then(|scaled_for| scaled_for.checked_div(total_stake as u128))
-        .and_then(|result| u32::try_from(result).ok())
-        .ok_or(ProgramError::ArithmeticOverflow)
-}
-
 fn get_stake_checked(
     authority_key: &Pubkey,
     stake_config_address: &Pubkey,
@@ -118,6 +89,39 @@ fn get_stake_checked(
     Ok(state.delegation.effective_amount)
 }
 
+/// Starts the cooldown period of a proposal that has reached quorum, unless
+/// it has already started.
+fn start_cooldown_at_quorum(
+    proposal_info: &AccountInfo,
+    proposal_state: &mut Proposal,
+    governance_config: &GovernanceConfig,
+    total_stake: u64,
+    clock: &Clock,
+) -> ProgramResult {
+    if outcome::voter_turnout(proposal_state.stake_for, total_stake)
+        .map_err(|_| ProgramError::ArithmeticOverflow)?
+        >= governance_config.proposal_minimum_quorum
+        && proposal_state.cooldown_timestamp.is_none()
+    {
+        proposal_state.cooldown_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
+
+        GovernanceEvent::CooldownStarted {
+            proposal: *proposal_info.key,
+            cooldown_timestamp: clock.unix_timestamp as u64,
+        }
+        .emit()?;
+    }
+    Ok(())
+}
+
+fn check_vote_option(proposal_state: &Proposal, option_index: Option<u8>) -> ProgramResult {
+    match option_index {
+        Some(option_index) if option_index < proposal_state.option_count => Ok(()),
+        None if !proposal_state.is_multiple_choice() => Ok(()),
+        _ => Err(PaladinGovernanceError::InvalidProposalOption.into()),
+    }
+}
+
 fn check_stake_config_exists(stake_config_info: &AccountInfo) -> ProgramResult {
     // Ensure the stake config account is owned by the Paladin Stake program.
     if stake_config_info.owner != &paladin_stake_program::id() {
@@ -134,6 +138,45 @@ fn check_stake_config_exists(stake_config_info: &AccountInfo) -> ProgramResult {
     Ok(())
 }
 
+fn check_governance_initializer(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stake_config_info: &AccountInfo,
+    program_data_info: &AccountInfo,
+) -> ProgramResult {
+    // The stake config authority may always initialize governance.
+    let stake_config_authority: Option<Pubkey> =
+        bytemuck::try_from_bytes::<StakeConfig>(&stake_config_info.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?
+            .authority
+            .into();
+    if stake_config_authority.as_ref() == Some(authority) {
+        return Ok(());
+    }
+
+    // Otherwise, the authority must be the program's upgrade authority.
+    if program_data_info.key != &get_program_data_address(program_id) {
+        return Err(PaladinGovernanceError::IncorrectProgramDataAddress.into());
+    }
+    if program_data_info.owner != &bpf_loader_upgradeable::id() {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Program data metadata layout (bincode):
+    // `u32` variant (3 = ProgramData), `u64` slot, `Option<Pubkey>` authority.
+    let data = program_data_info.try_borrow_data()?;
+    if data.len() < UpgradeableLoaderState::size_of_programdata_metadata()
+        || data[0..4] != 3u32.to_le_bytes()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if data[12] != 1 || &data[13..45] != authority.as_ref() {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    Ok(())
+}
+
 fn check_stake_exists(stake_info: &AccountInfo) -> ProgramResult {
     // Ensure the stake account is owned by the Paladin Stake program.
     if stake_info.owner != &paladin_stake_program::id() {
@@ -156,30 +199,170 @@ fn check_governance_exists(program_id: &Pubkey, governance_info: &AccountInfo) -
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    // Ensure the governance account has not been left in the legacy layout.
+    if governance_info.data_len() == LegacyGovernanceConfig::LEN {
+        return Err(PaladinGovernanceError::AccountNotMigrated.into());
+    }
+
     // Ensure the governance account is initialized.
-    if governance_info.data_len() != std::mem::size_of::<GovernanceConfig>() {
+    if !(governance_info.data_len() == std::mem::size_of::<GovernanceConfig>()
+        && &governance_info.try_borrow_data()?[0..8] == GovernanceConfig::SPL_DISCRIMINATOR_SLICE)
+    {
         return Err(ProgramError::UninitializedAccount);
     }
 
     Ok(())
 }
 
+/// Reads a governance config, accepting the legacy layout until the account
+/// is migrated.
+fn load_governance_config(
+    program_id: &Pubkey,
+    governance_info: &AccountInfo,
+) -> Result<GovernanceConfig, ProgramError> {
+    let is_legacy = match check_governance_exists(program_id, governance_info) {
+        Ok(()) => false,
+        Err(err) if err == PaladinGovernanceError::AccountNotMigrated.into() => true,
+        Err(err) => return Err(err),
+    };
+
+    let data = governance_info.try_borrow_data()?;
+    if is_legacy {
+        bytemuck::try_from_bytes::<LegacyGovernanceConfig>(&data)
+            .map(|legacy| (*legacy).into())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    } else {
+        bytemuck::try_from_bytes::<GovernanceConfig>(&data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
 fn check_proposal_exists(program_id: &Pubkey, proposal_info: &AccountInfo) -> ProgramResult {
     // Ensure the proposal account is owned by the Paladin Governance program.
     if proposal_info.owner != program_id {
         return Err(ProgramError::InvalidAccountOwner);
     }
 
-    // Ensure the proposal account is initialized.
-    if !(proposal_info.data_len() == std::mem::size_of::<Proposal>()
-        && &proposal_info.try_borrow_data()?[0..8] == Proposal::SPL_DISCRIMINATOR_SLICE)
+    let data = proposal_info.try_borrow_data()?;
+    let discriminator = data.get(0..8);
+
+    // Ensure the proposal account has not been left in the legacy layout.
+    if discriminator == Some(LegacyProposal::SPL_DISCRIMINATOR_SLICE)
+        && data.len() == LegacyProposal::LEN
     {
+        return Err(PaladinGovernanceError::AccountNotMigrated.into());
+    }
+
+    // Ensure the proposal account is initialized.
+    if !(discriminator == Some(Proposal::SPL_DISCRIMINATOR_SLICE) && data.len() == Proposal::LEN) {
         return Err(ProgramError::UninitializedAccount);
     }
 
     Ok(())
 }
 
+/// Reads a proposal, accepting the legacy layout until the account is
+/// migrated.
+fn load_proposal(
+    program_id: &Pubkey,
+    proposal_info: &AccountInfo,
+) -> Result<Proposal, ProgramError> {
+    let is_legacy = match check_proposal_exists(program_id, proposal_info) {
+        Ok(()) => false,
+        Err(err) if err == PaladinGovernanceError::AccountNotMigrated.into() => true,
+        Err(err) => return Err(err),
+    };
+
+    let data = proposal_info.try_borrow_data()?;
+    if is_legacy {
+        bytemuck::try_from_bytes::<LegacyProposal>(&data)
+            .map(|legacy| (*legacy).into())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    } else {
+        bytemuck::try_from_bytes::<Proposal>(&data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Reads the governance config a proposal was created under, ensuring the
+/// provided account is the one recorded in the proposal.
+fn load_proposal_governance_config(
+    program_id: &Pubkey,
+    governance_info: &AccountInfo,
+    proposal_state: &Proposal,
+) -> Result<GovernanceConfig, ProgramError> {
+    if governance_info.key != &proposal_state.governance_config.governance_config {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+    load_governance_config(program_id, governance_info)
+}
+
+/// Whether the outcome of a proposal is a single `SetPaused` instruction
+/// unpausing its governance config.
+///
+/// Such proposals can be processed while governance is paused, so a pause
+/// triggered through governance can be lifted through governance.
+fn is_unpause_proposal(
+    program_id: &Pubkey,
+    proposal_info: &AccountInfo,
+    proposal_state: &Proposal,
+    proposal_transaction_info: &AccountInfo,
+) -> bool {
+    if proposal_transaction_info.key
+        != &get_proposal_transaction_address(proposal_info.key, program_id)
+        || check_proposal_transaction_exists(program_id, proposal_transaction_info).is_err()
+    {
+        return false;
+    }
+    let Ok(data) = proposal_transaction_info.try_borrow_data() else {
+        return false;
+    };
+    let Ok(mut proposal_transaction_state) = ProposalTransaction::try_from_slice(&data) else {
+        return false;
+    };
+    let Ok(instructions) = proposal_transaction_state.outcome_instructions_mut(proposal_state)
+    else {
+        return false;
+    };
+
+    match instructions.as_slice() {
+        [instruction] => {
+            instruction.program_id == *program_id
+                && instruction.accounts.get(1).map(|meta| &meta.pubkey)
+                    == Some(&proposal_state.governance_config.governance_config)
+                && matches!(
+                    PaladinGovernanceInstruction::unpack(&instruction.data),
+                    Ok(PaladinGovernanceInstruction::SetPaused { paused: false })
+                )
+        }
+        _ => false,
+    }
+}
+
+fn load_treasury_token_account(
+    token_account_info: &AccountInfo,
+    treasury_address: &Pubkey,
+) -> Result<TokenAccount, ProgramError> {
+    // Ensure the token account is owned by the SPL Token or SPL Token-2022
+    // program.
+    if token_account_info.owner != &spl_token::id()
+        && token_account_info.owner != &spl_token_2022::id()
+    {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Ensure the token account belongs to the treasury.
+    let token_account =
+        StateWithExtensions::<TokenAccount>::unpack(&token_account_info.try_borrow_data()?)?.base;
+    if token_account.owner != *treasury_address {
+        return Err(PaladinGovernanceError::IncorrectTreasuryTokenAccount.into());
+    }
+
+    Ok(token_account)
+}
+
 fn check_proposal_transaction_exists(
     program_id: &Pubkey,
     proposal_transaction_info: &AccountInfo,
@@ -201,9 +384,14 @@ fn check_proposal_transaction_exists(
 fn process_initialize_author(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
-    let _system_program = next_account_info(accounts_iter)?;
     let stake_authority_info = next_account_info(accounts_iter)?;
     let author_info = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
     // Check the author account.
     let (author_pda, author_bump) =
@@ -240,20 +428,78 @@ fn process_initialize_author(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
     Ok(())
 }
 
+/// Processes a
+/// [CloseAuthor](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_close_author(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let author_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check & deserialize author.
+    if author_info.key
+        != &crate::state::get_proposal_author_address(stake_authority_info.key, program_id)
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if author_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let author_data = author_info.try_borrow_data()?;
+    let author_state = bytemuck::try_from_bytes::<Author>(&author_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the author has no active proposals.
+    if author_state.active_proposals != 0 {
+        return Err(PaladinGovernanceError::AuthorHasActiveProposals.into());
+    }
+
+    // Delete the author & refund the rent.
+    drop(author_data);
+    // NB: The runtime will revert us if we overflow as the sum of balances
+    // before/after will not match.
+    #[allow(clippy::arithmetic_side_effects)]
+    {
+        **stake_authority_info.lamports.borrow_mut() += author_info.lamports();
+    }
+    **author_info.lamports.borrow_mut() = 0;
+    author_info.realloc(0, true)?;
+
+    Ok(())
+}
+
 /// Processes a
 /// [CreateProposal](enum.PaladinGovernanceInstruction.html)
+/// or
+/// [CreateSignalProposal](enum.PaladinGovernanceInstruction.html)
 /// instruction.
-fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_create_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    kind: ProposalKind,
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let stake_authority_info = next_account_info(accounts_iter)?;
     let author_info = next_account_info(accounts_iter)?;
     let stake_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
-    let proposal_transaction_info = next_account_info(accounts_iter)?;
+    // Signal proposals have no proposal transaction account.
+    let proposal_transaction_info = match kind {
+        ProposalKind::Executable => Some(next_account_info(accounts_iter)?),
+        ProposalKind::Signal => None,
+    };
     let governance_info = next_account_info(accounts_iter)?;
     // NB: Must be loaded for CPIs but never directly accessed.
-    let _system_program_info = next_account_info(accounts_iter)?;
+    if kind == ProposalKind::Executable {
+        next_account_info(accounts_iter)?;
+    }
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
@@ -285,12 +531,13 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
     }
 
     // Check & deserialize governance config.
-    check_governance_exists(program_id, governance_info)?;
-    let governance_config = {
-        let governance_data = governance_info.try_borrow_data()?;
-        *bytemuck::try_from_bytes::<GovernanceConfig>(&governance_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-    };
+    let governance_config = load_governance_config(program_id, governance_info)?;
+
+    // Ensure governance is not paused.
+    governance_config.check_not_paused()?;
+
+    // Ensure the governance config has not been migrated away from.
+    governance_config.check_not_retired()?;
 
     // Ensure cooldown period has passed
     if Clock::get()?.unix_timestamp < governance_config.cooldown_expires as i64 {
@@ -315,7 +562,7 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
     }
 
     // Initialize the proposal account.
-    {
+    let creation_timestamp = {
         // Ensure the proposal account is owned by the Paladin Governance program.
         if proposal_info.owner != program_id {
             return Err(ProgramError::InvalidAccountOwner);
@@ -342,13 +589,29 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
 
         // Write the data.
         let mut proposal_data = proposal_info.try_borrow_mut_data()?;
-        *bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
-            .map_err(|_| ProgramError::InvalidAccountData)? = Proposal::new(
+        let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        *proposal_state = Proposal::new(
             stake_authority_info.key,
             creation_timestamp,
             governance_config,
         );
-    }
+        proposal_state.kind = kind;
+
+        creation_timestamp
+    };
+
+    let event = GovernanceEvent::ProposalCreated {
+        proposal: *proposal_info.key,
+        author: *stake_authority_info.key,
+        governance_config: governance_config.governance_config,
+        kind,
+        creation_timestamp,
+    };
+
+    let Some(proposal_transaction_info) = proposal_transaction_info else {
+        return event.emit();
+    };
 
     // Initialize the proposal transaction account.
     {
@@ -398,7 +661,7 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
         borsh::to_writer(&mut proposal_transaction_info.data.borrow_mut()[..], &state)?;
     }
 
-    Ok(())
+    event.emit()
 }
 
 /// Processes a
@@ -407,6 +670,7 @@ fn process_create_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
 fn process_push_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    option_index: Option<u8>,
     instruction_program_id: Pubkey,
     instruction_account_metas: Vec<ProposalAccountMeta>,
     instruction_data: Vec<u8>,
@@ -422,11 +686,7 @@ fn process_push_instruction(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    check_proposal_exists(program_id, proposal_info)?;
-
-    let proposal_data = proposal_info.try_borrow_data()?;
-    let proposal_state = bytemuck::try_from_bytes::<Proposal>(&proposal_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let proposal_state = load_proposal(program_id, proposal_info)?;
 
     // Ensure the stake authority is the proposal author.
     proposal_state.check_author(stake_authority_info.key)?;
@@ -436,6 +696,11 @@ fn process_push_instruction(
         return Err(PaladinGovernanceError::ProposalIsImmutable.into());
     }
 
+    // Ensure the proposal has a transaction to modify.
+    if proposal_state.kind == ProposalKind::Signal {
+        return Err(PaladinGovernanceError::ProposalNotExecutable.into());
+    }
+
     // Ensure the provided proposal transaction address is the correct address
     // derived from the proposal.
     if !proposal_transaction_info
@@ -453,15 +718,30 @@ fn process_push_instruction(
     let mut proposal_transaction_state =
         ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
 
+    // Select the instruction set to insert into. Multiple-choice proposals only
+    // execute the instructions of their options.
+    let instructions = match option_index {
+        Some(option_index) => {
+            &mut proposal_transaction_state
+                .options
+                .get_mut(option_index as usize)
+                .ok_or(PaladinGovernanceError::InvalidProposalOption)?
+                .instructions
+        }
+        None if proposal_state.is_multiple_choice() => {
+            return Err(PaladinGovernanceError::InvalidProposalOption.into());
+        }
+        None => &mut proposal_transaction_state.instructions,
+    };
+
     // Insert the instruction.
     let new_instruction = ProposalInstruction::new(
         &instruction_program_id,
         instruction_account_metas,
         instruction_data,
     );
-    proposal_transaction_state
-        .instructions
-        .push(new_instruction);
+    let instruction_index = instructions.len() as u32;
+    instructions.push(new_instruction);
 
     // Reallocate the account.
     let new_len = get_instance_packed_len(&proposal_transaction_state)?;
@@ -480,7 +760,13 @@ fn process_push_instruction(
         &proposal_transaction_state,
     )?;
 
-    Ok(())
+    GovernanceEvent::InstructionPushed {
+        proposal: *proposal_info.key,
+        option_index,
+        instruction_index,
+        program_id: instruction_program_id,
+    }
+    .emit()
 }
 
 /// Processes a
@@ -492,7 +778,8 @@ fn process_delete_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
     let stake_authority_info = next_account_info(accounts_iter)?;
     let author_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
-    let proposal_transaction_info = next_account_info(accounts_iter)?;
+    // Signal proposals have no proposal transaction account.
+    let proposal_transaction_info = accounts_iter.next();
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
@@ -523,18 +810,30 @@ fn process_delete_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
         return Err(PaladinGovernanceError::ProposalIsActive.into());
     }
 
-    // Validate the proposal transaction account.
-    let (proposal_transaction_address, _) =
-        get_proposal_transaction_address_and_bump_seed(proposal_info.key, program_id);
-    if proposal_transaction_info.key != &proposal_transaction_address {
-        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
-    }
+    // Validate the proposal transaction account. Signal proposals never
+    // create one, so their derived address is left untouched, even if someone
+    // has funded it.
+    let proposal_transaction_info = if proposal_state.kind == ProposalKind::Signal {
+        None
+    } else {
+        let proposal_transaction_info =
+            proposal_transaction_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let (proposal_transaction_address, _) =
+            get_proposal_transaction_address_and_bump_seed(proposal_info.key, program_id);
+        if proposal_transaction_info.key != &proposal_transaction_address {
+            return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+        }
+        Some(proposal_transaction_info)
+    };
 
-    // Decrease the user's active proposal count.
-    author_state.active_proposals = author_state
-        .active_proposals
-        .checked_sub(1)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Decrease the user's active proposal count, unless it was already
+    // released when the proposal was cancelled.
+    if proposal_state.status != ProposalStatus::Cancelled {
+        author_state.active_proposals = author_state
+            .active_proposals
+            .checked_sub(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
 
     // Delete the proposal & refund the rent.
     drop(proposal_data);
@@ -542,13 +841,19 @@ fn process_delete_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
     // before/after will not match.
     #[allow(clippy::arithmetic_side_effects)]
     {
-        **stake_authority_info.lamports.borrow_mut() +=
-            proposal_info.lamports() + proposal_transaction_info.lamports();
+        **stake_authority_info.lamports.borrow_mut() += proposal_info.lamports();
     }
     **proposal_info.lamports.borrow_mut() = 0;
-    **proposal_transaction_info.lamports.borrow_mut() = 0;
     proposal_info.realloc(0, true)?;
-    proposal_transaction_info.realloc(0, true)?;
+
+    if let Some(proposal_transaction_info) = proposal_transaction_info {
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            **stake_authority_info.lamports.borrow_mut() += proposal_transaction_info.lamports();
+        }
+        **proposal_transaction_info.lamports.borrow_mut() = 0;
+        proposal_transaction_info.realloc(0, true)?;
+    }
 
     Ok(())
 }
@@ -561,6 +866,7 @@ fn process_begin_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progra
 
     let stake_authority_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
@@ -581,14 +887,30 @@ fn process_begin_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progra
         return Err(PaladinGovernanceError::ProposalIsImmutable.into());
     }
 
+    // Ensure a multiple-choice proposal offers an actual choice.
+    if proposal_state.option_count == 1 {
+        return Err(PaladinGovernanceError::InvalidProposalOption.into());
+    }
+
+    // Ensure governance is not paused.
+    let governance_config =
+        load_proposal_governance_config(program_id, governance_info, proposal_state)?;
+    governance_config.check_not_paused()?;
+
     // Set the proposal's status to voting.
     proposal_state.status = ProposalStatus::Voting;
 
-    // Set the proposal's voting start timestamp.
+    // Set the proposal's voting start timestamp. Only time governance spends
+    // paused from now on extends the voting period.
     let clock = <Clock as Sysvar>::get()?;
     proposal_state.voting_start_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
+    proposal_state.paused_seconds_checkpoint = governance_config.total_paused_seconds(&clock);
 
-    Ok(())
+    GovernanceEvent::VotingBegan {
+        proposal: *proposal_info.key,
+        voting_start_timestamp: clock.unix_timestamp as u64,
+    }
+    .emit()
 }
 
 /// Processes a
@@ -598,6 +920,7 @@ fn process_vote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     election: ProposalVoteElection,
+    option_index: Option<u8>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -606,6 +929,7 @@ fn process_vote(
     let stake_config_info = next_account_info(accounts_iter)?;
     let proposal_vote_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
     let _system_program_info = next_account_info(accounts_iter)?;
 
     // Ensure the stake authority is a signer.
@@ -638,7 +962,18 @@ fn process_vote(
         return Err(PaladinGovernanceError::ProposalNotInVotingStage.into());
     }
 
+    // Ensure multiple-choice proposals are voted on with a valid option, and
+    // yes/no proposals without one.
+    check_vote_option(proposal_state, option_index)?;
+
+    // Ensure governance is not paused.
+    let current_governance_config =
+        load_proposal_governance_config(program_id, governance_info, proposal_state)?;
+    current_governance_config.check_not_paused()?;
+
+    // Extend the voting and cooldown periods by any time spent paused.
     let clock = <Clock as Sysvar>::get()?;
+    proposal_state.extend_paused_periods(&current_governance_config, &clock);
 
     // If the proposal has an active cooldown period, ensure it has not ended.
     if proposal_state.cooldown_has_ended(&clock) {
@@ -698,10 +1033,20 @@ fn process_vote(
                 stake,
                 authority: *stake_authority_info.key,
                 election,
+                option_index: option_index.unwrap_or_default(),
                 _padding: Default::default(),
             };
     }
 
+    // A multiple-choice vote also counts toward the total stake cast, tracked
+    // by `stake_for`.
+    if let Some(option_index) = option_index {
+        let option_stake = &mut proposal_state.option_stake[option_index as usize];
+        *option_stake = option_stake
+            .checked_add(stake)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
     match election {
         ProposalVoteElection::For => {
             // The vote was in favor. Increase the stake for the proposal.
@@ -719,15 +1064,25 @@ fn process_vote(
         }
     }
 
-    // If we have met quorum and the cooldown has not started yet, start it.
-    if calculate_voter_turnout(proposal_state, total_stake)?
-        >= governance_config.proposal_minimum_quorum
-        && proposal_state.cooldown_timestamp.is_none()
-    {
-        proposal_state.cooldown_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
-    }
-
-    Ok(())
+    GovernanceEvent::VoteCast {
+        proposal: *proposal_info.key,
+        vote: *proposal_vote_info.key,
+        authority: *stake_authority_info.key,
+        election,
+        option_index,
+        stake,
+        stake_for: proposal_state.stake_for,
+        stake_against: proposal_state.stake_against,
+    }
+    .emit()?;
+
+    start_cooldown_at_quorum(
+        proposal_info,
+        proposal_state,
+        &governance_config,
+        total_stake,
+        &clock,
+    )
 }
 
 /// Processes a
@@ -737,6 +1092,7 @@ fn process_switch_vote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     new_election: ProposalVoteElection,
+    new_option_index: Option<u8>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -745,6 +1101,7 @@ fn process_switch_vote(
     let stake_config_info = next_account_info(accounts_iter)?;
     let proposal_vote_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
 
     // Ensure the stake authority is a signer.
     if !stake_authority_info.is_signer {
@@ -776,7 +1133,18 @@ fn process_switch_vote(
         return Err(PaladinGovernanceError::ProposalNotInVotingStage.into());
     }
 
+    // Ensure multiple-choice proposals are voted on with a valid option, and
+    // yes/no proposals without one.
+    check_vote_option(proposal_state, new_option_index)?;
+
+    // Ensure governance is not paused.
+    let current_governance_config =
+        load_proposal_governance_config(program_id, governance_info, proposal_state)?;
+    current_governance_config.check_not_paused()?;
+
+    // Extend the voting and cooldown periods by any time spent paused.
     let clock = <Clock as Sysvar>::get()?;
+    proposal_state.extend_paused_periods(&current_governance_config, &clock);
 
     // If the proposal has an active cooldown period, ensure it has not ended.
     if proposal_state.cooldown_has_ended(&clock) {
@@ -792,7 +1160,7 @@ fn process_switch_vote(
     }
 
     // Update the proposal vote account.
-    let (last_election, last_stake) = {
+    let (last_election, last_option_index, last_stake) = {
         // Ensure the provided proposal vote address is the correct address
         // derived from the stake authority and proposal.
         if !proposal_vote_info.key.eq(&get_proposal_vote_address(
@@ -821,10 +1189,27 @@ fn process_switch_vote(
 
         (
             std::mem::replace(&mut state.election, new_election),
+            std::mem::replace(
+                &mut state.option_index,
+                new_option_index.unwrap_or_default(),
+            ),
             std::mem::replace(&mut state.stake, stake),
         )
     };
 
+    // Move the stake between options of a multiple-choice proposal.
+    if let Some(new_option_index) = new_option_index {
+        let last_option_stake = &mut proposal_state.option_stake[last_option_index as usize];
+        *last_option_stake = last_option_stake
+            .checked_sub(last_stake)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        let new_option_stake = &mut proposal_state.option_stake[new_option_index as usize];
+        *new_option_stake = new_option_stake
+            .checked_add(stake)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
     // If the program hasn't terminated by this point, the vote has changed.
     // Simply update the proposal by inversing the vote stake.
     match last_election {
@@ -862,15 +1247,28 @@ fn process_switch_vote(
         }
     }
 
-    // If we have met quorum and the cooldown has not started yet, start it.
-    if calculate_voter_turnout(proposal_state, total_stake)?
-        >= governance_config.proposal_minimum_quorum
-        && proposal_state.cooldown_timestamp.is_none()
-    {
-        proposal_state.cooldown_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
-    }
-
-    Ok(())
+    GovernanceEvent::VoteSwitched {
+        proposal: *proposal_info.key,
+        vote: *proposal_vote_info.key,
+        authority: *stake_authority_info.key,
+        previous_election: last_election,
+        previous_option_index: new_option_index.map(|_| last_option_index),
+        previous_stake: last_stake,
+        election: new_election,
+        option_index: new_option_index,
+        stake,
+        stake_for: proposal_state.stake_for,
+        stake_against: proposal_state.stake_against,
+    }
+    .emit()?;
+
+    start_cooldown_at_quorum(
+        proposal_info,
+        proposal_state,
+        &governance_config,
+        total_stake,
+        &clock,
+    )
 }
 
 /// Processes a
@@ -881,6 +1279,7 @@ fn process_finish_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progr
 
     let stake_config_info = next_account_info(accounts_iter)?;
     let proposal_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
 
     // Validate & deserialize stake config.
     check_stake_config_exists(stake_config_info)?;
@@ -900,41 +1299,45 @@ fn process_finish_voting(program_id: &Pubkey, accounts: &[AccountInfo]) -> Progr
         return Err(PaladinGovernanceError::ProposalNotInVotingStage.into());
     }
 
+    // Extend the voting and cooldown periods by any time spent paused,
+    // including an ongoing pause.
+    let governance_config =
+        load_proposal_governance_config(program_id, governance_info, proposal_state)?;
     let clock = <Clock as Sysvar>::get()?;
-    match proposal_state.cooldown_timestamp {
-        Some(_) => {
-            // If the proposal is in a cooldown period, check if it has ended.
-            if proposal_state.cooldown_has_ended(&clock) {
-                // The proposal must still be above quourum and have reached pass threshold.
-                let reached_quorum = calculate_voter_turnout(proposal_state, total_stake)?
-                    >= proposal_state.governance_config.proposal_minimum_quorum;
-                let passed = calculate_for_percentage(
-                    proposal_state.stake_for,
-                    proposal_state.stake_against,
-                )? >= proposal_state.governance_config.proposal_pass_threshold;
-
-                match reached_quorum && passed {
-                    true => proposal_state.status = ProposalStatus::Accepted,
-                    false => proposal_state.status = ProposalStatus::Rejected,
-                }
-
-                Ok(())
-            } else {
-                Err(PaladinGovernanceError::ProposalVotingPeriodStillActive.into())
-            }
+    proposal_state.extend_paused_periods(&governance_config, &clock);
+
+    // If the proposal is in a cooldown period, it must have ended with the
+    // proposal still above quorum and having reached the pass threshold. If
+    // voting has ended without a cooldown period, the proposal has failed.
+    let resolution = outcome::resolve(
+        &proposal_state.tally(total_stake),
+        &proposal_state.timeline(),
+        clock.unix_timestamp as u64,
+    )
+    .map_err(|_| ProgramError::ArithmeticOverflow)?;
+    match resolution {
+        Resolution::StillVoting => {
+            return Err(PaladinGovernanceError::ProposalVotingPeriodStillActive.into())
         }
-        None => {
-            // If voting has ended and there is no cooldown period, then this proposal has
-            // failed.
-            if proposal_state.voting_has_ended(&clock) {
-                proposal_state.status = ProposalStatus::Rejected;
-
-                Ok(())
-            } else {
-                Err(PaladinGovernanceError::ProposalVotingPeriodStillActive.into())
-            }
+        // Signal proposals have no instructions to process.
+        Resolution::Passed { .. } if proposal_state.kind == ProposalKind::Signal => {
+            proposal_state.status = ProposalStatus::Processed
         }
+        Resolution::Passed { .. } => proposal_state.status = ProposalStatus::Accepted,
+        Resolution::Failed { .. } => proposal_state.status = ProposalStatus::Rejected,
+    }
+    if let Some(winning_option) = resolution.winning_option() {
+        proposal_state.winning_option = winning_option;
+    }
+
+    GovernanceEvent::ProposalFinalized {
+        proposal: *proposal_info.key,
+        status: proposal_state.status,
+        winning_option: resolution.winning_option(),
+        stake_for: proposal_state.stake_for,
+        stake_against: proposal_state.stake_against,
     }
+    .emit()
 }
 
 /// Processes a
@@ -951,10 +1354,7 @@ fn process_delete_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> Program
         let proposal_data = proposal_info.data.borrow();
         if !proposal_data.is_empty() {
             // Ensure the proposal is not active..
-            check_proposal_exists(program_id, proposal_info)?;
-            let proposal_data = proposal_info.try_borrow_data()?;
-            let proposal_state = bytemuck::try_from_bytes::<Proposal>(&proposal_data)
-                .map_err(|_| ProgramError::InvalidAccountData)?;
+            let proposal_state = load_proposal(program_id, proposal_info)?;
             if proposal_state.status.is_active() {
                 return Err(PaladinGovernanceError::ProposalIsActive.into());
             }
@@ -963,9 +1363,18 @@ fn process_delete_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> Program
 
     // Validate the vote account.
     const _: () = assert!(std::mem::size_of::<ProposalVote>() != std::mem::size_of::<Proposal>());
+    const _: () =
+        assert!(std::mem::size_of::<ProposalVote>() != std::mem::size_of::<LegacyProposal>());
+    if vote_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if vote_info.data_len() != std::mem::size_of::<ProposalVote>() {
+        return Err(ProgramError::UninitializedAccount);
+    }
     let (proposal, authority) = {
         let vote = vote_info.data.borrow();
-        let vote = bytemuck::from_bytes::<ProposalVote>(&vote);
+        let vote = bytemuck::try_from_bytes::<ProposalVote>(&vote)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
 
         (vote.proposal, vote.authority)
     };
@@ -1003,6 +1412,7 @@ fn process_process_instruction(
 
     let proposal_info = next_account_info(accounts_iter)?;
     let proposal_transaction_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
 
     check_proposal_exists(program_id, proposal_info)?;
 
@@ -1010,6 +1420,18 @@ fn process_process_instruction(
     let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
 
+    // Ensure governance is not paused, unless the proposal only unpauses it.
+    let governance_config =
+        load_proposal_governance_config(program_id, governance_info, proposal_state)?;
+    if !is_unpause_proposal(
+        program_id,
+        proposal_info,
+        proposal_state,
+        proposal_transaction_info,
+    ) {
+        governance_config.check_not_paused()?;
+    }
+
     // Ensure the proposal was accepted.
     if proposal_state.status != ProposalStatus::Accepted {
         return Err(PaladinGovernanceError::ProposalNotAccepted.into());
@@ -1032,12 +1454,54 @@ fn process_process_instruction(
     let mut proposal_transaction_state =
         ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
 
+    // Prerequisites gate the start of execution only. Once an instruction has
+    // executed, a prerequisite may since have been deleted, which must not
+    // leave the proposal half-processed.
+    let execution_started = proposal_transaction_state
+        .outcome_instructions_mut(proposal_state)?
+        .iter()
+        .any(|instruction| instruction.executed);
+
+    // Ensure every prerequisite proposal has been processed.
+    for prerequisite in proposal_transaction_state.prerequisites.iter() {
+        let prerequisite_info = next_account_info(accounts_iter)?;
+
+        if prerequisite_info.key != prerequisite {
+            return Err(PaladinGovernanceError::IncorrectPrerequisiteAddress.into());
+        }
+
+        if execution_started {
+            continue;
+        }
+
+        // A deleted prerequisite can't be told apart from one that was never
+        // processed, so the proposal can't be processed either.
+        if prerequisite_info.data_is_empty() {
+            return Err(PaladinGovernanceError::PrerequisiteClosed.into());
+        }
+
+        let prerequisite_state = load_proposal(program_id, prerequisite_info)?;
+
+        match prerequisite_state.status {
+            ProposalStatus::Processed => {}
+            ProposalStatus::Rejected | ProposalStatus::Cancelled => {
+                return Err(PaladinGovernanceError::PrerequisiteClosed.into());
+            }
+            ProposalStatus::Draft | ProposalStatus::Voting | ProposalStatus::Accepted => {
+                return Err(PaladinGovernanceError::PrerequisiteNotProcessed.into());
+            }
+        }
+    }
+
+    // Only the instructions of the proposal's outcome can be executed.
+    let instructions = proposal_transaction_state.outcome_instructions_mut(proposal_state)?;
+
     // Ensure the index is valid.
     let instruction_index = instruction_index as usize;
-    if instruction_index >= proposal_transaction_state.instructions.len() {
+    if instruction_index >= instructions.len() {
         return Err(PaladinGovernanceError::InvalidTransactionIndex.into());
     }
-    let instruction = &proposal_transaction_state.instructions[instruction_index];
+    let instruction = &instructions[instruction_index];
 
     // Ensure the instruction has not already been executed.
     if instruction.executed {
@@ -1045,9 +1509,7 @@ fn process_process_instruction(
     }
 
     // Ensure the previous instruction has been executed.
-    if instruction_index > 0
-        && !proposal_transaction_state.instructions[instruction_index.saturating_sub(1)].executed
-    {
+    if instruction_index > 0 && !instructions[instruction_index.saturating_sub(1)].executed {
         return Err(PaladinGovernanceError::PreviousInstructionHasNotBeenExecuted.into());
     }
 
@@ -1071,23 +1533,40 @@ fn process_process_instruction(
     }
 
     // Mark the instruction as executed.
-    proposal_transaction_state.instructions[instruction_index].executed = true;
+    instructions[instruction_index].executed = true;
 
     // If this was the last instruction, mark the proposal as processed.
     #[allow(clippy::arithmetic_side_effects)]
     {
-        if instruction_index == proposal_transaction_state.instructions.len() - 1 {
+        if instruction_index == instructions.len() - 1 {
             proposal_state.status = ProposalStatus::Processed;
         }
     }
 
-    // Write the data (no reallocation necessary).
-    borsh::to_writer(
-        &mut proposal_transaction_info.data.borrow_mut()[..],
-        &proposal_transaction_state,
-    )?;
+    // Write the data (no reallocation necessary). Accounts written before
+    // prerequisites and options were added keep their layout, without them.
+    let mut proposal_transaction_data = proposal_transaction_info.try_borrow_mut_data()?;
+    if proposal_transaction_data.len() < get_instance_packed_len(&proposal_transaction_state)? {
+        borsh::to_writer(
+            &mut proposal_transaction_data[..],
+            &proposal_transaction_state.instructions,
+        )?;
+    } else {
+        borsh::to_writer(
+            &mut proposal_transaction_data[..],
+            &proposal_transaction_state,
+        )?;
+    }
 
-    Ok(())
+    GovernanceEvent::InstructionExecuted {
+        proposal: *proposal_info.key,
+        option_index: proposal_state
+            .is_multiple_choice()
+            .then_some(proposal_state.winning_option),
+        instruction_index: instruction_index as u32,
+        status: proposal_state.status,
+    }
+    .emit()
 }
 
 /// Processes a
@@ -1105,30 +1584,33 @@ fn process_initialize_governance(
     stake_per_proposal: u64,
     cooldown_seconds: u64,
 ) -> ProgramResult {
-    // Sanity check arguments.
-    // 0.1% <= proposal_minimum_quorum < 100%.
-    assert!(
-        ((THRESHOLD_SCALING_FACTOR / 1000)..THRESHOLD_SCALING_FACTOR)
-            .contains(&proposal_minimum_quorum),
-        "invalid proposal_minimum_quorum"
-    );
-    // 10% <= proposal_pass_threshold < 100%.
-    assert!(
-        ((THRESHOLD_SCALING_FACTOR / 10)..THRESHOLD_SCALING_FACTOR)
-            .contains(&proposal_pass_threshold),
-        "invalid proposal_pass_threshold"
-    );
-
     // Load accounts.
     let accounts_iter = &mut accounts.iter();
     let governance_info = next_account_info(accounts_iter)?;
     let stake_config_info = next_account_info(accounts_iter)?;
     let _system_program_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let program_data_info = next_account_info(accounts_iter)?;
 
     check_stake_config_exists(stake_config_info)?;
 
-    // Create the governance config account.
-    {
+    // Ensure the authority signed the transaction.
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the authority is either the stake config authority or the
+    // program's upgrade authority, so the governance config can't be
+    // initialized by anyone front-running the deployment.
+    check_governance_initializer(
+        program_id,
+        authority_info.key,
+        stake_config_info,
+        program_data_info,
+    )?;
+
+    // Create the governance config account.
+    {
         // Get expiration timestamp for the cooldown period.
         let cooldown_expires =
             (Clock::get()?.unix_timestamp as u64).saturating_add(cooldown_seconds);
@@ -1156,6 +1638,21 @@ fn process_initialize_governance(
             return Err(ProgramError::AccountNotRentExempt);
         }
 
+        // Ensure the governance parameters are within bounds.
+        let governance_config = GovernanceConfig {
+            discriminator: GovernanceConfig::SPL_DISCRIMINATOR.into(),
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            stake_config_address: *stake_config_info.key,
+            voting_period_seconds,
+            stake_per_proposal,
+            governance_config: governance_address,
+            cooldown_expires,
+            ..GovernanceConfig::default()
+        };
+        governance_config.validate()?;
+
         // Allocate & assign.
         invoke_signed(
             &system_instruction::allocate(&governance_address, size as u64),
@@ -1171,19 +1668,10 @@ fn process_initialize_governance(
         // Write the data.
         let mut data = governance_info.try_borrow_mut_data()?;
         *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
-            GovernanceConfig {
-                cooldown_period_seconds,
-                proposal_minimum_quorum,
-                proposal_pass_threshold,
-                stake_config_address: *stake_config_info.key,
-                voting_period_seconds,
-                stake_per_proposal,
-                governance_config: governance_address,
-                cooldown_expires,
-            };
-    }
+            governance_config;
 
-    Ok(())
+        GovernanceEvent::governance_updated(&governance_config).emit()
+    }
 }
 
 /// Processes an
@@ -1199,6 +1687,36 @@ fn process_update_governance(
     proposal_pass_threshold: u32,
     voting_period_seconds: u64,
     stake_per_proposal: u64,
+) -> ProgramResult {
+    process_partial_update_governance(
+        program_id,
+        accounts,
+        governance_id,
+        Some(cooldown_period_seconds),
+        Some(proposal_minimum_quorum),
+        Some(proposal_pass_threshold),
+        Some(voting_period_seconds),
+        Some(stake_per_proposal),
+        None,
+        None,
+    )
+}
+
+/// Processes a
+/// [PartialUpdateGovernance](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+#[allow(clippy::too_many_arguments)]
+fn process_partial_update_governance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    governance_id: u64,
+    cooldown_period_seconds: Option<u64>,
+    proposal_minimum_quorum: Option<u32>,
+    proposal_pass_threshold: Option<u32>,
+    voting_period_seconds: Option<u64>,
+    stake_per_proposal: Option<u64>,
+    pause_guardian: Option<Pubkey>,
+    proposal_winning_margin: Option<u32>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -1231,12 +1749,509 @@ fn process_update_governance(
         return Err(PaladinGovernanceError::IncorrectTreasuryAddress.into());
     }
 
-    // Update the governance config.
-    state.cooldown_period_seconds = cooldown_period_seconds;
-    state.proposal_minimum_quorum = proposal_minimum_quorum;
-    state.proposal_pass_threshold = proposal_pass_threshold;
-    state.voting_period_seconds = voting_period_seconds;
-    state.stake_per_proposal = stake_per_proposal;
+    // Update the provided governance config parameters.
+    if let Some(cooldown_period_seconds) = cooldown_period_seconds {
+        state.cooldown_period_seconds = cooldown_period_seconds;
+    }
+    if let Some(proposal_minimum_quorum) = proposal_minimum_quorum {
+        state.proposal_minimum_quorum = proposal_minimum_quorum;
+    }
+    if let Some(proposal_pass_threshold) = proposal_pass_threshold {
+        state.proposal_pass_threshold = proposal_pass_threshold;
+    }
+    if let Some(voting_period_seconds) = voting_period_seconds {
+        state.voting_period_seconds = voting_period_seconds;
+    }
+    if let Some(stake_per_proposal) = stake_per_proposal {
+        state.stake_per_proposal = stake_per_proposal;
+    }
+    if let Some(pause_guardian) = pause_guardian {
+        state.pause_guardian = pause_guardian;
+    }
+    if let Some(proposal_winning_margin) = proposal_winning_margin {
+        state.proposal_winning_margin = proposal_winning_margin;
+    }
+
+    // Ensure the updated governance parameters are within bounds.
+    state.validate()?;
+
+    GovernanceEvent::governance_updated(state).emit()
+}
+
+/// Processes a
+/// [CancelProposal](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_cancel_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let author_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check & deserialize proposal.
+    check_proposal_exists(program_id, proposal_info)?;
+    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Check & deserialize proposal author state.
+    if author_info.key
+        != &crate::state::get_proposal_author_address(stake_authority_info.key, program_id)
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let mut author_data = author_info.try_borrow_mut_data()?;
+    let author_state = bytemuck::try_from_bytes_mut::<Author>(&mut author_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the stake authority is the proposal author.
+    proposal_state.check_author(stake_authority_info.key)?;
+
+    // Ensure the proposal is in the voting stage.
+    if proposal_state.status != ProposalStatus::Voting {
+        return Err(PaladinGovernanceError::ProposalNotInVotingStage.into());
+    }
+
+    // Once quorum has been reached the outcome belongs to the voters, so the
+    // author can no longer withdraw the proposal.
+    if proposal_state.cooldown_timestamp.is_some() {
+        return Err(PaladinGovernanceError::ProposalCooldownStarted.into());
+    }
+
+    // Set the proposal's status to cancelled.
+    proposal_state.status = ProposalStatus::Cancelled;
+
+    // Decrease the user's active proposal count.
+    author_state.active_proposals = author_state
+        .active_proposals
+        .checked_sub(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    GovernanceEvent::ProposalFinalized {
+        proposal: *proposal_info.key,
+        status: proposal_state.status,
+        winning_option: None,
+        stake_for: proposal_state.stake_for,
+        stake_against: proposal_state.stake_against,
+    }
+    .emit()
+}
+
+/// Processes a
+/// [PushPrerequisite](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_push_prerequisite(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    prerequisite_proposal: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let proposal_state = load_proposal(program_id, proposal_info)?;
+
+    // Ensure the stake authority is the proposal author.
+    proposal_state.check_author(stake_authority_info.key)?;
+
+    // Ensure the proposal is in draft stage.
+    if proposal_state.status != ProposalStatus::Draft {
+        return Err(PaladinGovernanceError::ProposalIsImmutable.into());
+    }
+
+    // Ensure the proposal has a transaction to modify.
+    if proposal_state.kind == ProposalKind::Signal {
+        return Err(PaladinGovernanceError::ProposalNotExecutable.into());
+    }
+
+    // Ensure the provided proposal transaction address is the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let mut proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // Ensure the proposal does not depend on itself, and the prerequisite has
+    // not already been pushed.
+    if prerequisite_proposal == *proposal_info.key
+        || proposal_transaction_state
+            .prerequisites
+            .contains(&prerequisite_proposal)
+    {
+        return Err(PaladinGovernanceError::InvalidPrerequisite.into());
+    }
+
+    // Insert the prerequisite.
+    proposal_transaction_state
+        .prerequisites
+        .push(prerequisite_proposal);
+
+    // Reallocate the account.
+    let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+    proposal_transaction_info.realloc(new_len, true)?;
+
+    // Ensure the account is still rent exempt.
+    let rent = Rent::get().unwrap().minimum_balance(new_len);
+    require!(
+        proposal_transaction_info.lamports() >= rent,
+        ProgramError::AccountNotRentExempt,
+    );
+
+    // Write the data.
+    borsh::to_writer(
+        &mut proposal_transaction_info.data.borrow_mut()[..],
+        &proposal_transaction_state,
+    )?;
+
+    Ok(())
+}
+
+/// Processes a
+/// [PushOption](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_push_option(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let stake_authority_info = next_account_info(accounts_iter)?;
+    let proposal_info = next_account_info(accounts_iter)?;
+    let proposal_transaction_info = next_account_info(accounts_iter)?;
+
+    // Ensure the stake authority is a signer.
+    if !stake_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_proposal_exists(program_id, proposal_info)?;
+
+    let mut proposal_data = proposal_info.try_borrow_mut_data()?;
+    let proposal_state = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the stake authority is the proposal author.
+    proposal_state.check_author(stake_authority_info.key)?;
+
+    // Ensure the proposal is in draft stage.
+    if proposal_state.status != ProposalStatus::Draft {
+        return Err(PaladinGovernanceError::ProposalIsImmutable.into());
+    }
+
+    // Ensure the proposal has a transaction to modify.
+    if proposal_state.kind == ProposalKind::Signal {
+        return Err(PaladinGovernanceError::ProposalNotExecutable.into());
+    }
+
+    // Ensure the proposal has room for another option.
+    if proposal_state.option_count as usize >= MAX_PROPOSAL_OPTIONS {
+        return Err(PaladinGovernanceError::TooManyProposalOptions.into());
+    }
+
+    // Ensure the provided proposal transaction address is the correct address
+    // derived from the proposal.
+    if !proposal_transaction_info
+        .key
+        .eq(&get_proposal_transaction_address(
+            proposal_info.key,
+            program_id,
+        ))
+    {
+        return Err(PaladinGovernanceError::IncorrectProposalTransactionAddress.into());
+    }
+
+    check_proposal_transaction_exists(program_id, proposal_transaction_info)?;
+
+    let mut proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_info.try_borrow_data()?)?;
+
+    // Ensure the proposal does not already have yes/no instructions, which
+    // would never be executed once it becomes a multiple-choice proposal.
+    if !proposal_transaction_state.instructions.is_empty() {
+        return Err(PaladinGovernanceError::InvalidProposalOption.into());
+    }
+
+    // Insert the option.
+    proposal_transaction_state
+        .options
+        .push(ProposalOption::default());
+    proposal_state.option_count = proposal_state
+        .option_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Reallocate the account.
+    let new_len = get_instance_packed_len(&proposal_transaction_state)?;
+    proposal_transaction_info.realloc(new_len, true)?;
+
+    // Ensure the account is still rent exempt.
+    let rent = Rent::get().unwrap().minimum_balance(new_len);
+    require!(
+        proposal_transaction_info.lamports() >= rent,
+        ProgramError::AccountNotRentExempt,
+    );
+
+    // Write the data.
+    borsh::to_writer(
+        &mut proposal_transaction_info.data.borrow_mut()[..],
+        &proposal_transaction_state,
+    )?;
+
+    Ok(())
+}
+
+/// Processes a
+/// [MigrateAccount](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_migrate_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    governance_id: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let account_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the account is owned by the Paladin Governance program.
+    if account_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Upgrade the legacy layout to the current one.
+    let not_migrated = Err(PaladinGovernanceError::AccountNotMigrated.into());
+    let migrated_data = if check_proposal_exists(program_id, account_info) == not_migrated {
+        bytemuck::bytes_of(&load_proposal(program_id, account_info)?).to_vec()
+    } else if check_governance_exists(program_id, account_info) == not_migrated {
+        let governance_config = load_governance_config(program_id, account_info)?;
+
+        // Ensure the account is the governance config derived from its stake
+        // config, and not another account of the same size.
+        if account_info.key
+            != &get_governance_address(
+                &governance_config.stake_config_address,
+                &governance_id,
+                program_id,
+            )
+        {
+            return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+        }
+
+        bytemuck::bytes_of(&governance_config).to_vec()
+    } else {
+        return Err(ProgramError::InvalidAccountData);
+    };
+
+    // Top up the account to be rent exempt at its new size.
+    let shortfall = Rent::get()?
+        .minimum_balance(migrated_data.len())
+        .saturating_sub(account_info.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account_info.key, shortfall),
+            &[payer_info.clone(), account_info.clone()],
+        )?;
+    }
+
+    // Reallocate & write the data.
+    account_info.realloc(migrated_data.len(), false)?;
+    account_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&migrated_data);
+
+    Ok(())
+}
+
+/// Processes a
+/// [SetPaused](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_set_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    paused: bool,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let authority_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
+
+    // Ensure the authority is a signer.
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_governance_exists(program_id, governance_info)?;
+
+    let mut data = governance_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<GovernanceConfig>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Ensure the authority is either the treasury or the pause guardian.
+    let is_treasury = authority_info.key == &get_treasury_address(governance_info.key, program_id);
+    let is_pause_guardian =
+        state.pause_guardian != Pubkey::default() && authority_info.key == &state.pause_guardian;
+    if !(is_treasury || is_pause_guardian) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let clock = <Clock as Sysvar>::get()?;
+    match (paused, state.paused_timestamp) {
+        // Pause, unless already paused.
+        (true, None) => {
+            state.paused_timestamp = NonZeroU64::new(clock.unix_timestamp as u64);
+        }
+        // Unpause, accruing the time spent paused.
+        (false, Some(_)) => {
+            state.paused_seconds = state.total_paused_seconds(&clock);
+            state.paused_timestamp = None;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Processes a
+/// [MigrateGovernance](enum.PaladinGovernanceInstruction.html)
+/// instruction.
+fn process_migrate_governance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    governance_id: u64,
+    new_governance_id: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let treasury_info = next_account_info(accounts_iter)?;
+    let governance_info = next_account_info(accounts_iter)?;
+    let new_treasury_info = next_account_info(accounts_iter)?;
+    let new_governance_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let token_program_info = next_account_info(accounts_iter)?;
+    let token_2022_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the treasury is a signer.
+    if !treasury_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_governance_exists(program_id, governance_info)?;
+
+    let mut data = governance_info.try_borrow_mut_data()?;
+    let state = bytemuck::try_from_bytes_mut::<GovernanceConfig>(&mut data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let stake_config_address = state.stake_config_address;
+
+    // Ensure the provided governance account has the correct address derived
+    // from the stake config.
+    if governance_info.key
+        != &get_governance_address(&stake_config_address, &governance_id, program_id)
+    {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+
+    // Ensure the provided treasury account has the correct address derived
+    // from the governance config.
+    if treasury_info.key != &get_treasury_address(governance_info.key, program_id) {
+        return Err(PaladinGovernanceError::IncorrectTreasuryAddress.into());
+    }
+
+    // Ensure the governance config has not already been migrated.
+    state.check_not_retired()?;
+
+    // Ensure the new governance config is a distinct, active config for the
+    // same stake config.
+    if new_governance_info.key
+        != &get_governance_address(&stake_config_address, &new_governance_id, program_id)
+        || new_governance_info.key == governance_info.key
+    {
+        return Err(PaladinGovernanceError::IncorrectGovernanceConfigAddress.into());
+    }
+    let new_governance_config = load_governance_config(program_id, new_governance_info)?;
+    new_governance_config.check_stake_config(&stake_config_address)?;
+    new_governance_config.check_not_retired()?;
+
+    // Ensure the provided new treasury account has the correct address
+    // derived from the new governance config.
+    if new_treasury_info.key != &get_treasury_address(new_governance_info.key, program_id) {
+        return Err(PaladinGovernanceError::IncorrectTreasuryAddress.into());
+    }
+
+    // Retire the governance config, blocking new proposals.
+    state.successor = *new_governance_info.key;
+
+    // Transfer every provided token account balance to the new treasury,
+    // through the token program owning the account.
+    if token_program_info.key != &spl_token::id()
+        || token_2022_program_info.key != &spl_token_2022::id()
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    while let Some(token_account_info) = accounts_iter.next() {
+        let new_token_account_info = next_account_info(accounts_iter)?;
+        let mint_info = next_account_info(accounts_iter)?;
+
+        let amount = load_treasury_token_account(token_account_info, treasury_info.key)?.amount;
+        load_treasury_token_account(new_token_account_info, new_treasury_info.key)?;
+
+        if amount > 0 {
+            let decimals = StateWithExtensions::<Mint>::unpack(&mint_info.try_borrow_data()?)?
+                .base
+                .decimals;
+            invoke(
+                &spl_token_2022::instruction::transfer_checked(
+                    token_account_info.owner,
+                    token_account_info.key,
+                    mint_info.key,
+                    new_token_account_info.key,
+                    treasury_info.key,
+                    &[],
+                    amount,
+                    decimals,
+                )?,
+                &[
+                    token_account_info.clone(),
+                    mint_info.clone(),
+                    new_token_account_info.clone(),
+                    treasury_info.clone(),
+                ],
+            )?;
+        }
+    }
+
+    // Transfer the treasury's lamports to the new treasury.
+    let lamports = treasury_info.lamports();
+    if lamports > 0 {
+        invoke(
+            &system_instruction::transfer(treasury_info.key, new_treasury_info.key, lamports),
+            &[treasury_info.clone(), new_treasury_info.clone()],
+        )?;
+    }
 
     Ok(())
 }
@@ -1252,7 +2267,7 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
         }
         PaladinGovernanceInstruction::CreateProposal => {
             msg!("Instruction: CreateProposal");
-            process_create_proposal(program_id, accounts)
+            process_create_proposal(program_id, accounts, ProposalKind::Executable)
         }
         PaladinGovernanceInstruction::PushInstruction {
             instruction_program_id,
@@ -1263,6 +2278,7 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
             process_push_instruction(
                 program_id,
                 accounts,
+                None,
                 instruction_program_id,
                 instruction_account_metas,
                 instruction_data,
@@ -1278,11 +2294,11 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
         }
         PaladinGovernanceInstruction::Vote { election } => {
             msg!("Instruction: Vote");
-            process_vote(program_id, accounts, election)
+            process_vote(program_id, accounts, election, None)
         }
         PaladinGovernanceInstruction::SwitchVote { new_election } => {
             msg!("Instruction: SwitchVote");
-            process_switch_vote(program_id, accounts, new_election)
+            process_switch_vote(program_id, accounts, new_election, None)
         }
         PaladinGovernanceInstruction::FinishVoting => {
             msg!("Instruction: FinishVoting");
@@ -1338,56 +2354,100 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
                 stake_per_proposal,
             )
         }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use {super::*, proptest::prelude::*};
-
-    // Ensures the (intermediate) stake value is never greater than the total
-    // stake value, within the range 0 to the max value provided.
-    prop_compose! {
-        fn total_and_intermediate(max_value: u64)(total in 0..=max_value)
-                        (intermediate in 0..=total, total in Just(total))
-                        -> (u64, u64) {
-           (intermediate, total)
-       }
-    }
-
-    proptest! {
-        #[test]
-        fn test_calculate_proposal_vote_threshold(
-            (stake, total_stake) in total_and_intermediate(u64::MAX)
-        ) {
-            let mut proposal_state = Box::new([0; std::mem::size_of::<Proposal>()]);
-            let proposal_state = bytemuck::from_bytes_mut::<Proposal>(&mut proposal_state[..]);
-            proposal_state.stake_for = stake;
-
-            // Calculate.
-            //
-            // Since we've configured limits on the input values, we can safely
-            // unwrap the result.
-            let result = calculate_voter_turnout(proposal_state, total_stake).unwrap();
-            // Evaluate.
-            if total_stake == 0 {
-                prop_assert_eq!(result, 0);
-            } else {
-                // The scaling multiplication and subsequent division should
-                // always succeed, thanks to the limits on the input values.
-                let scaled_stake_ratio = u128::from(stake)
-                    .checked_mul(u128::from(THRESHOLD_SCALING_FACTOR))
-                    .and_then(|scaled_stake| scaled_stake.checked_div(total_stake as u128))
-                    .unwrap();
-
-                // Since a failure to convert to `u32` can only occur if the
-                // stake is greater than the total stake, which is not possible
-                // thanks to our inputs, we can safely unwrap here.
-                let expected = u32::try_from(scaled_stake_ratio).unwrap();
-
-                // The result should be the expected value.
-                prop_assert_eq!(result, expected);
-            }
+        PaladinGovernanceInstruction::CancelProposal => {
+            msg!("Instruction: CancelProposal");
+            process_cancel_proposal(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::PushPrerequisite {
+            prerequisite_proposal,
+        } => {
+            msg!("Instruction: PushPrerequisite");
+            process_push_prerequisite(program_id, accounts, prerequisite_proposal)
+        }
+        PaladinGovernanceInstruction::PushOption => {
+            msg!("Instruction: PushOption");
+            process_push_option(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::PushOptionInstruction {
+            option_index,
+            instruction_program_id,
+            instruction_account_metas,
+            instruction_data,
+        } => {
+            msg!("Instruction: PushOptionInstruction");
+            process_push_instruction(
+                program_id,
+                accounts,
+                Some(option_index),
+                instruction_program_id,
+                instruction_account_metas,
+                instruction_data,
+            )
+        }
+        PaladinGovernanceInstruction::VoteOption { option_index } => {
+            msg!("Instruction: VoteOption");
+            process_vote(
+                program_id,
+                accounts,
+                ProposalVoteElection::For,
+                Some(option_index),
+            )
+        }
+        PaladinGovernanceInstruction::SwitchVoteOption { new_option_index } => {
+            msg!("Instruction: SwitchVoteOption");
+            process_switch_vote(
+                program_id,
+                accounts,
+                ProposalVoteElection::For,
+                Some(new_option_index),
+            )
+        }
+        PaladinGovernanceInstruction::CreateSignalProposal => {
+            msg!("Instruction: CreateSignalProposal");
+            process_create_proposal(program_id, accounts, ProposalKind::Signal)
+        }
+        PaladinGovernanceInstruction::CloseAuthor => {
+            msg!("Instruction: CloseAuthor");
+            process_close_author(program_id, accounts)
+        }
+        PaladinGovernanceInstruction::PartialUpdateGovernance {
+            governance_id,
+            cooldown_period_seconds,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            voting_period_seconds,
+            stake_per_proposal,
+            pause_guardian,
+            proposal_winning_margin,
+        } => {
+            msg!("Instruction: PartialUpdateGovernance");
+            process_partial_update_governance(
+                program_id,
+                accounts,
+                governance_id,
+                cooldown_period_seconds,
+                proposal_minimum_quorum,
+                proposal_pass_threshold,
+                voting_period_seconds,
+                stake_per_proposal,
+                pause_guardian,
+                proposal_winning_margin,
+            )
+        }
+        PaladinGovernanceInstruction::MigrateAccount { governance_id } => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts, governance_id)
+        }
+        PaladinGovernanceInstruction::SetPaused { paused } => {
+            msg!("Instruction: SetPaused");
+            process_set_paused(program_id, accounts, paused)
+        }
+        PaladinGovernanceInstruction::MigrateGovernance {
+            governance_id,
+            new_governance_id,
+        } => {
+            msg!("Instruction: MigrateGovernance");
+            process_migrate_governance(program_id, accounts, governance_id, new_governance_id)
         }
     }
 }
@@ -1,7 +1,10 @@
 //! Program state types.
 
 use {
-    crate::error::PaladinGovernanceError,
+    crate::{
+        error::PaladinGovernanceError,
+        outcome::{self, Tally, Timeline, THRESHOLD_SCALING_FACTOR},
+    },
     borsh::{BorshDeserialize, BorshSerialize},
     bytemuck::{Pod, Zeroable},
     num_enum::{IntoPrimitive, TryFromPrimitive},
@@ -14,16 +17,19 @@ use {
         pubkey::Pubkey,
     },
     spl_discriminator::SplDiscriminate,
-    std::num::NonZeroU64,
+    std::{
+        io::{self, Read},
+        num::NonZeroU64,
+    },
 };
 
 /// The seed prefix (`"piggy_bank"`) in bytes used to derive the address of the
 /// treasury account.
-/// Seeds: `"piggy_bank" + stake_config_address`.
+/// Seeds: `"piggy_bank" + governance_config_address`.
 pub const SEED_PREFIX_TREASURY: &[u8] = b"piggy_bank";
 /// The seed prefix (`"governance"`) in bytes used to derive the address of the
 /// governance config account.
-/// Seeds: `"governance" + stake_config_address`.
+/// Seeds: `"governance" + stake_config_address + governance_id`.
 pub const SEED_PREFIX_GOVERNANCE: &[u8] = b"governance";
 /// The seed prefix (`"proposal_vote"`) in bytes used to derive the address of
 /// the proposal vote account, representing a vote cast by a validator for a
@@ -203,9 +209,14 @@ pub(crate) fn collect_proposal_author_seeds<'a>(
 }
 
 /// Governance configuration account.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, ShankType, Zeroable)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Pod, ShankAccount, ShankType, SplDiscriminate, Zeroable,
+)]
+#[discriminator_hash_input("governance::state::governance_config")]
 #[repr(C)]
 pub struct GovernanceConfig {
+    /// The account discriminator, identifying the current layout.
+    pub discriminator: [u8; 8],
     /// The cooldown period that begins when a proposal reaches the
     /// `proposal_acceptance_threshold` and upon its conclusion will execute
     /// the proposal's instruction.
@@ -215,6 +226,9 @@ pub struct GovernanceConfig {
     pub proposal_minimum_quorum: u32,
     /// The minimum required threshold of cast votes (in 1e9 scaled format) that
     /// must be `For` for the proposal to pass.
+    ///
+    /// Multiple-choice proposals are held to `proposal_winning_margin`
+    /// instead.
     pub proposal_pass_threshold: u32,
     /// The Paladin stake config account that this governance config account
     /// corresponds to.
@@ -231,6 +245,35 @@ pub struct GovernanceConfig {
     /// Timestamp for when the cooldown period expires and proposals can be
     /// created
     pub cooldown_expires: u64,
+    /// The key allowed to pause and unpause governance without a proposal.
+    ///
+    /// `Pubkey::default()` means no pause guardian is set.
+    pub pause_guardian: Pubkey,
+    /// Timestamp for when governance was paused.
+    ///
+    /// A `None` value means governance is not paused.
+    pub paused_timestamp: Option<NonZeroU64>,
+    /// Total time governance has spent paused, excluding an ongoing pause.
+    pub paused_seconds: u64,
+    /// The governance config this config was migrated to.
+    ///
+    /// `Pubkey::default()` means the config has not been retired.
+    pub successor: Pubkey,
+    /// The minimum share of cast votes (in 1e9 scaled format) by which the
+    /// winning option of a multiple-choice proposal must lead the runner-up.
+    ///
+    /// The winner must always lead strictly, so zero means a plurality wins.
+    pub proposal_winning_margin: u32,
+    _padding: [u8; 4],
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
+            ..Zeroable::zeroed()
+        }
+    }
 }
 
 impl GovernanceConfig {
@@ -241,6 +284,75 @@ impl GovernanceConfig {
         }
         Err(PaladinGovernanceError::IncorrectStakeConfig.into())
     }
+
+    /// Whether governance is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused_timestamp.is_some()
+    }
+
+    /// Ensure governance is not paused.
+    pub fn check_not_paused(&self) -> ProgramResult {
+        if self.is_paused() {
+            return Err(PaladinGovernanceError::GovernancePaused.into());
+        }
+        Ok(())
+    }
+
+    /// Whether the config was retired by migrating to another governance
+    /// config.
+    pub fn is_retired(&self) -> bool {
+        self.successor != Pubkey::default()
+    }
+
+    /// Ensure the config has not been retired.
+    pub fn check_not_retired(&self) -> ProgramResult {
+        if self.is_retired() {
+            return Err(PaladinGovernanceError::GovernanceRetired.into());
+        }
+        Ok(())
+    }
+
+    /// Total time governance has spent paused as of the clock sysvar,
+    /// including an ongoing pause.
+    pub fn total_paused_seconds(&self, clock: &Clock) -> u64 {
+        outcome::total_paused_seconds(
+            self.paused_seconds,
+            self.paused_timestamp,
+            clock.unix_timestamp as u64,
+        )
+    }
+
+    /// Validate the governance parameters against their bounds:
+    ///
+    /// * `0.1% <= proposal_minimum_quorum < 100%`
+    /// * `10% <= proposal_pass_threshold < 100%`
+    /// * `proposal_winning_margin < 100%`
+    /// * `voting_period_seconds > 0`
+    /// * `0 < cooldown_period_seconds <= voting_period_seconds`
+    pub fn validate(&self) -> ProgramResult {
+        if !((THRESHOLD_SCALING_FACTOR / 1000)..THRESHOLD_SCALING_FACTOR)
+            .contains(&self.proposal_minimum_quorum)
+        {
+            return Err(PaladinGovernanceError::InvalidProposalMinimumQuorum.into());
+        }
+        if !((THRESHOLD_SCALING_FACTOR / 10)..THRESHOLD_SCALING_FACTOR)
+            .contains(&self.proposal_pass_threshold)
+        {
+            return Err(PaladinGovernanceError::InvalidProposalPassThreshold.into());
+        }
+        if self.proposal_winning_margin >= THRESHOLD_SCALING_FACTOR {
+            return Err(PaladinGovernanceError::InvalidProposalWinningMargin.into());
+        }
+        if self.voting_period_seconds == 0 {
+            return Err(PaladinGovernanceError::InvalidVotingPeriod.into());
+        }
+        // Cooldown periods can keep voting open past the voting period, so
+        // they're capped at the voting period's length.
+        if !(1..=self.voting_period_seconds).contains(&self.cooldown_period_seconds) {
+            return Err(PaladinGovernanceError::InvalidCooldownPeriod.into());
+        }
+        Ok(())
+    }
 }
 
 /// An account metadata for a proposal instruction.
@@ -319,15 +431,77 @@ impl From<&Instruction> for ProposalInstruction {
     }
 }
 
-/// Governance proposal transaction account.
+/// An option of a multiple-choice governance proposal.
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq, ShankType)]
+pub struct ProposalOption {
+    /// The instructions to execute if the option wins.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// Governance proposal transaction account.
+///
+/// Accounts written before prerequisites and options were added end after
+/// the instructions, and are deserialized with both empty.
+#[derive(BorshSerialize, Clone, Debug, Default, PartialEq, ShankType)]
 pub struct ProposalTransaction {
     /// The instructions to execute.
     pub instructions: Vec<ProposalInstruction>,
+    /// Proposals that must be processed before any instruction can execute.
+    pub prerequisites: Vec<Pubkey>,
+    /// The options of a multiple-choice proposal.
+    ///
+    /// Empty for yes/no proposals, which execute `instructions` instead.
+    pub options: Vec<ProposalOption>,
+}
+
+impl BorshDeserialize for ProposalTransaction {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            instructions: Vec::deserialize_reader(reader)?,
+            prerequisites: deserialize_trailing_vec(reader)?,
+            options: deserialize_trailing_vec(reader)?,
+        })
+    }
+}
+
+/// Deserializes a vector added to the end of an account layout, which is
+/// empty if the reader ends before it.
+fn deserialize_trailing_vec<T: BorshDeserialize, R: Read>(reader: &mut R) -> io::Result<Vec<T>> {
+    let mut first = [0; 1];
+    if reader.read(&mut first)? == 0 {
+        return Ok(Vec::new());
+    }
+    Vec::deserialize_reader(&mut first.as_slice().chain(reader))
+}
+
+impl ProposalTransaction {
+    /// The instructions to execute for the outcome of the provided proposal.
+    pub fn outcome_instructions_mut(
+        &mut self,
+        proposal: &Proposal,
+    ) -> Result<&mut Vec<ProposalInstruction>, ProgramError> {
+        if !proposal.is_multiple_choice() {
+            return Ok(&mut self.instructions);
+        }
+        self.options
+            .get_mut(proposal.winning_option as usize)
+            .map(|option| &mut option.instructions)
+            .ok_or_else(|| PaladinGovernanceError::InvalidProposalOption.into())
+    }
 }
 
 /// The status of a governance proposal.
-#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, ShankType, TryFromPrimitive)]
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    ShankType,
+    TryFromPrimitive,
+)]
 #[repr(u8)]
 pub enum ProposalStatus {
     /// The proposal is in the draft stage.
@@ -340,13 +514,18 @@ pub enum ProposalStatus {
     Rejected,
     /// The proposal was accepted and processed.
     Processed,
+    /// The proposal was cancelled by its author during voting.
+    Cancelled,
 }
 
 impl ProposalStatus {
     pub fn is_active(&self) -> bool {
         match self {
             ProposalStatus::Voting | ProposalStatus::Accepted => true,
-            ProposalStatus::Draft | ProposalStatus::Rejected | ProposalStatus::Processed => false,
+            ProposalStatus::Draft
+            | ProposalStatus::Rejected
+            | ProposalStatus::Processed
+            | ProposalStatus::Cancelled => false,
         }
     }
 }
@@ -354,9 +533,41 @@ impl ProposalStatus {
 unsafe impl Pod for ProposalStatus {}
 unsafe impl Zeroable for ProposalStatus {}
 
+/// The kind of a governance proposal.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    ShankType,
+    TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum ProposalKind {
+    /// The proposal executes instructions once accepted.
+    Executable,
+    /// The proposal only records the outcome of the vote.
+    ///
+    /// Signal proposals have no proposal transaction account and are marked
+    /// processed as soon as they are accepted.
+    Signal,
+}
+
+unsafe impl Pod for ProposalKind {}
+unsafe impl Zeroable for ProposalKind {}
+
+/// The maximum number of options a multiple-choice proposal can have.
+pub const MAX_PROPOSAL_OPTIONS: usize = 8;
+
 /// Governance proposal account.
+///
+/// The discriminator differs from the one of the
+/// [LegacyProposal](struct.LegacyProposal.html) layout it replaced.
 #[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, SplDiscriminate, Zeroable)]
-#[discriminator_hash_input("governance::state::proposal")]
+#[discriminator_hash_input("governance::state::proposal_v2")]
 #[repr(C)]
 pub struct Proposal {
     discriminator: [u8; 8],
@@ -364,7 +575,8 @@ pub struct Proposal {
     pub author: Pubkey,
     /// Timestamp for when the cooldown period began.
     ///
-    /// A `None` value means cooldown has not begun.
+    /// A `None` value means cooldown has not begun. Shifted forward by any
+    /// time governance spends paused during the cooldown period.
     pub cooldown_timestamp: Option<NonZeroU64>,
     /// Timestamp for when proposal was created.
     pub creation_timestamp: UnixTimestamp,
@@ -376,9 +588,29 @@ pub struct Proposal {
     pub stake_for: u64,
     /// Proposal status
     pub status: ProposalStatus,
-    _padding: [u8; 7],
+    /// The number of options of a multiple-choice proposal.
+    ///
+    /// Zero for yes/no proposals.
+    pub option_count: u8,
+    /// The option that won a multiple-choice proposal.
+    ///
+    /// Only meaningful once the proposal has been accepted.
+    pub winning_option: u8,
+    /// The proposal kind.
+    pub kind: ProposalKind,
+    _padding: [u8; 4],
     /// The timestamp when voting began.
+    ///
+    /// Shifted forward by any time governance spends paused during voting.
     pub voting_start_timestamp: Option<NonZeroU64>,
+    /// Amount of stake for each option of a multiple-choice proposal.
+    ///
+    /// For multiple-choice proposals, `stake_for` holds the total stake cast
+    /// across all options.
+    pub option_stake: [u64; MAX_PROPOSAL_OPTIONS],
+    /// The governance config's total paused time when the proposal's voting
+    /// and cooldown periods were last extended.
+    pub paused_seconds_checkpoint: u64,
 }
 
 impl Proposal {
@@ -399,11 +631,21 @@ impl Proposal {
             stake_against: 0,
             stake_for: 0,
             status: ProposalStatus::Draft,
+            option_count: 0,
+            winning_option: 0,
+            kind: ProposalKind::Executable,
+            _padding: [0; 4],
             voting_start_timestamp: None,
-            _padding: [0; 7],
+            option_stake: [0; MAX_PROPOSAL_OPTIONS],
+            paused_seconds_checkpoint: 0,
         }
     }
 
+    /// Whether the proposal is a multiple-choice proposal.
+    pub fn is_multiple_choice(&self) -> bool {
+        self.option_count > 0
+    }
+
     /// Evaluate a provided address against the proposal author.
     pub fn check_author(&self, author: &Pubkey) -> ProgramResult {
         if self.author == *author {
@@ -412,37 +654,69 @@ impl Proposal {
         Err(ProgramError::IncorrectAuthority)
     }
 
+    /// Extend the proposal's voting and cooldown periods by the time
+    /// governance has spent paused since they were last extended.
+    ///
+    /// Both periods are extended by shifting their start timestamps forward.
+    pub fn extend_paused_periods(&mut self, governance_config: &GovernanceConfig, clock: &Clock) {
+        let total_paused_seconds = governance_config.total_paused_seconds(clock);
+        let extension = total_paused_seconds.saturating_sub(self.paused_seconds_checkpoint);
+        self.paused_seconds_checkpoint = total_paused_seconds;
+
+        let mut timeline = self.timeline();
+        timeline.extend(extension);
+        self.voting_start_timestamp = timeline.voting_start_timestamp;
+        self.cooldown_timestamp = timeline.cooldown_timestamp;
+    }
+
     /// Evaluate the proposal cooldown period against the clock sysvar.
     pub fn cooldown_has_ended(&self, clock: &Clock) -> bool {
-        if let Some(cooldown_timestamp) = self.cooldown_timestamp {
-            if (clock.unix_timestamp as u64)
-                .saturating_sub(self.governance_config.cooldown_period_seconds)
-                >= cooldown_timestamp.get()
-            {
-                return true;
-            }
-        }
-
-        false
+        self.timeline()
+            .cooldown_has_ended(clock.unix_timestamp as u64)
     }
 
     /// Evaluate the proposal voting period against the clock sysvar.
     pub fn voting_has_ended(&self, clock: &Clock) -> bool {
-        if let Some(voting_start_timestamp) = self.voting_start_timestamp {
-            if (clock.unix_timestamp as u64)
-                .saturating_sub(self.governance_config.voting_period_seconds)
-                >= voting_start_timestamp.get()
-            {
-                return true;
-            }
+        self.timeline()
+            .voting_has_ended(clock.unix_timestamp as u64)
+    }
+
+    /// The proposal's votes, against the total stake eligible to vote.
+    pub fn tally(&self, total_stake: u64) -> Tally<'_> {
+        Tally {
+            stake_for: self.stake_for,
+            stake_against: self.stake_against,
+            option_stake: &self.option_stake[..self.option_count as usize],
+            total_stake,
+            proposal_minimum_quorum: self.governance_config.proposal_minimum_quorum,
+            proposal_pass_threshold: self.governance_config.proposal_pass_threshold,
+            proposal_winning_margin: self.governance_config.proposal_winning_margin,
         }
+    }
 
-        false
+    /// The proposal's voting and cooldown periods.
+    pub fn timeline(&self) -> Timeline {
+        Timeline {
+            voting_start_timestamp: self.voting_start_timestamp,
+            voting_period_seconds: self.governance_config.voting_period_seconds,
+            cooldown_timestamp: self.cooldown_timestamp,
+            cooldown_period_seconds: self.governance_config.cooldown_period_seconds,
+        }
     }
 }
 
 /// Proposal vote election.
-#[derive(Clone, Copy, Debug, IntoPrimitive, PartialEq, ShankType, TryFromPrimitive)]
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    IntoPrimitive,
+    PartialEq,
+    ShankType,
+    TryFromPrimitive,
+)]
 #[repr(u8)]
 pub enum ProposalVoteElection {
     /// Validator voted in favor of the proposal.
@@ -466,7 +740,9 @@ pub struct ProposalVote {
     pub authority: Pubkey,
     /// Vote election.
     pub election: ProposalVoteElection,
-    pub _padding: [u8; 7],
+    /// The option voted for, if the proposal is a multiple-choice proposal.
+    pub option_index: u8,
+    pub _padding: [u8; 6],
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Pod, ShankAccount, SplDiscriminate, Zeroable)]
@@ -479,3 +755,358 @@ pub struct Author {
 impl Author {
     pub const LEN: usize = std::mem::size_of::<Author>();
 }
+
+/// Governance configuration account layout prior to the addition of the
+/// discriminator.
+///
+/// Still accepted for reads until the account is migrated with
+/// `MigrateAccount`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct LegacyGovernanceConfig {
+    pub cooldown_period_seconds: u64,
+    pub proposal_minimum_quorum: u32,
+    pub proposal_pass_threshold: u32,
+    pub stake_config_address: Pubkey,
+    pub voting_period_seconds: u64,
+    pub stake_per_proposal: u64,
+    pub governance_config: Pubkey,
+    pub cooldown_expires: u64,
+}
+
+impl LegacyGovernanceConfig {
+    pub const LEN: usize = std::mem::size_of::<LegacyGovernanceConfig>();
+}
+
+impl From<LegacyGovernanceConfig> for GovernanceConfig {
+    fn from(legacy: LegacyGovernanceConfig) -> Self {
+        Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
+            cooldown_period_seconds: legacy.cooldown_period_seconds,
+            proposal_minimum_quorum: legacy.proposal_minimum_quorum,
+            proposal_pass_threshold: legacy.proposal_pass_threshold,
+            stake_config_address: legacy.stake_config_address,
+            voting_period_seconds: legacy.voting_period_seconds,
+            stake_per_proposal: legacy.stake_per_proposal,
+            governance_config: legacy.governance_config,
+            cooldown_expires: legacy.cooldown_expires,
+            ..Zeroable::zeroed()
+        }
+    }
+}
+
+/// Proposal account layout prior to the addition of the governance config
+/// discriminator, identified by its own discriminator.
+///
+/// Embeds a [LegacyGovernanceConfig](struct.LegacyGovernanceConfig.html), and
+/// has none of the fields added to proposals since. Still accepted for reads
+/// until the account is migrated with `MigrateAccount`.
+#[derive(Clone, Copy, Debug, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("governance::state::proposal")]
+#[repr(C)]
+pub struct LegacyProposal {
+    discriminator: [u8; 8],
+    pub author: Pubkey,
+    pub cooldown_timestamp: Option<NonZeroU64>,
+    pub creation_timestamp: UnixTimestamp,
+    pub governance_config: LegacyGovernanceConfig,
+    pub stake_against: u64,
+    pub stake_for: u64,
+    pub status: ProposalStatus,
+    _padding: [u8; 7],
+    pub voting_start_timestamp: Option<NonZeroU64>,
+}
+
+impl LegacyProposal {
+    pub const LEN: usize = std::mem::size_of::<LegacyProposal>();
+}
+
+impl From<LegacyProposal> for Proposal {
+    fn from(legacy: LegacyProposal) -> Self {
+        Self {
+            discriminator: Self::SPL_DISCRIMINATOR.into(),
+            author: legacy.author,
+            cooldown_timestamp: legacy.cooldown_timestamp,
+            creation_timestamp: legacy.creation_timestamp,
+            governance_config: legacy.governance_config.into(),
+            stake_against: legacy.stake_against,
+            stake_for: legacy.stake_for,
+            status: legacy.status,
+            // Legacy proposals are all yes/no proposals with a transaction.
+            kind: ProposalKind::Executable,
+            ..Zeroable::zeroed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_governance_config() -> GovernanceConfig {
+        GovernanceConfig {
+            cooldown_period_seconds: 100,
+            proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR / 1000,
+            proposal_pass_threshold: THRESHOLD_SCALING_FACTOR / 10,
+            voting_period_seconds: 100,
+            ..GovernanceConfig::default()
+        }
+    }
+
+    fn validate_err(config: GovernanceConfig) -> ProgramError {
+        config.validate().unwrap_err()
+    }
+
+    #[test]
+    fn test_governance_config_validate() {
+        // Bounds are inclusive.
+        assert_eq!(valid_governance_config().validate(), Ok(()));
+
+        // Upper bounds.
+        assert_eq!(
+            GovernanceConfig {
+                proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR - 1,
+                proposal_pass_threshold: THRESHOLD_SCALING_FACTOR - 1,
+                proposal_winning_margin: THRESHOLD_SCALING_FACTOR - 1,
+                cooldown_period_seconds: u64::MAX,
+                voting_period_seconds: u64::MAX,
+                ..valid_governance_config()
+            }
+            .validate(),
+            Ok(())
+        );
+
+        // Proposal minimum quorum.
+        for proposal_minimum_quorum in [
+            0,
+            THRESHOLD_SCALING_FACTOR / 1000 - 1,
+            THRESHOLD_SCALING_FACTOR,
+            u32::MAX,
+        ] {
+            assert_eq!(
+                validate_err(GovernanceConfig {
+                    proposal_minimum_quorum,
+                    ..valid_governance_config()
+                }),
+                PaladinGovernanceError::InvalidProposalMinimumQuorum.into()
+            );
+        }
+
+        // Proposal pass threshold.
+        for proposal_pass_threshold in [
+            0,
+            THRESHOLD_SCALING_FACTOR / 10 - 1,
+            THRESHOLD_SCALING_FACTOR,
+            u32::MAX,
+        ] {
+            assert_eq!(
+                validate_err(GovernanceConfig {
+                    proposal_pass_threshold,
+                    ..valid_governance_config()
+                }),
+                PaladinGovernanceError::InvalidProposalPassThreshold.into()
+            );
+        }
+
+        // Proposal winning margin.
+        for proposal_winning_margin in [THRESHOLD_SCALING_FACTOR, u32::MAX] {
+            assert_eq!(
+                validate_err(GovernanceConfig {
+                    proposal_winning_margin,
+                    ..valid_governance_config()
+                }),
+                PaladinGovernanceError::InvalidProposalWinningMargin.into()
+            );
+        }
+
+        // Voting period.
+        assert_eq!(
+            validate_err(GovernanceConfig {
+                voting_period_seconds: 0,
+                ..valid_governance_config()
+            }),
+            PaladinGovernanceError::InvalidVotingPeriod.into()
+        );
+
+        // Cooldown period.
+        for cooldown_period_seconds in [0, 101] {
+            assert_eq!(
+                validate_err(GovernanceConfig {
+                    cooldown_period_seconds,
+                    ..valid_governance_config()
+                }),
+                PaladinGovernanceError::InvalidCooldownPeriod.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_proposal_extend_paused_periods() {
+        let clock = |unix_timestamp| Clock {
+            unix_timestamp,
+            ..Clock::default()
+        };
+
+        let mut governance_config = GovernanceConfig {
+            paused_seconds: 10,
+            ..GovernanceConfig::default()
+        };
+        let mut proposal = Proposal::new(&Pubkey::new_unique(), 0, governance_config);
+        proposal.voting_start_timestamp = NonZeroU64::new(100);
+        proposal.paused_seconds_checkpoint = 10;
+
+        // Nothing to extend while unpaused.
+        proposal.extend_paused_periods(&governance_config, &clock(150));
+        assert_eq!(proposal.voting_start_timestamp, NonZeroU64::new(100));
+        assert_eq!(proposal.cooldown_timestamp, None);
+
+        // An ongoing pause extends the periods up to the current time.
+        governance_config.paused_timestamp = NonZeroU64::new(150);
+        proposal.cooldown_timestamp = NonZeroU64::new(120);
+        proposal.extend_paused_periods(&governance_config, &clock(170));
+        assert_eq!(proposal.voting_start_timestamp, NonZeroU64::new(120));
+        assert_eq!(proposal.cooldown_timestamp, NonZeroU64::new(140));
+        assert_eq!(proposal.paused_seconds_checkpoint, 30);
+
+        // Once unpaused, only the remainder of the pause is added.
+        governance_config.paused_timestamp = None;
+        governance_config.paused_seconds = 35;
+        proposal.extend_paused_periods(&governance_config, &clock(200));
+        assert_eq!(proposal.voting_start_timestamp, NonZeroU64::new(125));
+        assert_eq!(proposal.cooldown_timestamp, NonZeroU64::new(145));
+        assert_eq!(proposal.paused_seconds_checkpoint, 35);
+    }
+
+    #[test]
+    fn test_legacy_proposal_transaction() {
+        // A transaction as written by the program before prerequisites and
+        // options were added.
+        let legacy_data = [
+            &[1, 0, 0, 0][..], // One instruction.
+            &[2; 32],          // Program ID.
+            &[1, 0, 0, 0],     // One account.
+            &[3; 32],          // Account pubkey.
+            &[1, 0],           // Signer, not writable.
+            &[2, 0, 0, 0],     // Two bytes of data.
+            &[4, 5],           // Data.
+            &[1],              // Executed.
+        ]
+        .concat();
+
+        let proposal_transaction = ProposalTransaction::try_from_slice(&legacy_data).unwrap();
+        assert_eq!(
+            proposal_transaction,
+            ProposalTransaction {
+                instructions: vec![ProposalInstruction {
+                    program_id: Pubkey::new_from_array([2; 32]),
+                    accounts: vec![ProposalAccountMeta {
+                        pubkey: Pubkey::new_from_array([3; 32]),
+                        is_signer: true,
+                        is_writable: false,
+                    }],
+                    data: vec![4, 5],
+                    executed: true,
+                }],
+                prerequisites: vec![],
+                options: vec![],
+            }
+        );
+
+        // The current layout holds both, even when empty.
+        let data = borsh::to_vec(&proposal_transaction).unwrap();
+        assert_eq!(data, [&legacy_data[..], &[0; 8]].concat());
+        assert_eq!(
+            ProposalTransaction::try_from_slice(&data).unwrap(),
+            proposal_transaction
+        );
+
+        // Truncated vectors are still rejected.
+        assert!(ProposalTransaction::try_from_slice(&data[..data.len() - 2]).is_err());
+    }
+
+    /// A proposal as written by the program before the governance config
+    /// gained its discriminator.
+    #[rustfmt::skip]
+    const LEGACY_PROPOSAL: [u8; 192] = [
+        // Discriminator.
+        62, 153, 195, 152, 54, 69, 225, 249,
+        // Author.
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        // Cooldown timestamp.
+        2, 0, 0, 0, 0, 0, 0, 0,
+        // Creation timestamp.
+        3, 0, 0, 0, 0, 0, 0, 0,
+        // Governance config: cooldown period, minimum quorum, pass threshold.
+        4, 0, 0, 0, 0, 0, 0, 0,
+        5, 0, 0, 0,
+        6, 0, 0, 0,
+        // Governance config: stake config address.
+        7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+        7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+        // Governance config: voting period, stake per proposal.
+        8, 0, 0, 0, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0,
+        // Governance config: address.
+        10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+        10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+        // Governance config: cooldown expiry.
+        11, 0, 0, 0, 0, 0, 0, 0,
+        // Stake against, stake for.
+        12, 0, 0, 0, 0, 0, 0, 0,
+        13, 0, 0, 0, 0, 0, 0, 0,
+        // Status (voting) and padding.
+        1, 0, 0, 0, 0, 0, 0, 0,
+        // Voting start timestamp.
+        14, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    #[test]
+    fn test_legacy_layouts() {
+        assert_eq!(LegacyGovernanceConfig::LEN, 104);
+        assert_eq!(LegacyProposal::LEN, 192);
+        assert_eq!(
+            LegacyProposal::SPL_DISCRIMINATOR_SLICE,
+            &LEGACY_PROPOSAL[..8]
+        );
+        assert_ne!(
+            LegacyProposal::SPL_DISCRIMINATOR_SLICE,
+            Proposal::SPL_DISCRIMINATOR_SLICE
+        );
+
+        let legacy: LegacyProposal = bytemuck::pod_read_unaligned(&LEGACY_PROPOSAL);
+        let proposal = Proposal::from(legacy);
+        assert_eq!(
+            &bytemuck::bytes_of(&proposal)[..8],
+            Proposal::SPL_DISCRIMINATOR_SLICE
+        );
+        assert_eq!(proposal.author, Pubkey::new_from_array([1; 32]));
+        assert_eq!(proposal.cooldown_timestamp, NonZeroU64::new(2));
+        assert_eq!(proposal.creation_timestamp, 3);
+        assert_eq!(
+            proposal.governance_config,
+            GovernanceConfig {
+                cooldown_period_seconds: 4,
+                proposal_minimum_quorum: 5,
+                proposal_pass_threshold: 6,
+                stake_config_address: Pubkey::new_from_array([7; 32]),
+                voting_period_seconds: 8,
+                stake_per_proposal: 9,
+                governance_config: Pubkey::new_from_array([10; 32]),
+                cooldown_expires: 11,
+                ..GovernanceConfig::default()
+            }
+        );
+        assert_eq!(proposal.stake_against, 12);
+        assert_eq!(proposal.stake_for, 13);
+        assert_eq!(proposal.status, ProposalStatus::Voting);
+        assert_eq!(proposal.voting_start_timestamp, NonZeroU64::new(14));
+
+        // Fields added since are zeroed.
+        assert_eq!(proposal.option_count, 0);
+        assert_eq!(proposal.winning_option, 0);
+        assert_eq!(proposal.kind, ProposalKind::Executable);
+        assert_eq!(proposal.option_stake, [0; MAX_PROPOSAL_OPTIONS]);
+        assert_eq!(proposal.paused_seconds_checkpoint, 0);
+    }
+}
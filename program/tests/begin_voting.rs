@@ -8,7 +8,7 @@ use {
         instruction::begin_voting,
         state::{GovernanceConfig, Proposal, ProposalStatus},
     },
-    setup::{setup, setup_proposal},
+    setup::{setup, setup_governance, setup_multiple_choice_proposal, setup_proposal},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -18,16 +18,18 @@ use {
         signer::Signer,
         transaction::{Transaction, TransactionError},
     },
+    std::num::NonZeroU64,
 };
 
 #[tokio::test]
 async fn fail_stake_authority_not_signer() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let context = setup().start_with_context().await;
 
-    let mut instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let mut instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
     instruction.accounts[0].is_signer = false; // Stake authority not signer.
 
     let transaction = Transaction::new_signed_with_payer(
@@ -54,6 +56,7 @@ async fn fail_stake_authority_not_signer() {
 async fn fail_proposal_incorrect_owner() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let mut context = setup().start_with_context().await;
 
@@ -68,7 +71,7 @@ async fn fail_proposal_incorrect_owner() {
         );
     }
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -94,6 +97,7 @@ async fn fail_proposal_incorrect_owner() {
 async fn fail_proposal_not_initialized() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let mut context = setup().start_with_context().await;
 
@@ -108,7 +112,7 @@ async fn fail_proposal_not_initialized() {
         );
     }
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -134,6 +138,7 @@ async fn fail_proposal_not_initialized() {
 async fn fail_stake_authority_not_author() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -146,7 +151,7 @@ async fn fail_stake_authority_not_author() {
     )
     .await;
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -172,6 +177,7 @@ async fn fail_stake_authority_not_author() {
 async fn fail_proposal_not_in_draft_stage() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -184,7 +190,7 @@ async fn fail_proposal_not_in_draft_stage() {
     )
     .await;
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -209,23 +215,179 @@ async fn fail_proposal_not_in_draft_stage() {
     );
 }
 
+#[tokio::test]
+async fn fail_proposal_has_single_option() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        &[0], // Only one option.
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalOption as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let governance_config = GovernanceConfig {
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = begin_voting(
+        &stake_authority.pubkey(),
+        &proposal,
+        &Pubkey::new_unique(), // Incorrect governance address.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_paused() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let governance_config = GovernanceConfig {
+        governance_config: governance,
+        paused_timestamp: NonZeroU64::new(1), // Paused.
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernancePaused as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let stake_authority = Keypair::new();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let governance_config = GovernanceConfig {
+        governance_config: governance,
+        paused_seconds: 10, // Paused before voting began.
+        ..GovernanceConfig::default()
+    };
 
     let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_proposal(
         &mut context,
         &proposal,
         &stake_authority.pubkey(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Draft,
     )
     .await;
 
-    let instruction = begin_voting(&stake_authority.pubkey(), &proposal);
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -249,4 +411,7 @@ async fn success() {
         .unwrap();
     let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
     assert_eq!(proposal_state.status, ProposalStatus::Voting);
+
+    // Assert time paused before voting began won't extend the voting period.
+    assert_eq!(proposal_state.paused_seconds_checkpoint, 10);
 }
@@ -0,0 +1,323 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::cancel_proposal,
+        state::{get_proposal_author_address, Author, GovernanceConfig, Proposal, ProposalStatus},
+    },
+    setup::{
+        setup, setup_author, setup_proposal, setup_proposal_with_stake,
+        setup_proposal_with_stake_and_cooldown,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let context = setup().start_with_context().await;
+
+    let mut instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_incorrect_owner() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up the proposal account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &Pubkey::new_unique()), // Incorrect owner.
+        );
+    }
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_initialized() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up the proposal account uninitialized.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::UninitializedAccount)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(), // Stake authority not author.
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Voting,
+    )
+    .await;
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_voting() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Draft, // Not in voting stage.
+    )
+    .await;
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotInVotingStage as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_cooldown_started() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        100,
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(1),
+        NonZeroU64::new(1), // Cooldown has begun.
+    )
+    .await;
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalCooldownStarted as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        10,
+        20,
+        ProposalStatus::Voting,
+        NonZeroU64::new(1),
+    )
+    .await;
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was cancelled.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Cancelled);
+    assert_eq!(proposal_state.stake_for, 10);
+    assert_eq!(proposal_state.stake_against, 20);
+
+    // Assert the author's active proposal slot was released.
+    let author = context
+        .banks_client
+        .get_account(get_proposal_author_address(
+            &stake_authority.pubkey(),
+            &paladin_governance_program::ID,
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let author = bytemuck::from_bytes::<Author>(&author.data);
+    assert_eq!(author.active_proposals, 0);
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError, instruction::close_author,
+        state::get_proposal_author_address,
+    },
+    setup::{setup, setup_author},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+
+    let mut instruction = close_author(stake_authority.pubkey());
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing stake authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_author() {
+    let stake_authority = Keypair::new();
+    let author = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+
+    let mut instruction = close_author(stake_authority.pubkey());
+    instruction.accounts[1].pubkey = author; // Invalid author address.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidSeeds)
+    );
+}
+
+#[tokio::test]
+async fn fail_author_incorrect_owner() {
+    let stake_authority = Keypair::new();
+    let author =
+        get_proposal_author_address(&stake_authority.pubkey(), &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+
+    // Set up an author account with the incorrect owner.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(8);
+        context.set_account(
+            &author,
+            &AccountSharedData::new(lamports, 8, &Pubkey::new_unique()),
+        );
+    }
+
+    let instruction = close_author(stake_authority.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_author_has_active_proposals() {
+    let stake_authority = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await; // One active proposal.
+
+    let instruction = close_author(stake_authority.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AuthorHasActiveProposals as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let author =
+        get_proposal_author_address(&stake_authority.pubkey(), &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+
+    let author_lamports = context
+        .banks_client
+        .get_account(author)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_author(stake_authority.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the author account was closed.
+    assert!(context
+        .banks_client
+        .get_account(author)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the rent was refunded to the stake authority.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(stake_authority.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        author_lamports
+    );
+}
@@ -15,8 +15,8 @@ use {
     },
     paladin_stake_program::state::{find_validator_stake_pda, ValidatorStake},
     setup::{
-        setup, setup_author, setup_governance, setup_proposal, setup_proposal_transaction,
-        setup_stake, setup_stake_config,
+        setup, setup_author, setup_governance, setup_legacy_governance, setup_proposal,
+        setup_proposal_transaction, setup_stake, setup_stake_config,
     },
     solana_program_test::*,
     solana_sdk::{
@@ -30,6 +30,7 @@ use {
         system_program,
         transaction::{Transaction, TransactionError},
     },
+    std::num::NonZeroU64,
 };
 
 #[tokio::test]
@@ -642,6 +643,7 @@ async fn fail_proposal_transaction_already_initialized() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
@@ -724,6 +726,7 @@ async fn fail_proposal_too_many_active_proposals() {
         stake_per_proposal: 1,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
@@ -813,6 +816,7 @@ async fn fail_proposal_before_cooldown_expires() {
         stake_per_proposal: 1,
         governance_config: governance,
         cooldown_expires: clock.unix_timestamp as u64 + 100,
+        ..GovernanceConfig::default()
     };
 
     setup_author(&mut context, &stake_authority.pubkey(), 0).await;
@@ -877,6 +881,170 @@ async fn fail_proposal_before_cooldown_expires() {
     );
 }
 
+#[tokio::test]
+async fn fail_governance_paused() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+    let clock = get_clock(&mut context).await;
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 100_000_000,
+        proposal_minimum_quorum: 5 * 10u32.pow(8), // 50%
+        proposal_pass_threshold: 5 * 10u32.pow(8), // 50%
+        stake_config_address: stake_config,
+        voting_period_seconds: 100_000_000,
+        stake_per_proposal: 1,
+        governance_config: governance,
+        cooldown_expires: 0,
+        paused_timestamp: NonZeroU64::new(clock.unix_timestamp as u64),
+        ..GovernanceConfig::default()
+    };
+
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    // Fund the proposal account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernancePaused as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_retired() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+    let clock = get_clock(&mut context).await;
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 100_000_000,
+        proposal_minimum_quorum: 5 * 10u32.pow(8), // 50%
+        proposal_pass_threshold: 5 * 10u32.pow(8), // 50%
+        stake_config_address: stake_config,
+        voting_period_seconds: 100_000_000,
+        stake_per_proposal: 1,
+        governance_config: governance,
+        cooldown_expires: 0,
+        successor: Pubkey::new_unique(), // Migrated to another governance config.
+        ..GovernanceConfig::default()
+    };
+
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    // Fund the proposal account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+        );
+    }
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernanceRetired as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let stake_authority = Keypair::new();
@@ -901,6 +1069,7 @@ async fn success() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: clock.unix_timestamp as u64 + 1,
+        ..GovernanceConfig::default()
     };
 
     // Move clock forward to ensure cooldown expires.
@@ -983,3 +1152,101 @@ async fn success() {
     let state = ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
     assert_eq!(state, ProposalTransaction::default());
 }
+
+#[tokio::test]
+async fn success_legacy_governance_config() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+    let clock = get_clock(&mut context).await;
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 100_000_000,
+        proposal_minimum_quorum: 5 * 10u32.pow(8), // 50%
+        proposal_pass_threshold: 5 * 10u32.pow(8), // 50%
+        stake_config_address: stake_config,
+        voting_period_seconds: 100_000_000,
+        stake_per_proposal: 0,
+        governance_config: governance,
+        cooldown_expires: clock.unix_timestamp as u64 + 1,
+        ..GovernanceConfig::default()
+    };
+
+    // Move clock forward to ensure cooldown expires.
+    context.warp_to_epoch(clock.epoch + 10).unwrap();
+
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        0,
+    )
+    .await;
+    setup_legacy_governance(&mut context, &governance, &governance_config).await;
+
+    // Fund the proposal and proposal transaction accounts.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+        );
+
+        let space = get_instance_packed_len(&ProposalTransaction::default()).unwrap();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal_transaction,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    // For checks later.
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let timestamp = clock.unix_timestamp;
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was created with the migrated governance config.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<Proposal>(&proposal_account.data),
+        &Proposal::new(&stake_authority.pubkey(), timestamp, governance_config)
+    );
+}
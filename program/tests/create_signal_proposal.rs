@@ -0,0 +1,163 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    crate::setup::get_clock,
+    paladin_governance_program::{
+        instruction::create_signal_proposal,
+        state::{
+            get_proposal_author_address, get_proposal_transaction_address, Author,
+            GovernanceConfig, Proposal, ProposalKind, ProposalStatus,
+        },
+    },
+    paladin_stake_program::state::find_validator_stake_pda,
+    setup::{setup, setup_author, setup_governance, setup_stake},
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let stake = Pubkey::new_unique(); // PDA doesn't matter here.
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let context = setup().start_with_context().await;
+
+    let mut instruction =
+        create_signal_proposal(&stake_authority.pubkey(), &stake, &proposal, &governance);
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Stake authority not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let governance = Pubkey::new_unique(); // PDA doesn't matter here.
+
+    let mut context = setup().start_with_context().await;
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 100_000_000,
+        proposal_minimum_quorum: 5 * 10u32.pow(8), // 50%
+        proposal_pass_threshold: 5 * 10u32.pow(8), // 50%
+        stake_config_address: stake_config,
+        voting_period_seconds: 100_000_000,
+        stake_per_proposal: 0,
+        governance_config: governance,
+        cooldown_expires: 0,
+        ..GovernanceConfig::default()
+    };
+
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    // Fund the proposal account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let space = std::mem::size_of::<Proposal>();
+        let lamports = rent.minimum_balance(space);
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(lamports, space, &paladin_governance_program::id()),
+        );
+    }
+
+    // For checks later.
+    let clock = get_clock(&mut context).await;
+    let timestamp = clock.unix_timestamp;
+
+    let instruction =
+        create_signal_proposal(&stake_authority.pubkey(), &stake, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was created as a signal proposal.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.kind, ProposalKind::Signal);
+    assert_eq!(proposal_state.status, ProposalStatus::Draft);
+    assert_eq!(proposal_state.creation_timestamp, timestamp);
+
+    // Assert no proposal transaction was created.
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    assert!(context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the author's active proposal count was incremented.
+    let author_account = context
+        .banks_client
+        .get_account(get_proposal_author_address(
+            &stake_authority.pubkey(),
+            &paladin_governance_program::id(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<Author>(&author_account.data).active_proposals,
+        1
+    );
+}
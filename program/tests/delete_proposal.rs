@@ -6,9 +6,11 @@ use {
     paladin_governance_program::{
         error::PaladinGovernanceError,
         instruction::delete_proposal,
-        state::{Author, GovernanceConfig, Proposal, ProposalStatus},
+        state::{
+            get_proposal_transaction_address, Author, GovernanceConfig, Proposal, ProposalStatus,
+        },
     },
-    setup::{setup, setup_author, setup_proposal},
+    setup::{setup, setup_author, setup_proposal, setup_signal_proposal},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -16,6 +18,7 @@ use {
         pubkey::Pubkey,
         signature::Keypair,
         signer::Signer,
+        system_program,
         transaction::{Transaction, TransactionError},
     },
 };
@@ -278,3 +281,177 @@ async fn success() {
     let author = bytemuck::from_bytes::<Author>(&author.data);
     assert_eq!(author.active_proposals, 0);
 }
+
+#[tokio::test]
+async fn success_cancelled() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    // Cancelling already released the author's active proposal slot.
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Cancelled,
+    )
+    .await;
+
+    // Act - Execute delete proposal transaction.
+    let instruction = delete_proposal(stake_authority.pubkey(), proposal);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert - The proposal was deleted.
+    assert!(context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert - Open proposal account is still zero.
+    let author = paladin_governance_program::state::get_proposal_author_address(
+        &stake_authority.pubkey(),
+        &paladin_governance_program::ID,
+    );
+    let author = context
+        .banks_client
+        .get_account(author)
+        .await
+        .unwrap()
+        .unwrap();
+    let author = bytemuck::from_bytes::<Author>(&author.data);
+    assert_eq!(author.active_proposals, 0);
+}
+
+#[tokio::test]
+async fn success_signal() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    // Signal proposals have no proposal transaction account.
+    setup_signal_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        0,
+        0,
+        ProposalStatus::Processed,
+        None,
+        None,
+    )
+    .await;
+
+    // Act - Execute delete proposal transaction.
+    let instruction = delete_proposal(stake_authority.pubkey(), proposal);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert - The proposal was deleted.
+    assert!(context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert - Open proposal account was decremented.
+    let author = paladin_governance_program::state::get_proposal_author_address(
+        &stake_authority.pubkey(),
+        &paladin_governance_program::ID,
+    );
+    let author = context
+        .banks_client
+        .get_account(author)
+        .await
+        .unwrap()
+        .unwrap();
+    let author = bytemuck::from_bytes::<Author>(&author.data);
+    assert_eq!(author.active_proposals, 0);
+}
+
+#[tokio::test]
+async fn success_signal_proposal_transaction_address_funded() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_signal_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        0,
+        0,
+        ProposalStatus::Processed,
+        None,
+        None,
+    )
+    .await;
+
+    // Fund the proposal transaction address that the signal proposal never
+    // created, leaving it owned by the system program.
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    context.set_account(
+        &proposal_transaction,
+        &AccountSharedData::new(1_000_000, 0, &system_program::id()),
+    );
+
+    let instruction = delete_proposal(stake_authority.pubkey(), proposal);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert - The proposal was deleted.
+    assert!(context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert - The funded address was left untouched.
+    let proposal_transaction = context
+        .banks_client
+        .get_account(proposal_transaction)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proposal_transaction.lamports, 1_000_000);
+    assert_eq!(proposal_transaction.owner, system_program::id());
+}
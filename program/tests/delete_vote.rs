@@ -161,6 +161,46 @@ async fn fail_incorrect_authority() {
     );
 }
 
+#[tokio::test]
+async fn fail_vote_not_initialized() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let vote = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Rejected,
+    )
+    .await;
+
+    // Act - Execute delete vote transaction for a vote that was never cast.
+    let instruction = delete_vote(proposal, vote, authority);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
 #[tokio::test]
 async fn success_delete_after_proposal_reject() {
     let stake_authority = Keypair::new();
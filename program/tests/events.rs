@@ -0,0 +1,579 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    paladin_governance_program::{
+        event::GovernanceEvent,
+        instruction::{
+            begin_voting, cancel_proposal, create_proposal, finish_voting, process_instruction,
+            push_instruction, switch_vote, update_governance, vote,
+        },
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_proposal_vote_address,
+            get_treasury_address, GovernanceConfig, Proposal, ProposalKind, ProposalStatus,
+            ProposalTransaction, ProposalVote, ProposalVoteElection,
+        },
+    },
+    paladin_stake_program::state::find_validator_stake_pda,
+    setup::{
+        get_clock, setup, setup_author, setup_governance, setup_proposal,
+        setup_proposal_transaction, setup_proposal_vote, setup_proposal_with_stake,
+        setup_proposal_with_stake_and_cooldown, setup_stake, setup_stake_config,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        borsh1::get_instance_packed_len,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::Transaction,
+    },
+    std::num::NonZeroU64,
+};
+
+const TOTAL_STAKE: u64 = 100_000_000_000;
+
+/// Decodes the governance events logged by a transaction.
+fn events(logs: &[String]) -> Vec<GovernanceEvent> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|fields| {
+            let fields = fields
+                .split(' ')
+                .map(|field| STANDARD.decode(field).unwrap())
+                .collect::<Vec<_>>();
+            GovernanceEvent::from_log_data(&fields.iter().map(Vec::as_slice).collect::<Vec<_>>())
+        })
+        .collect()
+}
+
+/// Processes an instruction, returning the events it logged.
+async fn process(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> Vec<GovernanceEvent> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+    events(&result.metadata.unwrap().log_messages)
+}
+
+#[tokio::test]
+async fn proposal_created() {
+    let stake_authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let validator_vote = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let governance = Pubkey::new_unique();
+
+    let governance_config = GovernanceConfig {
+        stake_config_address: stake_config,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 0).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        0,
+    )
+    .await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    // Fund the proposal and proposal transaction accounts.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        let space = std::mem::size_of::<Proposal>();
+        context.set_account(
+            &proposal,
+            &AccountSharedData::new(
+                rent.minimum_balance(space),
+                space,
+                &paladin_governance_program::id(),
+            ),
+        );
+
+        let space = get_instance_packed_len(&ProposalTransaction::default()).unwrap();
+        context.set_account(
+            &proposal_transaction,
+            &AccountSharedData::new(rent.minimum_balance(space), 0, &system_program::id()),
+        );
+    }
+
+    let clock = get_clock(&mut context).await;
+
+    let instruction = create_proposal(
+        &stake_authority.pubkey(),
+        &stake,
+        &proposal,
+        &proposal_transaction,
+        &governance,
+    );
+    assert_eq!(
+        process(&mut context, instruction, &[&stake_authority]).await,
+        vec![GovernanceEvent::ProposalCreated {
+            proposal,
+            author: stake_authority.pubkey(),
+            governance_config: governance,
+            kind: ProposalKind::Executable,
+            creation_timestamp: clock.unix_timestamp,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn instruction_pushed() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    let instruction_program_id = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction,
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    // Fund the proposal transaction account to cover the new rent-exemption.
+    {
+        let mut account = context
+            .banks_client
+            .get_account(proposal_transaction)
+            .await
+            .unwrap()
+            .unwrap();
+        account.lamports = account.lamports.saturating_add(1_000_000_000);
+        context.set_account(&proposal_transaction, &account.into());
+    }
+
+    let instruction = push_instruction(
+        &stake_authority.pubkey(),
+        &proposal,
+        &proposal_transaction,
+        &instruction_program_id,
+        vec![],
+        vec![1, 2, 3],
+    );
+    assert_eq!(
+        process(&mut context, instruction, &[&stake_authority]).await,
+        vec![GovernanceEvent::InstructionPushed {
+            proposal,
+            option_index: None,
+            instruction_index: 0,
+            program_id: instruction_program_id,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn voting_began() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let governance_config = GovernanceConfig {
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        governance_config,
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let clock = get_clock(&mut context).await;
+
+    let instruction = begin_voting(&stake_authority.pubkey(), &proposal, &governance);
+    assert_eq!(
+        process(&mut context, instruction, &[&stake_authority]).await,
+        vec![GovernanceEvent::VotingBegan {
+            proposal,
+            voting_start_timestamp: clock.unix_timestamp as u64,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn vote_cast_and_cooldown_started() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: 500_000_000, // 50%
+        proposal_pass_threshold: 500_000_000, // 50%
+        stake_config_address: stake_config,
+        voting_period_seconds: 1_000,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = get_clock(&mut context).await;
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        TOTAL_STAKE / 2,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        0,
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        ProposalVoteElection::For,
+    );
+    assert_eq!(
+        process(&mut context, instruction, &[&stake_authority]).await,
+        vec![
+            GovernanceEvent::VoteCast {
+                proposal,
+                vote: proposal_vote,
+                authority: stake_authority.pubkey(),
+                election: ProposalVoteElection::For,
+                option_index: None,
+                stake: TOTAL_STAKE / 2,
+                stake_for: TOTAL_STAKE / 2,
+                stake_against: 0,
+            },
+            GovernanceEvent::CooldownStarted {
+                proposal,
+                cooldown_timestamp: clock.unix_timestamp as u64,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn vote_switched() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: 500_000_000, // 50%
+        proposal_pass_threshold: 500_000_000, // 50%
+        stake_config_address: stake_config,
+        voting_period_seconds: 1_000,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = get_clock(&mut context).await;
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    // The voter's stake grew since they voted.
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        TOTAL_STAKE / 5,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        TOTAL_STAKE / 10,
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        proposal,
+        TOTAL_STAKE / 10,
+        stake_authority.pubkey(),
+        ProposalVoteElection::For,
+    )
+    .await;
+
+    let instruction = switch_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        ProposalVoteElection::Against,
+    );
+    assert_eq!(
+        process(&mut context, instruction, &[&stake_authority]).await,
+        vec![GovernanceEvent::VoteSwitched {
+            proposal,
+            vote: proposal_vote,
+            authority: stake_authority.pubkey(),
+            previous_election: ProposalVoteElection::For,
+            previous_option_index: None,
+            previous_stake: TOTAL_STAKE / 10,
+            election: ProposalVoteElection::Against,
+            option_index: None,
+            stake: TOTAL_STAKE / 5,
+            stake_for: 0,
+            stake_against: TOTAL_STAKE / 5,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn proposal_finalized() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: 500_000_000, // 50%
+        proposal_pass_threshold: 500_000_000, // 50%
+        stake_config_address: stake_config,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = get_clock(&mut context).await;
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        TOTAL_STAKE / 2,
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(1),
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(10) as u64), // Ended.
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+    assert_eq!(
+        process(&mut context, instruction, &[]).await,
+        vec![GovernanceEvent::ProposalFinalized {
+            proposal,
+            status: ProposalStatus::Accepted,
+            winning_option: None,
+            stake_for: TOTAL_STAKE / 2,
+            stake_against: 0,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn proposal_cancelled() {
+    let stake_authority = Keypair::new();
+    let proposal = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_author(&mut context, &stake_authority.pubkey(), 1).await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        10,
+        20,
+        ProposalStatus::Voting,
+        NonZeroU64::new(1),
+    )
+    .await;
+
+    let instruction = cancel_proposal(&stake_authority.pubkey(), &proposal);
+    assert_eq!(
+        process(&mut context, instruction, &[&stake_authority]).await,
+        vec![GovernanceEvent::ProposalFinalized {
+            proposal,
+            status: ProposalStatus::Cancelled,
+            winning_option: None,
+            stake_for: 10,
+            stake_against: 20,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn instruction_executed_and_governance_updated() {
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let proposal = Pubkey::new_unique();
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+
+    let governance_config = GovernanceConfig {
+        stake_config_address: stake_config,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction,
+        ProposalTransaction {
+            instructions: vec![(&update_governance(
+                &treasury,
+                &governance,
+                0,
+                1,
+                500_000_000,
+                600_000_000,
+                4,
+                5,
+            ))
+                .into()],
+            prerequisites: vec![],
+            options: vec![],
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal,
+        &proposal_transaction,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    // Events of the governance update, invoked by the proposal, come first.
+    assert_eq!(
+        process(&mut context, instruction, &[]).await,
+        vec![
+            GovernanceEvent::GovernanceUpdated {
+                governance_config: governance,
+                cooldown_period_seconds: 1,
+                proposal_minimum_quorum: 500_000_000,
+                proposal_pass_threshold: 600_000_000,
+                voting_period_seconds: 4,
+                stake_per_proposal: 5,
+                pause_guardian: Pubkey::default(),
+                proposal_winning_margin: 0,
+            },
+            GovernanceEvent::InstructionExecuted {
+                proposal,
+                option_index: None,
+                instruction_index: 0,
+                status: ProposalStatus::Processed,
+            },
+        ]
+    );
+}
@@ -9,7 +9,10 @@ use {
         instruction::finish_voting,
         state::{GovernanceConfig, Proposal, ProposalStatus},
     },
-    setup::{setup, setup_proposal, setup_proposal_with_stake_and_cooldown, setup_stake_config},
+    setup::{
+        setup, setup_governance, setup_multiple_choice_proposal, setup_proposal,
+        setup_proposal_with_stake_and_cooldown, setup_signal_proposal, setup_stake_config,
+    },
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -25,6 +28,7 @@ use {
 #[tokio::test]
 async fn fail_proposal_not_initialized() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut context = setup().start_with_context().await;
@@ -41,7 +45,7 @@ async fn fail_proposal_not_initialized() {
         );
     }
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -66,6 +70,7 @@ async fn fail_proposal_not_initialized() {
 #[tokio::test]
 async fn fail_proposal_not_in_voting_stage() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut governance_config = GovernanceConfig::default();
@@ -83,7 +88,7 @@ async fn fail_proposal_not_in_voting_stage() {
     )
     .await;
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -111,10 +116,12 @@ async fn fail_proposal_not_in_voting_stage() {
 #[tokio::test]
 async fn fail_proposal_has_cooldown_but_has_not_ended() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut governance_config = GovernanceConfig::default();
     governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
     governance_config.cooldown_period_seconds = 10; // 10 seconds.
 
     let mut context = setup().start_with_context().await;
@@ -123,6 +130,7 @@ async fn fail_proposal_has_cooldown_but_has_not_ended() {
     let cooldown_timestamp = clock.unix_timestamp.saturating_sub(5); // Only 5 seconds ago.
 
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     // Note that since there's no cooldown, stake doesn't play a role here.
     setup_proposal_with_stake_and_cooldown(
         &mut context,
@@ -138,7 +146,7 @@ async fn fail_proposal_has_cooldown_but_has_not_ended() {
     )
     .await;
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -168,10 +176,12 @@ async fn fail_proposal_has_cooldown_but_has_not_ended() {
 #[tokio::test]
 async fn success_cooldown_result_is_accepted() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut governance_config = GovernanceConfig::default();
     governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
     governance_config.cooldown_period_seconds = 10; // 10 seconds.
     governance_config.proposal_minimum_quorum = 500_000_000; // 50%
     governance_config.proposal_pass_threshold = 500_000_000; // 50%
@@ -184,6 +194,7 @@ async fn success_cooldown_result_is_accepted() {
     let cooldown_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
 
     setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_proposal_with_stake_and_cooldown(
         &mut context,
         &proposal,
@@ -198,7 +209,7 @@ async fn success_cooldown_result_is_accepted() {
     )
     .await;
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -227,10 +238,12 @@ async fn success_cooldown_result_is_accepted() {
 #[tokio::test]
 async fn success_cooldown_result_is_rejected() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut governance_config = GovernanceConfig::default();
     governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
     governance_config.cooldown_period_seconds = 10; // 10 seconds.
     governance_config.proposal_minimum_quorum = 500_000_000; // 50%
     governance_config.proposal_pass_threshold = 500_000_000; // 50%
@@ -243,6 +256,7 @@ async fn success_cooldown_result_is_rejected() {
     let cooldown_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
 
     setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_proposal_with_stake_and_cooldown(
         &mut context,
         &proposal,
@@ -257,7 +271,7 @@ async fn success_cooldown_result_is_rejected() {
     )
     .await;
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -286,10 +300,12 @@ async fn success_cooldown_result_is_rejected() {
 #[tokio::test]
 async fn fail_proposal_vote_period_not_ended() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut governance_config = GovernanceConfig::default();
     governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
     governance_config.voting_period_seconds = 10; // 10 seconds.
 
     let mut context = setup().start_with_context().await;
@@ -298,6 +314,7 @@ async fn fail_proposal_vote_period_not_ended() {
     let voting_start_timestamp = clock.unix_timestamp.saturating_sub(5); // Only 5 seconds ago.
 
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_proposal_with_stake_and_cooldown(
         &mut context,
         &proposal,
@@ -312,7 +329,7 @@ async fn fail_proposal_vote_period_not_ended() {
     )
     .await;
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -342,10 +359,12 @@ async fn fail_proposal_vote_period_not_ended() {
 #[tokio::test]
 async fn success_vote_period_ended_result_rejected() {
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
 
     let mut governance_config = GovernanceConfig::default();
     governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
     governance_config.voting_period_seconds = 10; // 10 seconds.
 
     let mut context = setup().start_with_context().await;
@@ -354,6 +373,7 @@ async fn success_vote_period_ended_result_rejected() {
     let voting_start_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
 
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_proposal_with_stake_and_cooldown(
         &mut context,
         &proposal,
@@ -368,7 +388,7 @@ async fn success_vote_period_ended_result_rejected() {
     )
     .await;
 
-    let instruction = finish_voting(stake_config, &proposal);
+    let instruction = finish_voting(stake_config, &proposal, &governance);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -393,3 +413,379 @@ async fn success_vote_period_ended_result_rejected() {
     let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
     assert_eq!(proposal_state.status, ProposalStatus::Rejected);
 }
+
+#[tokio::test]
+async fn success_multiple_choice_result_is_accepted() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.cooldown_period_seconds = 10; // 10 seconds.
+    governance_config.proposal_minimum_quorum = 500_000_000; // 50%
+    governance_config.proposal_winning_margin = 200_000_000; // 20%
+
+    let total_stake = 100_000_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let cooldown_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
+
+    setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        /* author */ &Pubkey::new_unique(),
+        governance_config,
+        /* option_stake */
+        &[total_stake / 10, total_stake / 2, total_stake / 10], // Leads by 57%.,
+        /* winning_option */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1),
+        /* cooldown_timestamp */ NonZeroU64::new(cooldown_timestamp as u64),
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was marked with accepted status and the winning option
+    // recorded.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Accepted);
+    assert_eq!(proposal_state.winning_option, 1);
+}
+
+#[tokio::test]
+async fn success_multiple_choice_result_is_rejected() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.cooldown_period_seconds = 10; // 10 seconds.
+    governance_config.proposal_minimum_quorum = 500_000_000; // 50%
+    governance_config.proposal_winning_margin = 200_000_000; // 20%
+
+    let total_stake = 100_000_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let cooldown_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
+
+    setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        /* author */ &Pubkey::new_unique(),
+        governance_config,
+        /* option_stake */
+        &[total_stake / 4, total_stake / 4, total_stake / 10], // Tied.,
+        /* winning_option */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1),
+        /* cooldown_timestamp */ NonZeroU64::new(cooldown_timestamp as u64),
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was marked with rejected status and the winning option
+    // recorded.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Rejected);
+    assert_eq!(proposal_state.winning_option, 0);
+}
+
+#[tokio::test]
+async fn success_multiple_choice_lead_below_margin_is_rejected() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.cooldown_period_seconds = 10; // 10 seconds.
+    governance_config.proposal_minimum_quorum = 500_000_000; // 50%
+    governance_config.proposal_winning_margin = 600_000_000; // 60%
+
+    let total_stake = 100_000_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let cooldown_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
+
+    setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        /* author */ &Pubkey::new_unique(),
+        governance_config,
+        /* option_stake */
+        &[total_stake / 10, total_stake / 2, total_stake / 10], // Leads by 57%.
+        /* winning_option */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1),
+        /* cooldown_timestamp */ NonZeroU64::new(cooldown_timestamp as u64),
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was marked with rejected status and the winning option
+    // recorded.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Rejected);
+    assert_eq!(proposal_state.winning_option, 1);
+}
+
+#[tokio::test]
+async fn success_signal_result_is_processed() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.cooldown_period_seconds = 10; // 10 seconds.
+    governance_config.proposal_minimum_quorum = 500_000_000; // 50%
+    governance_config.proposal_pass_threshold = 500_000_000; // 50%
+
+    let total_stake = 100_000_000_000;
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let cooldown_timestamp = clock.unix_timestamp.saturating_sub(10); // Ended.
+
+    setup_stake_config(&mut context, &stake_config, total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_signal_proposal(
+        &mut context,
+        &proposal,
+        /* author */ &Pubkey::new_unique(),
+        governance_config,
+        /* stake_for */ total_stake / 2, // 50%, accepted.
+        /* stake_against */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(1),
+        /* cooldown_timestamp */ NonZeroU64::new(cooldown_timestamp as u64),
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal skipped the accepted status, since it has nothing to
+    // process.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let voting_start_timestamp = clock.unix_timestamp.saturating_sub(20); // Ended.
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.voting_period_seconds = 10; // 10 seconds.
+
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        /* author */ &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(voting_start_timestamp as u64),
+        /* cooldown_timestamp */ None, // No cooldown.
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &Pubkey::new_unique());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_voting_period_extended_by_pause() {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let voting_start_timestamp = clock.unix_timestamp.saturating_sub(20); // Ended.
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.voting_period_seconds = 10; // 10 seconds.
+
+    // Governance was paused before the voting period ended, and still is.
+    governance_config.paused_timestamp =
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(15) as u64);
+
+    setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_proposal_with_stake_and_cooldown(
+        &mut context,
+        &proposal,
+        /* author */ &Pubkey::new_unique(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(voting_start_timestamp as u64),
+        /* cooldown_timestamp */ None, // No cooldown.
+    )
+    .await;
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::ProposalVotingPeriodStillActive as u32
+            )
+        )
+    );
+}
@@ -11,12 +11,39 @@ use {
         instruction::InstructionError,
         pubkey::Pubkey,
         rent::Rent,
+        signature::Keypair,
         signer::Signer,
         system_instruction,
         transaction::{Transaction, TransactionError},
     },
 };
 
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let context = setup().start_with_context().await;
+    let stake_authority = Keypair::new();
+
+    let mut initialize = initialize_author(stake_authority.pubkey());
+    initialize.accounts[0].is_signer = false; // Stake authority not signer.
+    let transaction = Transaction::new_signed_with_payer(
+        &[initialize],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing stake authority.
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
 #[tokio::test]
 async fn fail_incorrect_author() {
     let context = setup().start_with_context().await;
@@ -10,20 +10,23 @@ use {
         state::{get_governance_address, GovernanceConfig},
     },
     paladin_stake_program::state::Config as StakeConfig,
-    setup::{setup, setup_governance, setup_stake_config},
+    setup::{setup, setup_governance, setup_program_data, setup_stake_config_with_authority},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
         instruction::InstructionError,
         pubkey::Pubkey,
+        signature::Keypair,
         signer::Signer,
         system_program,
         transaction::{Transaction, TransactionError},
     },
+    test_case::test_case,
 };
 
 #[tokio::test]
 async fn fail_stake_config_incorrect_owner() {
+    let authority = Keypair::new();
     let stake_config = Pubkey::new_unique();
     let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
 
@@ -43,11 +46,12 @@ async fn fail_stake_config_incorrect_owner() {
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 0,
-        /* cooldown_period_seconds */ 0,
+        /* cooldown_period_seconds */ 100,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
         /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
-        /* voting_period_seconds */ 0,
+        /* voting_period_seconds */ 100,
         /* stake_per_proposal */ 0,
         /* cooldown_seconds */ 0,
     );
@@ -55,7 +59,7 @@ async fn fail_stake_config_incorrect_owner() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -74,6 +78,7 @@ async fn fail_stake_config_incorrect_owner() {
 
 #[tokio::test]
 async fn fail_stake_config_not_initialized() {
+    let authority = Keypair::new();
     let stake_config = Pubkey::new_unique();
     let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
 
@@ -93,11 +98,12 @@ async fn fail_stake_config_not_initialized() {
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 0,
-        /* cooldown_period_seconds */ 0,
+        /* cooldown_period_seconds */ 100,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
         /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
-        /* voting_period_seconds */ 0,
+        /* voting_period_seconds */ 100,
         /* stake_per_proposal */ 0,
         /* cooldown_seconds */ 0,
     );
@@ -105,7 +111,7 @@ async fn fail_stake_config_not_initialized() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -124,20 +130,28 @@ async fn fail_stake_config_not_initialized() {
 
 #[tokio::test]
 async fn fail_governance_incorrect_address() {
+    let authority = Keypair::new();
     let governance = Pubkey::new_unique(); // Incorrect governance address.
     let stake_config = Pubkey::new_unique();
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 0,
-        /* cooldown_period_seconds */ 0,
+        /* cooldown_period_seconds */ 100,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
         /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
-        /* voting_period_seconds */ 0,
+        /* voting_period_seconds */ 100,
         /* stake_per_proposal */ 0,
         /* cooldown_seconds */ 0,
     );
@@ -145,7 +159,7 @@ async fn fail_governance_incorrect_address() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -169,11 +183,18 @@ async fn fail_governance_incorrect_address() {
 
 #[tokio::test]
 async fn fail_governance_already_initialized() {
+    let authority = Keypair::new();
     let stake_config = Pubkey::new_unique();
     let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     // Set up an already initialized governance account.
     let governance_config = GovernanceConfig {
@@ -185,17 +206,19 @@ async fn fail_governance_already_initialized() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
     setup_governance(&mut context, &governance, &governance_config).await;
 
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 0,
-        /* cooldown_period_seconds */ 0,
+        /* cooldown_period_seconds */ 100,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
         /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
-        /* voting_period_seconds */ 0,
+        /* voting_period_seconds */ 100,
         /* stake_per_proposal */ 0,
         /* cooldown_seconds */ 0,
     );
@@ -203,7 +226,7 @@ async fn fail_governance_already_initialized() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -220,13 +243,294 @@ async fn fail_governance_already_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
+
+    let mut instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* governance_id */ 0,
+        /* cooldown_period_seconds */ 100,
+        /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
+        /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
+        /* voting_period_seconds */ 100,
+        /* stake_per_proposal */ 0,
+        /* cooldown_seconds */ 0,
+    );
+    instruction.accounts[3].is_signer = false; // Authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_program_data_address() {
+    let authority = Keypair::new(); // Not the stake config authority.
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(),
+        /* total_stake */ 100,
+    )
+    .await;
+    setup_program_data(&mut context, Some(authority.pubkey())).await;
+
+    let mut instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* governance_id */ 0,
+        /* cooldown_period_seconds */ 100,
+        /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
+        /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
+        /* voting_period_seconds */ 100,
+        /* stake_per_proposal */ 0,
+        /* cooldown_seconds */ 0,
+    );
+    instruction.accounts[4].pubkey = Pubkey::new_unique(); // Incorrect program data address.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectProgramDataAddress as u32)
+        )
+    );
+}
+
+#[test_case(Some(Pubkey::new_unique()); "different_upgrade_authority")]
+#[test_case(None; "no_upgrade_authority")]
+#[tokio::test]
+async fn fail_front_run_by_unauthorized_signer(upgrade_authority: Option<Pubkey>) {
+    // Someone other than the stake config authority or the upgrade authority
+    // attempts to initialize governance ahead of the deployer.
+    let authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(),
+        /* total_stake */ 100,
+    )
+    .await;
+    setup_program_data(&mut context, upgrade_authority).await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<GovernanceConfig>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* governance_id */ 0,
+        /* cooldown_period_seconds */ 100,
+        /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
+        /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
+        /* voting_period_seconds */ 100,
+        /* stake_per_proposal */ 0,
+        /* cooldown_seconds */ 0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+
+    // Assert the governance account was not created.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(governance_account.owner, system_program::id());
+    assert!(governance_account.data.is_empty());
+}
+
+#[test_case(
+    100, 0, THRESHOLD_SCALING_FACTOR / 2, 100,
+    PaladinGovernanceError::InvalidProposalMinimumQuorum;
+    "quorum_below_minimum"
+)]
+#[test_case(
+    100, THRESHOLD_SCALING_FACTOR, THRESHOLD_SCALING_FACTOR / 2, 100,
+    PaladinGovernanceError::InvalidProposalMinimumQuorum;
+    "quorum_at_maximum"
+)]
+#[test_case(
+    100, THRESHOLD_SCALING_FACTOR / 1000, THRESHOLD_SCALING_FACTOR / 10 - 1, 100,
+    PaladinGovernanceError::InvalidProposalPassThreshold;
+    "pass_threshold_below_minimum"
+)]
+#[test_case(
+    100, THRESHOLD_SCALING_FACTOR / 1000, THRESHOLD_SCALING_FACTOR, 100,
+    PaladinGovernanceError::InvalidProposalPassThreshold;
+    "pass_threshold_at_maximum"
+)]
+#[test_case(
+    0, THRESHOLD_SCALING_FACTOR / 1000, THRESHOLD_SCALING_FACTOR / 2, 0,
+    PaladinGovernanceError::InvalidVotingPeriod;
+    "voting_period_zero"
+)]
+#[test_case(
+    0, THRESHOLD_SCALING_FACTOR / 1000, THRESHOLD_SCALING_FACTOR / 2, 100,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_zero"
+)]
+#[test_case(
+    101, THRESHOLD_SCALING_FACTOR / 1000, THRESHOLD_SCALING_FACTOR / 2, 100,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_exceeds_voting_period"
+)]
+#[tokio::test]
+async fn fail_invalid_governance_config(
+    cooldown_period_seconds: u64,
+    proposal_minimum_quorum: u32,
+    proposal_pass_threshold: u32,
+    voting_period_seconds: u64,
+    expected_error: PaladinGovernanceError,
+) {
+    let authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<GovernanceConfig>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* governance_id */ 0,
+        cooldown_period_seconds,
+        proposal_minimum_quorum,
+        proposal_pass_threshold,
+        voting_period_seconds,
+        /* stake_per_proposal */ 0,
+        /* cooldown_seconds */ 0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(expected_error as u32))
+    );
+}
+
 #[tokio::test]
 async fn success() {
+    let authority = Keypair::new();
     let stake_config = Pubkey::new_unique();
     let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     // Fund the governance account.
     {
@@ -241,11 +545,12 @@ async fn success() {
     let instruction = initialize_governance(
         &governance,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 0,
-        /* cooldown_period_seconds */ 0,
+        /* cooldown_period_seconds */ 100,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
         /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
-        /* voting_period_seconds */ 0,
+        /* voting_period_seconds */ 100,
         /* stake_per_proposal */ 0,
         /* cooldown_seconds */ 0,
     );
@@ -253,7 +558,7 @@ async fn success() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
 
@@ -271,7 +576,7 @@ async fn success() {
         .unwrap()
         .unwrap();
     let governance_state = bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data);
-    assert_eq!(governance_state.cooldown_period_seconds, 0);
+    assert_eq!(governance_state.cooldown_period_seconds, 100);
     assert_eq!(
         governance_state.proposal_minimum_quorum,
         THRESHOLD_SCALING_FACTOR / 1000
@@ -284,14 +589,85 @@ async fn success() {
     assert_eq!(governance_state.stake_config_address, stake_config);
 }
 
+#[tokio::test]
+async fn success_upgrade_authority() {
+    let authority = Keypair::new();
+    let stake_config = Pubkey::new_unique();
+    let governance = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &Pubkey::new_unique(), // Not the stake config authority.
+        /* total_stake */ 100,
+    )
+    .await;
+    setup_program_data(&mut context, Some(authority.pubkey())).await;
+
+    // Fund the governance account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<GovernanceConfig>());
+        context.set_account(
+            &governance,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_governance(
+        &governance,
+        &stake_config,
+        &authority.pubkey(),
+        /* governance_id */ 0,
+        /* cooldown_period_seconds */ 100,
+        /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
+        /* proposal_pass_threshold */ THRESHOLD_SCALING_FACTOR / 2,
+        /* voting_period_seconds */ 100,
+        /* stake_per_proposal */ 0,
+        /* cooldown_seconds */ 0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the governance account was created.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(governance_account.owner, paladin_governance_program::id());
+    let governance_state = bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data);
+    assert_eq!(governance_state.stake_config_address, stake_config);
+}
+
 #[tokio::test]
 async fn setup_second_governance_same_stake_config() {
+    let authority = Keypair::new();
     let stake_config = Pubkey::new_unique();
     let governance_0 = get_governance_address(&stake_config, &0, &paladin_governance_program::id());
     let governance_1 = get_governance_address(&stake_config, &1, &paladin_governance_program::id());
 
     let mut context = setup().start_with_context().await;
-    setup_stake_config(&mut context, &stake_config, /* total_stake */ 100).await;
+    setup_stake_config_with_authority(
+        &mut context,
+        &stake_config,
+        &authority.pubkey(),
+        /* total_stake */ 100,
+    )
+    .await;
 
     // Fund the governance accounts.
     {
@@ -311,6 +687,7 @@ async fn setup_second_governance_same_stake_config() {
     let instruction = initialize_governance(
         &governance_0,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 0,
         /* cooldown_period_seconds */ 100,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
@@ -322,7 +699,7 @@ async fn setup_second_governance_same_stake_config() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
     context
@@ -333,6 +710,7 @@ async fn setup_second_governance_same_stake_config() {
     let instruction = initialize_governance(
         &governance_1,
         &stake_config,
+        &authority.pubkey(),
         /* governance_id */ 1,
         /* cooldown_period_seconds */ 1000,
         /* proposal_minimum_quorum */ THRESHOLD_SCALING_FACTOR / 1000,
@@ -344,7 +722,7 @@ async fn setup_second_governance_same_stake_config() {
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[&context.payer, &authority],
         context.last_blockhash,
     );
     context
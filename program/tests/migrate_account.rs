@@ -0,0 +1,346 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::migrate_account,
+        state::{get_governance_address, GovernanceConfig, Proposal, ProposalStatus},
+    },
+    setup::{setup, setup_governance, setup_legacy_governance, setup_legacy_proposal},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+};
+
+fn governance_config(stake_config_address: Pubkey, governance: Pubkey) -> GovernanceConfig {
+    GovernanceConfig {
+        cooldown_period_seconds: 100,
+        proposal_minimum_quorum: 1,
+        proposal_pass_threshold: 2,
+        stake_config_address,
+        voting_period_seconds: 400,
+        stake_per_proposal: 500,
+        governance_config: governance,
+        cooldown_expires: 600,
+        ..GovernanceConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn fail_account_incorrect_owner() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_legacy_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance),
+    )
+    .await;
+
+    // Set the governance account's owner to something other than the program.
+    {
+        let mut account = context
+            .banks_client
+            .get_account(governance)
+            .await
+            .unwrap()
+            .unwrap();
+        account.owner = Pubkey::new_unique(); // Incorrect owner.
+        context.set_account(&governance, &account.into());
+    }
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey(), 0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_account_already_migrated() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance),
+    )
+    .await;
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey(), 0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_legacy_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance),
+    )
+    .await;
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey(), 1); // Incorrect governance ID.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_legacy_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance),
+    )
+    .await;
+
+    let payer = Keypair::new();
+
+    let mut instruction = migrate_account(&governance, &payer.pubkey(), 0);
+    instruction.accounts[1].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn success_account_holding_legacy_rent() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let config = governance_config(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_legacy_governance(&mut context, &governance, &config).await;
+
+    // Only fund the governance account for its legacy size.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    {
+        let mut account = context
+            .banks_client
+            .get_account(governance)
+            .await
+            .unwrap()
+            .unwrap();
+        account.lamports = rent.minimum_balance(account.data.len());
+        context.set_account(&governance, &account.into());
+    }
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey(), 0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the payer topped the governance config up to be rent-exempt at
+    // its new size.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        governance_account.lamports,
+        rent.minimum_balance(std::mem::size_of::<GovernanceConfig>())
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data),
+        &config
+    );
+}
+
+#[tokio::test]
+async fn success_governance_config() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let config = governance_config(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_legacy_governance(&mut context, &governance, &config).await;
+
+    let instruction = migrate_account(&governance, &context.payer.pubkey(), 0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the governance config was migrated with its parameters intact.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        governance_account.data.len(),
+        std::mem::size_of::<GovernanceConfig>()
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data),
+        &config
+    );
+}
+
+#[tokio::test]
+async fn success_proposal() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let config = governance_config(stake_config_address, governance);
+
+    let proposal_address = Pubkey::new_unique();
+    let mut proposal = Proposal::new(&Pubkey::new_unique(), 700, config);
+    proposal.cooldown_timestamp = NonZeroU64::new(800);
+    proposal.stake_against = 900;
+    proposal.stake_for = 1_000;
+    proposal.status = ProposalStatus::Voting;
+    proposal.voting_start_timestamp = NonZeroU64::new(1_100);
+
+    let mut context = setup().start_with_context().await;
+    setup_legacy_proposal(&mut context, &proposal_address, &proposal).await;
+
+    // The governance ID is irrelevant for proposals.
+    let instruction = migrate_account(&proposal_address, &context.payer.pubkey(), 7);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was migrated with its state intact, and the fields
+    // added since zeroed.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proposal_account.data.len(), Proposal::LEN);
+    assert_eq!(
+        bytemuck::from_bytes::<Proposal>(&proposal_account.data),
+        &proposal
+    );
+}
@@ -0,0 +1,568 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{migrate_governance, process_instruction},
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_treasury_address,
+            GovernanceConfig, ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{
+        setup, setup_governance, setup_mint, setup_proposal, setup_proposal_transaction,
+        setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+fn governance_config_for(stake_config_address: Pubkey, governance: Pubkey) -> GovernanceConfig {
+    GovernanceConfig {
+        cooldown_period_seconds: 100,
+        proposal_minimum_quorum: 1,
+        proposal_pass_threshold: 2,
+        stake_config_address,
+        voting_period_seconds: 400,
+        stake_per_proposal: 500,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    }
+}
+
+/// Execute the provided instruction through an accepted proposal of the
+/// governance config, so it receives the treasury's signature.
+async fn process_with_treasury_signature(
+    context: &mut ProgramTestContext,
+    governance_config: GovernanceConfig,
+    instruction: Instruction,
+) -> Result<(), TransactionError> {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    setup_proposal(
+        context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+
+    let mut account_metas = instruction
+        .accounts
+        .iter()
+        .map(|meta| AccountMeta {
+            is_signer: false, // Treasury signature applied by the program.
+            ..meta.clone()
+        })
+        .collect::<Vec<_>>();
+    account_metas.push(AccountMeta::new_readonly(
+        paladin_governance_program::id(),
+        false,
+    ));
+
+    setup_proposal_transaction(
+        context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&instruction).into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_config.governance_config,
+        &[],
+        &account_metas,
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn fail_treasury_not_signer() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance =
+        get_governance_address(&stake_config_address, &1, &paladin_governance_program::id());
+    let new_treasury = get_treasury_address(&new_governance, &paladin_governance_program::id());
+
+    let context = setup().start_with_context().await;
+
+    // Try just invoking the instruction directly.
+    let mut instruction = migrate_governance(
+        &treasury,
+        &governance,
+        &new_treasury,
+        &new_governance,
+        &[],
+        0,
+        1,
+    );
+    instruction.accounts[0].is_signer = false; // Treasury not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_retired() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance =
+        get_governance_address(&stake_config_address, &1, &paladin_governance_program::id());
+    let new_treasury = get_treasury_address(&new_governance, &paladin_governance_program::id());
+
+    let governance_config = GovernanceConfig {
+        successor: Pubkey::new_unique(), // Already migrated.
+        ..governance_config_for(stake_config_address, governance)
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_governance(
+        &mut context,
+        &new_governance,
+        &governance_config_for(stake_config_address, new_governance),
+    )
+    .await;
+
+    let err = process_with_treasury_signature(
+        &mut context,
+        governance_config,
+        migrate_governance(
+            &treasury,
+            &governance,
+            &new_treasury,
+            &new_governance,
+            &[],
+            0,
+            1,
+        ),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernanceRetired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_new_governance_incorrect_address() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance = Pubkey::new_unique(); // Incorrect address.
+    let new_treasury = get_treasury_address(&new_governance, &paladin_governance_program::id());
+
+    let governance_config = governance_config_for(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_governance(
+        &mut context,
+        &new_governance,
+        &governance_config_for(stake_config_address, new_governance),
+    )
+    .await;
+
+    let err = process_with_treasury_signature(
+        &mut context,
+        governance_config,
+        migrate_governance(
+            &treasury,
+            &governance,
+            &new_treasury,
+            &new_governance,
+            &[],
+            0,
+            1,
+        ),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_new_treasury_incorrect_address() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance =
+        get_governance_address(&stake_config_address, &1, &paladin_governance_program::id());
+    let new_treasury = Pubkey::new_unique(); // Incorrect address.
+
+    let governance_config = governance_config_for(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_governance(
+        &mut context,
+        &new_governance,
+        &governance_config_for(stake_config_address, new_governance),
+    )
+    .await;
+
+    let err = process_with_treasury_signature(
+        &mut context,
+        governance_config,
+        migrate_governance(
+            &treasury,
+            &governance,
+            &new_treasury,
+            &new_governance,
+            &[],
+            0,
+            1,
+        ),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectTreasuryAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_new_token_account_incorrect_owner() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance =
+        get_governance_address(&stake_config_address, &1, &paladin_governance_program::id());
+    let new_treasury = get_treasury_address(&new_governance, &paladin_governance_program::id());
+
+    let mint = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let new_token_account = Pubkey::new_unique();
+
+    let governance_config = governance_config_for(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_governance(
+        &mut context,
+        &new_governance,
+        &governance_config_for(stake_config_address, new_governance),
+    )
+    .await;
+    setup_mint(&mut context, &mint, &spl_token::id(), 6).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &spl_token::id(),
+        &mint,
+        &treasury,
+        100,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &new_token_account,
+        &spl_token::id(),
+        &mint,
+        &Pubkey::new_unique(), // Not owned by the new treasury.
+        0,
+    )
+    .await;
+
+    let err = process_with_treasury_signature(
+        &mut context,
+        governance_config,
+        migrate_governance(
+            &treasury,
+            &governance,
+            &new_treasury,
+            &new_governance,
+            &[(token_account, new_token_account, mint)],
+            0,
+            1,
+        ),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectTreasuryTokenAccount as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_2022_program_incorrect_address() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance =
+        get_governance_address(&stake_config_address, &1, &paladin_governance_program::id());
+    let new_treasury = get_treasury_address(&new_governance, &paladin_governance_program::id());
+
+    let governance_config = governance_config_for(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_governance(
+        &mut context,
+        &new_governance,
+        &governance_config_for(stake_config_address, new_governance),
+    )
+    .await;
+
+    let mut instruction = migrate_governance(
+        &treasury,
+        &governance,
+        &new_treasury,
+        &new_governance,
+        &[],
+        0,
+        1,
+    );
+    instruction.accounts[6].pubkey = Pubkey::new_unique(); // Incorrect program.
+
+    let err = process_with_treasury_signature(&mut context, governance_config, instruction)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+    let new_governance =
+        get_governance_address(&stake_config_address, &1, &paladin_governance_program::id());
+    let new_treasury = get_treasury_address(&new_governance, &paladin_governance_program::id());
+
+    let mint_a = Pubkey::new_unique();
+    let token_account_a = Pubkey::new_unique();
+    let new_token_account_a = Pubkey::new_unique();
+    let mint_b = Pubkey::new_unique();
+    let token_account_b = Pubkey::new_unique();
+    let new_token_account_b = Pubkey::new_unique();
+
+    let treasury_starting_lamports = 500_000_000;
+    let new_treasury_starting_lamports = 100_000_000;
+
+    let governance_config = governance_config_for(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_governance(
+        &mut context,
+        &new_governance,
+        &governance_config_for(stake_config_address, new_governance),
+    )
+    .await;
+
+    // Set up both treasuries with some lamports and tokens.
+    context.set_account(
+        &treasury,
+        &AccountSharedData::new(treasury_starting_lamports, 0, &system_program::id()),
+    );
+    context.set_account(
+        &new_treasury,
+        &AccountSharedData::new(new_treasury_starting_lamports, 0, &system_program::id()),
+    );
+    setup_mint(&mut context, &mint_a, &spl_token::id(), 6).await;
+    setup_token_account(
+        &mut context,
+        &token_account_a,
+        &spl_token::id(),
+        &mint_a,
+        &treasury,
+        1_000,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &new_token_account_a,
+        &spl_token::id(),
+        &mint_a,
+        &new_treasury,
+        50,
+    )
+    .await;
+
+    // The second mint belongs to the SPL Token-2022 program.
+    setup_mint(&mut context, &mint_b, &spl_token_2022::id(), 9).await;
+    setup_token_account(
+        &mut context,
+        &token_account_b,
+        &spl_token_2022::id(),
+        &mint_b,
+        &treasury,
+        2_000,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &new_token_account_b,
+        &spl_token_2022::id(),
+        &mint_b,
+        &new_treasury,
+        0,
+    )
+    .await;
+
+    process_with_treasury_signature(
+        &mut context,
+        governance_config,
+        migrate_governance(
+            &treasury,
+            &governance,
+            &new_treasury,
+            &new_governance,
+            &[
+                (token_account_a, new_token_account_a, mint_a),
+                (token_account_b, new_token_account_b, mint_b),
+            ],
+            0,
+            1,
+        ),
+    )
+    .await
+    .unwrap();
+
+    // Assert the governance config was retired in favor of the new one.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data),
+        &GovernanceConfig {
+            successor: new_governance,
+            ..governance_config
+        }
+    );
+
+    // Assert the lamports were moved from the treasury to the new treasury.
+    assert!(context
+        .banks_client
+        .get_account(treasury)
+        .await
+        .unwrap()
+        .is_none());
+    let new_treasury_account = context
+        .banks_client
+        .get_account(new_treasury)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        new_treasury_account.lamports,
+        new_treasury_starting_lamports + treasury_starting_lamports
+    );
+
+    // Assert the token balances were moved from the treasury to the new
+    // treasury.
+    for (token_account, expected_amount) in [
+        (token_account_a, 0),
+        (new_token_account_a, 1_050),
+        (token_account_b, 0),
+        (new_token_account_b, 2_000),
+    ] {
+        let account = context
+            .banks_client
+            .get_account(token_account)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            spl_token::state::Account::unpack(&account.data)
+                .unwrap()
+                .amount,
+            expected_amount
+        );
+    }
+}
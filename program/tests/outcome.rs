@@ -0,0 +1,217 @@
+#![cfg(feature = "test-sbf")]
+#![allow(clippy::arithmetic_side_effects)]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::finish_voting,
+        outcome::{self, ArithmeticOverflow, Resolution, THRESHOLD_SCALING_FACTOR},
+        state::{GovernanceConfig, Proposal, ProposalStatus},
+    },
+    proptest::prelude::*,
+    setup::{setup, setup_governance, setup_stake_config},
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+};
+
+const VOTING_PERIOD_SECONDS: u64 = 100;
+const COOLDOWN_PERIOD_SECONDS: u64 = 10;
+
+/// A proposal in the voting stage and the total stake eligible to vote on it.
+#[derive(Debug)]
+struct Case {
+    stake_for: u64,
+    stake_against: u64,
+    option_stake: Vec<u64>,
+    total_stake: u64,
+    proposal_minimum_quorum: u32,
+    proposal_pass_threshold: u32,
+    proposal_winning_margin: u32,
+    voting_started_seconds_ago: u64,
+    cooldown_started_seconds_ago: Option<u64>,
+}
+
+prop_compose! {
+    fn case()(
+        total_stake in 0..=u64::MAX / 2,
+        stake_for_share in 0..=THRESHOLD_SCALING_FACTOR,
+        stake_against_share in 0..=THRESHOLD_SCALING_FACTOR,
+        option_stake in prop::collection::vec(0..=u64::MAX / 16, 0..=4),
+        proposal_minimum_quorum in (THRESHOLD_SCALING_FACTOR / 1000)..THRESHOLD_SCALING_FACTOR,
+        proposal_pass_threshold in (THRESHOLD_SCALING_FACTOR / 10)..THRESHOLD_SCALING_FACTOR,
+        proposal_winning_margin in 0..THRESHOLD_SCALING_FACTOR,
+        voting_started_seconds_ago in 0..=2 * VOTING_PERIOD_SECONDS,
+        cooldown_started_seconds_ago in prop::option::of(0..=2 * COOLDOWN_PERIOD_SECONDS),
+    ) -> Case {
+        let share = |share: u32| {
+            (u128::from(total_stake) * u128::from(share) / u128::from(THRESHOLD_SCALING_FACTOR))
+                as u64
+        };
+        // A multiple-choice vote counts toward `stake_for`.
+        let (stake_for, stake_against) = if option_stake.is_empty() {
+            (share(stake_for_share), share(stake_against_share))
+        } else {
+            (option_stake.iter().sum(), 0)
+        };
+        Case {
+            stake_for,
+            stake_against,
+            option_stake,
+            total_stake,
+            proposal_minimum_quorum,
+            proposal_pass_threshold,
+            proposal_winning_margin,
+            voting_started_seconds_ago,
+            cooldown_started_seconds_ago,
+        }
+    }
+}
+
+/// Finishes voting on the case's proposal, asserting the program agrees with
+/// `outcome::resolve`.
+async fn check(case: Case) -> Result<Resolution, ArithmeticOverflow> {
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+
+    let mut governance_config = GovernanceConfig::default();
+    governance_config.stake_config_address = stake_config;
+    governance_config.governance_config = governance;
+    governance_config.cooldown_period_seconds = COOLDOWN_PERIOD_SECONDS;
+    governance_config.voting_period_seconds = VOTING_PERIOD_SECONDS;
+    governance_config.proposal_minimum_quorum = case.proposal_minimum_quorum;
+    governance_config.proposal_pass_threshold = case.proposal_pass_threshold;
+    governance_config.proposal_winning_margin = case.proposal_winning_margin;
+
+    let mut context = setup().start_with_context().await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let now = clock.unix_timestamp as u64;
+    let started = |seconds_ago: u64| NonZeroU64::new(now.saturating_sub(seconds_ago).max(1));
+
+    let mut state = Proposal::new(&Pubkey::new_unique(), 0, governance_config);
+    state.stake_for = case.stake_for;
+    state.stake_against = case.stake_against;
+    state.option_count = case.option_stake.len() as u8;
+    state.option_stake[..case.option_stake.len()].copy_from_slice(&case.option_stake);
+    state.status = ProposalStatus::Voting;
+    state.voting_start_timestamp = started(case.voting_started_seconds_ago);
+    state.cooldown_timestamp = case.cooldown_started_seconds_ago.and_then(started);
+
+    let expected = outcome::resolve(&state.tally(case.total_stake), &state.timeline(), now);
+
+    setup_stake_config(&mut context, &stake_config, case.total_stake).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    {
+        let data = bytemuck::bytes_of(&state).to_vec();
+        let rent = context.banks_client.get_rent().await.unwrap();
+        context.set_account(
+            &proposal,
+            &AccountSharedData::from(Account {
+                lamports: rent.minimum_balance(data.len()),
+                data,
+                owner: paladin_governance_program::id(),
+                ..Account::default()
+            }),
+        );
+    }
+
+    let instruction = finish_voting(stake_config, &proposal, &governance);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let result = context.banks_client.process_transaction(transaction).await;
+
+    let expected_error = match expected {
+        Err(ArithmeticOverflow) => Some(InstructionError::ArithmeticOverflow),
+        Ok(Resolution::StillVoting) => Some(InstructionError::Custom(
+            PaladinGovernanceError::ProposalVotingPeriodStillActive as u32,
+        )),
+        Ok(_) => None,
+    };
+    if let Some(expected_error) = expected_error {
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(0, expected_error)
+        );
+        return expected;
+    }
+    result.unwrap();
+
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    let resolution = expected.unwrap();
+    let expected_status = match resolution {
+        Resolution::Passed { .. } => ProposalStatus::Accepted,
+        _ => ProposalStatus::Rejected,
+    };
+    assert_eq!(proposal_state.status, expected_status);
+    assert_eq!(
+        proposal_state.winning_option,
+        resolution.winning_option().unwrap_or_default()
+    );
+
+    Ok(resolution)
+}
+
+fn block_on(case: Case) -> Result<Resolution, ArithmeticOverflow> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(check(case))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn resolve_matches_finish_voting(case in case()) {
+        let _ = block_on(case);
+    }
+
+    #[test]
+    fn for_stake_needed_passes_finish_voting(case in case()) {
+        // Only yes/no proposals pass by `For` stake alone.
+        prop_assume!(case.option_stake.is_empty());
+
+        let mut state = Proposal::new(&Pubkey::new_unique(), 0, GovernanceConfig {
+            proposal_minimum_quorum: case.proposal_minimum_quorum,
+            proposal_pass_threshold: case.proposal_pass_threshold,
+            ..GovernanceConfig::default()
+        });
+        state.stake_for = case.stake_for;
+        state.stake_against = case.stake_against;
+
+        if let Some(needed) = state.tally(case.total_stake).for_stake_needed() {
+            // With the needed stake cast and the cooldown over, the program
+            // accepts the proposal.
+            let resolution = block_on(Case {
+                stake_for: case.stake_for + needed,
+                cooldown_started_seconds_ago: Some(2 * COOLDOWN_PERIOD_SECONDS),
+                ..case
+            });
+            prop_assert!(matches!(resolution, Ok(Resolution::Passed { .. })));
+        }
+    }
+}
@@ -0,0 +1,527 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{partial_update_governance, process_instruction},
+        processor::THRESHOLD_SCALING_FACTOR,
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_treasury_address,
+            GovernanceConfig, ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{setup, setup_governance, setup_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::{AccountMeta, InstructionError},
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+fn original_governance_config(
+    stake_config_address: Pubkey,
+    governance_config: Pubkey,
+) -> GovernanceConfig {
+    GovernanceConfig {
+        cooldown_period_seconds: 100,
+        proposal_minimum_quorum: THRESHOLD_SCALING_FACTOR / 100,
+        proposal_pass_threshold: THRESHOLD_SCALING_FACTOR / 2,
+        stake_config_address,
+        voting_period_seconds: 400,
+        stake_per_proposal: 500,
+        governance_config,
+        cooldown_expires: 0,
+        ..GovernanceConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn fail_treasury_not_signer() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let context = setup().start_with_context().await;
+
+    // Try just invoking the instruction directly.
+    let mut instruction = partial_update_governance(
+        &treasury,
+        &governance,
+        /* governance_id */ 0,
+        /* cooldown_period_seconds */ None,
+        /* proposal_minimum_quorum */ None,
+        /* proposal_pass_threshold */ None,
+        /* voting_period_seconds */ Some(800),
+        /* stake_per_proposal */ None,
+        /* pause_guardian */ None,
+        /* proposal_winning_margin */ None,
+    );
+    instruction.accounts[0].is_signer = false; // Treasury not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_invalid_governance_config() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = original_governance_config(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &original_governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&partial_update_governance(
+                &treasury,
+                &governance,
+                0,
+                None,
+                None,
+                None,
+                Some(50), // Shorter than the existing cooldown period.
+                None,
+                None,
+                None,
+            ))
+                .into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidCooldownPeriod as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_invalid_proposal_winning_margin() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = original_governance_config(stake_config_address, governance);
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &original_governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&partial_update_governance(
+                &treasury,
+                &governance,
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(THRESHOLD_SCALING_FACTOR), // A margin no option could reach.
+            ))
+                .into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalWinningMargin as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = original_governance_config(stake_config_address, governance);
+
+    let new_voting_period_seconds = 800;
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &original_governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&partial_update_governance(
+                &treasury,
+                &governance,
+                0,
+                None,
+                None,
+                None,
+                Some(new_voting_period_seconds),
+                None,
+                None,
+                None,
+            ))
+                .into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the voting period was updated.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data);
+    assert_eq!(
+        governance_state,
+        &GovernanceConfig {
+            voting_period_seconds: new_voting_period_seconds,
+            ..original_governance_config
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_pause_guardian() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = original_governance_config(stake_config_address, governance);
+
+    let pause_guardian = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &original_governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&partial_update_governance(
+                &treasury,
+                &governance,
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(pause_guardian),
+                None,
+            ))
+                .into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the pause guardian was updated.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data);
+    assert_eq!(
+        governance_state,
+        &GovernanceConfig {
+            pause_guardian,
+            ..original_governance_config
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_proposal_winning_margin() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = original_governance_config(stake_config_address, governance);
+
+    let proposal_winning_margin = THRESHOLD_SCALING_FACTOR / 10;
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &original_governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&partial_update_governance(
+                &treasury,
+                &governance,
+                0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(proposal_winning_margin),
+            ))
+                .into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the winning margin was updated.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    let governance_state = bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data);
+    assert_eq!(
+        governance_state,
+        &GovernanceConfig {
+            proposal_winning_margin,
+            ..original_governance_config
+        }
+    );
+}
@@ -3,15 +3,20 @@
 mod setup;
 
 use {
+    borsh::BorshDeserialize,
     paladin_governance_program::{
         error::PaladinGovernanceError,
-        instruction::process_instruction,
+        instruction::{delete_proposal, process_instruction},
         state::{
             get_proposal_transaction_address, get_treasury_address, GovernanceConfig, Proposal,
-            ProposalStatus, ProposalTransaction,
+            ProposalOption, ProposalStatus, ProposalTransaction,
         },
     },
-    setup::{create_mock_proposal_transaction, setup, setup_proposal, setup_proposal_transaction},
+    setup::{
+        create_mock_proposal_transaction, setup, setup_author, setup_governance,
+        setup_legacy_proposal_transaction, setup_multiple_choice_proposal, setup_proposal,
+        setup_proposal_transaction,
+    },
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -23,6 +28,8 @@ use {
         system_instruction, system_program,
         transaction::{Transaction, TransactionError},
     },
+    std::num::NonZeroU64,
+    test_case::test_case,
 };
 
 #[tokio::test]
@@ -30,6 +37,7 @@ async fn fail_proposal_incorrect_owner() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
 
     let instruction_index = 0u32;
 
@@ -49,6 +57,8 @@ async fn fail_proposal_incorrect_owner() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -78,6 +88,7 @@ async fn fail_proposal_not_initialized() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
 
     let instruction_index = 0u32;
 
@@ -97,6 +108,8 @@ async fn fail_proposal_not_initialized() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -126,6 +139,11 @@ async fn fail_proposal_not_accepted() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let instruction_index = 0u32;
 
@@ -135,14 +153,17 @@ async fn fail_proposal_not_accepted() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Voting, // Not accepted.
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
 
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -170,10 +191,73 @@ async fn fail_proposal_not_accepted() {
     );
 }
 
+#[tokio::test]
+async fn fail_governance_paused() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        paused_timestamp: NonZeroU64::new(1),
+        ..GovernanceConfig::default()
+    };
+
+    let instruction_index = 0u32;
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[],
+        &[],
+        instruction_index,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernancePaused as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn fail_proposal_transaction_incorrect_address() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address = Pubkey::new_unique(); // Incorrect proposal transaction address.
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let instruction_index = 0u32;
 
@@ -183,14 +267,17 @@ async fn fail_proposal_transaction_incorrect_address() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
 
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -225,6 +312,11 @@ async fn fail_proposal_transaction_incorrect_owner() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let instruction_index = 0u32;
 
@@ -234,10 +326,11 @@ async fn fail_proposal_transaction_incorrect_owner() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
 
     // Set up a proposal transaction account with incorrect owner.
     {
@@ -253,6 +346,8 @@ async fn fail_proposal_transaction_incorrect_owner() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -282,6 +377,11 @@ async fn fail_proposal_transaction_not_initialized() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let instruction_index = 0u32;
 
@@ -291,10 +391,11 @@ async fn fail_proposal_transaction_not_initialized() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
 
     // Set up a proposal transaction account uninitialized.
     {
@@ -310,6 +411,8 @@ async fn fail_proposal_transaction_not_initialized() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -339,6 +442,11 @@ async fn fail_invalid_instruction_index() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let proposal_transaction = create_mock_proposal_transaction(&[
         &Pubkey::new_unique(), // One instruction.
@@ -351,10 +459,11 @@ async fn fail_invalid_instruction_index() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_proposal_transaction(
         &mut context,
         &proposal_transaction_address,
@@ -365,6 +474,8 @@ async fn fail_invalid_instruction_index() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -397,6 +508,11 @@ async fn fail_instruction_already_executed() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let mut proposal_transaction =
         create_mock_proposal_transaction(&[&Pubkey::new_unique(), &Pubkey::new_unique()]);
@@ -409,10 +525,11 @@ async fn fail_instruction_already_executed() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_proposal_transaction(
         &mut context,
         &proposal_transaction_address,
@@ -423,6 +540,8 @@ async fn fail_instruction_already_executed() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -455,6 +574,11 @@ async fn fail_previous_instruction_not_executed() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let proposal_transaction =
         create_mock_proposal_transaction(&[&Pubkey::new_unique(), &Pubkey::new_unique()]);
@@ -466,10 +590,11 @@ async fn fail_previous_instruction_not_executed() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_proposal_transaction(
         &mut context,
         &proposal_transaction_address,
@@ -480,6 +605,8 @@ async fn fail_previous_instruction_not_executed() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance_address,
+        &[],
         &[],
         instruction_index,
     );
@@ -514,6 +641,11 @@ async fn fail_instruction_two_back_not_executed() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
     let proposal_transaction = create_mock_proposal_transaction(&[
         &Pubkey::new_unique(),
@@ -527,10 +659,11 @@ async fn fail_instruction_two_back_not_executed() {
         &proposal_address,
         &Pubkey::new_unique(),
         0,
-        GovernanceConfig::default(),
+        governance_config,
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_proposal_transaction(
         &mut context,
         &proposal_transaction_address,
@@ -538,7 +671,14 @@ async fn fail_instruction_two_back_not_executed() {
     )
     .await;
 
-    let instruction = process_instruction(&proposal_address, &proposal_transaction_address, &[], 2);
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[],
+        &[],
+        2,
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -586,6 +726,7 @@ async fn fail_incorrect_accounts() {
         stake_per_proposal: 0,
         governance_config: governance_address,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
@@ -605,6 +746,8 @@ async fn fail_incorrect_accounts() {
             (&system_instruction::transfer(&alice.pubkey(), &treasury, alice_to_treasury_lamports))
                 .into(),
         ],
+        prerequisites: vec![],
+        options: vec![],
     };
 
     let mut context = setup().start_with_context().await;
@@ -617,6 +760,7 @@ async fn fail_incorrect_accounts() {
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_proposal_transaction(
         &mut context,
         &proposal_transaction_address,
@@ -641,6 +785,8 @@ async fn fail_incorrect_accounts() {
         let instruction = process_instruction(
             &proposal_address,
             &proposal_transaction_address,
+            &governance_address,
+            &[],
             &[
                 AccountMeta::new(Pubkey::new_unique(), false),
                 AccountMeta::new(alice.pubkey(), false),
@@ -671,46 +817,20 @@ async fn fail_incorrect_accounts() {
 
 #[allow(clippy::arithmetic_side_effects)]
 #[tokio::test]
-async fn success() {
+async fn fail_prerequisite_incorrect_address() {
     let proposal_address = Pubkey::new_unique();
     let proposal_transaction_address =
         get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
-
-    let stake_config_address = Pubkey::new_unique();
-    let governance_address = paladin_governance_program::state::get_governance_address(
-        &stake_config_address,
-        &0,
-        &paladin_governance_program::ID,
-    );
+    let governance_address = Pubkey::new_unique();
     let governance_config = GovernanceConfig {
-        cooldown_period_seconds: 0,
-        proposal_minimum_quorum: 0,
-        proposal_pass_threshold: 0,
-        stake_config_address: stake_config_address,
-        voting_period_seconds: 0,
-        stake_per_proposal: 0,
         governance_config: governance_address,
-        cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
-    let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
-    let alice = Keypair::new();
-
-    let treasury_starting_lamports = 500_000_000;
-    let alice_starting_lamports = 350_000_000;
-
-    // Transfer amounts.
-    let treasury_to_alice_lamports = 100_000_000;
-    let alice_to_treasury_lamports = 50_000_000;
+    let prerequisite_address = Pubkey::new_unique();
 
-    let proposal_transaction = ProposalTransaction {
-        instructions: vec![
-            (&system_instruction::transfer(&treasury, &alice.pubkey(), treasury_to_alice_lamports))
-                .into(),
-            (&system_instruction::transfer(&alice.pubkey(), &treasury, alice_to_treasury_lamports))
-                .into(),
-        ],
-    };
+    let mut proposal_transaction = create_mock_proposal_transaction(&[&Pubkey::new_unique()]);
+    proposal_transaction.prerequisites = vec![prerequisite_address];
 
     let mut context = setup().start_with_context().await;
     setup_proposal(
@@ -722,98 +842,363 @@ async fn success() {
         ProposalStatus::Accepted,
     )
     .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_proposal_transaction(
         &mut context,
         &proposal_transaction_address,
         proposal_transaction,
     )
     .await;
+    setup_proposal(
+        &mut context,
+        &prerequisite_address,
+        &Pubkey::new_unique(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Processed,
+    )
+    .await;
 
-    // Set up treasury and alice with some lamports for transferring.
-    {
-        context.set_account(
-            &treasury,
-            &AccountSharedData::new(treasury_starting_lamports, 0, &system_program::id()), // System-owned.
-        );
-        context.set_account(
-            &alice.pubkey(),
-            &AccountSharedData::new(alice_starting_lamports, 0, &system_program::id()),
-        );
-    }
-
-    // Execute the first instruction.
-    {
-        let instruction = process_instruction(
-            &proposal_address,
-            &proposal_transaction_address,
-            &[
-                AccountMeta::new(treasury, false),
-                AccountMeta::new(alice.pubkey(), false),
-                AccountMeta::new_readonly(system_program::id(), false),
-            ],
-            0, // First instruction.
-        );
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[Pubkey::new_unique()], // Incorrect prerequisite address.
+        &[],
+        0,
+    );
 
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&context.payer.pubkey()),
-            &[&context.payer], // Note treasury not signer (PDA).
-            context.last_blockhash,
-        );
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
 
-        context
-            .banks_client
-            .process_transaction(transaction)
-            .await
-            .unwrap();
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
 
-        // Assert lamports were transferred from Alice to Bob.
-        assert_eq!(
-            context
-                .banks_client
-                .get_account(treasury)
-                .await
-                .unwrap()
-                .unwrap()
-                .lamports,
-            treasury_starting_lamports - treasury_to_alice_lamports
-        );
-        assert_eq!(
-            context
-                .banks_client
-                .get_account(alice.pubkey())
-                .await
-                .unwrap()
-                .unwrap()
-                .lamports,
-            alice_starting_lamports + treasury_to_alice_lamports
-        );
-    }
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::IncorrectPrerequisiteAddress as u32)
+        )
+    );
+}
 
-    // Execute the second instruction.
-    {
-        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+#[tokio::test]
+async fn fail_prerequisite_not_processed() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
 
-        let instruction = process_instruction(
-            &proposal_address,
-            &proposal_transaction_address,
-            &[
-                AccountMeta::new(alice.pubkey(), true),
-                AccountMeta::new(treasury, false),
-                AccountMeta::new_readonly(system_program::id(), false),
-            ],
-            1, // Second instruction.
-        );
+    let prerequisite_address = Pubkey::new_unique();
 
-        let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
-            Some(&context.payer.pubkey()),
-            &[&context.payer, &alice],
-            blockhash,
-        );
+    let mut proposal_transaction = create_mock_proposal_transaction(&[&Pubkey::new_unique()]);
+    proposal_transaction.prerequisites = vec![prerequisite_address];
 
-        context
-            .banks_client
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &prerequisite_address,
+        &Pubkey::new_unique(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Accepted, // Not yet processed.
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[prerequisite_address],
+        &[],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::PrerequisiteNotProcessed as u32)
+        )
+    );
+}
+
+#[test_case(None; "deleted")]
+#[test_case(Some(ProposalStatus::Rejected); "rejected")]
+#[test_case(Some(ProposalStatus::Cancelled); "cancelled")]
+#[tokio::test]
+async fn fail_prerequisite_closed(prerequisite_status: Option<ProposalStatus>) {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+    let governance_address = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
+
+    let prerequisite_address = Pubkey::new_unique();
+
+    let mut proposal_transaction = create_mock_proposal_transaction(&[&Pubkey::new_unique()]);
+    proposal_transaction.prerequisites = vec![prerequisite_address];
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+    // A deleted prerequisite's account no longer exists.
+    if let Some(status) = prerequisite_status {
+        setup_proposal(
+            &mut context,
+            &prerequisite_address,
+            &Pubkey::new_unique(),
+            0,
+            GovernanceConfig::default(),
+            status,
+        )
+        .await;
+    }
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[prerequisite_address],
+        &[],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::PrerequisiteClosed as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance_address = paladin_governance_program::state::get_governance_address(
+        &stake_config_address,
+        &0,
+        &paladin_governance_program::ID,
+    );
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 0,
+        proposal_minimum_quorum: 0,
+        proposal_pass_threshold: 0,
+        stake_config_address: stake_config_address,
+        voting_period_seconds: 0,
+        stake_per_proposal: 0,
+        governance_config: governance_address,
+        cooldown_expires: 0,
+        ..GovernanceConfig::default()
+    };
+
+    let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
+    let alice = Keypair::new();
+
+    let treasury_starting_lamports = 500_000_000;
+    let alice_starting_lamports = 350_000_000;
+
+    // Transfer amounts.
+    let treasury_to_alice_lamports = 100_000_000;
+    let alice_to_treasury_lamports = 50_000_000;
+
+    let proposal_transaction = ProposalTransaction {
+        instructions: vec![
+            (&system_instruction::transfer(&treasury, &alice.pubkey(), treasury_to_alice_lamports))
+                .into(),
+            (&system_instruction::transfer(&alice.pubkey(), &treasury, alice_to_treasury_lamports))
+                .into(),
+        ],
+        prerequisites: vec![],
+        options: vec![],
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+
+    // Set up treasury and alice with some lamports for transferring.
+    {
+        context.set_account(
+            &treasury,
+            &AccountSharedData::new(treasury_starting_lamports, 0, &system_program::id()), // System-owned.
+        );
+        context.set_account(
+            &alice.pubkey(),
+            &AccountSharedData::new(alice_starting_lamports, 0, &system_program::id()),
+        );
+    }
+
+    // Execute the first instruction.
+    {
+        let instruction = process_instruction(
+            &proposal_address,
+            &proposal_transaction_address,
+            &governance_address,
+            &[],
+            &[
+                AccountMeta::new(treasury, false),
+                AccountMeta::new(alice.pubkey(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            0, // First instruction.
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer], // Note treasury not signer (PDA).
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+
+        // Assert lamports were transferred from Alice to Bob.
+        assert_eq!(
+            context
+                .banks_client
+                .get_account(treasury)
+                .await
+                .unwrap()
+                .unwrap()
+                .lamports,
+            treasury_starting_lamports - treasury_to_alice_lamports
+        );
+        assert_eq!(
+            context
+                .banks_client
+                .get_account(alice.pubkey())
+                .await
+                .unwrap()
+                .unwrap()
+                .lamports,
+            alice_starting_lamports + treasury_to_alice_lamports
+        );
+    }
+
+    // Execute the second instruction.
+    {
+        let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+
+        let instruction = process_instruction(
+            &proposal_address,
+            &proposal_transaction_address,
+            &governance_address,
+            &[],
+            &[
+                AccountMeta::new(alice.pubkey(), true),
+                AccountMeta::new(treasury, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            1, // Second instruction.
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &alice],
+            blockhash,
+        );
+
+        context
+            .banks_client
             .process_transaction(transaction)
             .await
             .unwrap();
@@ -851,3 +1236,528 @@ async fn success() {
     let proposal = bytemuck::from_bytes::<Proposal>(&proposal.data);
     assert_eq!(proposal.status, ProposalStatus::Processed);
 }
+
+#[tokio::test]
+async fn success_legacy_proposal_transaction() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance_address = paladin_governance_program::state::get_governance_address(
+        &stake_config_address,
+        &0,
+        &paladin_governance_program::ID,
+    );
+    let governance_config = GovernanceConfig {
+        stake_config_address,
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
+
+    let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
+    let alice = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    // Written before prerequisites and options were added.
+    setup_legacy_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        vec![(&system_instruction::transfer(&treasury, &alice, 100_000_000)).into()],
+    )
+    .await;
+    let legacy_len = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+        .len();
+
+    context.set_account(
+        &treasury,
+        &AccountSharedData::new(500_000_000, 0, &system_program::id()),
+    );
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(alice, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the instruction was executed.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(alice)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        100_000_000
+    );
+
+    // Assert the instruction was marked as executed, keeping the legacy
+    // layout.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proposal_transaction_account.data.len(), legacy_len);
+    let proposal_transaction =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert!(proposal_transaction.instructions[0].executed);
+    assert!(proposal_transaction.prerequisites.is_empty());
+    assert!(proposal_transaction.options.is_empty());
+
+    let proposal = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal = bytemuck::from_bytes::<Proposal>(&proposal.data);
+    assert_eq!(proposal.status, ProposalStatus::Processed);
+}
+
+#[tokio::test]
+async fn success_with_prerequisite() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let prerequisite_address = Pubkey::new_unique();
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance_address = paladin_governance_program::state::get_governance_address(
+        &stake_config_address,
+        &0,
+        &paladin_governance_program::ID,
+    );
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 0,
+        proposal_minimum_quorum: 0,
+        proposal_pass_threshold: 0,
+        stake_config_address: stake_config_address,
+        voting_period_seconds: 0,
+        stake_per_proposal: 0,
+        governance_config: governance_address,
+        cooldown_expires: 0,
+        ..GovernanceConfig::default()
+    };
+
+    let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
+    let alice = Pubkey::new_unique();
+
+    let treasury_starting_lamports = 500_000_000;
+    let treasury_to_alice_lamports = 100_000_000;
+
+    let proposal_transaction = ProposalTransaction {
+        instructions: vec![(&system_instruction::transfer(
+            &treasury,
+            &alice,
+            treasury_to_alice_lamports,
+        ))
+            .into()],
+        prerequisites: vec![prerequisite_address],
+        options: vec![],
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+    setup_proposal(
+        &mut context,
+        &prerequisite_address,
+        &Pubkey::new_unique(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Processed,
+    )
+    .await;
+
+    // Set up treasury with some lamports for transferring.
+    context.set_account(
+        &treasury,
+        &AccountSharedData::new(treasury_starting_lamports, 0, &system_program::id()),
+    );
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[prerequisite_address],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(alice, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert lamports were transferred from the treasury to Alice.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(alice)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        treasury_to_alice_lamports
+    );
+
+    // Assert the proposal was marked as processed.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+}
+
+#[tokio::test]
+async fn success_prerequisite_deleted_after_execution_started() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let prerequisite_author = Keypair::new();
+    let prerequisite_address = Pubkey::new_unique();
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance_address = paladin_governance_program::state::get_governance_address(
+        &stake_config_address,
+        &0,
+        &paladin_governance_program::ID,
+    );
+    let governance_config = GovernanceConfig {
+        stake_config_address,
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
+
+    let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
+    let alice = Pubkey::new_unique();
+    let bob = Pubkey::new_unique();
+
+    let proposal_transaction = ProposalTransaction {
+        instructions: vec![
+            (&system_instruction::transfer(&treasury, &alice, 100_000_000)).into(),
+            (&system_instruction::transfer(&treasury, &bob, 100_000_000)).into(),
+        ],
+        prerequisites: vec![prerequisite_address],
+        options: vec![],
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+    setup_author(&mut context, &prerequisite_author.pubkey(), 1).await;
+    setup_proposal(
+        &mut context,
+        &prerequisite_address,
+        &prerequisite_author.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Processed,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &get_proposal_transaction_address(&prerequisite_address, &paladin_governance_program::id()),
+        create_mock_proposal_transaction(&[]),
+    )
+    .await;
+    context.set_account(
+        &treasury,
+        &AccountSharedData::new(500_000_000, 0, &system_program::id()),
+    );
+
+    // Execute the first instruction while the prerequisite still exists.
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[prerequisite_address],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(alice, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        0,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Delete the processed prerequisite.
+    let instruction = delete_proposal(prerequisite_author.pubkey(), prerequisite_address);
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &prerequisite_author],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(context
+        .banks_client
+        .get_account(prerequisite_address)
+        .await
+        .unwrap()
+        .is_none());
+
+    // The remaining instruction can still be executed.
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[prerequisite_address],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(bob, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        1,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal was marked as processed.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+}
+
+#[tokio::test]
+async fn success_multiple_choice() {
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let stake_config_address = Pubkey::new_unique();
+    let governance_address = paladin_governance_program::state::get_governance_address(
+        &stake_config_address,
+        &0,
+        &paladin_governance_program::ID,
+    );
+    let governance_config = GovernanceConfig {
+        stake_config_address,
+        governance_config: governance_address,
+        ..GovernanceConfig::default()
+    };
+
+    let treasury = get_treasury_address(&governance_address, &paladin_governance_program::id());
+    let alice = Pubkey::new_unique();
+    let bob = Pubkey::new_unique();
+
+    let treasury_starting_lamports = 500_000_000;
+    let treasury_transfer_lamports = 100_000_000;
+
+    // Each option pays a different recipient.
+    let proposal_transaction = ProposalTransaction {
+        instructions: vec![],
+        prerequisites: vec![],
+        options: vec![
+            ProposalOption {
+                instructions: vec![(&system_instruction::transfer(
+                    &treasury,
+                    &alice,
+                    treasury_transfer_lamports,
+                ))
+                    .into()],
+            },
+            ProposalOption {
+                instructions: vec![(&system_instruction::transfer(
+                    &treasury,
+                    &bob,
+                    treasury_transfer_lamports,
+                ))
+                    .into()],
+            },
+        ],
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        governance_config,
+        &[0, 0],
+        /* winning_option */ 1,
+        ProposalStatus::Accepted,
+        None,
+        None,
+    )
+    .await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction,
+    )
+    .await;
+
+    // Set up treasury with some lamports for transferring.
+    context.set_account(
+        &treasury,
+        &AccountSharedData::new(treasury_starting_lamports, 0, &system_program::id()),
+    );
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance_address,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(bob, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the winning option was executed.
+    assert_eq!(
+        context
+            .banks_client
+            .get_account(bob)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports,
+        treasury_transfer_lamports
+    );
+    assert!(context
+        .banks_client
+        .get_account(alice)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the proposal was marked as processed.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.status, ProposalStatus::Processed);
+}
@@ -9,10 +9,10 @@ use {
         instruction::push_instruction,
         state::{
             get_proposal_transaction_address, GovernanceConfig, Proposal, ProposalAccountMeta,
-            ProposalInstruction, ProposalStatus, ProposalTransaction,
+            ProposalInstruction, ProposalOption, ProposalStatus, ProposalTransaction,
         },
     },
-    setup::{setup, setup_proposal, setup_proposal_transaction},
+    setup::{setup, setup_multiple_choice_proposal, setup_proposal, setup_proposal_transaction},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -434,6 +434,123 @@ async fn fail_proposal_transaction_not_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_proposal_is_multiple_choice() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        &[0, 0], // Multiple-choice proposal.
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            options: vec![ProposalOption::default(), ProposalOption::default()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = push_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &Pubkey::new_unique(),
+        vec![],
+        vec![],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalOption as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_is_signal() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_signal_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        0,
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+
+    let instruction = push_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        &Pubkey::new_unique(),
+        vec![],
+        vec![],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalNotExecutable as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let stake_authority = Keypair::new();
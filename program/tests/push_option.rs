@@ -0,0 +1,358 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::push_option,
+        state::{
+            get_proposal_transaction_address, GovernanceConfig, Proposal, ProposalOption,
+            ProposalStatus, ProposalTransaction, MAX_PROPOSAL_OPTIONS,
+        },
+    },
+    setup::{
+        create_mock_proposal_transaction, setup, setup_multiple_choice_proposal, setup_proposal,
+        setup_proposal_transaction,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        borsh1::get_instance_packed_len,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_stake_authority_not_signer() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let context = setup().start_with_context().await;
+
+    let mut instruction = push_option(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+    instruction.accounts[0].is_signer = false; // Stake authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing stake authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(), // Stake authority not author.
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+
+    let instruction = push_option(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_not_in_draft_stage() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Voting, // Not in draft stage.
+    )
+    .await;
+
+    let instruction = push_option(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::ProposalIsImmutable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_too_many_options() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        &[0; MAX_PROPOSAL_OPTIONS], // Already at the maximum.
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+
+    let instruction = push_option(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::TooManyProposalOptions as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_has_instructions() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        create_mock_proposal_transaction(&[&Pubkey::new_unique()]), // Yes/no instructions.
+    )
+    .await;
+
+    let instruction = push_option(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalOption as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        0,
+        GovernanceConfig::default(),
+        ProposalStatus::Draft,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        ProposalTransaction::default(),
+    )
+    .await;
+
+    // Fund the proposal transaction account to cover the new rent-exemption.
+    #[allow(clippy::arithmetic_side_effects)]
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let additional_lamports =
+            rent.minimum_balance(2 * get_instance_packed_len(&ProposalOption::default()).unwrap());
+
+        let mut proposal_transaction_account = context
+            .banks_client
+            .get_account(proposal_transaction_address)
+            .await
+            .unwrap()
+            .unwrap();
+        proposal_transaction_account.lamports += additional_lamports;
+        context.set_account(
+            &proposal_transaction_address,
+            &proposal_transaction_account.into(),
+        );
+    }
+
+    // Push two options.
+    for _ in 0..2 {
+        // Identical transactions need distinct blockhashes.
+        let blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+        let instruction = push_option(
+            &stake_authority.pubkey(),
+            &proposal_address,
+            &proposal_transaction_address,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &stake_authority],
+            blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+    }
+
+    // Assert the proposal is now a multiple-choice proposal.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.option_count, 2);
+
+    // Assert the proposal transaction account was updated.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert_eq!(
+        proposal_transaction_state.options,
+        vec![ProposalOption::default(), ProposalOption::default()]
+    );
+}
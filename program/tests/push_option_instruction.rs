@@ -0,0 +1,250 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::push_option_instruction,
+        state::{
+            get_proposal_transaction_address, GovernanceConfig, ProposalAccountMeta,
+            ProposalInstruction, ProposalOption, ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{setup, setup_multiple_choice_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        borsh1::get_instance_packed_len,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+fn two_option_proposal_transaction() -> ProposalTransaction {
+    ProposalTransaction {
+        options: vec![ProposalOption::default(), ProposalOption::default()],
+        ..ProposalTransaction::default()
+    }
+}
+
+#[tokio::test]
+async fn fail_stake_authority_not_author() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(), // Stake authority not author.
+        GovernanceConfig::default(),
+        &[0, 0],
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+
+    let instruction = push_option_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        0,
+        &Pubkey::new_unique(),
+        vec![],
+        vec![],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_invalid_option_index() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        &[0, 0],
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        two_option_proposal_transaction(),
+    )
+    .await;
+
+    let instruction = push_option_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        2, // Only two options.
+        &Pubkey::new_unique(),
+        vec![],
+        vec![],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalOption as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let proposal_address = Pubkey::new_unique();
+
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let instruction_program_id = Pubkey::new_unique();
+    let instruction_account_metas = vec![ProposalAccountMeta {
+        pubkey: Pubkey::new_unique(),
+        is_signer: false,
+        is_writable: true,
+    }];
+    let instruction_data = vec![1, 2, 3];
+
+    let mut context = setup().start_with_context().await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal_address,
+        &stake_authority.pubkey(),
+        GovernanceConfig::default(),
+        &[0, 0],
+        0,
+        ProposalStatus::Draft,
+        None,
+        None,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        two_option_proposal_transaction(),
+    )
+    .await;
+
+    // Fund the proposal transaction account to cover the new rent-exemption.
+    #[allow(clippy::arithmetic_side_effects)]
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let new_instruction_len = get_instance_packed_len(&instruction_program_id).unwrap()
+            + get_instance_packed_len(&instruction_account_metas).unwrap()
+            + get_instance_packed_len(&instruction_data).unwrap();
+        let additional_lamports = rent.minimum_balance(new_instruction_len);
+
+        let mut proposal_transaction_account = context
+            .banks_client
+            .get_account(proposal_transaction_address)
+            .await
+            .unwrap()
+            .unwrap();
+        proposal_transaction_account.lamports += additional_lamports;
+        context.set_account(
+            &proposal_transaction_address,
+            &proposal_transaction_account.into(),
+        );
+    }
+
+    let instruction = push_option_instruction(
+        &stake_authority.pubkey(),
+        &proposal_address,
+        &proposal_transaction_address,
+        1,
+        &instruction_program_id,
+        instruction_account_metas.clone(),
+        instruction_data.clone(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the second option was updated.
+    let proposal_transaction_account = context
+        .banks_client
+        .get_account(proposal_transaction_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_transaction_state =
+        ProposalTransaction::try_from_slice(&proposal_transaction_account.data).unwrap();
+    assert!(proposal_transaction_state.instructions.is_empty());
+    assert!(proposal_transaction_state.options[0]
+        .instructions
+        .is_empty());
+    assert_eq!(
+        proposal_transaction_state.options[1].instructions,
+        vec![ProposalInstruction {
+            program_id: instruction_program_id,
+            accounts: instruction_account_metas,
+            data: instruction_data,
+            executed: false,
+        }]
+    );
+}
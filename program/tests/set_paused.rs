@@ -0,0 +1,361 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        instruction::{process_instruction, set_paused},
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_treasury_address,
+            GovernanceConfig, ProposalStatus, ProposalTransaction,
+        },
+    },
+    setup::{setup, setup_governance, setup_proposal, setup_proposal_transaction},
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::{AccountMeta, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+};
+
+fn governance_config(
+    stake_config_address: Pubkey,
+    governance: Pubkey,
+    pause_guardian: Pubkey,
+) -> GovernanceConfig {
+    GovernanceConfig {
+        cooldown_period_seconds: 100,
+        proposal_minimum_quorum: 1,
+        proposal_pass_threshold: 2,
+        stake_config_address,
+        voting_period_seconds: 400,
+        stake_per_proposal: 500,
+        governance_config: governance,
+        pause_guardian,
+        ..GovernanceConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let pause_guardian = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance, pause_guardian),
+    )
+    .await;
+
+    let mut instruction = set_paused(&pause_guardian, &governance, true);
+    instruction.accounts[0].is_signer = false; // Pause guardian not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_authority() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let authority = Keypair::new(); // Neither the treasury nor the pause guardian.
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance, Pubkey::new_unique()),
+    )
+    .await;
+
+    let instruction = set_paused(&authority.pubkey(), &governance, true);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn fail_no_pause_guardian() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(
+        &mut context,
+        &governance,
+        &governance_config(stake_config_address, governance, Pubkey::default()), // No guardian.
+    )
+    .await;
+
+    let authority = Keypair::new();
+    let instruction = set_paused(&authority.pubkey(), &governance, true);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+/// Sets up an accepted proposal setting whether governance is paused, and
+/// processes it with the treasury signing through the program.
+async fn process_treasury_set_paused(
+    context: &mut ProgramTestContext,
+    governance: &Pubkey,
+    governance_config: GovernanceConfig,
+    paused: bool,
+) -> Result<(), BanksClientError> {
+    let treasury = get_treasury_address(governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    setup_proposal(
+        context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        context,
+        &proposal_transaction_address,
+        ProposalTransaction {
+            instructions: vec![(&set_paused(&treasury, governance, paused)).into()],
+            ..ProposalTransaction::default()
+        },
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(*governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn success_treasury_pause_no_pause_guardian() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let governance_config = governance_config(stake_config_address, governance, Pubkey::default());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    // For checks later.
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // The treasury doesn't need a pause guardian to be set.
+    process_treasury_set_paused(&mut context, &governance, governance_config, true)
+        .await
+        .unwrap();
+
+    // Assert governance was paused.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data),
+        &GovernanceConfig {
+            paused_timestamp: NonZeroU64::new(clock.unix_timestamp as u64),
+            ..governance_config
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_treasury_pause() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let governance_config =
+        governance_config(stake_config_address, governance, Pubkey::new_unique());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    // For checks later.
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    process_treasury_set_paused(&mut context, &governance, governance_config, true)
+        .await
+        .unwrap();
+
+    // Assert governance was paused.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data),
+        &GovernanceConfig {
+            paused_timestamp: NonZeroU64::new(clock.unix_timestamp as u64),
+            ..governance_config
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_treasury_pause_and_unpause() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+
+    let governance_config =
+        governance_config(stake_config_address, governance, Pubkey::new_unique());
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    process_treasury_set_paused(&mut context, &governance, governance_config, true)
+        .await
+        .unwrap();
+
+    // A proposal only unpausing governance can be processed while paused.
+    process_treasury_set_paused(&mut context, &governance, governance_config, false)
+        .await
+        .unwrap();
+
+    // Assert governance was unpaused.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data).is_paused());
+}
+
+#[tokio::test]
+async fn success_pause_guardian_unpause() {
+    let stake_config_address = Pubkey::new_unique();
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let pause_guardian = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // Paused for 100 seconds, on top of 10 seconds from an earlier pause.
+    let governance_config = GovernanceConfig {
+        paused_timestamp: NonZeroU64::new(clock.unix_timestamp as u64 - 100),
+        paused_seconds: 10,
+        ..governance_config(stake_config_address, governance, pause_guardian.pubkey())
+    };
+    setup_governance(&mut context, &governance, &governance_config).await;
+
+    let instruction = set_paused(&pause_guardian.pubkey(), &governance, false);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pause_guardian],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert governance was unpaused, accruing the time spent paused.
+    let governance_account = context
+        .banks_client
+        .get_account(governance)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<GovernanceConfig>(&governance_account.data),
+        &GovernanceConfig {
+            paused_timestamp: None,
+            paused_seconds: 110,
+            ..governance_config
+        }
+    );
+}
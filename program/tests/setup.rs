@@ -5,14 +5,17 @@ use {
     borsh::BorshSerialize,
     paladin_governance_program::state::{
         get_proposal_author_address, GovernanceConfig, Proposal, ProposalAccountMeta,
-        ProposalInstruction, ProposalStatus, ProposalTransaction, ProposalVote,
+        ProposalInstruction, ProposalKind, ProposalStatus, ProposalTransaction, ProposalVote,
         ProposalVoteElection,
     },
     paladin_stake_program::state::{Config as StakeConfig, Delegation, ValidatorStake},
     solana_program_test::*,
     solana_sdk::{
         account::{Account, AccountSharedData},
+        account_utils::StateMut,
+        bpf_loader_upgradeable::{self, get_program_data_address, UpgradeableLoaderState},
         clock::{Clock, UnixTimestamp},
+        program_pack::Pack,
         pubkey::Pubkey,
     },
     spl_discriminator::SplDiscriminate,
@@ -88,10 +91,25 @@ pub async fn setup_stake_config(
     context: &mut ProgramTestContext,
     stake_config_address: &Pubkey,
     total_stake: u64,
+) {
+    setup_stake_config_with_authority(
+        context,
+        stake_config_address,
+        &Pubkey::new_unique(),
+        total_stake,
+    )
+    .await;
+}
+
+pub async fn setup_stake_config_with_authority(
+    context: &mut ProgramTestContext,
+    stake_config_address: &Pubkey,
+    authority_address: &Pubkey,
+    total_stake: u64,
 ) {
     let state = StakeConfig {
         discriminator: StakeConfig::SPL_DISCRIMINATOR.into(),
-        authority: Some(Pubkey::new_unique()).try_into().unwrap(),
+        authority: Some(*authority_address).try_into().unwrap(),
         slash_authority: Some(Pubkey::new_unique()).try_into().unwrap(),
         vault: Pubkey::new_unique(),
         cooldown_time_seconds: 0,
@@ -121,6 +139,28 @@ pub async fn setup_stake_config(
     );
 }
 
+pub async fn setup_program_data(
+    context: &mut ProgramTestContext,
+    upgrade_authority_address: Option<Pubkey>,
+) {
+    let state = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address,
+    };
+    let space = UpgradeableLoaderState::size_of_programdata_metadata();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(space);
+
+    let mut account = AccountSharedData::new(lamports, space, &bpf_loader_upgradeable::id());
+    account.set_state(&state).unwrap();
+
+    context.set_account(
+        &get_program_data_address(&paladin_governance_program::id()),
+        &account,
+    );
+}
+
 pub async fn setup_governance(
     context: &mut ProgramTestContext,
     governance_address: &Pubkey,
@@ -142,6 +182,92 @@ pub async fn setup_governance(
     );
 }
 
+/// The discriminator of proposals written before the governance config
+/// gained its discriminator.
+pub const LEGACY_PROPOSAL_DISCRIMINATOR: [u8; 8] = [62, 153, 195, 152, 54, 69, 225, 249];
+
+/// A governance config in the 104-byte layout it had before gaining its
+/// discriminator.
+pub fn legacy_governance_config_bytes(config: &GovernanceConfig) -> Vec<u8> {
+    let data = [
+        &config.cooldown_period_seconds.to_le_bytes()[..],
+        &config.proposal_minimum_quorum.to_le_bytes(),
+        &config.proposal_pass_threshold.to_le_bytes(),
+        config.stake_config_address.as_ref(),
+        &config.voting_period_seconds.to_le_bytes(),
+        &config.stake_per_proposal.to_le_bytes(),
+        config.governance_config.as_ref(),
+        &config.cooldown_expires.to_le_bytes(),
+    ]
+    .concat();
+    assert_eq!(data.len(), 104);
+    data
+}
+
+/// A proposal in the 192-byte layout it had before the governance config
+/// gained its discriminator. Fields added since are left out.
+pub fn legacy_proposal_bytes(proposal: &Proposal) -> Vec<u8> {
+    let timestamp = |timestamp: Option<NonZeroU64>| timestamp.map_or(0, NonZeroU64::get);
+    let data = [
+        &LEGACY_PROPOSAL_DISCRIMINATOR[..],
+        proposal.author.as_ref(),
+        &timestamp(proposal.cooldown_timestamp).to_le_bytes(),
+        &proposal.creation_timestamp.to_le_bytes(),
+        &legacy_governance_config_bytes(&proposal.governance_config),
+        &proposal.stake_against.to_le_bytes(),
+        &proposal.stake_for.to_le_bytes(),
+        &[u8::from(proposal.status)],
+        &[0; 7],
+        &timestamp(proposal.voting_start_timestamp).to_le_bytes(),
+    ]
+    .concat();
+    assert_eq!(data.len(), 192);
+    data
+}
+
+pub async fn setup_legacy_governance(
+    context: &mut ProgramTestContext,
+    governance_address: &Pubkey,
+    config: &GovernanceConfig,
+) {
+    let data = legacy_governance_config_bytes(config);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(std::mem::size_of::<GovernanceConfig>());
+
+    context.set_account(
+        governance_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+/// Writes `proposal` in the legacy layout, funded for the current one.
+pub async fn setup_legacy_proposal(
+    context: &mut ProgramTestContext,
+    proposal_address: &Pubkey,
+    proposal: &Proposal,
+) {
+    let data = legacy_proposal_bytes(proposal);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(std::mem::size_of::<Proposal>());
+
+    context.set_account(
+        proposal_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn _setup_proposal_inner(
     context: &mut ProgramTestContext,
@@ -154,9 +280,11 @@ async fn _setup_proposal_inner(
     status: ProposalStatus,
     voting_start_timestamp: Option<NonZeroU64>,
     cooldown_timestamp: Option<NonZeroU64>,
+    kind: ProposalKind,
 ) {
     let mut state = Proposal::new(author, creation_timestamp, governance_config);
     state.cooldown_timestamp = cooldown_timestamp;
+    state.kind = kind;
     state.stake_for = stake_for;
     state.stake_against = stake_against;
     state.status = status;
@@ -202,6 +330,7 @@ pub async fn setup_proposal_with_stake_and_cooldown(
         status,
         voting_start_timestamp,
         cooldown_timestamp,
+        ProposalKind::Executable,
     )
     .await;
 }
@@ -229,10 +358,76 @@ pub async fn setup_proposal_with_stake(
         status,
         voting_start_timestamp,
         None,
+        ProposalKind::Executable,
     )
     .await;
 }
 
+#[allow(clippy::too_many_arguments)]
+pub async fn setup_signal_proposal(
+    context: &mut ProgramTestContext,
+    proposal_address: &Pubkey,
+    author: &Pubkey,
+    governance_config: GovernanceConfig,
+    stake_for: u64,
+    stake_against: u64,
+    status: ProposalStatus,
+    voting_start_timestamp: Option<NonZeroU64>,
+    cooldown_timestamp: Option<NonZeroU64>,
+) {
+    _setup_proposal_inner(
+        context,
+        proposal_address,
+        author,
+        0,
+        governance_config,
+        stake_for,
+        stake_against,
+        status,
+        voting_start_timestamp,
+        cooldown_timestamp,
+        ProposalKind::Signal,
+    )
+    .await;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn setup_multiple_choice_proposal(
+    context: &mut ProgramTestContext,
+    proposal_address: &Pubkey,
+    author: &Pubkey,
+    governance_config: GovernanceConfig,
+    option_stake: &[u64],
+    winning_option: u8,
+    status: ProposalStatus,
+    voting_start_timestamp: Option<NonZeroU64>,
+    cooldown_timestamp: Option<NonZeroU64>,
+) {
+    let mut state = Proposal::new(author, 0, governance_config);
+    state.cooldown_timestamp = cooldown_timestamp;
+    state.option_count = option_stake.len() as u8;
+    state.option_stake[..option_stake.len()].copy_from_slice(option_stake);
+    state.stake_for = option_stake.iter().sum();
+    state.status = status;
+    state.voting_start_timestamp = voting_start_timestamp;
+    state.winning_option = winning_option;
+
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        proposal_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
 pub async fn setup_proposal(
     context: &mut ProgramTestContext,
     proposal_address: &Pubkey,
@@ -277,6 +472,30 @@ pub async fn setup_proposal_transaction(
     );
 }
 
+/// Writes a proposal transaction in the layout it had before prerequisites and
+/// options were added, which only holds its instructions.
+pub async fn setup_legacy_proposal_transaction(
+    context: &mut ProgramTestContext,
+    proposal_transaction_address: &Pubkey,
+    instructions: Vec<ProposalInstruction>,
+) {
+    let mut data = Vec::new();
+    instructions.serialize(&mut data).unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        proposal_transaction_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
 pub async fn setup_proposal_vote(
     context: &mut ProgramTestContext,
     proposal_vote_address: &Pubkey,
@@ -290,6 +509,7 @@ pub async fn setup_proposal_vote(
         stake,
         authority: stake_authority_address,
         election,
+        option_index: 0,
         _padding: Default::default(),
     };
     let data = bytemuck::bytes_of(&state).to_vec();
@@ -308,6 +528,98 @@ pub async fn setup_proposal_vote(
     );
 }
 
+pub async fn setup_proposal_option_vote(
+    context: &mut ProgramTestContext,
+    proposal_vote_address: &Pubkey,
+    proposal_address: Pubkey,
+    stake: u64,
+    stake_authority_address: Pubkey,
+    option_index: u8,
+) {
+    let state = ProposalVote {
+        proposal: proposal_address,
+        stake,
+        authority: stake_authority_address,
+        election: ProposalVoteElection::For,
+        option_index,
+        _padding: Default::default(),
+    };
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        proposal_vote_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_governance_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_mint(
+    context: &mut ProgramTestContext,
+    mint_address: &Pubkey,
+    token_program: &Pubkey,
+    decimals: u8,
+) {
+    let state = spl_token::state::Mint {
+        decimals,
+        is_initialized: true,
+        ..spl_token::state::Mint::default()
+    };
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    state.pack_into_slice(&mut data);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        mint_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *token_program,
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_token_account(
+    context: &mut ProgramTestContext,
+    token_account_address: &Pubkey,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) {
+    let state = spl_token::state::Account {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..spl_token::state::Account::default()
+    };
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    state.pack_into_slice(&mut data);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        token_account_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *token_program,
+            ..Account::default()
+        }),
+    );
+}
+
 pub fn create_mock_proposal_transaction(program_ids: &[&Pubkey]) -> ProposalTransaction {
     let mut instructions = Vec::new();
     for instruction_program_id in program_ids {
@@ -330,7 +642,11 @@ pub fn create_mock_proposal_transaction(program_ids: &[&Pubkey]) -> ProposalTran
             instruction_data,
         ));
     }
-    ProposalTransaction { instructions }
+    ProposalTransaction {
+        instructions,
+        prerequisites: vec![],
+        options: vec![],
+    }
 }
 
 pub async fn get_clock(context: &mut ProgramTestContext) -> Clock {
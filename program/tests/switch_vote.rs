@@ -14,7 +14,7 @@ use {
         find_validator_stake_pda, Config as StakeConfig, ValidatorStake,
     },
     setup::{
-        setup, setup_proposal, setup_proposal_vote, setup_proposal_with_stake,
+        setup, setup_governance, setup_proposal, setup_proposal_vote, setup_proposal_with_stake,
         setup_proposal_with_stake_and_cooldown, setup_stake, setup_stake_config,
     },
     solana_program_test::*,
@@ -37,6 +37,7 @@ async fn fail_stake_authority_not_signer() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -51,6 +52,7 @@ async fn fail_stake_authority_not_signer() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
     instruction.accounts[0].is_signer = false; // Stake authority not signer.
@@ -81,6 +83,7 @@ async fn fail_stake_incorrect_owner() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -106,6 +109,7 @@ async fn fail_stake_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -135,6 +139,7 @@ async fn fail_stake_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -160,6 +165,7 @@ async fn fail_stake_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -189,6 +195,7 @@ async fn fail_stake_incorrect_stake_authority() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -213,6 +220,7 @@ async fn fail_stake_incorrect_stake_authority() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -242,6 +250,7 @@ async fn fail_stake_config_incorrect_owner() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -284,6 +293,7 @@ async fn fail_stake_config_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -313,6 +323,7 @@ async fn fail_stake_config_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -355,6 +366,7 @@ async fn fail_stake_config_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -384,6 +396,7 @@ async fn fail_proposal_incorrect_owner() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -418,6 +431,7 @@ async fn fail_proposal_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -447,6 +461,7 @@ async fn fail_proposal_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -481,6 +496,7 @@ async fn fail_proposal_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -510,6 +526,7 @@ async fn fail_proposal_not_voting() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -547,6 +564,7 @@ async fn fail_proposal_not_voting() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -579,6 +597,7 @@ async fn fail_proposal_vote_incorrect_address() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -586,11 +605,13 @@ async fn fail_proposal_vote_incorrect_address() {
 
     let governance_config = GovernanceConfig {
         stake_config_address: stake_config,
+        governance_config: governance,
         ..Default::default()
     };
 
     let mut context = setup().start_with_context().await;
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -615,6 +636,7 @@ async fn fail_proposal_vote_incorrect_address() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -647,6 +669,7 @@ async fn fail_proposal_vote_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -655,11 +678,13 @@ async fn fail_proposal_vote_not_initialized() {
 
     let governance_config = GovernanceConfig {
         stake_config_address: stake_config,
+        governance_config: governance,
         ..Default::default()
     };
 
     let mut context = setup().start_with_context().await;
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -694,6 +719,7 @@ async fn fail_proposal_vote_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -960,12 +986,14 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
         stake_per_proposal: 0,
         governance_config: governance_address,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -1023,6 +1051,7 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance_address,
         switch.new_election,
     );
 
@@ -1053,6 +1082,7 @@ async fn success(proposal_starting: ProposalStarting, switch: VoteSwitch, expect
             stake: switch.new_vote_stake,
             authority: stake_authority.pubkey(),
             election: switch.new_election,
+            option_index: 0,
             _padding: Default::default(),
         },
     );
@@ -1115,11 +1145,13 @@ async fn success_voting_closed() {
         stake_per_proposal: 0,
         governance_config: governance_address,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -1160,6 +1192,7 @@ async fn success_voting_closed() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance_address,
         new_election,
     );
 
@@ -1217,12 +1250,14 @@ async fn success_voting_closed_but_cooldown_active() {
         stake_per_proposal: 0,
         governance_config: governance_address,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -1267,6 +1302,7 @@ async fn success_voting_closed_but_cooldown_active() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance_address,
         new_election,
     );
 
@@ -1337,6 +1373,7 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
         stake_per_proposal: 0,
         governance_config: governance_address,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     // We'll set up a proposal whose cooldown period has ended.
@@ -1357,6 +1394,7 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -1394,8 +1432,11 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
     )
     .await;
 
-    let instruction =
-        paladin_governance_program::instruction::finish_voting(stake_config, &proposal);
+    let instruction = paladin_governance_program::instruction::finish_voting(
+        stake_config,
+        &proposal,
+        &governance_address,
+    );
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
         Some(&context.payer.pubkey()),
@@ -1431,3 +1472,97 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
     assert_eq!(proposal_vote_state.stake, prev_vote_stake);
     assert_eq!(proposal_vote_state.election, prev_election);
 }
+
+#[tokio::test]
+async fn fail_governance_paused() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance_address =
+        get_governance_address(&stake_config, &0, &paladin_governance_program::ID);
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: MINIMUM_QUORUM,
+        proposal_pass_threshold: PASS_THRESHOLD,
+        stake_config_address: stake_config,
+        voting_period_seconds: 10,
+        governance_config: governance_address,
+        paused_timestamp: NonZeroU64::new(clock.unix_timestamp as u64), // Paused.
+        ..GovernanceConfig::default()
+    };
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance_address, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ vote_stake,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+    setup_proposal_vote(
+        &mut context,
+        &proposal_vote,
+        proposal,
+        vote_stake,
+        stake_authority.pubkey(),
+        ProposalVoteElection::Against,
+    )
+    .await;
+
+    let instruction = paladin_governance_program::instruction::switch_vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance_address,
+        ProposalVoteElection::For,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernancePaused as u32)
+        )
+    );
+}
@@ -0,0 +1,236 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::switch_vote_option,
+        state::{
+            get_proposal_vote_address, GovernanceConfig, Proposal, ProposalStatus, ProposalVote,
+            ProposalVoteElection,
+        },
+    },
+    paladin_stake_program::state::find_validator_stake_pda,
+    setup::{
+        setup, setup_governance, setup_multiple_choice_proposal, setup_proposal_option_vote,
+        setup_stake, setup_stake_config,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+};
+
+const TOTAL_STAKE: u64 = 100_000_000;
+const VOTING_PERIOD_SECONDS: u64 = 100_000_000;
+
+#[tokio::test]
+async fn fail_invalid_option_index() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+
+    let governance_config = GovernanceConfig {
+        stake_config_address: stake_config,
+        voting_period_seconds: VOTING_PERIOD_SECONDS,
+        ..Default::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_proposal_option_vote(
+        &mut context,
+        &proposal_vote,
+        proposal,
+        vote_stake,
+        stake_authority.pubkey(),
+        0,
+    )
+    .await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        governance_config,
+        &[vote_stake, 0],
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        None,
+    )
+    .await;
+
+    let instruction = switch_vote_option(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        2, // Only two options.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalOption as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let previous_vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+    let new_vote_stake = TOTAL_STAKE / 20; // 5% of total stake.
+
+    let governance_config = GovernanceConfig {
+        stake_config_address: stake_config,
+        governance_config: governance,
+        voting_period_seconds: VOTING_PERIOD_SECONDS,
+        ..Default::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        new_vote_stake,
+    )
+    .await;
+
+    // Set up the proposal vote account with a vote for the first option.
+    setup_proposal_option_vote(
+        &mut context,
+        &proposal_vote,
+        proposal,
+        previous_vote_stake,
+        stake_authority.pubkey(),
+        0,
+    )
+    .await;
+
+    // Set up the proposal with the previous vote counted.
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        governance_config,
+        &[previous_vote_stake, 0],
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        None,
+    )
+    .await;
+
+    let instruction = switch_vote_option(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        1,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vote was updated.
+    let vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<ProposalVote>(&vote_account.data),
+        &ProposalVote {
+            proposal,
+            stake: new_vote_stake,
+            authority: stake_authority.pubkey(),
+            election: ProposalVoteElection::For,
+            option_index: 1,
+            _padding: Default::default(),
+        },
+    );
+
+    // Assert the stake moved from the first option to the second.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.option_stake[..2], [0, new_vote_stake]);
+    assert_eq!(proposal_state.stake_for, new_vote_stake);
+}
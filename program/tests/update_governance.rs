@@ -6,12 +6,16 @@ use {
     paladin_governance_program::{
         error::PaladinGovernanceError,
         instruction::{process_instruction, update_governance},
+        processor::THRESHOLD_SCALING_FACTOR,
         state::{
             get_governance_address, get_proposal_transaction_address, get_treasury_address,
             GovernanceConfig, ProposalStatus, ProposalTransaction,
         },
     },
-    setup::{setup, setup_governance, setup_proposal, setup_proposal_transaction},
+    setup::{
+        setup, setup_governance, setup_legacy_governance, setup_proposal,
+        setup_proposal_transaction,
+    },
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
@@ -20,6 +24,7 @@ use {
         signer::Signer,
         transaction::{Transaction, TransactionError},
     },
+    test_case::test_case,
 };
 
 fn proposal_transaction_with_update_governance_instruction(
@@ -44,6 +49,8 @@ fn proposal_transaction_with_update_governance_instruction(
             stake_per_proposal,
         ))
             .into()],
+        prerequisites: vec![],
+        options: vec![],
     }
 }
 
@@ -111,11 +118,12 @@ async fn fail_governance_incorrect_owner() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let new_cooldown_period_seconds = 1;
-    let new_proposal_minimum_quoroum = 2;
-    let new_proposal_pass_threshold = 3;
+    let new_proposal_minimum_quoroum = THRESHOLD_SCALING_FACTOR / 100;
+    let new_proposal_pass_threshold = THRESHOLD_SCALING_FACTOR / 2;
     let new_voting_period_seconds = 4;
     let new_stake_per_proposal = 5;
 
@@ -159,6 +167,8 @@ async fn fail_governance_incorrect_owner() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance,
+        &[],
         &[
             AccountMeta::new(treasury, false),
             AccountMeta::new(governance, false),
@@ -208,11 +218,12 @@ async fn fail_governance_not_initialized() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let new_cooldown_period_seconds = 1;
-    let new_proposal_minimum_quoroum = 2;
-    let new_proposal_pass_threshold = 3;
+    let new_proposal_minimum_quoroum = THRESHOLD_SCALING_FACTOR / 100;
+    let new_proposal_pass_threshold = THRESHOLD_SCALING_FACTOR / 2;
     let new_voting_period_seconds = 4;
     let new_stake_per_proposal = 5;
 
@@ -255,6 +266,8 @@ async fn fail_governance_not_initialized() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance,
+        &[],
         &[
             AccountMeta::new(treasury, false),
             AccountMeta::new(governance, false),
@@ -283,6 +296,100 @@ async fn fail_governance_not_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_governance_not_migrated() {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = GovernanceConfig {
+        cooldown_period_seconds: 0,
+        proposal_minimum_quorum: 0,
+        proposal_pass_threshold: 0,
+        stake_config_address,
+        voting_period_seconds: 0,
+        stake_per_proposal: 0,
+        governance_config: governance,
+        cooldown_expires: 0,
+        ..GovernanceConfig::default()
+    };
+
+    let new_cooldown_period_seconds = 1;
+    let new_proposal_minimum_quoroum = THRESHOLD_SCALING_FACTOR / 100;
+    let new_proposal_pass_threshold = THRESHOLD_SCALING_FACTOR / 2;
+    let new_voting_period_seconds = 4;
+    let new_stake_per_proposal = 5;
+
+    let mut context = setup().start_with_context().await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_update_governance_instruction(
+            &treasury,
+            &governance,
+            0,
+            new_cooldown_period_seconds,
+            new_proposal_minimum_quoroum,
+            new_proposal_pass_threshold,
+            new_voting_period_seconds,
+            new_stake_per_proposal,
+        ),
+    )
+    .await;
+
+    setup_legacy_governance(&mut context, &governance, &original_governance_config).await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::AccountNotMigrated as u32)
+        )
+    );
+}
+
 #[tokio::test]
 async fn fail_treasury_incorrect_address() {
     let stake_config_address = Pubkey::new_unique();
@@ -304,11 +411,12 @@ async fn fail_treasury_incorrect_address() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let new_cooldown_period_seconds = 1;
-    let new_proposal_minimum_quoroum = 2;
-    let new_proposal_pass_threshold = 3;
+    let new_proposal_minimum_quoroum = THRESHOLD_SCALING_FACTOR / 100;
+    let new_proposal_pass_threshold = THRESHOLD_SCALING_FACTOR / 2;
     let new_voting_period_seconds = 4;
     let new_stake_per_proposal = 5;
 
@@ -342,6 +450,8 @@ async fn fail_treasury_incorrect_address() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance,
+        &[],
         &[
             AccountMeta::new(treasury, false),
             AccountMeta::new(governance, false),
@@ -390,11 +500,12 @@ async fn fail_governance_incorrect_address() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let new_cooldown_period_seconds = 1;
-    let new_proposal_minimum_quoroum = 2;
-    let new_proposal_pass_threshold = 3;
+    let new_proposal_minimum_quoroum = THRESHOLD_SCALING_FACTOR / 100;
+    let new_proposal_pass_threshold = THRESHOLD_SCALING_FACTOR / 2;
     let new_voting_period_seconds = 4;
     let new_stake_per_proposal = 5;
 
@@ -428,6 +539,8 @@ async fn fail_governance_incorrect_address() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance,
+        &[],
         &[
             AccountMeta::new(treasury, false),
             AccountMeta::new(governance, false),
@@ -461,6 +574,131 @@ async fn fail_governance_incorrect_address() {
     );
 }
 
+#[test_case(
+    1, 0, THRESHOLD_SCALING_FACTOR / 2, 4,
+    PaladinGovernanceError::InvalidProposalMinimumQuorum;
+    "quorum_below_minimum"
+)]
+#[test_case(
+    1, THRESHOLD_SCALING_FACTOR, THRESHOLD_SCALING_FACTOR / 2, 4,
+    PaladinGovernanceError::InvalidProposalMinimumQuorum;
+    "quorum_at_maximum"
+)]
+#[test_case(
+    1, THRESHOLD_SCALING_FACTOR / 100, THRESHOLD_SCALING_FACTOR / 10 - 1, 4,
+    PaladinGovernanceError::InvalidProposalPassThreshold;
+    "pass_threshold_below_minimum"
+)]
+#[test_case(
+    1, THRESHOLD_SCALING_FACTOR / 100, THRESHOLD_SCALING_FACTOR, 4,
+    PaladinGovernanceError::InvalidProposalPassThreshold;
+    "pass_threshold_at_maximum"
+)]
+#[test_case(
+    0, THRESHOLD_SCALING_FACTOR / 100, THRESHOLD_SCALING_FACTOR / 2, 0,
+    PaladinGovernanceError::InvalidVotingPeriod;
+    "voting_period_zero"
+)]
+#[test_case(
+    0, THRESHOLD_SCALING_FACTOR / 100, THRESHOLD_SCALING_FACTOR / 2, 4,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_zero"
+)]
+#[test_case(
+    5, THRESHOLD_SCALING_FACTOR / 100, THRESHOLD_SCALING_FACTOR / 2, 4,
+    PaladinGovernanceError::InvalidCooldownPeriod;
+    "cooldown_period_exceeds_voting_period"
+)]
+#[tokio::test]
+async fn fail_invalid_governance_config(
+    new_cooldown_period_seconds: u64,
+    new_proposal_minimum_quoroum: u32,
+    new_proposal_pass_threshold: u32,
+    new_voting_period_seconds: u64,
+    expected_error: PaladinGovernanceError,
+) {
+    let stake_config_address = Pubkey::new_unique();
+
+    let governance =
+        get_governance_address(&stake_config_address, &0, &paladin_governance_program::id());
+    let treasury = get_treasury_address(&governance, &paladin_governance_program::id());
+
+    let proposal_address = Pubkey::new_unique();
+    let proposal_transaction_address =
+        get_proposal_transaction_address(&proposal_address, &paladin_governance_program::id());
+
+    let original_governance_config = GovernanceConfig {
+        cooldown_period_seconds: 0,
+        proposal_minimum_quorum: 0,
+        proposal_pass_threshold: 0,
+        stake_config_address,
+        voting_period_seconds: 0,
+        stake_per_proposal: 0,
+        governance_config: governance,
+        cooldown_expires: 0,
+        ..GovernanceConfig::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_governance(&mut context, &governance, &original_governance_config).await;
+    setup_proposal(
+        &mut context,
+        &proposal_address,
+        &Pubkey::new_unique(),
+        0,
+        original_governance_config,
+        ProposalStatus::Accepted,
+    )
+    .await;
+    setup_proposal_transaction(
+        &mut context,
+        &proposal_transaction_address,
+        proposal_transaction_with_update_governance_instruction(
+            &treasury,
+            &governance,
+            0,
+            new_cooldown_period_seconds,
+            new_proposal_minimum_quoroum,
+            new_proposal_pass_threshold,
+            new_voting_period_seconds,
+            /* stake_per_proposal */ 5,
+        ),
+    )
+    .await;
+
+    let instruction = process_instruction(
+        &proposal_address,
+        &proposal_transaction_address,
+        &governance,
+        &[],
+        &[
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(paladin_governance_program::id(), false),
+        ],
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(expected_error as u32))
+    );
+}
+
 #[tokio::test]
 async fn success() {
     let stake_config_address = Pubkey::new_unique();
@@ -482,11 +720,12 @@ async fn success() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let new_cooldown_period_seconds = 1;
-    let new_proposal_minimum_quoroum = 2;
-    let new_proposal_pass_threshold = 3;
+    let new_proposal_minimum_quoroum = THRESHOLD_SCALING_FACTOR / 100;
+    let new_proposal_pass_threshold = THRESHOLD_SCALING_FACTOR / 2;
     let new_voting_period_seconds = 4;
     let new_stake_per_proposal = 5;
 
@@ -520,6 +759,8 @@ async fn success() {
     let instruction = process_instruction(
         &proposal_address,
         &proposal_transaction_address,
+        &governance,
+        &[],
         &[
             AccountMeta::new(treasury, false),
             AccountMeta::new(governance, false),
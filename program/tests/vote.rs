@@ -14,7 +14,7 @@ use {
         find_validator_stake_pda, Config as StakeConfig, ValidatorStake,
     },
     setup::{
-        setup, setup_proposal, setup_proposal_vote, setup_proposal_with_stake,
+        setup, setup_governance, setup_proposal, setup_proposal_vote, setup_proposal_with_stake,
         setup_proposal_with_stake_and_cooldown, setup_stake, setup_stake_config,
     },
     solana_program_test::*,
@@ -38,6 +38,7 @@ async fn fail_stake_authority_not_signer() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -52,6 +53,7 @@ async fn fail_stake_authority_not_signer() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
     instruction.accounts[0].is_signer = false; // Stake authority not signer.
@@ -82,6 +84,7 @@ async fn fail_stake_incorrect_owner() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -107,6 +110,7 @@ async fn fail_stake_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -136,6 +140,7 @@ async fn fail_stake_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -161,6 +166,7 @@ async fn fail_stake_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -190,6 +196,7 @@ async fn fail_stake_incorrect_stake_authority() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -214,6 +221,7 @@ async fn fail_stake_incorrect_stake_authority() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -243,6 +251,7 @@ async fn fail_stake_config_incorrect_owner() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -285,6 +294,7 @@ async fn fail_stake_config_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -314,6 +324,7 @@ async fn fail_stake_config_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -356,6 +367,7 @@ async fn fail_stake_config_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -385,6 +397,7 @@ async fn fail_proposal_incorrect_owner() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -419,6 +432,7 @@ async fn fail_proposal_incorrect_owner() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -448,6 +462,7 @@ async fn fail_proposal_not_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -482,6 +497,7 @@ async fn fail_proposal_not_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -511,6 +527,7 @@ async fn fail_proposal_not_voting() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -548,6 +565,7 @@ async fn fail_proposal_not_voting() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -580,6 +598,7 @@ async fn fail_proposal_vote_incorrect_address() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -587,11 +606,13 @@ async fn fail_proposal_vote_incorrect_address() {
 
     let governance_config = GovernanceConfig {
         stake_config_address: stake_config,
+        governance_config: governance,
         ..Default::default()
     };
 
     let mut context = setup().start_with_context().await;
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -616,6 +637,7 @@ async fn fail_proposal_vote_incorrect_address() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -648,6 +670,7 @@ async fn fail_proposal_vote_already_initialized() {
     let validator_vote = Pubkey::new_unique();
     let stake_config = Pubkey::new_unique();
     let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
 
     let stake =
         find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
@@ -656,11 +679,13 @@ async fn fail_proposal_vote_already_initialized() {
 
     let governance_config = GovernanceConfig {
         stake_config_address: stake_config,
+        governance_config: governance,
         ..Default::default()
     };
 
     let mut context = setup().start_with_context().await;
     setup_stake_config(&mut context, &stake_config, 0).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -696,6 +721,7 @@ async fn fail_proposal_vote_already_initialized() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         ProposalVoteElection::For,
     );
 
@@ -803,12 +829,14 @@ async fn success(vote: Vote, expect: Expect) {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -846,6 +874,7 @@ async fn success(vote: Vote, expect: Expect) {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         election,
     );
 
@@ -876,6 +905,7 @@ async fn success(vote: Vote, expect: Expect) {
             stake: vote_stake,
             authority: stake_authority.pubkey(),
             election,
+            option_index: 0,
             _padding: Default::default()
         }
     );
@@ -934,11 +964,13 @@ async fn error_voting_closed() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -979,6 +1011,7 @@ async fn error_voting_closed() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         election,
     );
 
@@ -1032,12 +1065,14 @@ async fn success_voting_closed_but_cooldown_active() {
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let mut context = setup().start_with_context().await;
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -1082,6 +1117,7 @@ async fn success_voting_closed_but_cooldown_active() {
         &stake_config,
         &proposal_vote,
         &proposal,
+        &governance,
         election,
     );
 
@@ -1123,6 +1159,7 @@ async fn success_voting_closed_but_cooldown_active() {
             stake: vote_stake,
             authority: stake_authority.pubkey(),
             election,
+            option_index: 0,
             _padding: Default::default()
         }
     );
@@ -1152,6 +1189,7 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
         stake_per_proposal: 0,
         governance_config: governance,
         cooldown_expires: 0,
+        ..GovernanceConfig::default()
     };
 
     let vote_stake = TOTAL_STAKE / 10;
@@ -1174,6 +1212,7 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
     let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
 
     setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
     setup_stake(
         &mut context,
         &stake,
@@ -1211,8 +1250,11 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
         );
     }
 
-    let instruction =
-        paladin_governance_program::instruction::finish_voting(stake_config, &proposal);
+    let instruction = paladin_governance_program::instruction::finish_voting(
+        stake_config,
+        &proposal,
+        &governance,
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -1249,3 +1291,301 @@ async fn success_cooldown_has_ended(threshold_met: bool, expected_status: Propos
     assert_eq!(proposal_vote_account.data.len(), 0);
     assert_eq!(proposal_vote_account.owner, system_program::id());
 }
+
+#[tokio::test]
+async fn fail_governance_incorrect_address() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &0, &paladin_stake_program::ID);
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10;
+    let election = ProposalVoteElection::Against;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: MINIMUM_QUORUM,
+        proposal_pass_threshold: PASS_THRESHOLD,
+        stake_config_address: stake_config,
+        voting_period_seconds: 10,
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &Pubkey::new_unique(), // Incorrect governance address.
+        election,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinGovernanceError::IncorrectGovernanceConfigAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_governance_paused() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &0, &paladin_stake_program::ID);
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10;
+    let election = ProposalVoteElection::Against;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: MINIMUM_QUORUM,
+        proposal_pass_threshold: PASS_THRESHOLD,
+        stake_config_address: stake_config,
+        voting_period_seconds: 10,
+        governance_config: governance,
+        paused_timestamp: NonZeroU64::new(clock.unix_timestamp as u64), // Paused.
+        ..GovernanceConfig::default()
+    };
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */ NonZeroU64::new(clock.unix_timestamp as u64),
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        election,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::GovernancePaused as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_voting_period_extended_by_pause() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+
+    let governance = get_governance_address(&stake_config, &0, &paladin_stake_program::ID);
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10;
+    let election = ProposalVoteElection::Against;
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let governance_config = GovernanceConfig {
+        cooldown_period_seconds: 10,
+        proposal_minimum_quorum: MINIMUM_QUORUM,
+        proposal_pass_threshold: PASS_THRESHOLD,
+        stake_config_address: stake_config,
+        voting_period_seconds: 10,
+        governance_config: governance,
+        paused_seconds: 15,
+        ..GovernanceConfig::default()
+    };
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        vote_stake,
+    )
+    .await;
+
+    // Set up a proposal whose voting period would have ended 10 seconds ago,
+    // had governance not been paused for 15 seconds since voting began.
+    setup_proposal_with_stake(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        /* creation_timestamp */ 0,
+        governance_config,
+        /* stake_for */ 0,
+        /* stake_against */ 0,
+        ProposalStatus::Voting,
+        /* voting_start_timestamp */
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(20) as u64),
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = paladin_governance_program::instruction::vote(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        election,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+
+    // Assert the vote was cast and the voting period extended.
+    assert_eq!(proposal_state.stake_against, vote_stake);
+    assert_eq!(
+        proposal_state.voting_start_timestamp,
+        NonZeroU64::new(clock.unix_timestamp.saturating_sub(5) as u64)
+    );
+    assert_eq!(proposal_state.paused_seconds_checkpoint, 15);
+}
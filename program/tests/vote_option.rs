@@ -0,0 +1,254 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        instruction::{vote, vote_option},
+        state::{
+            get_proposal_vote_address, GovernanceConfig, Proposal, ProposalStatus, ProposalVote,
+            ProposalVoteElection,
+        },
+    },
+    paladin_stake_program::state::find_validator_stake_pda,
+    setup::{
+        setup, setup_governance, setup_multiple_choice_proposal, setup_proposal_with_stake,
+        setup_stake, setup_stake_config,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    std::num::NonZeroU64,
+    test_case::test_case,
+};
+
+const TOTAL_STAKE: u64 = 100_000_000;
+const VOTING_PERIOD_SECONDS: u64 = 100_000_000;
+
+#[test_case(true, None; "yes_no_vote_on_multiple_choice_proposal")]
+#[test_case(false, Some(0); "option_vote_on_yes_no_proposal")]
+#[test_case(true, Some(3); "option_index_out_of_range")]
+#[tokio::test]
+async fn fail_invalid_option(multiple_choice: bool, option_index: Option<u8>) {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let governance_config = GovernanceConfig {
+        stake_config_address: stake_config,
+        voting_period_seconds: VOTING_PERIOD_SECONDS,
+        ..Default::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        TOTAL_STAKE / 10,
+    )
+    .await;
+    if multiple_choice {
+        setup_multiple_choice_proposal(
+            &mut context,
+            &proposal,
+            &stake_authority.pubkey(),
+            governance_config,
+            &[0, 0, 0],
+            0,
+            ProposalStatus::Voting,
+            NonZeroU64::new(clock.unix_timestamp as u64),
+            None,
+        )
+        .await;
+    } else {
+        setup_proposal_with_stake(
+            &mut context,
+            &proposal,
+            &stake_authority.pubkey(),
+            0,
+            governance_config,
+            0,
+            0,
+            ProposalStatus::Voting,
+            NonZeroU64::new(clock.unix_timestamp as u64),
+        )
+        .await;
+    }
+
+    let instruction = match option_index {
+        Some(option_index) => vote_option(
+            &stake_authority.pubkey(),
+            &stake,
+            &stake_config,
+            &proposal_vote,
+            &proposal,
+            &governance,
+            option_index,
+        ),
+        None => vote(
+            &stake_authority.pubkey(),
+            &stake,
+            &stake_config,
+            &proposal_vote,
+            &proposal,
+            &governance,
+            ProposalVoteElection::For,
+        ),
+    };
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinGovernanceError::InvalidProposalOption as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let stake_authority = Keypair::new();
+    let validator_vote = Pubkey::new_unique();
+    let stake_config = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let governance = Pubkey::new_unique();
+
+    let stake =
+        find_validator_stake_pda(&validator_vote, &stake_config, &paladin_stake_program::id()).0;
+    let proposal_vote =
+        get_proposal_vote_address(&stake, &proposal, &paladin_governance_program::id());
+
+    let vote_stake = TOTAL_STAKE / 10; // 10% of total stake.
+
+    let governance_config = GovernanceConfig {
+        stake_config_address: stake_config,
+        governance_config: governance,
+        voting_period_seconds: VOTING_PERIOD_SECONDS,
+        ..Default::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    setup_stake_config(&mut context, &stake_config, TOTAL_STAKE).await;
+    setup_governance(&mut context, &governance, &governance_config).await;
+    setup_stake(
+        &mut context,
+        &stake,
+        stake_authority.pubkey(),
+        validator_vote,
+        vote_stake,
+    )
+    .await;
+    setup_multiple_choice_proposal(
+        &mut context,
+        &proposal,
+        &stake_authority.pubkey(),
+        governance_config,
+        &[0, 5, 0],
+        0,
+        ProposalStatus::Voting,
+        NonZeroU64::new(clock.unix_timestamp as u64),
+        None,
+    )
+    .await;
+
+    // Fund the proposal vote account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<ProposalVote>());
+        context.set_account(
+            &proposal_vote,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = vote_option(
+        &stake_authority.pubkey(),
+        &stake,
+        &stake_config,
+        &proposal_vote,
+        &proposal,
+        &governance,
+        1,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the proposal vote was created.
+    let proposal_vote_account = context
+        .banks_client
+        .get_account(proposal_vote)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<ProposalVote>(&proposal_vote_account.data),
+        &ProposalVote {
+            proposal,
+            stake: vote_stake,
+            authority: stake_authority.pubkey(),
+            election: ProposalVoteElection::For,
+            option_index: 1,
+            _padding: Default::default()
+        }
+    );
+
+    // Assert the stake was tallied against the chosen option.
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .unwrap()
+        .unwrap();
+    let proposal_state = bytemuck::from_bytes::<Proposal>(&proposal_account.data);
+    assert_eq!(proposal_state.option_stake[..3], [0, vote_stake + 5, 0]);
+    assert_eq!(proposal_state.stake_for, vote_stake + 5);
+    assert_eq!(proposal_state.stake_against, 0);
+}
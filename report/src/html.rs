@@ -0,0 +1,234 @@
+//! Static HTML rendering.
+
+use {
+    crate::{
+        outcome_summary, percent, stage_summary, timestamp, vote_label, Digest, InstructionReport,
+        ProposalReport,
+    },
+    std::fmt::Write,
+};
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60rem; margin: 2rem auto; \
+                     padding: 0 1rem; } table { border-collapse: collapse; margin: 1rem 0; } th, \
+                     td { border: 1px solid #ccc; padding: 0.25rem 0.5rem; text-align: left; } \
+                     pre { background: #f4f4f4; padding: 0.5rem; overflow-x: auto; } .paused { \
+                     border-left: 4px solid #c90; padding-left: 0.5rem; }";
+
+pub(crate) fn render(digest: &Digest) -> String {
+    let mut out = String::new();
+    let _ = write_digest(&mut out, digest);
+    out
+}
+
+fn write_digest(out: &mut String, digest: &Digest) -> std::fmt::Result {
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html lang=\"en\">")?;
+    writeln!(out, "<head>")?;
+    writeln!(out, "<meta charset=\"utf-8\">")?;
+    writeln!(out, "<title>Governance report</title>")?;
+    writeln!(out, "<style>{STYLE}</style>")?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+    writeln!(out, "<h1>Governance report</h1>")?;
+    writeln!(
+        out,
+        "<p>Generated {}, covering {} proposal{}.</p>",
+        timestamp(digest.generated_at as i64),
+        digest.proposals.len(),
+        if digest.proposals.len() == 1 { "" } else { "s" }
+    )?;
+    for report in &digest.proposals {
+        write_proposal(out, report)?;
+    }
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")
+}
+
+fn write_proposal(out: &mut String, report: &ProposalReport) -> std::fmt::Result {
+    let votes = &report.votes;
+
+    writeln!(out, "<section id=\"{}\">", report.address)?;
+    writeln!(out, "<h2>Proposal <code>{}</code></h2>", report.address)?;
+    writeln!(out, "<table>")?;
+    row(out, "Status", &escape(&stage_summary(report)))?;
+    row(out, "Kind", &escape(&report.kind))?;
+    row(out, "Author", &format!("<code>{}</code>", report.author))?;
+    row(
+        out,
+        "Governance config",
+        &format!("<code>{}</code>", report.governance_config),
+    )?;
+    row(out, "Created", &timestamp(report.creation_timestamp))?;
+    if let Some(voting_start_timestamp) = report.voting_start_timestamp {
+        row(
+            out,
+            "Voting began",
+            &timestamp(voting_start_timestamp as i64),
+        )?;
+    }
+    writeln!(out, "</table>")?;
+    if report.paused {
+        writeln!(
+            out,
+            "<p class=\"paused\">Governance is paused. Voting and cooldown periods are extended \
+             for as long as the pause lasts.</p>"
+        )?;
+    }
+
+    writeln!(out, "<h3>Votes</h3>")?;
+    writeln!(out, "<table>")?;
+    writeln!(
+        out,
+        "<tr><th></th><th>Current</th><th>Required</th><th>Met</th></tr>"
+    )?;
+    threshold_row(
+        out,
+        "Turnout (<code>For</code> of total stake)",
+        votes.turnout,
+        votes.proposal_minimum_quorum,
+        votes.reached_quorum,
+    )?;
+    match (votes.leading_option, votes.margin) {
+        (Some(leading_option), Some(margin)) => threshold_row(
+            out,
+            &format!("Lead of option {leading_option}"),
+            margin,
+            votes.proposal_winning_margin,
+            margin > 0 && margin >= votes.proposal_winning_margin,
+        )?,
+        _ => threshold_row(
+            out,
+            "<code>For</code> of cast stake",
+            votes.for_percentage,
+            votes.proposal_pass_threshold,
+            votes.for_percentage >= votes.proposal_pass_threshold,
+        )?,
+    }
+    writeln!(out, "</table>")?;
+    writeln!(
+        out,
+        "<p>{} <code>For</code> and {} <code>Against</code> stake, of {} total stake, from {} \
+         voter{}.</p>",
+        votes.stake_for,
+        votes.stake_against,
+        votes.total_stake,
+        votes.voter_count,
+        if votes.voter_count == 1 { "" } else { "s" }
+    )?;
+    if let Some(summary) = outcome_summary(report) {
+        writeln!(out, "<p>{}</p>", escape(&summary))?;
+    }
+
+    if report.is_multiple_choice() {
+        writeln!(out, "<table>")?;
+        writeln!(out, "<tr><th>Option</th><th>Stake</th><th>Share</th></tr>")?;
+        for option in &report.options {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                option.index,
+                option.stake,
+                percent(option.share)
+            )?;
+        }
+        writeln!(out, "</table>")?;
+    }
+
+    if !report.top_voters.is_empty() {
+        writeln!(out, "<h3>Top voters</h3>")?;
+        writeln!(out, "<table>")?;
+        writeln!(
+            out,
+            "<tr><th>Voter</th><th>Vote</th><th>Stake</th><th>Share</th></tr>"
+        )?;
+        for voter in &report.top_voters {
+            writeln!(
+                out,
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                voter.authority,
+                escape(&vote_label(voter)),
+                voter.stake,
+                percent(voter.share)
+            )?;
+        }
+        writeln!(out, "</table>")?;
+    }
+
+    if !report.prerequisites.is_empty() {
+        writeln!(out, "<h3>Prerequisites</h3>")?;
+        writeln!(out, "<ul>")?;
+        for prerequisite in &report.prerequisites {
+            writeln!(out, "<li><code>{prerequisite}</code></li>")?;
+        }
+        writeln!(out, "</ul>")?;
+    }
+
+    if report.is_multiple_choice() {
+        for option in &report.options {
+            writeln!(out, "<h3>Option {} instructions</h3>", option.index)?;
+            write_instructions(out, &option.instructions)?;
+        }
+    } else {
+        writeln!(out, "<h3>Instructions</h3>")?;
+        write_instructions(out, &report.instructions)?;
+    }
+    writeln!(out, "</section>")
+}
+
+fn write_instructions(out: &mut String, instructions: &[InstructionReport]) -> std::fmt::Result {
+    if instructions.is_empty() {
+        return writeln!(out, "<p>None.</p>");
+    }
+    writeln!(out, "<ul>")?;
+    for instruction in instructions {
+        writeln!(
+            out,
+            "<li>Instruction {}, {}:<pre>{}</pre></li>",
+            instruction.index,
+            if instruction.executed {
+                "executed"
+            } else {
+                "not executed"
+            },
+            escape(instruction.decoded.to_string().trim_end())
+        )?;
+    }
+    writeln!(out, "</ul>")
+}
+
+/// Writes a table row of a header and HTML content.
+fn row(out: &mut String, header: &str, content: &str) -> std::fmt::Result {
+    writeln!(out, "<tr><th>{header}</th><td>{content}</td></tr>")
+}
+
+fn threshold_row(
+    out: &mut String,
+    header: &str,
+    current: u32,
+    required: u32,
+    met: bool,
+) -> std::fmt::Result {
+    writeln!(
+        out,
+        "<tr><th>{header}</th><td>{}</td><td>{}</td><td>{}</td></tr>",
+        percent(current),
+        percent(required),
+        if met { "Yes" } else { "No" }
+    )
+}
+
+/// Escapes text for HTML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,471 @@
+//! Status reports of Paladin Governance proposals, rendered as Markdown or
+//! static HTML.
+//!
+//! A [`ProposalReport`] is built from a proposal's decoded accounts: where the
+//! proposal stands in its voting and cooldown periods, its turnout against the
+//! minimum quorum, its share of `For` stake against the pass threshold, its
+//! largest voters, and its instructions, decoded with
+//! `paladin-governance-decoder`. Votes are counted with the program's
+//! `outcome` rules, so a report predicts exactly what `FinishVoting` will do.
+//!
+//! A [`Digest`] collects the reports of several proposals into one document.
+
+mod html;
+mod markdown;
+
+use {
+    paladin_governance_decoder::{decode, DecodedInstruction},
+    paladin_governance_program::{
+        outcome::{self, Resolution},
+        state::{
+            get_treasury_address, GovernanceConfig, Proposal, ProposalInstruction, ProposalStatus,
+            ProposalTransaction, ProposalVote,
+        },
+    },
+    serde::Serialize,
+    serde_with::{serde_as, DisplayFromStr},
+    solana_program::pubkey::Pubkey,
+};
+
+/// The format a report is rendered in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Markdown,
+    /// A standalone HTML page, without scripts or external resources.
+    Html,
+}
+
+/// The decoded accounts of a proposal.
+#[derive(Clone, Copy, Debug)]
+pub struct ProposalAccounts<'a> {
+    pub address: Pubkey,
+    pub proposal: &'a Proposal,
+    /// The proposal's governance config, as currently stored.
+    ///
+    /// Only its pause state is used, to extend the proposal's periods. The
+    /// proposal is held to the parameters it was created with.
+    pub governance_config: &'a GovernanceConfig,
+    /// The proposal transaction, if the proposal has one. Signal proposals
+    /// don't.
+    pub transaction: Option<&'a ProposalTransaction>,
+    /// The votes on the proposal. Votes on other proposals are ignored.
+    pub votes: &'a [ProposalVote],
+    /// The total effective stake of the stake config, against which turnout
+    /// is measured.
+    pub total_stake: u64,
+}
+
+/// Where a proposal stands in its lifecycle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "stage", rename_all = "camelCase")]
+pub enum Stage {
+    /// The proposal is in draft, not yet open for voting.
+    Draft,
+    /// Voting is open until `ends_at`.
+    #[serde(rename_all = "camelCase")]
+    Voting { ends_at: u64 },
+    /// The proposal reached quorum, and voting closes when its cooldown ends
+    /// at `ends_at`.
+    #[serde(rename_all = "camelCase")]
+    Cooldown { ends_at: u64 },
+    /// Voting has ended, but has yet to be finished. `FinishVoting` will
+    /// accept the proposal if it `passes`, and reject it otherwise.
+    Finishable { passes: bool },
+    /// Voting is over.
+    Closed,
+}
+
+/// A proposal's votes, counted against its quorum and pass threshold.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VotesReport {
+    pub stake_for: u64,
+    pub stake_against: u64,
+    pub total_stake: u64,
+    /// The share of the total stake voting `For` (in 1e9 scaled format).
+    pub turnout: u32,
+    pub proposal_minimum_quorum: u32,
+    pub reached_quorum: bool,
+    /// The share of the cast stake voting `For` (in 1e9 scaled format).
+    pub for_percentage: u32,
+    pub proposal_pass_threshold: u32,
+    pub proposal_winning_margin: u32,
+    /// The option with the most stake, for a multiple-choice proposal.
+    pub leading_option: Option<u8>,
+    /// The share of the cast stake (in 1e9 scaled format) by which the
+    /// leading option leads the runner-up, for a multiple-choice proposal.
+    pub margin: Option<u32>,
+    /// Whether the proposal would pass if voting closed now.
+    pub passing: bool,
+    /// The least additional `For` stake with which the proposal would pass,
+    /// if the stake that has not voted yet is enough.
+    pub for_stake_needed: Option<u64>,
+    pub voter_count: usize,
+}
+
+/// A vote on a proposal.
+#[serde_as]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoterReport {
+    #[serde_as(as = "DisplayFromStr")]
+    pub authority: Pubkey,
+    pub election: String,
+    /// The option voted for, on a multiple-choice proposal.
+    pub option_index: Option<u8>,
+    pub stake: u64,
+    /// The share of the cast stake (in 1e9 scaled format).
+    pub share: u32,
+}
+
+/// An instruction of a proposal.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionReport {
+    pub index: u32,
+    pub executed: bool,
+    pub decoded: DecodedInstruction,
+}
+
+/// An option of a multiple-choice proposal.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionReport {
+    pub index: u8,
+    pub stake: u64,
+    /// The share of the cast stake (in 1e9 scaled format).
+    pub share: u32,
+    pub instructions: Vec<InstructionReport>,
+}
+
+/// The status of a proposal at a point in time.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalReport {
+    #[serde_as(as = "DisplayFromStr")]
+    pub address: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub author: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub governance_config: Pubkey,
+    pub status: String,
+    pub kind: String,
+    pub creation_timestamp: i64,
+    /// When voting began, extended by any time governance spent paused.
+    pub voting_start_timestamp: Option<u64>,
+    /// The time the report was made at.
+    pub as_of: u64,
+    #[serde(flatten)]
+    pub stage: Stage,
+    /// Seconds until the current voting or cooldown period ends.
+    pub seconds_remaining: Option<u64>,
+    /// Whether governance is paused, which extends the proposal's periods for
+    /// as long as it lasts.
+    pub paused: bool,
+    pub votes: VotesReport,
+    /// The largest votes, by stake.
+    pub top_voters: Vec<VoterReport>,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub prerequisites: Vec<Pubkey>,
+    /// The instructions of a yes/no proposal.
+    pub instructions: Vec<InstructionReport>,
+    /// The options of a multiple-choice proposal.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionReport>,
+}
+
+impl ProposalReport {
+    /// Reports a proposal's status at `now`, listing its `top_voters` largest
+    /// votes.
+    pub fn new(accounts: &ProposalAccounts, now: u64, top_voters: usize) -> Self {
+        let proposal = accounts.proposal;
+        let governance_config = accounts.governance_config;
+
+        // Governance pauses extend the proposal's periods, which the program
+        // only records the next time it touches the proposal.
+        let mut timeline = proposal.timeline();
+        timeline.extend(
+            outcome::total_paused_seconds(
+                governance_config.paused_seconds,
+                governance_config.paused_timestamp,
+                now,
+            )
+            .saturating_sub(proposal.paused_seconds_checkpoint),
+        );
+
+        let tally = proposal.tally(accounts.total_stake);
+        let verdict = tally.verdict().ok();
+        let stage = match proposal.status {
+            ProposalStatus::Draft => Stage::Draft,
+            // The program can't finish voting on a proposal whose tally
+            // overflows, so it stays open.
+            ProposalStatus::Voting => {
+                match outcome::resolve(&tally, &timeline, now).unwrap_or(Resolution::StillVoting) {
+                    Resolution::StillVoting => match timeline.cooldown_ends_at() {
+                        Some(ends_at) => Stage::Cooldown { ends_at },
+                        // Voting never ends without a start timestamp.
+                        None => Stage::Voting {
+                            ends_at: timeline.voting_ends_at().unwrap_or(u64::MAX),
+                        },
+                    },
+                    Resolution::Passed { .. } => Stage::Finishable { passes: true },
+                    Resolution::Failed { .. } => Stage::Finishable { passes: false },
+                }
+            }
+            ProposalStatus::Accepted
+            | ProposalStatus::Rejected
+            | ProposalStatus::Processed
+            | ProposalStatus::Cancelled => Stage::Closed,
+        };
+        let seconds_remaining = match stage {
+            Stage::Voting { ends_at } | Stage::Cooldown { ends_at } => {
+                Some(ends_at.saturating_sub(now))
+            }
+            Stage::Draft | Stage::Finishable { .. } | Stage::Closed => None,
+        };
+
+        let mut votes: Vec<&ProposalVote> = accounts
+            .votes
+            .iter()
+            .filter(|vote| vote.proposal == accounts.address)
+            .collect();
+        votes.sort_by(|a, b| {
+            b.stake
+                .cmp(&a.stake)
+                .then_with(|| a.authority.cmp(&b.authority))
+        });
+        let cast_stake = proposal.stake_for.saturating_add(proposal.stake_against);
+        let winning_option = tally
+            .is_multiple_choice()
+            .then(|| outcome::winning_option(tally.option_stake).ok())
+            .flatten();
+
+        let treasury = get_treasury_address(
+            &proposal.governance_config.governance_config,
+            &paladin_governance_program::id(),
+        );
+        let instructions = |instructions: &[ProposalInstruction]| {
+            instructions
+                .iter()
+                .enumerate()
+                .map(|(index, instruction)| InstructionReport {
+                    index: index as u32,
+                    executed: instruction.executed,
+                    decoded: decode(&instruction.into(), Some(&treasury)),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Self {
+            address: accounts.address,
+            author: proposal.author,
+            governance_config: proposal.governance_config.governance_config,
+            status: format!("{:?}", proposal.status),
+            kind: format!("{:?}", proposal.kind),
+            creation_timestamp: proposal.creation_timestamp,
+            voting_start_timestamp: timeline.voting_start_timestamp.map(u64::from),
+            as_of: now,
+            stage,
+            seconds_remaining,
+            paused: governance_config.is_paused(),
+            votes: VotesReport {
+                stake_for: proposal.stake_for,
+                stake_against: proposal.stake_against,
+                total_stake: accounts.total_stake,
+                turnout: share(proposal.stake_for, accounts.total_stake),
+                proposal_minimum_quorum: tally.proposal_minimum_quorum,
+                reached_quorum: tally.reached_quorum().unwrap_or(false),
+                for_percentage: share(proposal.stake_for, cast_stake),
+                proposal_pass_threshold: tally.proposal_pass_threshold,
+                proposal_winning_margin: tally.proposal_winning_margin,
+                leading_option: winning_option.map(|(option, _)| option),
+                margin: winning_option.map(|(_, margin)| margin),
+                passing: verdict.is_some_and(|verdict| verdict.passed),
+                for_stake_needed: (proposal.status == ProposalStatus::Voting)
+                    .then(|| tally.for_stake_needed())
+                    .flatten(),
+                voter_count: votes.len(),
+            },
+            top_voters: votes
+                .iter()
+                .take(top_voters)
+                .map(|vote| VoterReport {
+                    authority: vote.authority,
+                    election: format!("{:?}", vote.election),
+                    option_index: proposal.is_multiple_choice().then_some(vote.option_index),
+                    stake: vote.stake,
+                    share: share(vote.stake, cast_stake),
+                })
+                .collect(),
+            prerequisites: accounts
+                .transaction
+                .map(|transaction| transaction.prerequisites.clone())
+                .unwrap_or_default(),
+            instructions: accounts
+                .transaction
+                .map(|transaction| instructions(&transaction.instructions))
+                .unwrap_or_default(),
+            options: tally
+                .option_stake
+                .iter()
+                .enumerate()
+                .map(|(index, &stake)| OptionReport {
+                    index: index as u8,
+                    stake,
+                    share: share(stake, proposal.stake_for),
+                    instructions: accounts
+                        .transaction
+                        .and_then(|transaction| transaction.options.get(index))
+                        .map(|option| instructions(&option.instructions))
+                        .unwrap_or_default(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_multiple_choice(&self) -> bool {
+        !self.options.is_empty()
+    }
+
+    /// Renders the report as a standalone document.
+    pub fn render(&self, format: Format) -> String {
+        Digest {
+            generated_at: self.as_of,
+            proposals: vec![self.clone()],
+        }
+        .render(format)
+    }
+}
+
+/// The reports of several proposals, rendered as one document.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Digest {
+    pub generated_at: u64,
+    pub proposals: Vec<ProposalReport>,
+}
+
+impl Digest {
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Markdown => markdown::render(self),
+            Format::Html => html::render(self),
+        }
+    }
+}
+
+/// Where the proposal stands, e.g. `Voting, ends in 2d 4h (2024-06-01 12:00
+/// UTC)`.
+fn stage_summary(report: &ProposalReport) -> String {
+    match report.stage {
+        Stage::Draft => "Draft".to_string(),
+        Stage::Voting { ends_at } => format!("Voting, ends {}", deadline(ends_at, report.as_of)),
+        Stage::Cooldown { ends_at } => format!(
+            "Cooldown, voting closes {}",
+            deadline(ends_at, report.as_of)
+        ),
+        Stage::Finishable { passes: true } => "Voting ended, accepted once finished".to_string(),
+        Stage::Finishable { passes: false } => "Voting ended, rejected once finished".to_string(),
+        Stage::Closed => report.status.clone(),
+    }
+}
+
+/// What closing voting would do with the current votes, and the `For` stake
+/// it would take to change that, while voting is open.
+fn outcome_summary(report: &ProposalReport) -> Option<String> {
+    let votes = &report.votes;
+    match report.stage {
+        Stage::Voting { .. } | Stage::Cooldown { .. } => Some(if votes.passing {
+            "Passing with the current votes.".to_string()
+        } else if let Some(needed) = votes.for_stake_needed {
+            format!("Failing with the current votes; passes with {needed} more For stake.")
+        } else {
+            "Failing, and cannot pass with the stake that has not voted.".to_string()
+        }),
+        Stage::Draft | Stage::Finishable { .. } | Stage::Closed => None,
+    }
+}
+
+/// How a voter voted, e.g. `For` or `Option 2`.
+fn vote_label(voter: &VoterReport) -> String {
+    match voter.option_index {
+        Some(option_index) => format!("Option {option_index}"),
+        None => voter.election.clone(),
+    }
+}
+
+/// A deadline relative to `now`, e.g. `in 2d 4h (2024-06-01 12:00 UTC)`.
+fn deadline(ends_at: u64, now: u64) -> String {
+    match i64::try_from(ends_at) {
+        Ok(ends_at_timestamp) => format!(
+            "in {} ({})",
+            duration(ends_at.saturating_sub(now)),
+            timestamp(ends_at_timestamp)
+        ),
+        Err(_) => "never".to_string(),
+    }
+}
+
+/// The share of `total` that `part` is (in 1e9 scaled format), saturating for
+/// shares the program could not represent.
+fn share(part: u64, total: u64) -> u32 {
+    outcome::voter_turnout(part, total).unwrap_or(u32::MAX)
+}
+
+/// A share in 1e9 scaled format, as a percentage with two decimal places.
+fn percent(share: u32) -> String {
+    let hundredths = share / (outcome::THRESHOLD_SCALING_FACTOR / 10_000);
+    format!("{}.{:02}%", hundredths / 100, hundredths % 100)
+}
+
+/// A duration, as its two most significant units, e.g. `2d 4h`.
+fn duration(seconds: u64) -> String {
+    let units = [
+        (seconds / 86_400, "d"),
+        (seconds / 3_600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+    let parts: Vec<String> = units
+        .iter()
+        .skip_while(|(value, _)| *value == 0)
+        .take(2)
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// A Unix timestamp, as a UTC date and time, e.g. `2024-06-01 12:00 UTC`.
+fn timestamp(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86_400);
+    let seconds = timestamp.rem_euclid(86_400);
+
+    // Converts days since the epoch to a proleptic Gregorian date, counting
+    // 400-year eras from 0000-03-01.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        seconds / 3_600,
+        seconds / 60 % 60
+    )
+}
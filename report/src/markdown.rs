@@ -0,0 +1,201 @@
+//! Markdown rendering.
+
+use {
+    crate::{
+        outcome_summary, percent, stage_summary, timestamp, vote_label, Digest, InstructionReport,
+        ProposalReport,
+    },
+    std::fmt::Write,
+};
+
+pub(crate) fn render(digest: &Digest) -> String {
+    let mut out = String::new();
+    let _ = write_digest(&mut out, digest);
+    out
+}
+
+fn write_digest(out: &mut String, digest: &Digest) -> std::fmt::Result {
+    writeln!(out, "# Governance report")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Generated {}, covering {} proposal{}.",
+        timestamp(digest.generated_at as i64),
+        digest.proposals.len(),
+        if digest.proposals.len() == 1 { "" } else { "s" }
+    )?;
+    for report in &digest.proposals {
+        writeln!(out)?;
+        write_proposal(out, report)?;
+    }
+    Ok(())
+}
+
+fn write_proposal(out: &mut String, report: &ProposalReport) -> std::fmt::Result {
+    let votes = &report.votes;
+
+    writeln!(out, "## Proposal `{}`", report.address)?;
+    writeln!(out)?;
+    writeln!(out, "| | |")?;
+    writeln!(out, "| --- | --- |")?;
+    writeln!(out, "| Status | {} |", stage_summary(report))?;
+    writeln!(out, "| Kind | {} |", report.kind)?;
+    writeln!(out, "| Author | `{}` |", report.author)?;
+    writeln!(
+        out,
+        "| Governance config | `{}` |",
+        report.governance_config
+    )?;
+    writeln!(
+        out,
+        "| Created | {} |",
+        timestamp(report.creation_timestamp)
+    )?;
+    if let Some(voting_start_timestamp) = report.voting_start_timestamp {
+        writeln!(
+            out,
+            "| Voting began | {} |",
+            timestamp(voting_start_timestamp as i64)
+        )?;
+    }
+    if report.paused {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "> Governance is paused. Voting and cooldown periods are extended for as long as the \
+             pause lasts."
+        )?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "### Votes")?;
+    writeln!(out)?;
+    writeln!(out, "| | Current | Required | Met |")?;
+    writeln!(out, "| --- | --- | --- | --- |")?;
+    writeln!(
+        out,
+        "| Turnout (`For` of total stake) | {} | {} | {} |",
+        percent(votes.turnout),
+        percent(votes.proposal_minimum_quorum),
+        yes_no(votes.reached_quorum)
+    )?;
+    match (votes.leading_option, votes.margin) {
+        (Some(leading_option), Some(margin)) => writeln!(
+            out,
+            "| Lead of option {leading_option} | {} | {} | {} |",
+            percent(margin),
+            percent(votes.proposal_winning_margin),
+            yes_no(margin > 0 && margin >= votes.proposal_winning_margin)
+        )?,
+        _ => writeln!(
+            out,
+            "| `For` of cast stake | {} | {} | {} |",
+            percent(votes.for_percentage),
+            percent(votes.proposal_pass_threshold),
+            yes_no(votes.for_percentage >= votes.proposal_pass_threshold)
+        )?,
+    }
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{} `For` and {} `Against` stake, of {} total stake, from {} voter{}.",
+        votes.stake_for,
+        votes.stake_against,
+        votes.total_stake,
+        votes.voter_count,
+        if votes.voter_count == 1 { "" } else { "s" }
+    )?;
+    if let Some(summary) = outcome_summary(report) {
+        writeln!(out, "{summary}")?;
+    }
+
+    if report.is_multiple_choice() {
+        writeln!(out)?;
+        writeln!(out, "| Option | Stake | Share |")?;
+        writeln!(out, "| --- | --- | --- |")?;
+        for option in &report.options {
+            writeln!(
+                out,
+                "| {} | {} | {} |",
+                option.index,
+                option.stake,
+                percent(option.share)
+            )?;
+        }
+    }
+
+    if !report.top_voters.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "### Top voters")?;
+        writeln!(out)?;
+        writeln!(out, "| Voter | Vote | Stake | Share |")?;
+        writeln!(out, "| --- | --- | --- | --- |")?;
+        for voter in &report.top_voters {
+            writeln!(
+                out,
+                "| `{}` | {} | {} | {} |",
+                voter.authority,
+                vote_label(voter),
+                voter.stake,
+                percent(voter.share)
+            )?;
+        }
+    }
+
+    if !report.prerequisites.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "### Prerequisites")?;
+        writeln!(out)?;
+        for prerequisite in &report.prerequisites {
+            writeln!(out, "- `{prerequisite}`")?;
+        }
+    }
+
+    if report.is_multiple_choice() {
+        for option in &report.options {
+            writeln!(out)?;
+            writeln!(out, "### Option {} instructions", option.index)?;
+            write_instructions(out, &option.instructions)?;
+        }
+    } else {
+        writeln!(out)?;
+        writeln!(out, "### Instructions")?;
+        write_instructions(out, &report.instructions)?;
+    }
+    Ok(())
+}
+
+fn write_instructions(out: &mut String, instructions: &[InstructionReport]) -> std::fmt::Result {
+    if instructions.is_empty() {
+        writeln!(out)?;
+        return writeln!(out, "None.");
+    }
+    for instruction in instructions {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "- Instruction {}, {}:",
+            instruction.index,
+            if instruction.executed {
+                "executed"
+            } else {
+                "not executed"
+            }
+        )?;
+        writeln!(out)?;
+        writeln!(out, "  ```text")?;
+        for line in instruction.decoded.to_string().lines() {
+            writeln!(out, "  {line}")?;
+        }
+        writeln!(out, "  ```")?;
+    }
+    Ok(())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "Yes"
+    } else {
+        "No"
+    }
+}
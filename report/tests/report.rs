@@ -0,0 +1,331 @@
+use {
+    paladin_governance_program::state::{
+        get_treasury_address, GovernanceConfig, Proposal, ProposalInstruction, ProposalOption,
+        ProposalStatus, ProposalTransaction, ProposalVote, ProposalVoteElection,
+    },
+    paladin_governance_report::{
+        Digest, Format, ProposalAccounts, ProposalReport, Stage, VoterReport,
+    },
+    solana_program::{pubkey::Pubkey, system_instruction},
+    std::num::NonZeroU64,
+};
+
+/// 2024-06-01 12:00 UTC.
+const NOW: u64 = 1_717_243_200;
+
+fn governance_config() -> GovernanceConfig {
+    GovernanceConfig {
+        cooldown_period_seconds: 3_600,
+        proposal_minimum_quorum: 100_000_000, // 10%
+        proposal_pass_threshold: 500_000_000, // 50%
+        proposal_winning_margin: 500_000_000, // 50%
+        voting_period_seconds: 86_400,
+        governance_config: Pubkey::new_unique(),
+        ..GovernanceConfig::default()
+    }
+}
+
+fn voting_proposal(governance_config: &GovernanceConfig, voting_started: u64) -> Proposal {
+    let mut proposal = Proposal::new(
+        &Pubkey::new_unique(),
+        voting_started as i64 - 60,
+        *governance_config,
+    );
+    proposal.status = ProposalStatus::Voting;
+    proposal.voting_start_timestamp = NonZeroU64::new(voting_started);
+    proposal
+}
+
+fn vote(
+    proposal: &Pubkey,
+    stake: u64,
+    election: ProposalVoteElection,
+    option_index: u8,
+) -> ProposalVote {
+    ProposalVote {
+        proposal: *proposal,
+        stake,
+        authority: Pubkey::new_unique(),
+        election,
+        option_index,
+        _padding: [0; 6],
+    }
+}
+
+#[test]
+fn report_voting_proposal() {
+    let address = Pubkey::new_unique();
+    let governance_config = governance_config();
+    let mut proposal = voting_proposal(&governance_config, NOW - 3_600);
+    proposal.stake_for = 50;
+    proposal.stake_against = 150;
+
+    let votes = [
+        vote(&address, 50, ProposalVoteElection::For, 0),
+        vote(&address, 100, ProposalVoteElection::Against, 0),
+        vote(&address, 30, ProposalVoteElection::Against, 0),
+        vote(&address, 20, ProposalVoteElection::Against, 0),
+        // A vote on another proposal.
+        vote(&Pubkey::new_unique(), 1_000, ProposalVoteElection::For, 0),
+    ];
+    let treasury = get_treasury_address(
+        &governance_config.governance_config,
+        &paladin_governance_program::id(),
+    );
+    let transaction = ProposalTransaction {
+        instructions: vec![(&system_instruction::transfer(
+            &treasury,
+            &Pubkey::new_unique(),
+            1_000,
+        ))
+            .into()],
+        ..ProposalTransaction::default()
+    };
+
+    let report = ProposalReport::new(
+        &ProposalAccounts {
+            address,
+            proposal: &proposal,
+            governance_config: &governance_config,
+            transaction: Some(&transaction),
+            votes: &votes,
+            total_stake: 1_000,
+        },
+        NOW,
+        2,
+    );
+
+    assert_eq!(
+        report.stage,
+        Stage::Voting {
+            ends_at: NOW + 82_800
+        }
+    );
+    assert_eq!(report.seconds_remaining, Some(82_800));
+    assert!(!report.paused);
+
+    assert_eq!(report.votes.turnout, 50_000_000); // 5%
+    assert!(!report.votes.reached_quorum);
+    assert_eq!(report.votes.for_percentage, 250_000_000); // 25%
+    assert!(!report.votes.passing);
+    // 100 more `For` stake reaches a 15% turnout and a 50% share.
+    assert_eq!(report.votes.for_stake_needed, Some(100));
+    assert_eq!(report.votes.voter_count, 4);
+
+    // The largest votes first.
+    assert_eq!(
+        report.top_voters,
+        vec![
+            VoterReport {
+                authority: votes[1].authority,
+                election: "Against".to_string(),
+                option_index: None,
+                stake: 100,
+                share: 500_000_000,
+            },
+            VoterReport {
+                authority: votes[0].authority,
+                election: "For".to_string(),
+                option_index: None,
+                stake: 50,
+                share: 250_000_000,
+            },
+        ]
+    );
+
+    assert_eq!(report.instructions.len(), 1);
+    assert!(!report.instructions[0].executed);
+    assert_eq!(
+        report.instructions[0].decoded.name.as_deref(),
+        Some("Transfer")
+    );
+    assert!(report.instructions[0].decoded.touches_treasury);
+}
+
+#[test]
+fn report_extends_periods_while_paused() {
+    let mut governance_config = governance_config();
+    // Paused for the last ten minutes, after an earlier five minute pause.
+    governance_config.paused_timestamp = NonZeroU64::new(NOW - 600);
+    governance_config.paused_seconds = 300;
+
+    let mut proposal = voting_proposal(&governance_config, NOW - 86_400);
+    // The earlier pause was already applied to the proposal.
+    proposal.paused_seconds_checkpoint = 300;
+
+    let report = ProposalReport::new(
+        &ProposalAccounts {
+            address: Pubkey::new_unique(),
+            proposal: &proposal,
+            governance_config: &governance_config,
+            transaction: None,
+            votes: &[],
+            total_stake: 1_000,
+        },
+        NOW,
+        10,
+    );
+
+    assert!(report.paused);
+    assert_eq!(report.stage, Stage::Voting { ends_at: NOW + 600 });
+    assert_eq!(report.voting_start_timestamp, Some(NOW - 85_800));
+}
+
+#[test]
+fn report_finishable_and_closed_proposals() {
+    let address = Pubkey::new_unique();
+    let governance_config = governance_config();
+    let mut proposal = voting_proposal(&governance_config, NOW - 7_200);
+    proposal.stake_for = 600;
+    proposal.stake_against = 100;
+    proposal.cooldown_timestamp = NonZeroU64::new(NOW - 1_800);
+
+    let accounts = ProposalAccounts {
+        address,
+        proposal: &proposal,
+        governance_config: &governance_config,
+        transaction: None,
+        votes: &[],
+        total_stake: 1_000,
+    };
+
+    // The cooldown is still running.
+    let report = ProposalReport::new(&accounts, NOW, 10);
+    assert_eq!(
+        report.stage,
+        Stage::Cooldown {
+            ends_at: NOW + 1_800
+        }
+    );
+    assert!(report.votes.reached_quorum);
+    assert!(report.votes.passing);
+    assert_eq!(report.votes.for_stake_needed, Some(0));
+
+    // The cooldown has ended, but voting has yet to be finished.
+    let report = ProposalReport::new(&accounts, NOW + 1_800, 10);
+    assert_eq!(report.stage, Stage::Finishable { passes: true });
+    assert_eq!(report.seconds_remaining, None);
+
+    let mut processed = proposal;
+    processed.status = ProposalStatus::Processed;
+    let report = ProposalReport::new(
+        &ProposalAccounts {
+            proposal: &processed,
+            ..accounts
+        },
+        NOW + 1_800,
+        10,
+    );
+    assert_eq!(report.stage, Stage::Closed);
+    assert_eq!(report.status, "Processed");
+    assert_eq!(report.votes.for_stake_needed, None);
+}
+
+#[test]
+fn report_multiple_choice_proposal() {
+    let address = Pubkey::new_unique();
+    let governance_config = governance_config();
+    let mut proposal = voting_proposal(&governance_config, NOW - 3_600);
+    proposal.option_count = 3;
+    proposal.option_stake[..3].copy_from_slice(&[100, 300, 0]);
+    proposal.stake_for = 400;
+
+    let votes = [
+        vote(&address, 100, ProposalVoteElection::For, 0),
+        vote(&address, 300, ProposalVoteElection::For, 1),
+    ];
+    let transaction = ProposalTransaction {
+        options: vec![
+            ProposalOption::default(),
+            ProposalOption {
+                instructions: vec![ProposalInstruction::new(
+                    &Pubkey::new_unique(),
+                    vec![],
+                    vec![1, 2, 3],
+                )],
+            },
+            ProposalOption::default(),
+        ],
+        ..ProposalTransaction::default()
+    };
+
+    let report = ProposalReport::new(
+        &ProposalAccounts {
+            address,
+            proposal: &proposal,
+            governance_config: &governance_config,
+            transaction: Some(&transaction),
+            votes: &votes,
+            total_stake: 1_000,
+        },
+        NOW,
+        10,
+    );
+
+    assert!(report.is_multiple_choice());
+    assert_eq!(report.votes.leading_option, Some(1));
+    assert_eq!(report.votes.margin, Some(500_000_000)); // 50%
+    assert_eq!(report.options[1].stake, 300);
+    assert_eq!(report.options[1].share, 750_000_000);
+    assert_eq!(report.options[1].instructions.len(), 1);
+    assert!(report.options[0].instructions.is_empty());
+    assert_eq!(report.top_voters[0].option_index, Some(1));
+    assert!(report.instructions.is_empty());
+
+    let markdown = report.render(Format::Markdown);
+    assert!(markdown.contains("| Lead of option 1 | 50.00% | 50.00% | Yes |"));
+    assert!(markdown.contains("| 1 | 300 | 75.00% |"));
+    assert!(markdown.contains(&format!(
+        "| `{}` | Option 1 | 300 | 75.00% |",
+        votes[1].authority
+    )));
+    assert!(markdown.contains("### Option 1 instructions"));
+}
+
+#[test]
+fn render_digest() {
+    let address = Pubkey::new_unique();
+    let governance_config = governance_config();
+    let mut proposal = voting_proposal(&governance_config, NOW - 3_600);
+    proposal.stake_for = 50;
+    proposal.stake_against = 150;
+    let votes = [vote(&address, 50, ProposalVoteElection::For, 0)];
+
+    let digest = Digest {
+        generated_at: NOW,
+        proposals: vec![ProposalReport::new(
+            &ProposalAccounts {
+                address,
+                proposal: &proposal,
+                governance_config: &governance_config,
+                transaction: Some(&ProposalTransaction::default()),
+                votes: &votes,
+                total_stake: 1_000,
+            },
+            NOW,
+            10,
+        )],
+    };
+
+    let markdown = digest.render(Format::Markdown);
+    assert!(markdown.starts_with("# Governance report\n"));
+    assert!(markdown.contains("Generated 2024-06-01 12:00 UTC, covering 1 proposal."));
+    assert!(markdown.contains(&format!("## Proposal `{address}`")));
+    assert!(markdown.contains("| Status | Voting, ends in 23h (2024-06-02 11:00 UTC) |"));
+    assert!(markdown.contains("| Turnout (`For` of total stake) | 5.00% | 10.00% | No |"));
+    assert!(markdown.contains("| `For` of cast stake | 25.00% | 50.00% | No |"));
+    assert!(markdown.contains("Failing with the current votes; passes with 100 more For stake."));
+    assert!(markdown.contains(&format!("| `{}` | For | 50 | 25.00% |", votes[0].authority)));
+    assert!(markdown.ends_with("### Instructions\n\nNone.\n"));
+
+    let html = digest.render(Format::Html);
+    assert!(html.starts_with("<!DOCTYPE html>\n"));
+    assert!(html.ends_with("</html>\n"));
+    assert!(html.contains(&format!("<section id=\"{address}\">")));
+    assert!(html
+        .contains("<tr><th>Status</th><td>Voting, ends in 23h (2024-06-02 11:00 UTC)</td></tr>"));
+    assert!(html.contains(
+        "<tr><th><code>For</code> of cast stake</th><td>25.00%</td><td>50.00%</td><td>No</td></tr>"
+    ));
+    assert!(!html.contains("<script"));
+}
@@ -11,6 +11,8 @@ import {
 // ['--arg1', '--arg2', ...cliArguments()]
 const lintArgs = [
   '-Zunstable-options',
+  '--features',
+  'conversions,decoder,outcome,simulator',
   '--',
   '--deny=warnings',
   ...cliArguments()
@@ -4,7 +4,7 @@ import { cliArguments, workingDirectory } from '../utils.mjs';
 
 // Configure additional arguments here, e.g.:
 // ['--arg1', '--arg2', ...cliArguments()]
-const testArgs = cliArguments();
+const testArgs = ['--features', 'conversions,decoder,outcome', ...cliArguments()];
 
 const hasSolfmt = await which('solfmt', { nothrow: true });
 
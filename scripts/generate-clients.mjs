@@ -33,37 +33,72 @@ kinobi.update(
             k.definedTypeNode({
               name: "config",
               type: k.structTypeNode([
+                k.structFieldTypeNode({
+                  name: "discriminator",
+                  type: k.arrayTypeNode(
+                    k.numberTypeNode("u8"),
+                    k.fixedCountNode(8),
+                  ),
+                }),
                 k.structFieldTypeNode({
                   name: "cooldownPeriodSeconds",
                   type: k.numberTypeNode("u64"),
                 }),
                 k.structFieldTypeNode({
-                  name: "proposalAcceptanceThreshold",
+                  name: "proposalMinimumQuorum",
                   type: k.numberTypeNode("u32"),
                 }),
                 k.structFieldTypeNode({
-                  name: "proposalRejectionThreshold",
+                  name: "proposalPassThreshold",
                   type: k.numberTypeNode("u32"),
                 }),
                 k.structFieldTypeNode({
-                  name: "signerBumpSeed",
-                  type: k.numberTypeNode("u8"),
+                  name: "stakeConfigAddress",
+                  type: k.publicKeyTypeNode(),
                 }),
                 k.structFieldTypeNode({
-                  name: "_padding",
-                  type: k.arrayTypeNode(
-                    k.numberTypeNode("u8"),
-                    k.fixedCountNode(7),
-                  ),
+                  name: "votingPeriodSeconds",
+                  type: k.numberTypeNode("u64"),
                 }),
                 k.structFieldTypeNode({
-                  name: "stakeConfigAddress",
+                  name: "stakePerProposal",
+                  type: k.numberTypeNode("u64"),
+                }),
+                k.structFieldTypeNode({
+                  name: "governanceConfig",
                   type: k.publicKeyTypeNode(),
                 }),
                 k.structFieldTypeNode({
-                  name: "votingPeriodSeconds",
+                  name: "cooldownExpires",
+                  type: k.numberTypeNode("u64"),
+                }),
+                k.structFieldTypeNode({
+                  name: "pauseGuardian",
+                  type: k.publicKeyTypeNode(),
+                }),
+                k.structFieldTypeNode({
+                  name: "pausedTimestamp",
+                  type: k.definedTypeLinkNode("nullableU64", "hooked"),
+                }),
+                k.structFieldTypeNode({
+                  name: "pausedSeconds",
                   type: k.numberTypeNode("u64"),
                 }),
+                k.structFieldTypeNode({
+                  name: "successor",
+                  type: k.publicKeyTypeNode(),
+                }),
+                k.structFieldTypeNode({
+                  name: "proposalWinningMargin",
+                  type: k.numberTypeNode("u32"),
+                }),
+                k.structFieldTypeNode({
+                  name: "padding",
+                  type: k.arrayTypeNode(
+                    k.numberTypeNode("u8"),
+                    k.fixedCountNode(4),
+                  ),
+                }),
               ]),
             }),
           ],
@@ -72,7 +107,7 @@ kinobi.update(
     },
     {
       // GovernanceConfig -> Config
-      select: "[structFieldTypeNode]governanceConfig",
+      select: "[accountNode]proposal.[structFieldTypeNode]governanceConfig",
       transform: (node) => {
         k.assertIsNode(node, "structFieldTypeNode");
         return {
@@ -103,6 +138,17 @@ kinobi.update(
         };
       },
     },
+    {
+      // Option<NonZeroU64> -> NullableU64
+      select: "[structFieldTypeNode]pausedTimestamp",
+      transform: (node) => {
+        k.assertIsNode(node, "structFieldTypeNode");
+        return {
+          ...node,
+          type: k.definedTypeLinkNode("nullableU64", "hooked"),
+        };
+      },
+    },
     {
       // UnixTimestamp -> i64
       select: "[structFieldTypeNode]creationTimestamp",
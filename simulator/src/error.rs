@@ -0,0 +1,42 @@
+//! Simulation error types.
+
+use {
+    solana_program_test::BanksClientError,
+    solana_sdk::{program_error::ProgramError, pubkey::Pubkey},
+    thiserror::Error,
+};
+
+/// Errors that prevent a simulation from running.
+///
+/// Failures of the simulated instructions themselves are part of the
+/// `SimulationReport` instead.
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    /// An account dump could not be read or written.
+    #[error("Failed to access account dump: {0}")]
+    Io(#[from] std::io::Error),
+    /// An account dump is not valid JSON.
+    #[error("Invalid account dump: {0}")]
+    Json(#[from] serde_json::Error),
+    /// An account dump's data is not valid base64.
+    #[error("Invalid account data: {0}")]
+    Base64(#[from] base64::DecodeError),
+    /// An account dump's data uses an encoding other than `base64`.
+    #[error("Unsupported account data encoding: {0}")]
+    UnsupportedEncoding(String),
+    /// An account the simulation needs is missing from the snapshot.
+    #[error("Account {0} is missing from the snapshot")]
+    MissingAccount(Pubkey),
+    /// The proposal account is not a valid proposal.
+    #[error("Account {0} is not a proposal")]
+    InvalidProposal(Pubkey),
+    /// The proposal transaction account is not a valid proposal transaction.
+    #[error("Account {0} is not a proposal transaction")]
+    InvalidProposalTransaction(Pubkey),
+    /// The proposal's instructions could not be selected.
+    #[error("Invalid proposal: {0}")]
+    Program(#[from] ProgramError),
+    /// The test bank failed to process a transaction.
+    #[error("Test bank error: {0}")]
+    Banks(#[from] BanksClientError),
+}
@@ -0,0 +1,219 @@
+//! Offline simulation of Paladin Governance proposals.
+//!
+//! Before asking stakers to vote, a proposal can be checked to actually
+//! execute. The accounts it references are loaded from a local snapshot of
+//! JSON account dumps into a `solana-program-test` bank, the proposal is
+//! marked `Accepted`, and each of its instructions is run through
+//! `ProcessInstruction` in order.
+//!
+//! The governance program runs natively unless `BPF_OUT_DIR` points to its
+//! compiled program, in which case the reported compute units match the
+//! deployed program. Other programs the proposal invokes must be part of the
+//! snapshot, along with their program data account if upgradeable; SPL
+//! programs are loaded by `solana-program-test`.
+
+mod error;
+mod snapshot;
+
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        instruction::process_instruction,
+        state::{
+            get_proposal_transaction_address, get_treasury_address, Proposal, ProposalStatus,
+            ProposalTransaction,
+        },
+    },
+    paladin_governance_program_client::sdk,
+    solana_program_test::{processor, BanksClient, ProgramTest},
+    solana_sdk::{
+        account::Account,
+        instruction::AccountMeta,
+        native_loader,
+        pubkey::Pubkey,
+        signature::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+pub use {
+    error::SimulationError,
+    snapshot::{account_to_json, referenced_addresses, Snapshot},
+};
+
+/// The outcome of a simulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationReport {
+    /// The simulated instructions, in order. The simulation stops at the
+    /// first failure.
+    pub instructions: Vec<InstructionReport>,
+}
+
+impl SimulationReport {
+    /// The instruction that failed, if any.
+    pub fn failure(&self) -> Option<&InstructionReport> {
+        self.instructions
+            .iter()
+            .find(|instruction| instruction.error.is_some())
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failure().is_none()
+    }
+}
+
+/// The outcome of one proposal instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstructionReport {
+    /// The index of the instruction in the proposal's outcome.
+    pub index: u32,
+    pub compute_units: u64,
+    pub logs: Vec<String>,
+    /// The accounts the instruction changed.
+    pub account_diffs: Vec<AccountDiff>,
+    pub error: Option<TransactionError>,
+}
+
+/// An account before and after an instruction, `None` if it didn't exist.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountDiff {
+    pub address: Pubkey,
+    pub before: Option<Account>,
+    pub after: Option<Account>,
+}
+
+impl AccountDiff {
+    /// The change in the account's lamports.
+    pub fn lamports_delta(&self) -> i128 {
+        let lamports =
+            |account: &Option<Account>| account.as_ref().map_or(0, |account| account.lamports);
+        i128::from(lamports(&self.after)) - i128::from(lamports(&self.before))
+    }
+}
+
+/// Simulates executing a proposal against a snapshot of its accounts.
+///
+/// The proposal is marked `Accepted` regardless of its stored status. For a
+/// multiple-choice proposal, `winning_option` selects the option to execute,
+/// defaulting to its stored winning option. Instructions already executed
+/// are skipped.
+pub async fn simulate(
+    snapshot: &Snapshot,
+    proposal_address: &Pubkey,
+    winning_option: Option<u8>,
+) -> Result<SimulationReport, SimulationError> {
+    let program_id = paladin_governance_program::id();
+
+    let mut proposal_account = snapshot.get(proposal_address)?.clone();
+    let proposal = {
+        let proposal = bytemuck::try_from_bytes_mut::<Proposal>(&mut proposal_account.data)
+            .map_err(|_| SimulationError::InvalidProposal(*proposal_address))?;
+        proposal.status = ProposalStatus::Accepted;
+        if let Some(winning_option) = winning_option {
+            proposal.winning_option = winning_option;
+        }
+        *proposal
+    };
+
+    let governance_config = proposal.governance_config.governance_config;
+    let treasury = get_treasury_address(&governance_config, &program_id);
+    let proposal_transaction_address =
+        get_proposal_transaction_address(proposal_address, &program_id);
+    let mut proposal_transaction =
+        ProposalTransaction::try_from_slice(&snapshot.get(&proposal_transaction_address)?.data)
+            .map_err(|_| {
+                SimulationError::InvalidProposalTransaction(proposal_transaction_address)
+            })?;
+    let prerequisites = proposal_transaction.prerequisites.clone();
+    let instructions = proposal_transaction
+        .outcome_instructions_mut(&proposal)?
+        .clone();
+
+    let mut program_test = ProgramTest::new(
+        "paladin_governance_program",
+        program_id,
+        processor!(paladin_governance_program::processor::process),
+    );
+    // Builtin programs are part of every bank.
+    for (address, account) in &snapshot.accounts {
+        if *address != program_id
+            && address != proposal_address
+            && account.owner != native_loader::id()
+        {
+            program_test.add_account(*address, account.clone());
+        }
+    }
+    program_test.add_account(*proposal_address, proposal_account);
+    let mut context = program_test.start_with_context().await;
+
+    let mut reports = Vec::with_capacity(instructions.len());
+    for (index, instruction) in instructions.iter().enumerate() {
+        if instruction.executed {
+            continue;
+        }
+        let index = index as u32;
+        let account_metas = sdk::instruction_account_metas(&treasury, &instruction.into());
+
+        let before = get_accounts(&mut context.banks_client, &account_metas).await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[process_instruction(
+                proposal_address,
+                &proposal_transaction_address,
+                &governance_config,
+                &prerequisites,
+                &account_metas,
+                index,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+        let after = get_accounts(&mut context.banks_client, &account_metas).await?;
+
+        let (logs, compute_units) = result
+            .metadata
+            .map(|metadata| (metadata.log_messages, metadata.compute_units_consumed))
+            .unwrap_or_default();
+        let account_diffs = account_metas
+            .iter()
+            .zip(before.into_iter().zip(after))
+            .filter(|(_, (before, after))| before != after)
+            .map(|(meta, (before, after))| AccountDiff {
+                address: meta.pubkey,
+                before,
+                after,
+            })
+            .collect();
+        let error = result.result.err();
+        let failed = error.is_some();
+
+        reports.push(InstructionReport {
+            index,
+            compute_units,
+            logs,
+            account_diffs,
+            error,
+        });
+        if failed {
+            break;
+        }
+    }
+
+    Ok(SimulationReport {
+        instructions: reports,
+    })
+}
+
+async fn get_accounts(
+    banks_client: &mut BanksClient,
+    account_metas: &[AccountMeta],
+) -> Result<Vec<Option<Account>>, SimulationError> {
+    let mut accounts = Vec::with_capacity(account_metas.len());
+    for meta in account_metas {
+        accounts.push(banks_client.get_account(meta.pubkey).await?);
+    }
+    Ok(accounts)
+}
@@ -0,0 +1,153 @@
+//! Local snapshots of the accounts a proposal references.
+
+use {
+    crate::SimulationError,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    paladin_governance_program::state::{
+        get_proposal_transaction_address, get_treasury_address, Proposal, ProposalTransaction,
+    },
+    serde::{Deserialize, Serialize},
+    serde_with::{serde_as, DisplayFromStr},
+    solana_sdk::{account::Account, pubkey::Pubkey},
+    std::{collections::HashMap, fs, path::Path},
+};
+
+/// Account dump in the format written by `solana account --output json`.
+#[serde_as]
+#[derive(Deserialize, Serialize)]
+struct AccountDump {
+    #[serde_as(as = "DisplayFromStr")]
+    pubkey: Pubkey,
+    account: UiAccount,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UiAccount {
+    lamports: u64,
+    /// The account data and its encoding, which must be `base64`.
+    data: (String, String),
+    #[serde_as(as = "DisplayFromStr")]
+    owner: Pubkey,
+    executable: bool,
+    rent_epoch: u64,
+}
+
+/// Accounts to load into a simulation, by address.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    pub accounts: HashMap<Pubkey, Account>,
+}
+
+impl Snapshot {
+    /// Loads every `.json` account dump in a directory.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, SimulationError> {
+        let mut snapshot = Self::default();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                snapshot.load_json(&fs::read_to_string(path)?)?;
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Adds an account from its JSON dump.
+    pub fn load_json(&mut self, json: &str) -> Result<(), SimulationError> {
+        let dump: AccountDump = serde_json::from_str(json)?;
+        let (data, encoding) = dump.account.data;
+        if encoding != "base64" {
+            return Err(SimulationError::UnsupportedEncoding(encoding));
+        }
+        self.accounts.insert(
+            dump.pubkey,
+            Account {
+                lamports: dump.account.lamports,
+                data: STANDARD.decode(data)?,
+                owner: dump.account.owner,
+                executable: dump.account.executable,
+                rent_epoch: dump.account.rent_epoch,
+            },
+        );
+        Ok(())
+    }
+
+    /// Writes each account as a `<address>.json` dump in a directory.
+    pub fn write_dir(&self, dir: impl AsRef<Path>) -> Result<(), SimulationError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        for (address, account) in &self.accounts {
+            fs::write(
+                dir.join(format!("{address}.json")),
+                account_to_json(address, account)?,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, address: &Pubkey) -> Result<&Account, SimulationError> {
+        self.accounts
+            .get(address)
+            .ok_or(SimulationError::MissingAccount(*address))
+    }
+}
+
+/// Serializes an account as a JSON dump, in the format written by
+/// `solana account --output json`.
+pub fn account_to_json(address: &Pubkey, account: &Account) -> Result<String, SimulationError> {
+    Ok(serde_json::to_string_pretty(&AccountDump {
+        pubkey: *address,
+        account: UiAccount {
+            lamports: account.lamports,
+            data: (STANDARD.encode(&account.data), "base64".to_string()),
+            owner: account.owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        },
+    })?)
+}
+
+/// The addresses of every account simulating a proposal needs: the proposal,
+/// its transaction, governance config and treasury, its prerequisites, and
+/// the accounts and programs of each of its instructions.
+///
+/// Upgradeable programs also need their program data account.
+pub fn referenced_addresses(
+    proposal_address: &Pubkey,
+    proposal: &Proposal,
+    proposal_transaction: &ProposalTransaction,
+) -> Vec<Pubkey> {
+    let program_id = paladin_governance_program::id();
+    let governance_config = proposal.governance_config.governance_config;
+
+    let mut addresses = vec![
+        *proposal_address,
+        get_proposal_transaction_address(proposal_address, &program_id),
+        governance_config,
+        get_treasury_address(&governance_config, &program_id),
+    ];
+    addresses.extend(&proposal_transaction.prerequisites);
+
+    let instructions = proposal_transaction.instructions.iter().chain(
+        proposal_transaction
+            .options
+            .iter()
+            .flat_map(|option| &option.instructions),
+    );
+    for instruction in instructions {
+        addresses.push(instruction.program_id);
+        addresses.extend(instruction.accounts.iter().map(|meta| meta.pubkey));
+    }
+
+    let mut unique = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        if !unique.contains(&address) {
+            unique.push(address);
+        }
+    }
+    unique
+}
@@ -0,0 +1,186 @@
+#![cfg(feature = "test-sbf")]
+
+use {
+    paladin_governance_program::state::{
+        get_proposal_transaction_address, get_treasury_address, GovernanceConfig, Proposal,
+        ProposalInstruction, ProposalTransaction,
+    },
+    paladin_governance_simulator::{simulate, SimulationError, Snapshot},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
+        transaction::TransactionError,
+    },
+};
+
+struct Setup {
+    snapshot: Snapshot,
+    proposal: Pubkey,
+    treasury: Pubkey,
+}
+
+/// A draft proposal transferring each of `transfers` from the treasury to
+/// `recipient`, with the treasury holding `treasury_lamports`.
+fn setup(recipient: &Pubkey, transfers: &[u64], treasury_lamports: u64) -> Setup {
+    let program_id = paladin_governance_program::id();
+    let rent = Rent::default();
+    let program_account = |data: Vec<u8>| Account {
+        lamports: rent.minimum_balance(data.len()),
+        data,
+        owner: program_id,
+        ..Account::default()
+    };
+
+    let governance = Pubkey::new_unique();
+    let treasury = get_treasury_address(&governance, &program_id);
+    let proposal = Pubkey::new_unique();
+    let governance_config = GovernanceConfig {
+        governance_config: governance,
+        ..GovernanceConfig::default()
+    };
+
+    let proposal_transaction = ProposalTransaction {
+        instructions: transfers
+            .iter()
+            .map(|lamports| {
+                ProposalInstruction::from(&system_instruction::transfer(
+                    &treasury, recipient, *lamports,
+                ))
+            })
+            .collect(),
+        prerequisites: vec![],
+        options: vec![],
+    };
+
+    let mut snapshot = Snapshot::default();
+    snapshot.accounts.insert(
+        governance,
+        program_account(bytemuck::bytes_of(&governance_config).to_vec()),
+    );
+    snapshot.accounts.insert(
+        proposal,
+        program_account(
+            bytemuck::bytes_of(&Proposal::new(&Pubkey::new_unique(), 0, governance_config))
+                .to_vec(),
+        ),
+    );
+    snapshot.accounts.insert(
+        get_proposal_transaction_address(&proposal, &program_id),
+        program_account(borsh::to_vec(&proposal_transaction).unwrap()),
+    );
+    snapshot.accounts.insert(
+        treasury,
+        Account::new(treasury_lamports, 0, &system_program::id()),
+    );
+
+    Setup {
+        snapshot,
+        proposal,
+        treasury,
+    }
+}
+
+#[tokio::test]
+async fn success() {
+    let recipient = Pubkey::new_unique();
+    let Setup {
+        snapshot,
+        proposal,
+        treasury,
+    } = setup(&recipient, &[100_000_000, 200_000_000], 1_000_000_000);
+
+    let report = simulate(&snapshot, &proposal, None).await.unwrap();
+    assert!(report.is_success());
+    assert_eq!(report.instructions.len(), 2);
+
+    for (instruction, lamports) in report.instructions.iter().zip([100_000_000, 200_000_000]) {
+        assert!(instruction.compute_units > 0);
+        assert!(!instruction.logs.is_empty());
+
+        let diff = |address: &Pubkey| {
+            instruction
+                .account_diffs
+                .iter()
+                .find(|diff| diff.address == *address)
+                .unwrap()
+        };
+        assert_eq!(diff(&treasury).lamports_delta(), -lamports);
+        assert_eq!(diff(&recipient).lamports_delta(), lamports);
+        assert_eq!(diff(&recipient).before, None);
+    }
+}
+
+#[tokio::test]
+async fn fail_stops_at_first_failure() {
+    let recipient = Pubkey::new_unique();
+    let Setup {
+        snapshot, proposal, ..
+    } = setup(
+        &recipient,
+        &[100_000_000, 2_000_000_000, 100_000_000],
+        1_000_000_000,
+    );
+
+    let report = simulate(&snapshot, &proposal, None).await.unwrap();
+    assert!(!report.is_success());
+    assert_eq!(report.instructions.len(), 2);
+
+    let failure = report.failure().unwrap();
+    assert_eq!(failure.index, 1);
+    assert!(matches!(
+        failure.error,
+        Some(TransactionError::InstructionError(0, _))
+    ));
+    assert!(failure.account_diffs.is_empty());
+}
+
+#[tokio::test]
+async fn fail_missing_account() {
+    let Setup {
+        mut snapshot,
+        proposal,
+        ..
+    } = setup(&Pubkey::new_unique(), &[1], 1_000_000_000);
+    let proposal_transaction =
+        get_proposal_transaction_address(&proposal, &paladin_governance_program::id());
+    snapshot.accounts.remove(&proposal_transaction);
+
+    let err = simulate(&snapshot, &proposal, None).await.unwrap_err();
+    assert!(matches!(
+        err,
+        SimulationError::MissingAccount(address) if address == proposal_transaction
+    ));
+}
+
+#[test]
+fn snapshot_dir_round_trip() {
+    let Setup { snapshot, .. } = setup(&Pubkey::new_unique(), &[1], 1_000_000_000);
+
+    let dir = std::env::temp_dir().join(format!("snapshot-{}", Pubkey::new_unique()));
+    snapshot.write_dir(&dir).unwrap();
+    let loaded = Snapshot::from_dir(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(loaded, snapshot);
+}
+
+#[test]
+fn fail_load_unsupported_encoding() {
+    let json = format!(
+        r#"{{
+            "pubkey": "{}",
+            "account": {{
+                "lamports": 1,
+                "data": ["", "base58"],
+                "owner": "{}",
+                "executable": false,
+                "rentEpoch": 0,
+                "space": 0
+            }}
+        }}"#,
+        Pubkey::new_unique(),
+        system_program::id()
+    );
+
+    let err = Snapshot::default().load_json(&json).unwrap_err();
+    assert!(matches!(err, SimulationError::UnsupportedEncoding(encoding) if encoding == "base58"));
+}
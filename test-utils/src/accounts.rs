@@ -0,0 +1,123 @@
+//! Writes accounts directly into the test bank, bypassing the programs that
+//! own them.
+
+use {
+    paladin_stake_program::state::{Config as StakeConfig, Delegation, ValidatorStake},
+    solana_program_test::ProgramTestContext,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        account_utils::StateMut,
+        bpf_loader_upgradeable::{self, get_program_data_address, UpgradeableLoaderState},
+        pubkey::Pubkey,
+    },
+    spl_discriminator::SplDiscriminate,
+};
+
+/// Writes a rent-exempt account holding `data`.
+pub async fn set_account_data(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    owner: &Pubkey,
+    data: Vec<u8>,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *owner,
+            ..Account::default()
+        }),
+    );
+}
+
+/// Writes a stake config with `total_stake` effective stake.
+pub async fn set_stake_config(
+    context: &mut ProgramTestContext,
+    stake_config_address: &Pubkey,
+    authority_address: &Pubkey,
+    total_stake: u64,
+) {
+    let state = StakeConfig {
+        discriminator: StakeConfig::SPL_DISCRIMINATOR.into(),
+        authority: Some(*authority_address).try_into().unwrap(),
+        slash_authority: Some(Pubkey::new_unique()).try_into().unwrap(),
+        vault: Pubkey::new_unique(),
+        cooldown_time_seconds: 0,
+        max_deactivation_basis_points: 0,
+        sync_rewards_lamports: 0,
+        vault_authority_bump: 0,
+        lamports_last: 0,
+        token_amount_effective: total_stake,
+        accumulated_stake_rewards_per_token: 0.into(),
+        duna_document_hash: [0; 32],
+        _padding: [0; 5],
+    };
+
+    set_account_data(
+        context,
+        stake_config_address,
+        &paladin_stake_program::id(),
+        bytemuck::bytes_of(&state).to_vec(),
+    )
+    .await;
+}
+
+/// Writes a validator stake of `amount`, delegated to `authority_address`.
+///
+/// The stake config's total stake is not updated.
+pub async fn set_stake(
+    context: &mut ProgramTestContext,
+    stake_address: &Pubkey,
+    authority_address: &Pubkey,
+    validator_vote: &Pubkey,
+    amount: u64,
+) {
+    let state = ValidatorStake {
+        _discriminator: ValidatorStake::SPL_DISCRIMINATOR.into(),
+        delegation: Delegation {
+            staked_amount: amount,
+            effective_amount: amount,
+            authority: *authority_address,
+            validator_vote: *validator_vote,
+            ..Default::default()
+        },
+        total_staked_lamports_amount: 0,
+        total_staked_lamports_amount_min: 0,
+    };
+
+    set_account_data(
+        context,
+        stake_address,
+        &paladin_stake_program::id(),
+        bytemuck::bytes_of(&state).to_vec(),
+    )
+    .await;
+}
+
+/// Writes the governance program's program data account, so
+/// `upgrade_authority_address` can initialize governance configs.
+pub async fn set_program_data(
+    context: &mut ProgramTestContext,
+    upgrade_authority_address: Option<Pubkey>,
+) {
+    let state = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address,
+    };
+    let space = UpgradeableLoaderState::size_of_programdata_metadata();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(space);
+
+    let mut account = AccountSharedData::new(lamports, space, &bpf_loader_upgradeable::id());
+    account.set_state(&state).unwrap();
+
+    context.set_account(
+        &get_program_data_address(&paladin_governance_program::id()),
+        &account,
+    );
+}
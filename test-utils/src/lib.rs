@@ -0,0 +1,563 @@
+//! Test harness for programs gated by Paladin Governance.
+//!
+//! `GovernanceTestContext` starts a `solana-program-test` bank with the
+//! governance program, a stake config and an initialized governance config,
+//! then drives proposals through their lifecycle: creating stakers, building
+//! and voting on proposals, warping the clock past the voting and cooldown
+//! periods, and executing accepted proposals through the treasury.
+//!
+//! ```ignore
+//! let mut context = GovernanceTestContext::builder()
+//!     .with_program_test(|program_test| {
+//!         program_test.add_program("my_program", my_program::id(), None);
+//!     })
+//!     .start()
+//!     .await;
+//! let staker = context.create_staker(500_000).await;
+//! let treasury = context.treasury();
+//!
+//! let proposal = context
+//!     .proposal(&staker)
+//!     .instruction(my_program::instruction::set_fee(&treasury, 10))
+//!     .begin_voting()
+//!     .await
+//!     .unwrap();
+//! context.vote(&staker, &proposal, ProposalVoteElection::For).await.unwrap();
+//! context.warp_past_cooldown_period(&proposal).await;
+//! context.finish_voting(&proposal).await.unwrap();
+//! context.assert_status(&proposal, ProposalStatus::Accepted).await;
+//! context.process_instructions(&proposal).await.unwrap();
+//! ```
+//!
+//! Warping accounts for pauses that have ended, but not for an ongoing one,
+//! so the clock should only be warped while governance is unpaused.
+
+pub mod accounts;
+mod proposal;
+
+pub use proposal::ProposalBuilder;
+use {
+    borsh::BorshDeserialize,
+    paladin_governance_program::{
+        instruction::{
+            begin_voting, finish_voting, initialize_governance, process_instruction, switch_vote,
+            switch_vote_option, vote, vote_option,
+        },
+        outcome::Timeline,
+        state::{
+            get_governance_address, get_proposal_transaction_address, get_proposal_vote_address,
+            get_treasury_address, GovernanceConfig, Proposal, ProposalStatus, ProposalTransaction,
+            ProposalVote, ProposalVoteElection,
+        },
+    },
+    paladin_governance_program_client::sdk,
+    solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext},
+    solana_sdk::{
+        clock::{Clock, UnixTimestamp},
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+/// A staker able to author and vote on proposals.
+pub struct Staker {
+    /// The stake's delegation authority, which signs for the staker.
+    pub authority: Keypair,
+    /// The validator vote account the stake is delegated to.
+    pub validator_vote: Pubkey,
+    /// The validator stake account.
+    pub stake: Pubkey,
+    /// The staked amount.
+    pub amount: u64,
+}
+
+/// Configures and starts a `GovernanceTestContext`.
+pub struct GovernanceTestContextBuilder {
+    program_test: ProgramTest,
+    total_stake: u64,
+    governance_id: u64,
+    cooldown_period_seconds: u64,
+    proposal_minimum_quorum: u32,
+    proposal_pass_threshold: u32,
+    voting_period_seconds: u64,
+    stake_per_proposal: u64,
+}
+
+impl Default for GovernanceTestContextBuilder {
+    fn default() -> Self {
+        Self {
+            program_test: ProgramTest::new(
+                "paladin_governance_program",
+                paladin_governance_program::id(),
+                processor!(paladin_governance_program::processor::process),
+            ),
+            total_stake: 1_000_000,
+            governance_id: 0,
+            cooldown_period_seconds: 3_600,
+            proposal_minimum_quorum: 100_000_000, // 10%
+            proposal_pass_threshold: 500_000_000, // 50%
+            voting_period_seconds: 86_400,
+            stake_per_proposal: 0,
+        }
+    }
+}
+
+impl GovernanceTestContextBuilder {
+    /// The effective stake of the stake config, which turnout is measured
+    /// against. Stakers created afterwards do not add to it.
+    pub fn total_stake(mut self, total_stake: u64) -> Self {
+        self.total_stake = total_stake;
+        self
+    }
+
+    pub fn governance_id(mut self, governance_id: u64) -> Self {
+        self.governance_id = governance_id;
+        self
+    }
+
+    pub fn cooldown_period_seconds(mut self, cooldown_period_seconds: u64) -> Self {
+        self.cooldown_period_seconds = cooldown_period_seconds;
+        self
+    }
+
+    pub fn proposal_minimum_quorum(mut self, proposal_minimum_quorum: u32) -> Self {
+        self.proposal_minimum_quorum = proposal_minimum_quorum;
+        self
+    }
+
+    pub fn proposal_pass_threshold(mut self, proposal_pass_threshold: u32) -> Self {
+        self.proposal_pass_threshold = proposal_pass_threshold;
+        self
+    }
+
+    pub fn voting_period_seconds(mut self, voting_period_seconds: u64) -> Self {
+        self.voting_period_seconds = voting_period_seconds;
+        self
+    }
+
+    pub fn stake_per_proposal(mut self, stake_per_proposal: u64) -> Self {
+        self.stake_per_proposal = stake_per_proposal;
+        self
+    }
+
+    /// Configures the underlying `ProgramTest`, e.g. to add the programs
+    /// governance proposals will invoke.
+    pub fn with_program_test(mut self, configure: impl FnOnce(&mut ProgramTest)) -> Self {
+        configure(&mut self.program_test);
+        self
+    }
+
+    /// Starts the bank, writes a stake config and initializes the governance
+    /// config with `InitializeGovernance`.
+    pub async fn start(self) -> GovernanceTestContext {
+        let mut context = self.program_test.start_with_context().await;
+        let payer = context.payer.pubkey();
+
+        let stake_config = Pubkey::new_unique();
+        accounts::set_stake_config(&mut context, &stake_config, &payer, self.total_stake).await;
+        accounts::set_program_data(&mut context, None).await;
+
+        let governance = get_governance_address(
+            &stake_config,
+            &self.governance_id,
+            &paladin_governance_program::id(),
+        );
+        let mut context = GovernanceTestContext {
+            context,
+            stake_config,
+            governance,
+        };
+        let fund = context
+            .fund_rent(&governance, std::mem::size_of::<GovernanceConfig>())
+            .await;
+        context
+            .process(
+                &[
+                    fund,
+                    vec![initialize_governance(
+                        &governance,
+                        &stake_config,
+                        &payer,
+                        self.governance_id,
+                        self.cooldown_period_seconds,
+                        self.proposal_minimum_quorum,
+                        self.proposal_pass_threshold,
+                        self.voting_period_seconds,
+                        self.stake_per_proposal,
+                        0,
+                    )],
+                ]
+                .concat(),
+                &[],
+            )
+            .await
+            .unwrap();
+        context
+    }
+}
+
+/// A bank with an initialized governance config.
+pub struct GovernanceTestContext {
+    pub context: ProgramTestContext,
+    pub stake_config: Pubkey,
+    pub governance: Pubkey,
+}
+
+impl GovernanceTestContext {
+    pub fn builder() -> GovernanceTestContextBuilder {
+        GovernanceTestContextBuilder::default()
+    }
+
+    /// The treasury of the governance config, which signs the instructions of
+    /// accepted proposals.
+    pub fn treasury(&self) -> Pubkey {
+        get_treasury_address(&self.governance, &paladin_governance_program::id())
+    }
+
+    /// Transfers `lamports` from the payer to the treasury.
+    pub async fn fund_treasury(&mut self, lamports: u64) {
+        let instruction =
+            system_instruction::transfer(&self.context.payer.pubkey(), &self.treasury(), lamports);
+        self.process(&[instruction], &[]).await.unwrap();
+    }
+
+    /// Writes a validator stake of `amount` for a new staker.
+    pub async fn create_staker(&mut self, amount: u64) -> Staker {
+        let staker = Staker {
+            authority: Keypair::new(),
+            validator_vote: Pubkey::new_unique(),
+            stake: Pubkey::new_unique(),
+            amount,
+        };
+        accounts::set_stake(
+            &mut self.context,
+            &staker.stake,
+            &staker.authority.pubkey(),
+            &staker.validator_vote,
+            amount,
+        )
+        .await;
+        staker
+    }
+
+    /// Starts building a proposal authored by `author`.
+    pub fn proposal<'a>(&'a mut self, author: &'a Staker) -> ProposalBuilder<'a> {
+        ProposalBuilder::new(self, author)
+    }
+
+    pub async fn begin_voting(
+        &mut self,
+        author: &Staker,
+        proposal: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let instruction = begin_voting(&author.authority.pubkey(), proposal, &self.governance);
+        self.process(&[instruction], &[&author.authority]).await
+    }
+
+    pub async fn vote(
+        &mut self,
+        staker: &Staker,
+        proposal: &Pubkey,
+        election: ProposalVoteElection,
+    ) -> Result<(), BanksClientError> {
+        let proposal_vote = self.fund_proposal_vote(staker, proposal).await;
+        let instruction = vote(
+            &staker.authority.pubkey(),
+            &staker.stake,
+            &self.stake_config,
+            &self.proposal_vote_address(staker, proposal),
+            proposal,
+            &self.governance,
+            election,
+        );
+        self.process(
+            &[proposal_vote, vec![instruction]].concat(),
+            &[&staker.authority],
+        )
+        .await
+    }
+
+    pub async fn vote_option(
+        &mut self,
+        staker: &Staker,
+        proposal: &Pubkey,
+        option_index: u8,
+    ) -> Result<(), BanksClientError> {
+        let proposal_vote = self.fund_proposal_vote(staker, proposal).await;
+        let instruction = vote_option(
+            &staker.authority.pubkey(),
+            &staker.stake,
+            &self.stake_config,
+            &self.proposal_vote_address(staker, proposal),
+            proposal,
+            &self.governance,
+            option_index,
+        );
+        self.process(
+            &[proposal_vote, vec![instruction]].concat(),
+            &[&staker.authority],
+        )
+        .await
+    }
+
+    pub async fn switch_vote(
+        &mut self,
+        staker: &Staker,
+        proposal: &Pubkey,
+        new_election: ProposalVoteElection,
+    ) -> Result<(), BanksClientError> {
+        let instruction = switch_vote(
+            &staker.authority.pubkey(),
+            &staker.stake,
+            &self.stake_config,
+            &self.proposal_vote_address(staker, proposal),
+            proposal,
+            &self.governance,
+            new_election,
+        );
+        self.process(&[instruction], &[&staker.authority]).await
+    }
+
+    pub async fn switch_vote_option(
+        &mut self,
+        staker: &Staker,
+        proposal: &Pubkey,
+        new_option_index: u8,
+    ) -> Result<(), BanksClientError> {
+        let instruction = switch_vote_option(
+            &staker.authority.pubkey(),
+            &staker.stake,
+            &self.stake_config,
+            &self.proposal_vote_address(staker, proposal),
+            proposal,
+            &self.governance,
+            new_option_index,
+        );
+        self.process(&[instruction], &[&staker.authority]).await
+    }
+
+    pub async fn finish_voting(&mut self, proposal: &Pubkey) -> Result<(), BanksClientError> {
+        let instruction = finish_voting(self.stake_config, proposal, &self.governance);
+        self.process(&[instruction], &[]).await
+    }
+
+    /// Executes the unexecuted instructions of an accepted proposal's
+    /// outcome, in order, each in its own transaction.
+    ///
+    /// Panics if the proposal is multiple-choice and voting has not been
+    /// finished.
+    pub async fn process_instructions(
+        &mut self,
+        proposal: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let proposal_state = self.get_proposal(proposal).await;
+        let proposal_transaction =
+            get_proposal_transaction_address(proposal, &paladin_governance_program::id());
+        let mut proposal_transaction_state = self.get_proposal_transaction(proposal).await;
+        let prerequisites = proposal_transaction_state.prerequisites.clone();
+        let treasury = self.treasury();
+
+        let instructions: Vec<Instruction> = proposal_transaction_state
+            .outcome_instructions_mut(&proposal_state)
+            .expect("multiple-choice proposal has no winning option")
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| !instruction.executed)
+            .map(|(index, instruction)| {
+                process_instruction(
+                    proposal,
+                    &proposal_transaction,
+                    &self.governance,
+                    &prerequisites,
+                    &sdk::instruction_account_metas(&treasury, &instruction.into()),
+                    index as u32,
+                )
+            })
+            .collect();
+        for instruction in instructions {
+            self.process(&[instruction], &[]).await?;
+        }
+        Ok(())
+    }
+
+    /// Sets the clock to `unix_timestamp`.
+    pub async fn warp_to_timestamp(&mut self, unix_timestamp: UnixTimestamp) {
+        let clock = self.get_clock().await;
+        self.context.set_sysvar(&Clock {
+            unix_timestamp,
+            ..clock
+        });
+    }
+
+    /// Moves the clock forward by `seconds`.
+    pub async fn warp_forward(&mut self, seconds: u64) {
+        let clock = self.get_clock().await;
+        self.warp_to_timestamp(clock.unix_timestamp.saturating_add(seconds as i64))
+            .await;
+    }
+
+    /// Moves the clock to the end of the proposal's voting period, after
+    /// which `FinishVoting` can close it. The clock never moves backwards.
+    ///
+    /// Panics if voting has not begun.
+    pub async fn warp_past_voting_period(&mut self, proposal: &Pubkey) {
+        let timeline = self.timeline(proposal).await;
+        let ends_at = timeline
+            .voting_ends_at()
+            .expect("voting has not begun on the proposal");
+        self.warp_to_at_least(ends_at as i64).await;
+    }
+
+    /// Moves the clock to the end of the proposal's cooldown period, or of
+    /// its voting period if the cooldown has not begun. The clock never moves
+    /// backwards.
+    ///
+    /// A cooldown begins once the proposal reaches quorum. Without one,
+    /// `FinishVoting` rejects the proposal once the voting period ends.
+    pub async fn warp_past_cooldown_period(&mut self, proposal: &Pubkey) {
+        let timeline = self.timeline(proposal).await;
+        let ends_at = timeline
+            .cooldown_ends_at()
+            .or(timeline.voting_ends_at())
+            .expect("voting has not begun on the proposal");
+        self.warp_to_at_least(ends_at as i64).await;
+    }
+
+    /// Moves the clock to `unix_timestamp`, unless it is already past it.
+    async fn warp_to_at_least(&mut self, unix_timestamp: UnixTimestamp) {
+        let clock = self.get_clock().await;
+        if clock.unix_timestamp < unix_timestamp {
+            self.warp_to_timestamp(unix_timestamp).await;
+        }
+    }
+
+    pub async fn get_clock(&mut self) -> Clock {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_governance_config(&mut self) -> GovernanceConfig {
+        let governance = self.governance;
+        *bytemuck::from_bytes(&self.get_account_data(&governance).await)
+    }
+
+    pub async fn get_proposal(&mut self, proposal: &Pubkey) -> Proposal {
+        *bytemuck::from_bytes(&self.get_account_data(proposal).await)
+    }
+
+    pub async fn get_proposal_transaction(&mut self, proposal: &Pubkey) -> ProposalTransaction {
+        let address = get_proposal_transaction_address(proposal, &paladin_governance_program::id());
+        ProposalTransaction::try_from_slice(&self.get_account_data(&address).await).unwrap()
+    }
+
+    pub async fn get_proposal_vote(&mut self, staker: &Staker, proposal: &Pubkey) -> ProposalVote {
+        let address = self.proposal_vote_address(staker, proposal);
+        *bytemuck::from_bytes(&self.get_account_data(&address).await)
+    }
+
+    /// Asserts the proposal has the expected status.
+    pub async fn assert_status(&mut self, proposal: &Pubkey, status: ProposalStatus) {
+        let proposal_state = self.get_proposal(proposal).await;
+        assert_eq!(
+            proposal_state.status, status,
+            "unexpected status for proposal {proposal}"
+        );
+    }
+
+    /// Asserts the proposal's `For` and `Against` stake.
+    pub async fn assert_tally(&mut self, proposal: &Pubkey, stake_for: u64, stake_against: u64) {
+        let proposal_state = self.get_proposal(proposal).await;
+        assert_eq!(
+            (proposal_state.stake_for, proposal_state.stake_against),
+            (stake_for, stake_against),
+            "unexpected tally for proposal {proposal}"
+        );
+    }
+
+    /// Sends a transaction of `instructions`, paid for by the context's payer
+    /// and signed by `signers`.
+    ///
+    /// Sending the same instructions twice from the same blockhash is
+    /// rejected as a duplicate transaction.
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let recent_blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let mut keypairs = vec![&self.context.payer];
+        keypairs.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &keypairs,
+            recent_blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    async fn get_account_data(&mut self, address: &Pubkey) -> Vec<u8> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {address} not found"))
+            .data
+    }
+
+    async fn timeline(&mut self, proposal: &Pubkey) -> Timeline {
+        let proposal_state = self.get_proposal(proposal).await;
+        let governance_config = self.get_governance_config().await;
+
+        // Pauses that have ended extend the proposal's periods.
+        let mut timeline = proposal_state.timeline();
+        timeline.extend(
+            governance_config
+                .paused_seconds
+                .saturating_sub(proposal_state.paused_seconds_checkpoint),
+        );
+        timeline
+    }
+
+    fn proposal_vote_address(&self, staker: &Staker, proposal: &Pubkey) -> Pubkey {
+        get_proposal_vote_address(&staker.stake, proposal, &paladin_governance_program::id())
+    }
+
+    async fn fund_proposal_vote(&mut self, staker: &Staker, proposal: &Pubkey) -> Vec<Instruction> {
+        let proposal_vote = self.proposal_vote_address(staker, proposal);
+        self.fund_rent(&proposal_vote, std::mem::size_of::<ProposalVote>())
+            .await
+    }
+
+    /// Transfers the lamports `address` needs to be rent exempt at
+    /// `data_len` bytes, if any.
+    async fn fund_rent(&mut self, address: &Pubkey, data_len: usize) -> Vec<Instruction> {
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let lamports = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .map_or(0, |account| account.lamports);
+
+        sdk::fund_rent(
+            self.context.payer.pubkey(),
+            *address,
+            lamports,
+            rent.minimum_balance(data_len),
+        )
+        .into_iter()
+        .collect()
+    }
+}
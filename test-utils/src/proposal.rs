@@ -0,0 +1,219 @@
+//! Building proposals.
+
+use {
+    crate::{GovernanceTestContext, Staker},
+    paladin_governance_program::{
+        instruction::{
+            create_proposal, create_signal_proposal, initialize_author, push_instruction,
+            push_option, push_option_instruction, push_prerequisite,
+        },
+        state::{
+            get_proposal_author_address, get_proposal_transaction_address, Author, Proposal,
+            ProposalAccountMeta, ProposalInstruction, ProposalOption, ProposalTransaction,
+        },
+    },
+    solana_program_test::BanksClientError,
+    solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+    },
+};
+
+/// Builds a proposal, created by `create` or `begin_voting`.
+///
+/// Instructions that should be signed by the treasury take
+/// `GovernanceTestContext::treasury` as a signer.
+pub struct ProposalBuilder<'a> {
+    context: &'a mut GovernanceTestContext,
+    author: &'a Staker,
+    instructions: Vec<Instruction>,
+    options: Vec<Vec<Instruction>>,
+    prerequisites: Vec<Pubkey>,
+    signal: bool,
+}
+
+impl<'a> ProposalBuilder<'a> {
+    pub(crate) fn new(context: &'a mut GovernanceTestContext, author: &'a Staker) -> Self {
+        Self {
+            context,
+            author,
+            instructions: vec![],
+            options: vec![],
+            prerequisites: vec![],
+            signal: false,
+        }
+    }
+
+    /// Adds an instruction to execute if the proposal is accepted.
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Adds an option, making the proposal multiple-choice, with the
+    /// instructions to execute if it wins.
+    pub fn option(mut self, instructions: Vec<Instruction>) -> Self {
+        self.options.push(instructions);
+        self
+    }
+
+    /// Requires `proposal` to be processed before any instruction executes.
+    pub fn prerequisite(mut self, proposal: Pubkey) -> Self {
+        self.prerequisites.push(proposal);
+        self
+    }
+
+    /// Makes the proposal a signal proposal, which records the stakers'
+    /// sentiment without executing anything.
+    pub fn signal(mut self) -> Self {
+        self.signal = true;
+        self
+    }
+
+    /// Creates the proposal in `Draft`, initializing the author's account if
+    /// needed, and pushes its prerequisites, options and instructions.
+    pub async fn create(self) -> Result<Pubkey, BanksClientError> {
+        self.send(false).await
+    }
+
+    /// Creates the proposal and begins voting on it.
+    pub async fn begin_voting(self) -> Result<Pubkey, BanksClientError> {
+        self.send(true).await
+    }
+
+    async fn send(self, begin_voting: bool) -> Result<Pubkey, BanksClientError> {
+        let Self {
+            context,
+            author,
+            instructions,
+            options,
+            prerequisites,
+            signal,
+        } = self;
+        assert!(
+            !signal || (instructions.is_empty() && options.is_empty() && prerequisites.is_empty()),
+            "signal proposals have no instructions, options or prerequisites"
+        );
+
+        let authority = author.authority.pubkey();
+        let proposal = Keypair::new();
+        let proposal_transaction =
+            get_proposal_transaction_address(&proposal.pubkey(), &paladin_governance_program::id());
+
+        let author_address =
+            get_proposal_author_address(&authority, &paladin_governance_program::id());
+        let mut setup = vec![];
+        if context
+            .context
+            .banks_client
+            .get_account(author_address)
+            .await?
+            .is_none()
+        {
+            setup.extend(context.fund_rent(&author_address, Author::LEN).await);
+            setup.push(initialize_author(authority));
+        }
+
+        let rent = context.context.banks_client.get_rent().await?;
+        setup.push(system_instruction::create_account(
+            &context.context.payer.pubkey(),
+            &proposal.pubkey(),
+            rent.minimum_balance(Proposal::LEN),
+            Proposal::LEN as u64,
+            &paladin_governance_program::id(),
+        ));
+        if signal {
+            setup.push(create_signal_proposal(
+                &authority,
+                &author.stake,
+                &proposal.pubkey(),
+                &context.governance,
+            ));
+        } else {
+            // Fund the proposal transaction for everything pushed below.
+            let final_transaction = ProposalTransaction {
+                instructions: instructions.iter().map(ProposalInstruction::from).collect(),
+                prerequisites: prerequisites.clone(),
+                options: options
+                    .iter()
+                    .map(|instructions| ProposalOption {
+                        instructions: instructions.iter().map(ProposalInstruction::from).collect(),
+                    })
+                    .collect(),
+            };
+            setup.extend(
+                context
+                    .fund_rent(
+                        &proposal_transaction,
+                        borsh::to_vec(&final_transaction).unwrap().len(),
+                    )
+                    .await,
+            );
+            setup.push(create_proposal(
+                &authority,
+                &author.stake,
+                &proposal.pubkey(),
+                &proposal_transaction,
+                &context.governance,
+            ));
+        }
+        context
+            .process(&setup, &[&author.authority, &proposal])
+            .await?;
+
+        // Each push is sent in its own transaction, to stay within the
+        // transaction size limit.
+        let mut pushes = vec![];
+        for prerequisite in &prerequisites {
+            pushes.push(push_prerequisite(
+                &authority,
+                &proposal.pubkey(),
+                &proposal_transaction,
+                prerequisite,
+            ));
+        }
+        for (option_index, option_instructions) in options.iter().enumerate() {
+            pushes.push(push_option(
+                &authority,
+                &proposal.pubkey(),
+                &proposal_transaction,
+            ));
+            for instruction in option_instructions {
+                pushes.push(push_option_instruction(
+                    &authority,
+                    &proposal.pubkey(),
+                    &proposal_transaction,
+                    option_index as u8,
+                    &instruction.program_id,
+                    account_metas(instruction),
+                    instruction.data.clone(),
+                ));
+            }
+        }
+        for instruction in &instructions {
+            pushes.push(push_instruction(
+                &authority,
+                &proposal.pubkey(),
+                &proposal_transaction,
+                &instruction.program_id,
+                account_metas(instruction),
+                instruction.data.clone(),
+            ));
+        }
+        for push in pushes {
+            context.process(&[push], &[&author.authority]).await?;
+        }
+
+        if begin_voting {
+            context.begin_voting(author, &proposal.pubkey()).await?;
+        }
+
+        Ok(proposal.pubkey())
+    }
+}
+
+fn account_metas(instruction: &Instruction) -> Vec<ProposalAccountMeta> {
+    instruction.accounts.iter().map(Into::into).collect()
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "test-sbf")]
+
+use {
+    paladin_governance_program::{
+        error::PaladinGovernanceError,
+        state::{ProposalStatus, ProposalVoteElection},
+    },
+    paladin_governance_test_utils::GovernanceTestContext,
+    solana_program_test::BanksClientError,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, system_instruction,
+        transaction::TransactionError,
+    },
+};
+
+fn assert_custom_error(result: Result<(), BanksClientError>, error: PaladinGovernanceError) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+    );
+}
+
+#[tokio::test]
+async fn accepted_proposal_transfers_from_treasury() {
+    let mut context = GovernanceTestContext::builder()
+        .total_stake(1_000)
+        .start()
+        .await;
+    let staker = context.create_staker(600).await;
+    context.fund_treasury(1_000_000_000).await;
+
+    let treasury = context.treasury();
+    let recipient = Pubkey::new_unique();
+    let proposal = context
+        .proposal(&staker)
+        .instruction(system_instruction::transfer(
+            &treasury,
+            &recipient,
+            100_000_000,
+        ))
+        .begin_voting()
+        .await
+        .unwrap();
+    context
+        .assert_status(&proposal, ProposalStatus::Voting)
+        .await;
+
+    context
+        .vote(&staker, &proposal, ProposalVoteElection::For)
+        .await
+        .unwrap();
+    context.assert_tally(&proposal, 600, 0).await;
+
+    // The cooldown is still running.
+    assert_custom_error(
+        context.finish_voting(&proposal).await,
+        PaladinGovernanceError::ProposalVotingPeriodStillActive,
+    );
+
+    context.warp_past_cooldown_period(&proposal).await;
+    context.finish_voting(&proposal).await.unwrap();
+    context
+        .assert_status(&proposal, ProposalStatus::Accepted)
+        .await;
+
+    context.process_instructions(&proposal).await.unwrap();
+    context
+        .assert_status(&proposal, ProposalStatus::Processed)
+        .await;
+    let recipient_account = context
+        .context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(recipient_account.lamports, 100_000_000);
+}
+
+#[tokio::test]
+async fn proposal_without_quorum_is_rejected() {
+    let mut context = GovernanceTestContext::builder()
+        .total_stake(1_000)
+        .start()
+        .await;
+    let staker = context.create_staker(50).await;
+
+    let proposal = context
+        .proposal(&staker)
+        .signal()
+        .begin_voting()
+        .await
+        .unwrap();
+    context
+        .vote(&staker, &proposal, ProposalVoteElection::For)
+        .await
+        .unwrap();
+
+    // 5% turnout never reaches the 10% quorum, so no cooldown begins.
+    context.warp_past_cooldown_period(&proposal).await;
+    context.finish_voting(&proposal).await.unwrap();
+    context
+        .assert_status(&proposal, ProposalStatus::Rejected)
+        .await;
+}
+
+#[tokio::test]
+async fn multiple_choice_proposal_with_prerequisite() {
+    let mut context = GovernanceTestContext::builder()
+        .total_stake(1_000)
+        .start()
+        .await;
+    let staker = context.create_staker(400).await;
+    let other_staker = context.create_staker(100).await;
+    context.fund_treasury(1_000_000_000).await;
+
+    let prerequisite = context
+        .proposal(&staker)
+        .signal()
+        .begin_voting()
+        .await
+        .unwrap();
+
+    let treasury = context.treasury();
+    let recipient = Pubkey::new_unique();
+    let proposal = context
+        .proposal(&staker)
+        .option(vec![system_instruction::transfer(
+            &treasury,
+            &recipient,
+            100_000_000,
+        )])
+        .option(vec![system_instruction::transfer(
+            &treasury,
+            &recipient,
+            200_000_000,
+        )])
+        .prerequisite(prerequisite)
+        .begin_voting()
+        .await
+        .unwrap();
+
+    context.vote_option(&staker, &proposal, 1).await.unwrap();
+    context
+        .vote_option(&other_staker, &proposal, 0)
+        .await
+        .unwrap();
+    context.warp_past_cooldown_period(&proposal).await;
+    context.finish_voting(&proposal).await.unwrap();
+    context
+        .assert_status(&proposal, ProposalStatus::Accepted)
+        .await;
+    assert_eq!(context.get_proposal(&proposal).await.winning_option, 1);
+
+    // The prerequisite has yet to be processed.
+    assert_custom_error(
+        context.process_instructions(&proposal).await,
+        PaladinGovernanceError::PrerequisiteNotProcessed,
+    );
+
+    context
+        .vote(&staker, &prerequisite, ProposalVoteElection::For)
+        .await
+        .unwrap();
+    context.warp_past_cooldown_period(&prerequisite).await;
+    context.finish_voting(&prerequisite).await.unwrap();
+    context
+        .assert_status(&prerequisite, ProposalStatus::Processed)
+        .await;
+
+    context.process_instructions(&proposal).await.unwrap();
+    context
+        .assert_status(&proposal, ProposalStatus::Processed)
+        .await;
+    let recipient_account = context
+        .context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(recipient_account.lamports, 200_000_000);
+}